@@ -1,7 +1,9 @@
+use regex::Regex;
+use std::borrow::Cow;
 use std::fmt::Debug;
 use thiserror::Error;
 
-use crate::expr::selective_map::SelectiveMap;
+use crate::expr::matcher::{Matcher, is_regex_start};
 
 #[cfg(test)]
 #[path = "./logical_expressions.tests.rs"]
@@ -25,6 +27,33 @@ pub enum ParserError {
     /// Closing bracket was not expected at the `index` position.
     #[error("unexpected closing bracket at index {0}")]
     UnexpectedClosingBracket(usize),
+
+    /// Closing slash was expected for regex starting at the `index` position.
+    #[error("expected closing slash for regex at index {0}")]
+    ExpectedClosingSlash(usize),
+
+    /// Value at the `index` position is not a valid regex or column predicate.
+    #[error("invalid value at index {0}")]
+    InvalidValue(usize),
+
+    /// Field query at the `index` position cannot be sent to the API server.
+    #[error("unexpected field query at index {0}")]
+    UnexpectedFieldQuery(usize),
+}
+
+impl ParserError {
+    /// Returns the position of the error in the parsed expression.
+    pub fn index(&self) -> usize {
+        match self {
+            ParserError::ExpectedOperator(i)
+            | ParserError::UnexpectedOperator(i)
+            | ParserError::ExpectedClosingBracket(i)
+            | ParserError::UnexpectedClosingBracket(i)
+            | ParserError::ExpectedClosingSlash(i)
+            | ParserError::InvalidValue(i)
+            | ParserError::UnexpectedFieldQuery(i) => *i,
+        }
+    }
 }
 
 /// Validates if the provided logical expression can be parsed.
@@ -39,6 +68,46 @@ pub fn parse(expression: &str) -> Result<Expression, ParserError> {
     Ok(parse_tokens(tokens))
 }
 
+/// Single value of the logical expression together with its position.
+#[derive(Debug, PartialEq)]
+pub struct ExpressionTerm<'a> {
+    pub value: &'a str,
+    pub index: usize,
+    pub is_conjunct: bool,
+}
+
+/// Returns all values of the provided logical expression.\
+/// **Note** that a value is a conjunct only if it is not negated, is not in brackets and the expression has no top level `|`,
+/// so the whole expression can be `true` only if that value matches.
+pub fn terms(expression: &str) -> Result<Vec<ExpressionTerm<'_>>, ParserError> {
+    let tokens = tokenize(expression)?;
+    let mut depth = 0;
+    let mut has_top_level_or = false;
+    let mut result = Vec::new();
+
+    for token in tokens {
+        match token {
+            Token::Open | Token::NotOpen => depth += 1,
+            Token::Close => depth -= 1,
+            Token::Or if depth == 0 => has_top_level_or = true,
+            Token::Value(value, index) | Token::NotValue(value, index) => result.push(ExpressionTerm {
+                value: value.trim(),
+                index: index + value.len() - value.trim_start().len(),
+                is_conjunct: depth == 0 && matches!(token, Token::Value(..)),
+            }),
+            _ => (),
+        }
+    }
+
+    if has_top_level_or {
+        for term in &mut result {
+            term.is_conjunct = false;
+        }
+    }
+
+    Ok(result)
+}
+
 /// Possible operators for logical expression.
 #[derive(Default, Debug, PartialEq)]
 pub enum Operator {
@@ -56,10 +125,12 @@ pub struct Expression {
     op: Operator,
     is_negation: bool,
     value: Option<String>,
+    matcher: Matcher,
 }
 
 impl Expression {
-    /// Creates new [`Expression`] instance that represents an end value.
+    /// Creates new [`Expression`] instance that represents an end value.\
+    /// **Note** that values that are not valid regexes or column predicates are treated as a plain text.
     pub fn new(s: &str, is_negation: bool) -> Self {
         Self {
             lhs: None,
@@ -67,6 +138,7 @@ impl Expression {
             op: Operator::Or,
             is_negation,
             value: Some(s.trim().to_ascii_lowercase()),
+            matcher: Matcher::parse(s).unwrap_or_default(),
         }
     }
 
@@ -132,19 +204,7 @@ pub trait ExpressionExt {
     fn evaluate(&self, expression: &Expression) -> bool;
 }
 
-impl ExpressionExt for Vec<String> {
-    fn evaluate(&self, expression: &Expression) -> bool {
-        evaluate(expression, self)
-    }
-}
-
-impl ExpressionExt for Vec<&String> {
-    fn evaluate(&self, expression: &Expression) -> bool {
-        evaluate(expression, self)
-    }
-}
-
-impl ExpressionExt for SelectiveMap {
+impl<S: EvaluationSource> ExpressionExt for S {
     fn evaluate(&self, expression: &Expression) -> bool {
         evaluate(expression, self)
     }
@@ -155,10 +215,25 @@ pub trait EvaluationSource {
     /// Returns `true` if any item under the given `key` contains `value`.
     fn contains_in_key(&self, key: &str, value: &str) -> bool;
 
+    /// Returns `true` if any item under the given `key` is equal to `value`, respecting case.
+    fn equals_in_key(&self, key: &str, value: &str) -> bool;
+
     /// Returns `true` if any item in the default search scope contains `value`.
     /// - for flat lists, this searches everything.
-    /// - for [`SelectiveMap`], this skips explicit-only keys.
+    /// - for [`SelectiveMap`](crate::expr::SelectiveMap), this skips explicit-only keys.
     fn contains_in_any(&self, value: &str) -> bool;
+
+    /// Returns `true` if any item under the given `key` matches the `regex`.
+    fn is_match_in_key(&self, key: &str, regex: &Regex) -> bool;
+
+    /// Returns `true` if any item in the default search scope matches the `regex`.
+    fn is_match_in_any(&self, regex: &Regex) -> bool;
+
+    /// Returns text of the column with the specified lowercase `name`.\
+    /// **Note** that by default there are no columns, so all column predicates fail.
+    fn column_text(&self, _name: &str) -> Option<Cow<'_, str>> {
+        None
+    }
 }
 
 impl<T: AsRef<str>> EvaluationSource for Vec<T> {
@@ -168,9 +243,23 @@ impl<T: AsRef<str>> EvaluationSource for Vec<T> {
         self.iter().any(|s| s.as_ref().contains(value))
     }
 
+    /// Prefix keys are ignored for flat lists.
+    fn equals_in_key(&self, _key: &str, value: &str) -> bool {
+        self.iter().any(|s| s.as_ref() == value)
+    }
+
     fn contains_in_any(&self, value: &str) -> bool {
         self.iter().any(|s| s.as_ref().contains(value))
     }
+
+    /// Prefix keys are ignored for flat lists.
+    fn is_match_in_key(&self, _key: &str, regex: &Regex) -> bool {
+        self.is_match_in_any(regex)
+    }
+
+    fn is_match_in_any(&self, regex: &Regex) -> bool {
+        self.iter().any(|s| regex.is_match(s.as_ref()))
+    }
 }
 
 /// Possible tokens for tokenized logical expression.
@@ -182,8 +271,8 @@ enum Token<'a> {
     Open,
     NotOpen,
     Close,
-    Value(&'a str),
-    NotValue(&'a str),
+    Value(&'a str, usize),
+    NotValue(&'a str, usize),
 }
 
 /// Parses vector of [`Token`]s and returns them as a [`Expression`].
@@ -220,11 +309,11 @@ fn parse_tokens(tokens: Vec<Token>) -> Expression {
                     current = prev;
                 }
             },
-            Token::Value(s) => {
+            Token::Value(s, _) => {
                 check_current(&mut current);
                 current.push(Expression::new(s, false));
             },
-            Token::NotValue(s) => {
+            Token::NotValue(s, _) => {
                 check_current(&mut current);
                 current.push(Expression::new(s, true));
             },
@@ -253,8 +342,22 @@ fn tokenize(expression: &str) -> Result<Vec<Token<'_>>, ParserError> {
     let mut has_negation = false;
     let mut open_start = 0;
     let mut open_count = 0;
+    let mut regex_start = None;
+    let mut is_escaped = false;
 
     for (index, char) in expression.char_indices() {
+        if regex_start.is_some() {
+            if is_escaped {
+                is_escaped = false;
+            } else if char == '\\' {
+                is_escaped = true;
+            } else if char == '/' {
+                regex_start = None;
+            }
+
+            continue;
+        }
+
         if let Some(token) = match char {
             '&' => Some(Token::And),
             '|' => Some(Token::Or),
@@ -267,6 +370,10 @@ fn tokenize(expression: &str) -> Result<Vec<Token<'_>>, ParserError> {
                     return Err(ParserError::ExpectedOperator(index));
                 }
 
+                if char == '/' && is_regex_start(&expression[token_start..index]) {
+                    regex_start = Some(index);
+                }
+
                 has_value = true;
                 None
             },
@@ -285,7 +392,7 @@ fn tokenize(expression: &str) -> Result<Vec<Token<'_>>, ParserError> {
             }
 
             if has_value && token_start != index {
-                push_value(&mut result, &expression[token_start..index]);
+                push_value(&mut result, &expression[token_start..index], token_start)?;
             }
 
             push_token(&mut result, token);
@@ -297,12 +404,16 @@ fn tokenize(expression: &str) -> Result<Vec<Token<'_>>, ParserError> {
         }
     }
 
+    if let Some(index) = regex_start {
+        return Err(ParserError::ExpectedClosingSlash(index));
+    }
+
     if open_count != 0 {
         return Err(ParserError::ExpectedClosingBracket(open_start));
     }
 
     if has_value {
-        push_value(&mut result, &expression[token_start..]);
+        push_value(&mut result, &expression[token_start..], token_start)?;
     }
 
     Ok(result)
@@ -331,15 +442,21 @@ fn push_token<'a>(tokens: &mut Vec<Token<'a>>, token: Token<'a>) {
     tokens.push(token);
 }
 
-fn push_value<'a>(tokens: &mut Vec<Token<'a>>, value: &'a str) {
+fn push_value<'a>(tokens: &mut Vec<Token<'a>>, value: &'a str, start: usize) -> Result<(), ParserError> {
+    if let Err(position) = Matcher::parse(value) {
+        let leading_spaces = value.len() - value.trim_start().len();
+        return Err(ParserError::InvalidValue(start + leading_spaces + position));
+    }
+
     if let Some(last) = tokens.last_mut()
         && *last == Token::Not
     {
-        *last = Token::NotValue(value);
-        return;
+        *last = Token::NotValue(value, start);
+        return Ok(());
     }
 
-    tokens.push(Token::Value(value));
+    tokens.push(Token::Value(value, start));
+    Ok(())
 }
 
 /// Holds currently processed [`Expression`] together with evaluated values for `lhs` and `rhs`.
@@ -442,11 +559,16 @@ fn evaluate_value<S: EvaluationSource>(expression: &Expression, source: &S) -> b
         return false;
     };
 
-    let contains = if prefix.is_empty() {
-        source.contains_in_any(value)
-    } else {
-        source.contains_in_key(prefix, value)
+    let is_match = match &expression.matcher {
+        Matcher::Text if prefix.is_empty() => source.contains_in_any(value),
+        Matcher::Text => source.contains_in_key(prefix, value),
+        Matcher::Exact(key, value) => source.equals_in_key(key, value),
+        Matcher::Regex(key, regex) if key.is_empty() => source.is_match_in_any(regex),
+        Matcher::Regex(key, regex) => source.is_match_in_key(key, regex),
+        Matcher::Column(predicate) => source
+            .column_text(&predicate.name)
+            .is_some_and(|text| predicate.is_match(&text)),
     };
 
-    if expression.is_negation { !contains } else { contains }
+    if expression.is_negation { !is_match } else { is_match }
 }
@@ -1,3 +1,5 @@
+use crate::expr::SelectiveMap;
+
 use super::*;
 
 #[test]
@@ -124,3 +126,100 @@ fn selective_map_basic_test() {
     assert!(data.evaluate(&parse("c:john").unwrap()));
     assert!(!data.evaluate(&parse("c:tokyo").unwrap()));
 }
+
+#[test]
+fn regex_test() {
+    let data = SelectiveMap::default()
+        .with("n", vec!["api-12".to_string()])
+        .with("l", vec!["app=web".to_string()]);
+
+    assert!(data.evaluate(&parse("n:/^api-\\d+$/").unwrap()));
+    assert!(data.evaluate(&parse("/^API-(1|2)/ & l:app=web").unwrap()));
+    assert!(!data.evaluate(&parse("n:/^web/").unwrap()));
+    assert!(data.evaluate(&parse("!n:/^web|db/").unwrap()));
+    assert!(!data.evaluate(&parse("l:/^api/").unwrap()));
+}
+
+#[test]
+fn exact_label_test() {
+    let mut data = SelectiveMap::default();
+    data.insert("l", vec!["app=web-api".to_string(), "tier=frontend".to_string()]);
+    data.insert_exact("l", vec!["app=web-api".to_string(), "tier=Frontend".to_string()]);
+
+    assert!(data.evaluate(&parse("l:app=web-api").unwrap()));
+    assert!(!data.evaluate(&parse("l:app=web").unwrap()));
+    assert!(data.evaluate(&parse("l:app = web-api").unwrap()));
+    assert!(data.evaluate(&parse("l:tier=Frontend").unwrap()));
+    assert!(!data.evaluate(&parse("l:tier=frontend").unwrap()));
+    assert!(data.evaluate(&parse("l:app & l:tier").unwrap()));
+    assert!(data.evaluate(&parse("!l:app=web & l:/^app=web/").unwrap()));
+}
+
+#[test]
+fn terms_test() {
+    let values = terms("f:x=y & ( n:a | !n:b ) & !c").unwrap();
+    let values = values.iter().map(|t| (t.value, t.index, t.is_conjunct)).collect::<Vec<_>>();
+    assert_eq!(
+        vec![("f:x=y", 0, true), ("n:a", 10, false), ("n:b", 17, false), ("c", 26, false)],
+        values
+    );
+
+    assert!(terms("a & b | c").unwrap().iter().all(|t| !t.is_conjunct));
+}
+
+#[test]
+fn column_test() {
+    struct Columns(Vec<(&'static str, &'static str)>);
+
+    impl EvaluationSource for Columns {
+        fn contains_in_key(&self, _key: &str, _value: &str) -> bool {
+            false
+        }
+
+        fn equals_in_key(&self, _key: &str, _value: &str) -> bool {
+            false
+        }
+
+        fn contains_in_any(&self, _value: &str) -> bool {
+            false
+        }
+
+        fn is_match_in_key(&self, _key: &str, _regex: &Regex) -> bool {
+            false
+        }
+
+        fn is_match_in_any(&self, _regex: &Regex) -> bool {
+            false
+        }
+
+        fn column_text(&self, name: &str) -> Option<Cow<'_, str>> {
+            self.0.iter().find(|(n, _)| *n == name).map(|(_, v)| Cow::Borrowed(*v))
+        }
+    }
+
+    let row = Columns(vec![("status", "Running"), ("restarts", "7"), ("age", "3h20m")]);
+
+    assert!(evaluate(&parse("c:status=running & c:restarts>5").unwrap(), &row));
+    assert!(evaluate(&parse("c:age>=1h | c:restarts<1").unwrap(), &row));
+    assert!(!evaluate(&parse("c:age<10m").unwrap(), &row));
+    assert!(!evaluate(&parse("c:missing=1").unwrap(), &row));
+    assert!(evaluate(&parse("!c:missing=1").unwrap(), &row));
+}
+
+#[test]
+#[should_panic(expected = "ExpectedClosingSlash(6)")]
+fn no_closing_slash_test() {
+    parse("a & n:/(api").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidValue(15)")]
+fn invalid_column_value_test() {
+    parse("a & c:restarts>many").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidValue(8)")]
+fn invalid_regex_test() {
+    parse("a | ( n:/a{2,1}/ )").unwrap();
+}
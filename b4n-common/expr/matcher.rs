@@ -0,0 +1,239 @@
+use regex::{Regex, RegexBuilder};
+use std::cmp::Ordering;
+
+use crate::expr::selectors::{LABEL_PREFIX, split_selector};
+
+#[cfg(test)]
+#[path = "./matcher.tests.rs"]
+mod matcher_tests;
+
+/// Prefix that marks the column predicate, e.g. `c:restarts>5`.
+pub const COLUMN_PREFIX: &str = "c";
+
+/// Possible comparison operators for the column predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    /// Returns `true` if the provided `ordering` satisfies this comparison.
+    fn is_satisfied(self, ordering: Ordering) -> bool {
+        match self {
+            Comparison::Equal => ordering == Ordering::Equal,
+            Comparison::Less => ordering == Ordering::Less,
+            Comparison::LessOrEqual => ordering != Ordering::Greater,
+            Comparison::Greater => ordering == Ordering::Greater,
+            Comparison::GreaterOrEqual => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// Predicate that checks value of the named column, e.g. `c:status=running` or `c:age<10m`.
+#[derive(Debug)]
+pub struct ColumnPredicate {
+    pub name: String,
+    pub comparison: Comparison,
+    value: String,
+    regex: Option<Regex>,
+}
+
+impl ColumnPredicate {
+    /// Returns `true` if the column `text` satisfies the predicate.\
+    /// **Note** that values are compared as numbers, quantities or durations when both sides can be parsed as such,
+    /// otherwise only the `=` comparison is possible and it checks for equality ignoring case.
+    pub fn is_match(&self, text: &str) -> bool {
+        let text = text.trim();
+        if let Some(regex) = &self.regex {
+            return regex.is_match(text);
+        }
+
+        if let Some(ordering) = compare_values(text, &self.value) {
+            self.comparison.is_satisfied(ordering)
+        } else {
+            self.comparison == Comparison::Equal && text.eq_ignore_ascii_case(&self.value)
+        }
+    }
+}
+
+/// Describes how a single expression value is matched against the evaluation source.
+#[derive(Debug, Default)]
+pub enum Matcher {
+    /// Checks if any item under the prefix key (or any item if there is no prefix) contains the text.
+    #[default]
+    Text,
+
+    /// Checks if any item under the key (or any item if the key is empty) matches the regex.
+    Regex(String, Regex),
+
+    /// Checks if any item under the key is exactly the `key=value` text, the same way as the label selector does.
+    Exact(String, String),
+
+    /// Checks the value of the named column.
+    Column(ColumnPredicate),
+}
+
+impl Matcher {
+    /// Parses provided expression value into a [`Matcher`].\
+    /// **Note** that `c:` values without a comparison operator are plain text, `l:key=value` values are exact matches,
+    /// and the returned error is the byte position of the invalid part in the trimmed `value`.
+    pub fn parse(value: &str) -> Result<Self, usize> {
+        let value = value.trim();
+        if value.starts_with('/') {
+            return Ok(Matcher::Regex(String::new(), parse_regex(value).ok_or(0_usize)?));
+        }
+
+        let Some((prefix, rest)) = value.split_once(':') else {
+            return Ok(Matcher::Text);
+        };
+
+        let offset = prefix.len() + 1;
+        if prefix.eq_ignore_ascii_case(COLUMN_PREFIX) && rest.contains(['=', '<', '>']) {
+            parse_column_predicate(rest)
+                .map(Matcher::Column)
+                .map_err(|position| offset + position)
+        } else if rest.starts_with('/') {
+            let regex = parse_regex(rest).ok_or(offset)?;
+            Ok(Matcher::Regex(prefix.to_ascii_lowercase(), regex))
+        } else if prefix.eq_ignore_ascii_case(LABEL_PREFIX)
+            && let Some((key, value)) = split_selector(rest)
+        {
+            Ok(Matcher::Exact(LABEL_PREFIX.to_owned(), format!("{key}={value}")))
+        } else {
+            Ok(Matcher::Text)
+        }
+    }
+}
+
+/// Returns `true` if the value that is being tokenized starts a regex at the current position.\
+/// **Note** that regex can be provided as the whole value, after a prefix or after the column comparison operator.
+pub fn is_regex_start(value_so_far: &str) -> bool {
+    let value = value_so_far.trim_start();
+    match value.split_once(':') {
+        None => value.is_empty(),
+        Some((prefix, rest)) if prefix.eq_ignore_ascii_case(COLUMN_PREFIX) => rest.trim_end().ends_with('='),
+        Some((prefix, rest)) => rest.is_empty() && prefix.chars().all(|c| c.is_ascii_alphanumeric()),
+    }
+}
+
+/// Parses numeric value, Kubernetes quantity (e.g. `500m`, `128Mi`) or percentage to a number.
+pub fn parse_quantity(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(value.len());
+    let (number, suffix) = value.split_at(split);
+    let number = number.parse::<f64>().ok()?;
+    let multiplier = match suffix {
+        "" | "%" => 1.0,
+        "n" => 1e-9,
+        "u" => 1e-6,
+        "m" => 1e-3,
+        "k" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        "P" => 1e15,
+        "E" => 1e18,
+        "Ki" => 1024.0,
+        "Mi" => 1024f64.powi(2),
+        "Gi" => 1024f64.powi(3),
+        "Ti" => 1024f64.powi(4),
+        "Pi" => 1024f64.powi(5),
+        "Ei" => 1024f64.powi(6),
+        _ => return None,
+    };
+
+    Some(number * multiplier)
+}
+
+/// Parses duration in the `1d2h30m10s` format to a number of seconds.
+pub fn parse_duration(value: &str) -> Option<f64> {
+    let mut total = 0.0;
+    let mut number = String::new();
+    let mut has_unit = false;
+
+    for ch in value.trim().chars() {
+        if ch.is_ascii_digit() || ch == '.' {
+            number.push(ch);
+            continue;
+        }
+
+        let multiplier = match ch {
+            's' => 1.0,
+            'm' => 60.0,
+            'h' => 3_600.0,
+            'd' => 86_400.0,
+            'w' => 604_800.0,
+            'y' => 31_536_000.0,
+            _ => return None,
+        };
+
+        total += number.parse::<f64>().ok()? * multiplier;
+        number.clear();
+        has_unit = true;
+    }
+
+    (has_unit && number.is_empty()).then_some(total)
+}
+
+/// Compares two values as quantities or as durations, whichever fits both of them.
+fn compare_values(lhs: &str, rhs: &str) -> Option<Ordering> {
+    let lhs = lhs.split_whitespace().next().unwrap_or_default();
+    if let (Some(lhs), Some(rhs)) = (parse_quantity(lhs), parse_quantity(rhs)) {
+        return lhs.partial_cmp(&rhs);
+    }
+
+    if let (Some(lhs), Some(rhs)) = (parse_duration(lhs), parse_duration(rhs)) {
+        return lhs.partial_cmp(&rhs);
+    }
+
+    None
+}
+
+fn parse_column_predicate(value: &str) -> Result<ColumnPredicate, usize> {
+    let position = value.find(['=', '<', '>']).unwrap_or_default();
+
+    let name = value[..position].trim();
+    if name.is_empty() {
+        return Err(0);
+    }
+
+    let (comparison, len) = match &value[position..] {
+        v if v.starts_with("<=") => (Comparison::LessOrEqual, 2),
+        v if v.starts_with(">=") => (Comparison::GreaterOrEqual, 2),
+        v if v.starts_with('<') => (Comparison::Less, 1),
+        v if v.starts_with('>') => (Comparison::Greater, 1),
+        _ => (Comparison::Equal, 1),
+    };
+
+    let expected = value[position + len..].trim();
+    let expected_position = position + len;
+    let regex = if comparison == Comparison::Equal && expected.starts_with('/') {
+        Some(parse_regex(expected).ok_or(expected_position)?)
+    } else {
+        None
+    };
+
+    if expected.is_empty()
+        || (comparison != Comparison::Equal && parse_quantity(expected).is_none() && parse_duration(expected).is_none())
+    {
+        return Err(expected_position);
+    }
+
+    Ok(ColumnPredicate {
+        name: name.to_ascii_lowercase(),
+        comparison,
+        value: expected.to_owned(),
+        regex,
+    })
+}
+
+fn parse_regex(value: &str) -> Option<Regex> {
+    let pattern = value.strip_prefix('/')?.strip_suffix('/')?;
+    RegexBuilder::new(pattern).case_insensitive(true).build().ok()
+}
@@ -0,0 +1,66 @@
+use super::*;
+
+fn column(value: &str) -> ColumnPredicate {
+    match Matcher::parse(value) {
+        Ok(Matcher::Column(predicate)) => predicate,
+        other => panic!("expected column predicate for {value}, got {other:?}"),
+    }
+}
+
+#[test]
+fn parse_quantity_test() {
+    assert_eq!(Some(5.0), parse_quantity("5"));
+    assert_eq!(Some(0.5), parse_quantity("500m"));
+    assert_eq!(Some(128.0 * 1024.0 * 1024.0), parse_quantity("128Mi"));
+    assert_eq!(Some(45.0), parse_quantity("45%"));
+    assert_eq!(None, parse_quantity("5d"));
+    assert_eq!(None, parse_quantity("running"));
+}
+
+#[test]
+fn parse_duration_test() {
+    assert_eq!(Some(600.0), parse_duration("10m"));
+    assert_eq!(Some(93_784.0), parse_duration("1d2h03m04s"));
+    assert_eq!(None, parse_duration("10"));
+    assert_eq!(None, parse_duration("10m5"));
+    assert_eq!(None, parse_duration("10Mi"));
+}
+
+#[test]
+fn column_predicate_test() {
+    assert!(column("c:status=Running").is_match("Running"));
+    assert!(column("c:status=running").is_match("Running"));
+    assert!(!column("c:status=running").is_match("Pending"));
+    assert!(column("c:status=/^run/").is_match("Running"));
+
+    assert!(column("c:restarts>5").is_match("6"));
+    assert!(!column("c:restarts>5").is_match("5"));
+    assert!(column("c:restarts>=5").is_match("5"));
+    assert!(column("c:restarts > 5").is_match("12 (3m ago)"));
+
+    assert!(column("c:age<10m").is_match("5m03s"));
+    assert!(!column("c:age<10m").is_match("1d02h"));
+    assert!(column("c:cpu>500m").is_match("1"));
+    assert!(column("c:cpu>500m").is_match("750m"));
+    assert!(!column("c:cpu>500m").is_match("250m"));
+    assert!(column("c:memory<=1Gi").is_match("512Mi"));
+
+    assert_eq!("restarts", column("c:Restarts>5").name);
+}
+
+#[test]
+fn parse_errors_test() {
+    assert!(matches!(Matcher::parse("c:restarts"), Ok(Matcher::Text)));
+    assert_eq!(Some(2), Matcher::parse("c:>5").err());
+    assert_eq!(Some(11), Matcher::parse("c:restarts>many").err());
+    assert_eq!(Some(11), Matcher::parse("c:restarts>").err());
+    assert_eq!(Some(2), Matcher::parse("n:/api[/").err());
+    assert_eq!(Some(0), Matcher::parse("/(/").err());
+    assert!(matches!(Matcher::parse("n:/^api-\\d+/"), Ok(Matcher::Regex(key, _)) if key == "n"));
+    assert!(
+        matches!(Matcher::parse("l:app.kubernetes.io/name=web"), Ok(Matcher::Exact(_, value)) if value == "app.kubernetes.io/name=web")
+    );
+    assert!(matches!(Matcher::parse("l:App = Web"), Ok(Matcher::Exact(key, value)) if key == "l" && value == "App=Web"));
+    assert!(matches!(Matcher::parse("l:app=my web"), Ok(Matcher::Text)));
+    assert!(matches!(Matcher::parse("l:app"), Ok(Matcher::Text)));
+}
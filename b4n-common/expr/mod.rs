@@ -1,5 +1,9 @@
 pub use self::logical_expressions::*;
+pub use self::matcher::*;
 pub use self::selective_map::*;
+pub use self::selectors::*;
 
 mod logical_expressions;
+mod matcher;
 mod selective_map;
+mod selectors;
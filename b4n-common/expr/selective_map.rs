@@ -1,3 +1,4 @@
+use regex::Regex;
 use std::collections::{HashMap, HashSet};
 
 use crate::expr::EvaluationSource;
@@ -6,6 +7,7 @@ use crate::expr::EvaluationSource;
 #[derive(Debug, Default, Clone)]
 pub struct SelectiveMap {
     map: HashMap<&'static str, Vec<String>>,
+    exact: HashMap<&'static str, Vec<String>>,
     explicit_only: HashSet<&'static str>,
     optional: HashSet<&'static str>,
 }
@@ -53,6 +55,13 @@ impl SelectiveMap {
         self
     }
 
+    /// Inserts case-sensitive values for a key that are used only by the exact matches.\
+    /// **Note** that if they are absent, exact matches compare values inserted for that key in other ways.
+    pub fn insert_exact(&mut self, key: &'static str, values: Vec<String>) -> &mut Self {
+        self.exact.insert(key, values);
+        self
+    }
+
     /// Marks an existing key as optional.
     pub fn set_optional(&mut self, key: &'static str) -> &mut Self {
         self.optional.insert(key);
@@ -86,20 +95,43 @@ impl SelectiveMap {
     pub fn is_optional(&self, key: &str) -> bool {
         self.optional.contains(key)
     }
-}
 
-impl EvaluationSource for SelectiveMap {
-    fn contains_in_key(&self, key: &str, value: &str) -> bool {
+    fn any_in_key(&self, key: &str, predicate: impl Fn(&str) -> bool) -> bool {
         match self.map.get(key) {
-            Some(items) => items.iter().any(|s| s.contains(value)),
+            Some(items) => items.iter().any(|s| predicate(s)),
             None => self.optional.contains(key), // true if optional, false otherwise
         }
     }
 
-    fn contains_in_any(&self, value: &str) -> bool {
+    fn any_in_any(&self, predicate: impl Fn(&str) -> bool) -> bool {
         self.map
             .iter()
             .filter(|(k, _)| !self.explicit_only.contains(*k))
-            .any(|(_, items)| items.iter().any(|s| s.contains(value)))
+            .any(|(_, items)| items.iter().any(|s| predicate(s)))
+    }
+}
+
+impl EvaluationSource for SelectiveMap {
+    fn contains_in_key(&self, key: &str, value: &str) -> bool {
+        self.any_in_key(key, |s| s.contains(value))
+    }
+
+    fn contains_in_any(&self, value: &str) -> bool {
+        self.any_in_any(|s| s.contains(value))
+    }
+
+    fn equals_in_key(&self, key: &str, value: &str) -> bool {
+        match self.exact.get(key) {
+            Some(items) => items.iter().any(|s| s == value),
+            None => self.any_in_key(key, |s| s == value),
+        }
+    }
+
+    fn is_match_in_key(&self, key: &str, regex: &Regex) -> bool {
+        self.any_in_key(key, |s| regex.is_match(s))
+    }
+
+    fn is_match_in_any(&self, regex: &Regex) -> bool {
+        self.any_in_any(|s| regex.is_match(s))
     }
 }
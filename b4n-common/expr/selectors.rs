@@ -0,0 +1,105 @@
+use std::fmt::Display;
+
+use crate::expr::{ParserError, terms};
+
+#[cfg(test)]
+#[path = "./selectors.tests.rs"]
+mod selectors_tests;
+
+/// Prefix that marks the label query, e.g. `l:app=web`.
+pub const LABEL_PREFIX: &str = "l";
+
+/// Prefix that marks the field query, e.g. `f:spec.nodeName=node-1`.
+pub const FIELD_PREFIX: &str = "f";
+
+/// Label and field selectors that can be pushed down to the Kubernetes API server.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ServerSelectors {
+    pub labels: Option<String>,
+    pub fields: Option<String>,
+}
+
+impl ServerSelectors {
+    /// Builds [`ServerSelectors`] from the `l:key=value` and `f:path=value` terms that are joined with `&` at the top level
+    /// of the filter `expression`. Returns `None` if there are no such terms.\
+    /// **Note** that field queries can be evaluated only by the API server, so any other `f:` term is reported as an error.
+    pub fn parse(expression: &str) -> Result<Option<Self>, ParserError> {
+        Ok(Self::parse_with_fields_index(expression)?.0)
+    }
+
+    /// Validates if all field queries of the filter `expression` can be sent to the API server.\
+    /// **Note** that every `f:` term is reported as an error if `can_push_down` is `false`.
+    pub fn validate(expression: &str, can_push_down: bool) -> Result<(), ParserError> {
+        match Self::parse_with_fields_index(expression)? {
+            (_, Some(index)) if !can_push_down => Err(ParserError::UnexpectedFieldQuery(index)),
+            _ => Ok(()),
+        }
+    }
+
+    fn parse_with_fields_index(expression: &str) -> Result<(Option<Self>, Option<usize>), ParserError> {
+        let mut labels = Vec::new();
+        let mut fields = Vec::new();
+        let mut fields_index = None;
+
+        for term in terms(expression)? {
+            let Some((prefix, rest)) = term.value.split_once(':') else {
+                continue;
+            };
+
+            let is_field = prefix.eq_ignore_ascii_case(FIELD_PREFIX);
+            if !is_field && !prefix.eq_ignore_ascii_case(LABEL_PREFIX) {
+                continue;
+            }
+
+            match split_selector(rest).filter(|_| term.is_conjunct) {
+                Some((key, value)) if is_field => {
+                    fields_index.get_or_insert(term.index);
+                    fields.push(format!("{key}={value}"));
+                },
+                Some((key, value)) => labels.push(format!("{key}={value}")),
+                None if is_field => return Err(ParserError::UnexpectedFieldQuery(term.index)),
+                None => (),
+            }
+        }
+
+        if labels.is_empty() && fields.is_empty() {
+            return Ok((None, None));
+        }
+
+        let selectors = Self {
+            labels: join_selectors(&labels),
+            fields: join_selectors(&fields),
+        };
+
+        Ok((Some(selectors), fields_index))
+    }
+}
+
+impl Display for ServerSelectors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let selectors = [self.labels.as_deref(), self.fields.as_deref()];
+        write!(f, "{}", selectors.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Splits the `key=value` part of the label or field query.\
+/// **Note** that it returns `None` if that part cannot be used as a selector.
+pub(crate) fn split_selector(value: &str) -> Option<(&str, &str)> {
+    let (key, value) = value.split_once('=')?;
+    let (key, value) = (key.trim(), value.trim());
+    let is_invalid =
+        |text: &str, special: &str| text.is_empty() || text.contains(|c: char| c.is_whitespace() || special.contains(c));
+    if is_invalid(key, "=!,()") || is_invalid(value, "=!,()/") {
+        None
+    } else {
+        Some((key, value))
+    }
+}
+
+fn join_selectors(selectors: &[String]) -> Option<String> {
+    if selectors.is_empty() {
+        None
+    } else {
+        Some(selectors.join(","))
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+#[test]
+fn parse_labels_and_fields_test() {
+    let selectors = ServerSelectors::parse("l:app=web & L:tier=Frontend & f:spec.nodeName=node-1")
+        .unwrap()
+        .unwrap();
+    assert_eq!(Some("app=web,tier=Frontend"), selectors.labels.as_deref());
+    assert_eq!(Some("spec.nodeName=node-1"), selectors.fields.as_deref());
+    assert_eq!("app=web,tier=Frontend,spec.nodeName=node-1", selectors.to_string());
+
+    let selectors = ServerSelectors::parse(" l:app = web ").unwrap().unwrap();
+    assert_eq!(Some("app=web"), selectors.labels.as_deref());
+    assert_eq!(None, selectors.fields);
+}
+
+#[test]
+fn parse_mixed_expression_test() {
+    let selectors = ServerSelectors::parse("f:status.phase=Running & nginx").unwrap().unwrap();
+    assert_eq!(None, selectors.labels);
+    assert_eq!(Some("status.phase=Running"), selectors.fields.as_deref());
+
+    let selectors = ServerSelectors::parse("(n:api | n:web) & l:app=web & !l:tier=db")
+        .unwrap()
+        .unwrap();
+    assert_eq!(Some("app=web"), selectors.labels.as_deref());
+    assert_eq!(None, selectors.fields);
+}
+
+#[test]
+fn parse_not_pushable_test() {
+    assert_eq!(None, ServerSelectors::parse("").unwrap());
+    assert_eq!(None, ServerSelectors::parse("web").unwrap());
+    assert_eq!(None, ServerSelectors::parse("l:app").unwrap());
+    assert_eq!(None, ServerSelectors::parse("l:app=").unwrap());
+    assert_eq!(None, ServerSelectors::parse("l:app=web | l:app=api").unwrap());
+    assert_eq!(None, ServerSelectors::parse("!l:app=web").unwrap());
+    assert_eq!(None, ServerSelectors::parse("!(l:app=web)").unwrap());
+    assert_eq!(None, ServerSelectors::parse("l:app=/web.*/").unwrap());
+    assert_eq!(None, ServerSelectors::parse("a:app=web").unwrap());
+    assert_eq!(None, ServerSelectors::parse("c:status=running").unwrap());
+}
+
+#[test]
+fn parse_not_pushable_fields_test() {
+    assert!(matches!(
+        ServerSelectors::parse("!f:status.phase=Running"),
+        Err(ParserError::UnexpectedFieldQuery(1))
+    ));
+    assert!(matches!(
+        ServerSelectors::parse("f:x=y | n:foo"),
+        Err(ParserError::UnexpectedFieldQuery(0))
+    ));
+    assert!(matches!(
+        ServerSelectors::parse("nginx & (f:x=y)"),
+        Err(ParserError::UnexpectedFieldQuery(9))
+    ));
+    assert!(matches!(
+        ServerSelectors::parse("nginx &  f:spec.nodeName"),
+        Err(ParserError::UnexpectedFieldQuery(9))
+    ));
+}
+
+#[test]
+fn validate_fields_test() {
+    assert!(ServerSelectors::validate("f:x=y & nginx", true).is_ok());
+    assert!(ServerSelectors::validate("l:app=web & nginx", false).is_ok());
+    assert!(matches!(
+        ServerSelectors::validate("l:app=web & f:x=y", false),
+        Err(ParserError::UnexpectedFieldQuery(12))
+    ));
+    assert!(matches!(
+        ServerSelectors::validate("a & (f:x=y", true),
+        Err(ParserError::ExpectedClosingBracket(4))
+    ));
+}
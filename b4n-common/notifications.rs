@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
 
 use crate::sanitize_and_split;
 
@@ -104,6 +104,15 @@ impl NotificationSink {
         }
     }
 
+    /// Creates new [`NotificationSink`] instance that silently discards all notifications.
+    pub fn muted() -> Self {
+        Self {
+            messages: unbounded_channel().0,
+            icons: unbounded_channel().0,
+            trail: unbounded_channel().0,
+        }
+    }
+
     /// Displays an informational message for the specified duration (in milliseconds).
     pub fn show_info<'a>(&self, text: impl Into<Cow<'a, str>>, duration: u16) {
         let _ = self
@@ -159,6 +159,49 @@ pub fn sanitize_and_split(input: &str) -> Vec<String> {
     lines
 }
 
+/// Returns score of the fuzzy match of a `pattern` in the `text` or `None` if not all pattern characters were found.\
+/// **Note** that consecutive characters, word starts and an exact substring are ranked higher.
+pub fn fuzzy_score(pattern: &str, text: &str) -> Option<usize> {
+    let mut pattern_chars = pattern.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut score = 0;
+    let mut previous: Option<char> = None;
+    let mut is_previous_matched = false;
+
+    for ch in text.chars() {
+        let Some(expected) = pattern_chars.peek() else {
+            break;
+        };
+
+        if ch.to_lowercase().eq(expected.to_lowercase()) {
+            score += 1;
+            if is_previous_matched {
+                score += 5;
+            }
+
+            if previous.is_none_or(|p| matches!(p, '-' | '_' | '.' | '/' | ':' | ' ')) {
+                score += 8;
+            }
+
+            is_previous_matched = true;
+            pattern_chars.next();
+        } else {
+            is_previous_matched = false;
+        }
+
+        previous = Some(ch);
+    }
+
+    if pattern_chars.peek().is_some() {
+        return None;
+    }
+
+    if !pattern.is_empty() && text.to_lowercase().contains(&pattern.to_lowercase()) {
+        score += 20;
+    }
+
+    Some(score)
+}
+
 /// Creates random `Uuid` as `String`.
 pub fn random_uuid() -> String {
     Uuid::new_v4()
@@ -58,3 +58,20 @@ fn sanitize_and_split_test() {
     assert_eq!(vec!["test1", "test2"], sanitize_and_split("test1\ntest2"));
     assert_eq!(vec!["test1", "test2"], sanitize_and_split("test1\r\ntest2"));
 }
+
+#[test]
+fn fuzzy_score_test() {
+    assert_eq!(Some(0), fuzzy_score("", "billing"));
+    assert_eq!(None, fuzzy_score("xyz", "billing"));
+    assert_eq!(None, fuzzy_score("billingx", "billing"));
+    assert!(fuzzy_score("sbill", "something-billing").is_some());
+    assert!(fuzzy_score("BILL", "something-billing").is_some());
+
+    let exact = fuzzy_score("billing", "something-billing").unwrap();
+    let scattered = fuzzy_score("billing", "big-lazy-list-of-things").unwrap_or_default();
+    assert!(exact > scattered);
+
+    let word_start = fuzzy_score("bi", "something-billing").unwrap();
+    let middle = fuzzy_score("bi", "cabinet").unwrap();
+    assert!(word_start > middle);
+}
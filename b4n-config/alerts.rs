@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[path = "./alerts.tests.rs"]
+mod alerts_tests;
+
+/// Condition that raises an alert for the observed resource.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AlertCondition {
+    /// Resource status or any of its containers state reason is equal to the value.
+    Status(String),
+
+    /// Restarts count of the resource containers increased.
+    RestartsIncreased,
+
+    /// Resource `Ready` condition is not `True` anymore.
+    NotReady,
+
+    /// Warning event appeared, to be used with the `events` kind.
+    WarningEvent,
+}
+
+/// Alert rule that is evaluated continuously on the observed resources.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    pub condition: AlertCondition,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub bell: bool,
+}
+
+impl AlertRule {
+    /// Returns name of the rule or its kind if the name is not set.
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.kind)
+    }
+}
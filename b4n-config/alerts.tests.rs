@@ -0,0 +1,57 @@
+use crate::Config;
+
+use super::*;
+
+#[test]
+fn deserialize_alert_rules_test() {
+    let yaml = "alerts:
+- name: crashing
+  kind: pods
+  namespace: payments
+  filter: c:restarts>0
+  condition:
+    status: CrashLoopBackOff
+  bell: true
+- kind: nodes
+  condition: not-ready
+- kind: pods
+  condition: restarts-increased
+- kind: events
+  condition: warning-event
+";
+    let config: Config = serde_saphyr::from_str(yaml).unwrap();
+
+    assert_eq!(config.alerts.len(), 4);
+    assert_eq!(config.alerts[0].name(), "crashing");
+    assert_eq!(config.alerts[0].namespace.as_deref(), Some("payments"));
+    assert_eq!(config.alerts[0].filter.as_deref(), Some("c:restarts>0"));
+    assert_eq!(
+        config.alerts[0].condition,
+        AlertCondition::Status("CrashLoopBackOff".to_owned())
+    );
+    assert!(config.alerts[0].bell);
+    assert_eq!(config.alerts[1].name(), "nodes");
+    assert_eq!(config.alerts[1].condition, AlertCondition::NotReady);
+    assert!(!config.alerts[1].bell);
+    assert_eq!(config.alerts[2].condition, AlertCondition::RestartsIncreased);
+    assert_eq!(config.alerts[3].condition, AlertCondition::WarningEvent);
+}
+
+#[test]
+fn serialize_alert_rules_test() {
+    let mut config = Config::default();
+    config.alerts.push(AlertRule {
+        name: None,
+        kind: "pods".to_owned(),
+        namespace: None,
+        filter: None,
+        condition: AlertCondition::Status("OOMKilled".to_owned()),
+        bell: true,
+    });
+
+    let serialized = serde_saphyr::to_string(&config).unwrap();
+    let deserialized: Config = serde_saphyr::from_str(&serialized).unwrap();
+
+    assert_eq!(config.alerts, deserialized.alerts);
+    assert!(!serde_saphyr::to_string(&Config::default()).unwrap().contains("alerts"));
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::runtime::Handle;
+
+use crate::{Config, ConfigError, ConfigWatcher, Persistable};
+
+#[cfg(test)]
+#[path = "./bookmarks.tests.rs"]
+mod bookmarks_tests;
+
+/// Named view of the cluster: context, namespace(s), kind and optional filter, sort and highlighted resource.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct Bookmark {
+    pub name: String,
+    pub context: String,
+    #[serde(default)]
+    pub namespace: String,
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_column: Option<usize>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub sort_descending: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub highlighted: Option<BookmarkItem>,
+}
+
+impl Bookmark {
+    /// Returns short description of the bookmarked view.
+    pub fn description(&self) -> String {
+        let namespace = if self.namespace.is_empty() { "all" } else { &self.namespace };
+        match &self.filter {
+            Some(filter) => format!("{} in {} [{}] @ {}", self.kind, namespace, filter, self.context),
+            None => format!("{} in {} @ {}", self.kind, namespace, self.context),
+        }
+    }
+}
+
+/// Resource highlighted when a bookmark is opened.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct BookmarkItem {
+    pub name: String,
+    #[serde(default)]
+    pub group: String,
+}
+
+/// Application bookmarks.
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct Bookmarks {
+    #[serde(default)]
+    pub bookmarks: Vec<Bookmark>,
+}
+
+impl Bookmarks {
+    /// Returns watcher for bookmarks.
+    pub fn watcher(runtime: Handle) -> ConfigWatcher<Bookmarks> {
+        ConfigWatcher::new(runtime, Bookmarks::default_path())
+    }
+
+    /// Loads bookmarks from a file or creates default one if the file does not exist.\
+    /// **Note** that the file is not overwritten if it cannot be deserialized.
+    pub async fn load_or_create() -> (Self, Option<ConfigError>) {
+        let path = Bookmarks::default_path();
+        match Self::load(&path).await {
+            Ok(bookmarks) => (bookmarks, None),
+            Err(ConfigError::DeserializationError(error)) => {
+                tracing::error!("Cannot deserialize bookmarks: {}", error);
+                (Self::default(), Some(ConfigError::DeserializationError(error)))
+            },
+            Err(_) => {
+                let bookmarks = Self::default();
+                if let Err(error) = bookmarks.save(&path).await {
+                    tracing::error!("Cannot save bookmarks: {}", error);
+                }
+                (bookmarks, None)
+            },
+        }
+    }
+
+    /// Returns bookmark at the specified position in the bookmarks file.
+    pub fn get_at(&self, index: usize) -> Option<&Bookmark> {
+        self.bookmarks.get(index)
+    }
+
+    /// Returns bookmark with the specified `name`.
+    pub fn get(&self, name: &str) -> Option<&Bookmark> {
+        self.bookmarks.iter().find(|b| b.name == name)
+    }
+
+    /// Adds a new bookmark or replaces the existing one with the same name.
+    pub fn put(&mut self, bookmark: Bookmark) {
+        if let Some(existing) = self.bookmarks.iter_mut().find(|b| b.name == bookmark.name) {
+            *existing = bookmark;
+        } else {
+            self.bookmarks.push(bookmark);
+        }
+    }
+
+    /// Removes bookmark with the specified `name`.
+    pub fn remove(&mut self, name: &str) -> Option<Bookmark> {
+        let index = self.bookmarks.iter().position(|b| b.name == name)?;
+        Some(self.bookmarks.remove(index))
+    }
+}
+
+impl Persistable<Bookmarks> for Bookmarks {
+    /// Returns the default bookmarks file path.
+    fn default_path() -> PathBuf {
+        Config::data_dir().join("bookmarks.yaml")
+    }
+
+    async fn load(path: &Path) -> Result<Bookmarks, ConfigError> {
+        let mut file = File::open(path).await?;
+
+        let mut bookmarks_str = String::new();
+        file.read_to_string(&mut bookmarks_str).await?;
+
+        Ok(serde_saphyr::from_str::<Bookmarks>(&bookmarks_str)?)
+    }
+
+    async fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        let bookmarks_str = serde_saphyr::to_string(self)?;
+
+        let mut file = File::create(path).await?;
+        file.write_all(bookmarks_str.as_bytes()).await?;
+        file.flush().await?;
+
+        Ok(())
+    }
+}
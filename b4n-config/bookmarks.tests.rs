@@ -0,0 +1,60 @@
+use super::*;
+
+fn bookmark(name: &str, context: &str) -> Bookmark {
+    Bookmark {
+        name: name.to_owned(),
+        context: context.to_owned(),
+        namespace: "payments".to_owned(),
+        kind: "pods".to_owned(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn put_replaces_existing_bookmark_test() {
+    let mut bookmarks = Bookmarks::default();
+    bookmarks.put(bookmark("payments-prod", "prod"));
+    bookmarks.put(bookmark("payments-dev", "dev"));
+    bookmarks.put(bookmark("payments-prod", "prod-eu"));
+
+    assert_eq!(bookmarks.bookmarks.len(), 2);
+    assert_eq!(bookmarks.get("payments-prod").unwrap().context, "prod-eu");
+    assert_eq!(bookmarks.get_at(1).unwrap().name, "payments-dev");
+
+    assert!(bookmarks.remove("payments-prod").is_some());
+    assert!(bookmarks.remove("payments-prod").is_none());
+    assert_eq!(bookmarks.get_at(0).unwrap().name, "payments-dev");
+}
+
+#[test]
+fn serialize_bookmarks_test() {
+    let mut full = bookmark("payments-errors", "prod");
+    full.filter = Some("crash".to_owned());
+    full.sort_column = Some(3);
+    full.sort_descending = true;
+    full.highlighted = Some(BookmarkItem {
+        name: "api-7f9".to_owned(),
+        group: "payments".to_owned(),
+    });
+
+    let mut bookmarks = Bookmarks::default();
+    bookmarks.put(full);
+    bookmarks.put(bookmark("payments-prod", "prod"));
+
+    let serialized = serde_saphyr::to_string(&bookmarks).unwrap();
+    let deserialized: Bookmarks = serde_saphyr::from_str(&serialized).unwrap();
+
+    assert_eq!(bookmarks.bookmarks, deserialized.bookmarks);
+}
+
+#[test]
+fn deserialize_minimal_bookmark_test() {
+    let yaml = "bookmarks:\n- name: prod\n  context: prod\n  kind: pods\n";
+    let bookmarks: Bookmarks = serde_saphyr::from_str(yaml).unwrap();
+    let bookmark = bookmarks.get("prod").unwrap();
+
+    assert_eq!(bookmark.namespace, "");
+    assert_eq!(bookmark.filter, None);
+    assert!(!bookmark.sort_descending);
+    assert_eq!(bookmark.description(), "pods in all @ prod");
+}
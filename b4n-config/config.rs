@@ -9,8 +9,8 @@ use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::runtime::Handle;
 
-use crate::History;
 use crate::themes::{TextColors, Theme};
+use crate::{AlertRule, History};
 use crate::{ConfigWatcher, Persistable, keys::KeyBindings, utils::sorted_map};
 
 pub const APP_NAME: &str = "b4n";
@@ -72,6 +72,18 @@ impl Default for Terminal {
     }
 }
 
+/// TLS certificates configuration.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Certificates {
+    pub expiry_window: Option<i64>,
+}
+
+impl Default for Certificates {
+    fn default() -> Self {
+        Self { expiry_window: Some(30) }
+    }
+}
+
 /// Application configuration.
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Config {
@@ -87,6 +99,9 @@ pub struct Config {
     #[serde(default = "default_theme_name")]
     pub theme: String,
 
+    #[serde(default)]
+    pub certificates: Certificates,
+
     #[serde(default = "default_images_list")]
     pub debug_images: Vec<String>,
 
@@ -98,6 +113,9 @@ pub struct Config {
     pub aliases: HashMap<String, String>,
 
     pub key_bindings: Option<KeyBindings>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alerts: Vec<AlertRule>,
 }
 
 fn default_mouse() -> bool {
@@ -140,10 +158,12 @@ impl Default for Config {
             mouse: default_mouse(),
             terminal: Terminal::default(),
             theme: default_theme_name(),
+            certificates: Certificates::default(),
             debug_images: default_images_list(),
             contexts: None,
             key_bindings: Some(KeyBindings::default()),
             aliases: default_aliases(),
+            alerts: Vec::new(),
         }
     }
 }
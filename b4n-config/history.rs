@@ -21,6 +21,8 @@ pub struct ContextInfo {
     pub filter_history: Vec<HistoryItem>,
     pub search_history: Vec<HistoryItem>,
     pub namespace_history: Vec<HistoryItem>,
+    #[serde(default)]
+    pub query_history: Vec<HistoryItem>,
 }
 
 impl ContextInfo {
@@ -135,6 +137,13 @@ impl History {
         self.current_config().and_then(|c| c.current_context.as_deref())
     }
 
+    /// Returns names of all contexts remembered for the current kube config.
+    pub fn context_names(&self) -> Vec<String> {
+        self.current_config()
+            .map(|c| c.contexts.iter().map(|c| c.name.clone()).collect())
+            .unwrap_or_default()
+    }
+
     /// Creates or updates (if exists) context data.
     pub fn create_or_update_context(&mut self, context: String, kind: Option<String>, namespace: Option<String>) {
         if let Some(config) = self.current_config_mut() {
@@ -198,6 +207,21 @@ impl History {
         self.remove_history_item_from(context, item, |c| &mut c.namespace_history)
     }
 
+    /// Gets `query_history` from the specified `context` of the current kube config.
+    pub fn query_history(&self, context: &str) -> &[HistoryItem] {
+        self.get_history(context, |c| &c.query_history)
+    }
+
+    /// Puts item to `query_history` in the specified `context` of the current kube config.
+    pub fn put_query_history_item(&mut self, context: &str, item: HistoryItem, max_list_size: usize) {
+        self.put_history_item_to(context, item, max_list_size, |c| &mut c.query_history);
+    }
+
+    /// Removes an item from `query_history` in the specified `context` of the current kube config.
+    pub fn remove_query_history_item(&mut self, context: &str, item: &str) -> Option<HistoryItem> {
+        self.remove_history_item_from(context, item, |c| &mut c.query_history)
+    }
+
     fn config_key(&self) -> &str {
         match &self.current_hash {
             Some(hash) => hash,
@@ -26,7 +26,18 @@ define_key_commands! {
     /// Defines what part of the UI the command targets.
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub enum KeyCommand {
+        AlertJump => "alert.jump" @ "Alt+J",
         ApplicationExit => "app.exit" @ "Ctrl+C",
+        BookmarkAdd => "bookmark.add" @ "Alt+B",
+        Bookmark1 => "bookmark.1" @ "F1",
+        Bookmark2 => "bookmark.2" @ "F2",
+        Bookmark3 => "bookmark.3" @ "F3",
+        Bookmark4 => "bookmark.4" @ "F4",
+        Bookmark5 => "bookmark.5" @ "F5",
+        Bookmark6 => "bookmark.6" @ "F6",
+        Bookmark7 => "bookmark.7" @ "F7",
+        Bookmark8 => "bookmark.8" @ "F8",
+        Bookmark9 => "bookmark.9" @ "F9",
         CommandPaletteOpen => "command-palette.open" @ ":", ">", "Shift+:", "Shift+>",
         CommandPaletteReset => "command-palette.close" @ "Esc",
         ContainerAttach => "container.attach" @ "A",
@@ -43,10 +54,13 @@ define_key_commands! {
         EditRedo => "edit.redo" @ "Ctrl+Y",
         EditSelectAll => "edit.select-all" @ "Ctrl+A",
         EditUndo => "edit.undo" @ "Ctrl+Z",
+        EventStreamOpen => "event-stream.open" @ "Alt+E",
         EventsShow => "events.show" @ "E",
+        ExecSelected => "exec.selected" @ "Ctrl+E",
         FilterOpen => "filter.open" @ "/", "Shift+/",
         FilterPin => "filter.pin" @ "Ctrl+P",
         FilterReset => "filter.reset" @ "Esc",
+        GlobalSearchOpen => "global-search.open" @ "Ctrl+G",
         HistoryOpen => "history.open" @ "H",
         InvolvedObjectShow => "involved-object.show" @ "I",
         LogsOpen => "logs.open" @ "L",
@@ -58,23 +72,35 @@ define_key_commands! {
         NavigateBack => "navigate.back" @ "Esc",
         NavigateComplete => "navigate.complete" @ "Tab",
         NavigateDelete => "navigate.delete" @ "Ctrl+D",
+        NavigateHistoryBack => "navigate.history-back" @ "Alt+Left",
+        NavigateHistoryForward => "navigate.history-forward" @ "Alt+Right",
         NavigateInto => "navigate.into" @ "Enter",
         NavigateInvertSelection => "navigate.invert-selection" @ "Ctrl+Space",
         NavigateNext => "navigate.next" @ "Tab",
         NavigateSelect => "navigate.select" @ "Space",
         NavigateSelectAll => "navigate.select-all" @ "Ctrl+A",
+        PodCopy => "pod.copy" @ "Ctrl+K",
         PortForwardsCreate => "port-forwards.create" @ "F",
         PortForwardsOpen => "port-forwards.open" @ "Ctrl+F",
         PortForwardsCleanup => "port-forwards.cleanup" @ "Ctrl+R",
+        PreviewGrow => "preview.grow" @ "Alt+=",
+        PreviewMode => "preview.mode" @ "Ctrl+O",
+        PreviewShrink => "preview.shrink" @ "Alt+-",
+        PreviewToggle => "preview.toggle" @ "Ctrl+W",
         PreviousLogsOpen => "previous-logs.open" @ "P",
         SearchOpen => "search.open" @ "/", "Shift+/",
         SearchReset => "search.reset" @ "Esc",
         SelectorLeft => "selector.left" @ "Left",
         SelectorRight => "selector.right" @ "Right",
+        SessionBackground => "session.background" @ "B",
+        SessionsOpen => "sessions.open" @ "Ctrl+B",
         ShellEscape => "shell.escape" @ "Esc",
         ShellOpen => "shell.open" @ "S",
+        TransferCancelAll => "transfer.cancel-all" @ "Alt+T",
         TransferFrom => "transfer.from" @ "Ctrl+T",
         TransferTo => "transfer.to" @ "T",
+        WarningsToggle => "warnings.toggle" @ "W",
+        XrayOpen => "xray.open" @ "R",
         YamlCreate => "yaml.create" @ "N",
         YamlDecode => "yaml.decode" @ "X",
         YamlEdit => "yaml.edit" @ "I",
@@ -82,6 +108,21 @@ define_key_commands! {
     }
 }
 
+impl KeyCommand {
+    /// Commands that open bookmarks, in the same order as bookmarks in the bookmarks file.
+    pub const BOOKMARKS: [KeyCommand; 9] = [
+        KeyCommand::Bookmark1,
+        KeyCommand::Bookmark2,
+        KeyCommand::Bookmark3,
+        KeyCommand::Bookmark4,
+        KeyCommand::Bookmark5,
+        KeyCommand::Bookmark6,
+        KeyCommand::Bookmark7,
+        KeyCommand::Bookmark8,
+        KeyCommand::Bookmark9,
+    ];
+}
+
 impl Serialize for KeyCommand {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.serialize_str(&self.to_string())
@@ -1,3 +1,5 @@
+pub use self::alerts::{AlertCondition, AlertRule};
+pub use self::bookmarks::{Bookmark, BookmarkItem, Bookmarks};
 pub use self::config::{APP_NAME, APP_VERSION, Config, ConfigError, DEFAULT_THEME_NAME};
 pub use self::history::{History, HistoryItem};
 pub use self::plugins::{
@@ -9,6 +11,8 @@ pub use self::watcher::{ConfigWatcher, Persistable};
 pub mod keys;
 pub mod themes;
 
+mod alerts;
+mod bookmarks;
 mod config;
 mod history;
 mod plugins;
@@ -0,0 +1,207 @@
+use base64::{DecodeError, Engine, engine};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, Time};
+use k8s_openapi::jiff::Timestamp;
+use k8s_openapi::serde_json::json;
+use kube::api::DynamicObject;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use x509_parser::extensions::GeneralName;
+use x509_parser::pem::Pem;
+use x509_parser::public_key::PublicKey;
+use x509_parser::x509::SubjectPublicKeyInfo;
+
+#[cfg(test)]
+#[path = "./certificate.tests.rs"]
+mod certificate_tests;
+
+pub const TLS_CERTIFICATE: &str = "TLSCertificate";
+pub const TLS_SECRET_TYPE: &str = "kubernetes.io/tls";
+pub const TLS_CERT_KEY: &str = "tls.crt";
+
+const SECONDS_IN_DAY: i64 = 86_400;
+
+/// Possible errors from reading TLS certificates.
+#[derive(thiserror::Error, Debug)]
+pub enum CertificateError {
+    /// Secret does not contain certificate data.
+    #[error("secret has no '{TLS_CERT_KEY}' data")]
+    MissingData,
+
+    /// Cannot decode base64 certificate data.
+    #[error("cannot decode certificate data")]
+    DecodeError(#[from] DecodeError),
+
+    /// Certificate data is not a valid PEM.
+    #[error("invalid PEM data")]
+    InvalidPem,
+
+    /// Certificate cannot be parsed.
+    #[error("invalid certificate: {0}")]
+    InvalidCertificate(String),
+
+    /// There are no certificates in the data.
+    #[error("no certificates found")]
+    NoCertificates,
+}
+
+/// Details of the single X.509 certificate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Certificate {
+    pub subject: String,
+    pub common_name: Option<String>,
+    pub issuer: String,
+    pub sans: Vec<String>,
+    pub not_before: Timestamp,
+    pub not_after: Timestamp,
+    pub key_type: String,
+    pub serial: String,
+    pub is_ca: bool,
+}
+
+impl Certificate {
+    /// Reads all certificates (the whole chain) from the `tls.crt` data of the secret.
+    pub fn from_secret(secret: &DynamicObject) -> Result<Vec<Self>, CertificateError> {
+        let data = secret.data["data"][TLS_CERT_KEY]
+            .as_str()
+            .ok_or(CertificateError::MissingData)?;
+        let data = engine::general_purpose::STANDARD.decode(data)?;
+        Self::from_pem(&data)
+    }
+
+    /// Parses all certificates from the PEM encoded `data`.
+    pub fn from_pem(data: &[u8]) -> Result<Vec<Self>, CertificateError> {
+        let mut result = Vec::new();
+        for pem in Pem::iter_from_buffer(data) {
+            let pem = pem.map_err(|_| CertificateError::InvalidPem)?;
+            if pem.label != "CERTIFICATE" {
+                continue;
+            }
+
+            let cert = pem
+                .parse_x509()
+                .map_err(|err| CertificateError::InvalidCertificate(err.to_string()))?;
+            let validity = cert.validity();
+            result.push(Self {
+                subject: cert.subject().to_string(),
+                common_name: cert
+                    .subject()
+                    .iter_common_name()
+                    .next()
+                    .and_then(|cn| cn.as_str().ok())
+                    .map(String::from),
+                issuer: cert.issuer().to_string(),
+                sans: get_sans(
+                    cert.subject_alternative_name()
+                        .ok()
+                        .flatten()
+                        .map(|san| &san.value.general_names),
+                ),
+                not_before: to_timestamp(validity.not_before.timestamp()),
+                not_after: to_timestamp(validity.not_after.timestamp()),
+                key_type: get_key_type(cert.public_key()),
+                serial: cert.raw_serial_as_string(),
+                is_ca: cert.is_ca(),
+            });
+        }
+
+        if result.is_empty() {
+            Err(CertificateError::NoCertificates)
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Returns number of full days left to the certificate expiry.\
+    /// **Note** that it is negative if the certificate has already expired.
+    pub fn days_left(&self, now: Timestamp) -> i64 {
+        days_until(self.not_after, now)
+    }
+
+    /// Returns [`DynamicObject`] that represents certificate stored in the `secret` on the resources list.
+    pub fn to_object(&self, secret: &DynamicObject) -> DynamicObject {
+        DynamicObject {
+            types: None,
+            metadata: ObjectMeta {
+                name: secret.metadata.name.clone(),
+                namespace: secret.metadata.namespace.clone(),
+                uid: secret.metadata.uid.clone(),
+                labels: secret.metadata.labels.clone(),
+                creation_timestamp: Some(Time(self.not_before)),
+                ..Default::default()
+            },
+            data: json!({
+                "subject": self.common_name.as_deref().unwrap_or(&self.subject),
+                "issuer": self.issuer,
+                "keyType": self.key_type,
+                "notAfter": self.not_after.to_string(),
+                "involvedObject": {
+                    "kind": "Secret",
+                    "apiVersion": "v1",
+                    "namespace": secret.metadata.namespace,
+                    "name": secret.metadata.name,
+                },
+            }),
+        }
+    }
+}
+
+/// Returns number of full days from `now` to the `time`.
+pub fn days_until(time: Timestamp, now: Timestamp) -> i64 {
+    (time.as_second() - now.as_second()).div_euclid(SECONDS_IN_DAY)
+}
+
+/// Returns the certificate that expires first.
+pub fn first_to_expire(certificates: &[Certificate]) -> Option<&Certificate> {
+    certificates.iter().min_by_key(|c| c.not_after)
+}
+
+fn to_timestamp(seconds: i64) -> Timestamp {
+    Timestamp::from_second(seconds).unwrap_or_default()
+}
+
+fn get_sans(names: Option<&Vec<GeneralName<'_>>>) -> Vec<String> {
+    let Some(names) = names else {
+        return Vec::new();
+    };
+
+    names
+        .iter()
+        .filter_map(|name| match name {
+            GeneralName::DNSName(dns) => Some(format!("DNS:{dns}")),
+            GeneralName::IPAddress(ip) => get_ip_address(ip).map(|ip| format!("IP:{ip}")),
+            GeneralName::RFC822Name(email) => Some(format!("email:{email}")),
+            GeneralName::URI(uri) => Some(format!("URI:{uri}")),
+            _ => None,
+        })
+        .collect()
+}
+
+fn get_ip_address(bytes: &[u8]) -> Option<IpAddr> {
+    if let Ok(octets) = <[u8; 4]>::try_from(bytes) {
+        Some(IpAddr::V4(Ipv4Addr::from(octets)))
+    } else if let Ok(octets) = <[u8; 16]>::try_from(bytes) {
+        Some(IpAddr::V6(Ipv6Addr::from(octets)))
+    } else {
+        None
+    }
+}
+
+fn get_key_type(key: &SubjectPublicKeyInfo<'_>) -> String {
+    match key.parsed() {
+        Ok(PublicKey::RSA(rsa)) => format!("RSA {}", rsa.key_size()),
+        Ok(PublicKey::EC(ec)) => {
+            let curve = key.algorithm.parameters.as_ref().and_then(|p| p.as_oid().ok());
+            match curve.map(|c| c.to_id_string()).as_deref() {
+                Some("1.2.840.10045.3.1.7") => "ECDSA P-256".to_owned(),
+                Some("1.3.132.0.34") => "ECDSA P-384".to_owned(),
+                Some("1.3.132.0.35") => "ECDSA P-521".to_owned(),
+                _ => format!("ECDSA {}", ec.key_size()),
+            }
+        },
+        Ok(PublicKey::DSA(_)) => "DSA".to_owned(),
+        _ => match key.algorithm.algorithm.to_id_string().as_str() {
+            "1.3.101.112" => "Ed25519".to_owned(),
+            "1.3.101.113" => "Ed448".to_owned(),
+            other => other.to_owned(),
+        },
+    }
+}
@@ -0,0 +1,77 @@
+use super::*;
+
+const CHAIN: &str = "\
+-----BEGIN CERTIFICATE-----\n\
+MIICfjCCAiSgAwIBAgIUGh4d+1gmRM1G93ZHdc4tseuA8J8wCgYIKoZIzj0EAwIw\n\
+IDEQMA4GA1UEAwwHVGVzdCBDQTEMMAoGA1UECgwDYjRuMB4XDTI2MTAxODE0Mzcy\n\
+MFoXDTI3MTAxODE0MzcyMFowGjEYMBYGA1UEAwwPd2ViLmV4YW1wbGUuY29tMIIB\n\
+IjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEApmR/mNd86c6AhhR4pQsAMjUw\n\
+nfrv1Qqp2yuEES5OrrPjiiioBGWvzR0RI453BuB+PiIIfSyyC4gwfk6pUQOELvue\n\
+HMKCNuBVKEmvotpeHciUJ+/6saA2pzIpoGgpjkkvBrtUarbi6LEVXFIDAOu8oyYJ\n\
+GfMiCrDR+UDPKSH+owB9rwWcoWKmkzkOHlgJ4LlvNRldPfSz1Vj4I9Q16ha2POSF\n\
+X/CJnPan05T8QkicYzpwgGBrOmKcc7jNmi9k8Ic7WDf4ePaTUxVl1Qylt+lVtd1j\n\
+830WfO+bWLIBfIzVzrSK5vBvyjPtMVMOowQcFi9fFDumPMYdwGvt4v6M3+VDVwID\n\
+AQABo3cwdTAzBgNVHREELDAqgg93ZWIuZXhhbXBsZS5jb22CESoud2ViLmV4YW1w\n\
+bGUuY29thwQKAAABMB0GA1UdDgQWBBSD4ITDsG3OWZmnBJE/QI+LdXeUzTAfBgNV\n\
+HSMEGDAWgBTDWquMTzZq1ibdtdn8HeiLqxhozDAKBggqhkjOPQQDAgNIADBFAiAa\n\
+6CDyn+WlO8b55K9vwbNA9nEqvKND3pN2IWYbNMZmpwIhALngmkZzE5j+JAoaZA+Z\n\
+n1caENRugz/XXaaQqPZg74Od\n\
+-----END CERTIFICATE-----\n\
+-----BEGIN CERTIFICATE-----\n\
+MIIBljCCATugAwIBAgIUS9zJYDisUaSQkzlxYxvU2biQ2gEwCgYIKoZIzj0EAwIw\n\
+IDEQMA4GA1UEAwwHVGVzdCBDQTEMMAoGA1UECgwDYjRuMB4XDTI2MTAxODE0Mzcy\n\
+MFoXDTM2MTAxNTE0MzcyMFowIDEQMA4GA1UEAwwHVGVzdCBDQTEMMAoGA1UECgwD\n\
+YjRuMFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEGRRttn2luX0I4YMDJghTfkNU\n\
+/uCr3kHUu33voWwJhIN9sjWAjJ1dKsc9+UOX5SgLnkC8xqbVBWy664vNHXnH9KNT\n\
+MFEwHQYDVR0OBBYEFMNaq4xPNmrWJt212fwd6IurGGjMMB8GA1UdIwQYMBaAFMNa\n\
+q4xPNmrWJt212fwd6IurGGjMMA8GA1UdEwEB/wQFMAMBAf8wCgYIKoZIzj0EAwID\n\
+SQAwRgIhAIvN5SE9nK+PnAwtmCatli3WOUlK1KWRLVH/JhgwyUP2AiEA05otS+0m\n\
+KaWny6z+GhCTWANPIhM40CdNwjjoMTXVNYQ=\n\
+-----END CERTIFICATE-----\n\
+";
+
+fn timestamp(value: &str) -> Timestamp {
+    value.parse().unwrap()
+}
+
+#[test]
+fn from_pem_test() {
+    let certificates = Certificate::from_pem(CHAIN.as_bytes()).unwrap();
+    assert_eq!(2, certificates.len());
+
+    let leaf = &certificates[0];
+    assert_eq!("CN=web.example.com", leaf.subject);
+    assert_eq!(Some("web.example.com"), leaf.common_name.as_deref());
+    assert_eq!("CN=Test CA, O=b4n", leaf.issuer);
+    assert_eq!(vec!["DNS:web.example.com", "DNS:*.web.example.com", "IP:10.0.0.1"], leaf.sans);
+    assert_eq!(timestamp("2026-10-18T14:37:20Z"), leaf.not_before);
+    assert_eq!(timestamp("2027-10-18T14:37:20Z"), leaf.not_after);
+    assert_eq!("RSA 2048", leaf.key_type);
+    assert!(!leaf.is_ca);
+
+    let ca = &certificates[1];
+    assert_eq!("CN=Test CA, O=b4n", ca.subject);
+    assert_eq!("ECDSA P-256", ca.key_type);
+    assert!(ca.sans.is_empty());
+    assert!(ca.is_ca);
+
+    assert_eq!(Some(leaf), first_to_expire(&certificates));
+}
+
+#[test]
+fn from_pem_error_test() {
+    assert!(matches!(Certificate::from_pem(b""), Err(CertificateError::NoCertificates)));
+    assert!(matches!(
+        Certificate::from_pem(b"-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----\n"),
+        Err(CertificateError::InvalidCertificate(_))
+    ));
+}
+
+#[test]
+fn days_left_test() {
+    let certificates = Certificate::from_pem(CHAIN.as_bytes()).unwrap();
+    let leaf = &certificates[0];
+    assert_eq!(365, leaf.days_left(timestamp("2026-10-18T14:37:20Z")));
+    assert_eq!(0, leaf.days_left(timestamp("2027-10-18T00:00:00Z")));
+    assert_eq!(-1, leaf.days_left(timestamp("2027-10-18T14:37:21Z")));
+}
@@ -0,0 +1,5 @@
+pub use self::certificate::{
+    Certificate, CertificateError, TLS_CERT_KEY, TLS_CERTIFICATE, TLS_SECRET_TYPE, days_until, first_to_expire,
+};
+
+mod certificate;
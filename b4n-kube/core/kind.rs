@@ -1,6 +1,6 @@
 use crate::is_builtin_api_group;
 
-use super::{CONTAINERS, NAMESPACES};
+use super::{CONTAINERS, HELM_RELEASES, NAMESPACES, TLS_CERTIFICATES};
 
 #[cfg(test)]
 #[path = "./kind.tests.rs"]
@@ -50,6 +50,23 @@ impl Kind {
         self.name == CONTAINERS
     }
 
+    /// Returns `true` if kind represents helm releases.\
+    /// **Note** that this is a virtual kind without a group, helm releases are stored in secrets.
+    pub fn is_helm_releases(&self) -> bool {
+        self.name() == HELM_RELEASES && self.group().is_empty()
+    }
+
+    /// Returns `true` if kind represents TLS certificates.\
+    /// **Note** that this is a virtual kind without a group, certificates are stored in `kubernetes.io/tls` secrets.
+    pub fn is_tls_certificates(&self) -> bool {
+        self.name() == TLS_CERTIFICATES && self.group().is_empty()
+    }
+
+    /// Returns `true` if kind is a virtual one, observed through other resources.
+    pub fn is_virtual(&self) -> bool {
+        self.is_helm_releases() || self.is_tls_certificates()
+    }
+
     /// Returns kind as string slice.
     pub fn as_str(&self) -> &str {
         &self.name
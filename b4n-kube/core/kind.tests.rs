@@ -30,3 +30,20 @@ fn kind_with_group_test() {
     assert!(!kind.has_version());
     assert_eq!("", kind.version());
 }
+
+#[test]
+fn virtual_kinds_test() {
+    let kind: Kind = "helmreleases".into();
+    assert!(kind.is_helm_releases());
+    assert!(kind.is_virtual());
+
+    let kind: Kind = "helmreleases.helm.toolkit.fluxcd.io/v2".into();
+    assert!(!kind.is_helm_releases());
+    assert!(!kind.is_virtual());
+
+    let kind: Kind = "tlscertificates".into();
+    assert!(kind.is_tls_certificates());
+
+    let kind: Kind = "tlscertificates.example.com".into();
+    assert!(!kind.is_tls_certificates());
+}
@@ -0,0 +1,169 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use k8s_openapi::serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+#[path = "./metadata.tests.rs"]
+mod metadata_tests;
+
+/// Possible errors from parsing metadata changes.
+#[derive(thiserror::Error, Debug)]
+pub enum MetadataChangesError {
+    /// Labels changes cannot be parsed.
+    #[error("invalid labels at position {0}")]
+    InvalidLabels(usize),
+
+    /// Annotations changes cannot be parsed.
+    #[error("invalid annotations at position {0}")]
+    InvalidAnnotations(usize),
+}
+
+/// Changes to apply to the resource's labels and annotations.\
+/// **Note** that `None` value means that the key should be removed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MetadataChanges {
+    pub labels: BTreeMap<String, Option<String>>,
+    pub annotations: BTreeMap<String, Option<String>>,
+}
+
+impl MetadataChanges {
+    /// Creates new [`MetadataChanges`] instance from the `labels` and `annotations` changes.\
+    /// **Note** that both are expected in the `key=value key2=value2 key3-` format.
+    pub fn parse(labels: &str, annotations: &str) -> Result<Self, MetadataChangesError> {
+        Ok(Self {
+            labels: parse_metadata_changes(labels, true).map_err(MetadataChangesError::InvalidLabels)?,
+            annotations: parse_metadata_changes(annotations, false).map_err(MetadataChangesError::InvalidAnnotations)?,
+        })
+    }
+
+    /// Returns `true` if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty() && self.annotations.is_empty()
+    }
+
+    /// Returns JSON merge patch that applies all changes.
+    pub fn to_patch(&self) -> Value {
+        let mut metadata = Map::new();
+        if !self.labels.is_empty() {
+            metadata.insert("labels".to_owned(), to_patch_map(&self.labels));
+        }
+
+        if !self.annotations.is_empty() {
+            metadata.insert("annotations".to_owned(), to_patch_map(&self.annotations));
+        }
+
+        json!({ "metadata": metadata })
+    }
+
+    /// Returns keys that already exist in the provided `metadata` with a different value.
+    pub fn conflicts(&self, metadata: &ObjectMeta) -> Vec<&str> {
+        let mut conflicts = get_conflicts(&self.labels, metadata.labels.as_ref());
+        conflicts.extend(get_conflicts(&self.annotations, metadata.annotations.as_ref()));
+        conflicts
+    }
+}
+
+/// Parses metadata changes in the `key=value key2=value2 key3-` format.\
+/// **Note** that values can be quoted, and the returned error is the character position of the invalid entry.
+pub fn parse_metadata_changes(input: &str, is_label: bool) -> Result<BTreeMap<String, Option<String>>, usize> {
+    let mut result = BTreeMap::new();
+    for (position, token) in tokenize(input)? {
+        if let Some((key, value)) = token.split_once('=') {
+            if !is_valid_key(key) || (is_label && !is_valid_label_value(value)) {
+                return Err(position);
+            }
+
+            result.insert(key.to_owned(), Some(value.to_owned()));
+        } else if let Some(key) = token.strip_suffix('-')
+            && is_valid_key(key)
+        {
+            result.insert(key.to_owned(), None);
+        } else {
+            return Err(position);
+        }
+    }
+
+    Ok(result)
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, String)>, usize> {
+    let mut tokens = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+    let mut quote: Option<(usize, char)> = None;
+
+    for (position, ch) in input.chars().enumerate() {
+        if let Some((_, q)) = quote {
+            if ch == q {
+                quote = None;
+            } else if let Some((_, token)) = &mut current {
+                token.push(ch);
+            }
+        } else if ch.is_whitespace() {
+            if let Some(token) = current.take() {
+                tokens.push(token);
+            }
+        } else {
+            let token = current.get_or_insert_with(|| (position, String::new()));
+            if ch == '"' || ch == '\'' {
+                quote = Some((position, ch));
+            } else {
+                token.1.push(ch);
+            }
+        }
+    }
+
+    if let Some((position, _)) = quote {
+        return Err(position);
+    }
+
+    tokens.extend(current);
+    Ok(tokens)
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let (prefix, name) = key.split_once('/').map_or((None, key), |(p, n)| (Some(p), n));
+    if let Some(prefix) = prefix
+        && (prefix.is_empty()
+            || prefix.len() > 253
+            || !prefix
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.'))
+    {
+        return false;
+    }
+
+    !name.is_empty() && is_valid_label_value(name)
+}
+
+fn is_valid_label_value(value: &str) -> bool {
+    if value.is_empty() {
+        return true;
+    }
+
+    value.len() <= 63
+        && value.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && value.ends_with(|c: char| c.is_ascii_alphanumeric())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+fn to_patch_map(changes: &BTreeMap<String, Option<String>>) -> Value {
+    changes
+        .iter()
+        .map(|(key, value)| (key.clone(), value.as_ref().map_or(Value::Null, |v| Value::String(v.clone()))))
+        .collect::<Map<_, _>>()
+        .into()
+}
+
+fn get_conflicts<'a>(changes: &'a BTreeMap<String, Option<String>>, existing: Option<&BTreeMap<String, String>>) -> Vec<&'a str> {
+    let Some(existing) = existing else {
+        return Vec::new();
+    };
+
+    changes
+        .iter()
+        .filter(|(key, value)| value.as_ref().is_some_and(|v| existing.get(*key).is_some_and(|e| e != v)))
+        .map(|(key, _)| key.as_str())
+        .collect()
+}
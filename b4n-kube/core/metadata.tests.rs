@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn parse_metadata_changes_test() {
+    let changes = parse_metadata_changes("app=web  tier= example.com/team=a-b  old-", true).unwrap();
+
+    assert_eq!(4, changes.len());
+    assert_eq!(Some(&Some("web".to_owned())), changes.get("app"));
+    assert_eq!(Some(&Some(String::new())), changes.get("tier"));
+    assert_eq!(Some(&Some("a-b".to_owned())), changes.get("example.com/team"));
+    assert_eq!(Some(&None), changes.get("old"));
+
+    let changes = parse_metadata_changes("description=\"hello, world\" 'note=it''s'", false).unwrap();
+    assert_eq!(Some(&Some("hello, world".to_owned())), changes.get("description"));
+    assert_eq!(Some(&Some("its".to_owned())), changes.get("note"));
+}
+
+#[test]
+fn parse_metadata_changes_errors_test() {
+    assert_eq!(Err(8), parse_metadata_changes("app=web tier", true));
+    assert_eq!(Err(0), parse_metadata_changes("app=web!", true));
+    assert_eq!(Err(0), parse_metadata_changes("-app=web", true));
+    assert_eq!(Err(0), parse_metadata_changes("Example.com/app=web", true));
+    assert_eq!(Err(8), parse_metadata_changes("app=web \"note=x", false));
+    assert!(parse_metadata_changes("app=web!", false).is_ok());
+    assert!(parse_metadata_changes("", true).unwrap().is_empty());
+}
+
+#[test]
+fn to_patch_test() {
+    let changes = MetadataChanges::parse("app=web old-", "").unwrap();
+    assert_eq!(
+        json!({ "metadata": { "labels": { "app": "web", "old": null } } }),
+        changes.to_patch()
+    );
+
+    let changes = MetadataChanges::parse("", "note=x").unwrap();
+    assert_eq!(json!({ "metadata": { "annotations": { "note": "x" } } }), changes.to_patch());
+}
+
+#[test]
+fn conflicts_test() {
+    let changes = MetadataChanges::parse("app=web tier=db old-", "note=x").unwrap();
+    let metadata = ObjectMeta {
+        labels: Some(BTreeMap::from([
+            ("app".to_owned(), "web".to_owned()),
+            ("tier".to_owned(), "api".to_owned()),
+            ("old".to_owned(), "1".to_owned()),
+        ])),
+        annotations: Some(BTreeMap::from([("note".to_owned(), "y".to_owned())])),
+        ..Default::default()
+    };
+
+    assert_eq!(vec!["tier", "note"], changes.conflicts(&metadata));
+}
@@ -3,23 +3,32 @@ pub const PODS: &str = "pods";
 pub const CONTAINERS: &str = "containers";
 pub const SERVICES: &str = "services";
 pub const JOBS: &str = "jobs";
+pub const CRON_JOBS: &str = "cronjobs";
 pub const DEPLOYMENTS: &str = "deployments";
 pub const REPLICA_SETS: &str = "replicasets";
 pub const DAEMON_SETS: &str = "daemonsets";
 pub const STATEFUL_SETS: &str = "statefulsets";
 pub const SECRETS: &str = "secrets";
+pub const CONFIG_MAPS: &str = "configmaps";
+pub const SERVICE_ACCOUNTS: &str = "serviceaccounts";
 pub const EVENTS: &str = "events";
 pub const CRDS: &str = "customresourcedefinitions";
 pub const PVC: &str = "persistentvolumeclaims";
 pub const PV: &str = "persistentvolumes";
+pub const HPA: &str = "horizontalpodautoscalers";
+pub const PDB: &str = "poddisruptionbudgets";
+pub const HELM_RELEASES: &str = "helmreleases";
+pub const TLS_CERTIFICATES: &str = "tlscertificates";
 
 pub use self::kind::{CORE_VERSION, Kind};
-pub use self::namespace::{ALL_NAMESPACES, NAMESPACES, Namespace};
+pub use self::metadata::{MetadataChanges, MetadataChangesError, parse_metadata_changes};
+pub use self::namespace::{ALL_NAMESPACES, NAMESPACES, NAMESPACES_SEPARATOR, Namespace};
 pub use self::ports::{Port, PortProtocol};
 pub use self::propagation_policy::PropagationPolicy;
 pub use self::resource_ref::{ContainerRef, ContainerType, ResourceRef, ResourceRefFilter, ResourceTag};
 
 mod kind;
+mod metadata;
 mod namespace;
 mod ports;
 mod propagation_policy;
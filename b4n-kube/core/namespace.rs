@@ -1,10 +1,16 @@
 use std::fmt::Display;
 
+#[cfg(test)]
+#[path = "./namespace.tests.rs"]
+mod namespace_tests;
+
 pub const ALL_NAMESPACES: &str = "all";
 pub const NAMESPACES: &str = "namespaces";
+pub const NAMESPACES_SEPARATOR: char = ',';
 
 /// Represents kubernetes namespace.\
-/// **Note** that it treats string `all` as a special case: all namespaces.
+/// **Note** that it treats string `all` as a special case: all namespaces.\
+/// It can also represent a set of namespaces, e.g. `team-a,team-b` or a glob pattern `team-*`.
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Namespace {
     value: Option<String>,
@@ -47,6 +53,57 @@ impl Namespace {
         self.value.is_none()
     }
 
+    /// Returns `true` if the [`Namespace`] instance represents a set of namespaces.
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        self.value.as_deref().is_some_and(is_set)
+    }
+
+    /// Returns `true` if the [`Namespace`] instance represents all namespaces or a set of them.
+    #[inline]
+    pub fn is_multiple(&self) -> bool {
+        self.is_all() || self.is_set()
+    }
+
+    /// Returns `true` if the specified `namespace` belongs to this [`Namespace`].
+    pub fn contains(&self, namespace: &str) -> bool {
+        match self.value.as_deref() {
+            None => true,
+            Some(value) if is_set(value) => value
+                .split(NAMESPACES_SEPARATOR)
+                .any(|pattern| matches_pattern(pattern.as_bytes(), namespace.as_bytes())),
+            Some(value) => value == namespace,
+        }
+    }
+
+    /// Returns `true` if the [`Namespace`] instance has glob patterns that need to be resolved to the concrete namespaces.
+    pub fn has_patterns(&self) -> bool {
+        self.value.as_deref().is_some_and(|v| v.contains(['*', '?']))
+    }
+
+    /// Returns the concrete namespaces of this [`Namespace`] with glob patterns resolved against the `known` namespaces.\
+    /// **Note** that it returns an empty list for all namespaces.
+    pub fn resolve(&self, known: &[String]) -> Vec<String> {
+        let Some(value) = self.value.as_deref() else {
+            return Vec::new();
+        };
+
+        let mut result: Vec<String> = Vec::new();
+        for pattern in value.split(NAMESPACES_SEPARATOR) {
+            if pattern.contains(['*', '?']) {
+                for name in known.iter().filter(|n| matches_pattern(pattern.as_bytes(), n.as_bytes())) {
+                    if !result.contains(name) {
+                        result.push(name.clone());
+                    }
+                }
+            } else if !result.iter().any(|n| n == pattern) {
+                result.push(pattern.to_owned());
+            }
+        }
+
+        result
+    }
+
     /// Returns `true` if the [`Namespace`] instance is equal to the specified option.
     #[inline]
     pub fn is_equal(&self, other: Option<&str>) -> bool {
@@ -56,22 +113,18 @@ impl Namespace {
 
 impl From<Option<String>> for Namespace {
     fn from(value: Option<String>) -> Self {
-        if value.as_deref().is_some_and(|v| v.is_empty() || v == ALL_NAMESPACES) {
-            Self { value: None }
-        } else {
-            Self { value }
+        match value {
+            Some(value) => Self::from(value),
+            None => Self { value: None },
         }
     }
 }
 
 impl From<Option<&str>> for Namespace {
     fn from(value: Option<&str>) -> Self {
-        if value.is_some_and(|v| v.is_empty() || v == ALL_NAMESPACES) {
-            Self { value: None }
-        } else {
-            Self {
-                value: value.map(String::from),
-            }
+        match value {
+            Some(value) => Self::from(value),
+            None => Self { value: None },
         }
     }
 }
@@ -80,6 +133,8 @@ impl From<String> for Namespace {
     fn from(value: String) -> Self {
         if value.is_empty() || value == ALL_NAMESPACES {
             Self { value: None }
+        } else if value.contains(NAMESPACES_SEPARATOR) {
+            Self::from(value.as_str())
         } else {
             Self { value: Some(value) }
         }
@@ -90,6 +145,10 @@ impl From<&str> for Namespace {
     fn from(value: &str) -> Self {
         if value.is_empty() || value == ALL_NAMESPACES {
             Self { value: None }
+        } else if value.contains(NAMESPACES_SEPARATOR) {
+            Self {
+                value: normalize_set(value),
+            }
         } else {
             Self {
                 value: Some(value.to_owned()),
@@ -109,3 +168,36 @@ impl From<Namespace> for Option<String> {
         value.value
     }
 }
+
+/// Returns `true` if the `value` describes more than one namespace.
+fn is_set(value: &str) -> bool {
+    value.contains([NAMESPACES_SEPARATOR, '*', '?'])
+}
+
+/// Removes empty and duplicated entries from the namespaces set.\
+/// **Note** that it returns `None` (all namespaces) if any of the entries is `all`.
+fn normalize_set(value: &str) -> Option<String> {
+    let mut entries = Vec::new();
+    for entry in value.split(NAMESPACES_SEPARATOR).map(str::trim).filter(|e| !e.is_empty()) {
+        if entry == ALL_NAMESPACES {
+            return None;
+        }
+        if !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+
+    let separator = NAMESPACES_SEPARATOR.to_string();
+    (!entries.is_empty()).then(|| entries.join(&separator))
+}
+
+/// Matches `name` against the glob `pattern` that can contain `*` and `?` wildcards.
+fn matches_pattern(pattern: &[u8], name: &[u8]) -> bool {
+    match (pattern.first(), name.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => matches_pattern(&pattern[1..], name) || (!name.is_empty() && matches_pattern(pattern, &name[1..])),
+        (Some(b'?'), Some(_)) => matches_pattern(&pattern[1..], &name[1..]),
+        (Some(p), Some(n)) if p == n => matches_pattern(&pattern[1..], &name[1..]),
+        _ => false,
+    }
+}
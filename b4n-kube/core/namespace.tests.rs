@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn namespace_set_test() {
+    let namespace = Namespace::from("team-a, team-b,,team-a");
+    assert!(namespace.is_set());
+    assert!(namespace.is_multiple());
+    assert!(!namespace.is_all());
+    assert_eq!("team-a,team-b", namespace.as_str());
+    assert!(namespace.contains("team-a"));
+    assert!(namespace.contains("team-b"));
+    assert!(!namespace.contains("team-c"));
+
+    let namespace = Namespace::from("team-b,all");
+    assert!(namespace.is_all());
+
+    let namespace = Namespace::from("kube-system,");
+    assert!(!namespace.is_set());
+    assert_eq!("kube-system", namespace.as_str());
+}
+
+#[test]
+fn namespace_glob_test() {
+    let namespace = Namespace::from("team-*");
+    assert!(namespace.is_set());
+    assert!(namespace.contains("team-a"));
+    assert!(namespace.contains("team-"));
+    assert!(!namespace.contains("teams"));
+
+    let namespace = Namespace::from(String::from("kube-?ublic,*-prod"));
+    assert!(namespace.contains("kube-public"));
+    assert!(namespace.contains("api-prod"));
+    assert!(!namespace.contains("kube-system"));
+    assert!(!namespace.contains("api-prod-2"));
+}
+
+#[test]
+fn namespace_single_test() {
+    let namespace = Namespace::from("default");
+    assert!(!namespace.is_set());
+    assert!(!namespace.is_multiple());
+    assert!(namespace.contains("default"));
+    assert!(!namespace.contains("kube-system"));
+    assert!(Namespace::all().contains("default"));
+}
+
+#[test]
+fn namespace_resolve_test() {
+    let known = ["team-a", "team-b", "kube-system", "kube-public"].map(String::from);
+
+    assert_eq!(vec!["team-a", "team-b"], Namespace::from("team-*").resolve(&known));
+    assert_eq!(
+        vec!["team-b", "kube-public", "team-c", "team-a"],
+        Namespace::from("team-b,kube-?ublic,team-c,team-?").resolve(&known)
+    );
+    assert!(Namespace::from("prod-*").resolve(&known).is_empty());
+    assert_eq!(vec!["default"], Namespace::from("default").resolve(&known));
+    assert!(Namespace::all().resolve(&known).is_empty());
+    assert!(Namespace::from("team-*").has_patterns());
+    assert!(!Namespace::from("team-a,team-b").has_patterns());
+}
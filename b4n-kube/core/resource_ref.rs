@@ -1,3 +1,4 @@
+use b4n_common::expr::ServerSelectors;
 use k8s_openapi::jiff::Timestamp;
 use kube::{api::ApiResource, discovery::Scope};
 use std::fmt::Display;
@@ -130,9 +131,15 @@ impl ResourceRef {
         self.all_containers || self.container.is_some()
     }
 
-    /// Returns `true` if [`ResourceRef`] points to a filtered resource.
+    /// Returns `true` if [`ResourceRef`] points to a filtered resource.\
+    /// **Note** that server-side filters are not taken into account, as they do not change the scope of the list.
     pub fn is_filtered(&self) -> bool {
-        self.filter.is_some()
+        self.filter.as_ref().is_some_and(|f| !f.is_server_side)
+    }
+
+    /// Returns `true` if [`ResourceRef`] is narrowed down by the server-side filter.
+    pub fn is_server_filtered(&self) -> bool {
+        self.filter.as_ref().is_some_and(|f| f.is_server_side)
     }
 
     /// Returns `true` if [`ResourceRef`] is equal to `other` considering specified `scope`.
@@ -162,6 +169,7 @@ pub struct ResourceRefFilter {
     pub name: Option<String>,
     pub fields: Option<String>,
     pub labels: Option<String>,
+    pub is_server_side: bool,
 }
 
 impl ResourceRefFilter {
@@ -171,6 +179,7 @@ impl ResourceRefFilter {
             name: Some(name),
             fields: Some(format!("involvedObject.uid={uid}")),
             labels: None,
+            is_server_side: false,
         }
     }
 
@@ -180,6 +189,7 @@ impl ResourceRefFilter {
             name: Some(name),
             fields: Some(format!("spec.nodeName={node_name}")),
             labels: None,
+            is_server_side: false,
         }
     }
 
@@ -189,6 +199,7 @@ impl ResourceRefFilter {
             name: Some(name),
             fields: None,
             labels: Some(format!("job-name={job_name}")),
+            is_server_side: false,
         }
     }
 
@@ -198,6 +209,18 @@ impl ResourceRefFilter {
             name: Some(name),
             fields: None,
             labels: Some(labels),
+            is_server_side: false,
+        }
+    }
+
+    /// Creates new [`ResourceRefFilter`] instance for the label and field `selectors` extracted from the list filter.\
+    /// **Note** that such filter does not change the scope of the list, it only lets the API server do the filtering.
+    pub fn server_side(selectors: &ServerSelectors) -> Self {
+        Self {
+            name: Some(format!("server-side: {selectors}")),
+            fields: selectors.fields.clone(),
+            labels: selectors.labels.clone(),
+            is_server_side: true,
         }
     }
 
@@ -0,0 +1,3 @@
+pub use self::schedule::{CronError, CronSchedule};
+
+mod schedule;
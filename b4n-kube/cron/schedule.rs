@@ -0,0 +1,409 @@
+use jiff::civil::{DateTime, Time};
+use jiff::tz::TimeZone;
+use jiff::{Timestamp, ToSpan, Zoned};
+
+#[cfg(test)]
+#[path = "./schedule.tests.rs"]
+mod schedule_tests;
+
+/// Number of years after which the search for the next run stops.\
+/// **Note** that 8 years covers schedules that fire only on the 29th of February.
+const MAX_SEARCH_YEARS: i16 = 8;
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+const MONTH_LABELS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAY_LABELS: [&str; 7] = ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+
+/// Possible errors from parsing cron schedule.
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum CronError {
+    /// Schedule has wrong number of fields.
+    #[error("expected 5 fields, found {0}")]
+    FieldsCount(usize),
+
+    /// Schedule uses an unsupported macro.
+    #[error("unsupported macro '{0}'")]
+    UnknownMacro(String),
+
+    /// Field contains value that cannot be parsed.
+    #[error("invalid value '{value}' in the {field} field")]
+    InvalidValue { field: &'static str, value: String },
+
+    /// Field contains value outside of the allowed range.
+    #[error("value {value} is out of range {min}-{max} in the {field} field")]
+    OutOfRange {
+        field: &'static str,
+        value: u8,
+        min: u8,
+        max: u8,
+    },
+
+    /// Time zone cannot be found.
+    #[error("unknown time zone '{0}'")]
+    UnknownTimeZone(String),
+}
+
+/// Definition of a single cron schedule field.
+struct FieldKind {
+    name: &'static str,
+    plural: &'static str,
+    min: u8,
+    max: u8,
+    names: &'static [&'static str],
+    labels: &'static [&'static str],
+}
+
+const MINUTES: FieldKind = FieldKind::new("minute", "minutes", 0, 59);
+const HOURS: FieldKind = FieldKind::new("hour", "hours", 0, 23);
+const DAYS: FieldKind = FieldKind::new("day of month", "days of month", 1, 31);
+const MONTHS: FieldKind = FieldKind::new("month", "months", 1, 12).with_names(&MONTH_NAMES, &MONTH_LABELS);
+const WEEKDAYS: FieldKind = FieldKind::new("day of week", "days of week", 0, 7).with_names(&WEEKDAY_NAMES, &WEEKDAY_LABELS);
+
+impl FieldKind {
+    const fn new(name: &'static str, plural: &'static str, min: u8, max: u8) -> Self {
+        Self {
+            name,
+            plural,
+            min,
+            max,
+            names: &[],
+            labels: &[],
+        }
+    }
+
+    const fn with_names(mut self, names: &'static [&'static str], labels: &'static [&'static str]) -> Self {
+        self.names = names;
+        self.labels = labels;
+        self
+    }
+
+    fn parse_value(&self, value: &str) -> Result<u8, CronError> {
+        let lowercase = value.to_ascii_lowercase();
+        let parsed = if let Some(index) = self.names.iter().position(|n| *n == lowercase) {
+            index as u8 + self.min
+        } else {
+            value.parse::<u8>().map_err(|_| self.invalid(value))?
+        };
+
+        if parsed < self.min || parsed > self.max {
+            Err(CronError::OutOfRange {
+                field: self.name,
+                value: parsed,
+                min: self.min,
+                max: self.max,
+            })
+        } else {
+            Ok(parsed)
+        }
+    }
+
+    fn label(&self, value: &str) -> String {
+        match self.parse_value(value) {
+            Ok(parsed) if !self.labels.is_empty() => self.labels[usize::from(parsed - self.min) % self.labels.len()].to_owned(),
+            _ => value.to_owned(),
+        }
+    }
+
+    fn invalid(&self, value: &str) -> CronError {
+        CronError::InvalidValue {
+            field: self.name,
+            value: value.to_owned(),
+        }
+    }
+}
+
+/// Parsed cron schedule field.
+#[derive(Debug, Clone)]
+struct Field {
+    bits: u64,
+    is_any: bool,
+    expr: String,
+}
+
+impl Field {
+    fn parse(expr: &str, kind: &FieldKind) -> Result<Self, CronError> {
+        let mut bits = 0;
+        for item in expr.split(',') {
+            bits |= parse_item(item, kind)?;
+        }
+
+        if kind.max == 7 && bits & (1 << 7) != 0 {
+            bits = (bits & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            bits,
+            is_any: expr == "*" || expr == "?",
+            expr: expr.to_owned(),
+        })
+    }
+
+    fn matches(&self, value: i8) -> bool {
+        self.bits & (1 << value) != 0
+    }
+}
+
+/// Cron schedule as used by the Kubernetes `CronJob` resource.
+#[derive(Debug, Clone)]
+pub struct CronSchedule {
+    minutes: Field,
+    hours: Field,
+    days: Field,
+    months: Field,
+    weekdays: Field,
+    time_zone: TimeZone,
+}
+
+impl CronSchedule {
+    /// Parses standard 5 fields cron expression or one of the `@yearly`, `@monthly`, `@weekly`, `@daily` and `@hourly` macros.\
+    /// **Note** that the schedule uses `UTC` time zone unless it is changed with [`CronSchedule::with_time_zone`].
+    pub fn parse(expr: &str) -> Result<Self, CronError> {
+        let expr = expr.trim();
+        let expr = if expr.starts_with('@') { expand_macro(expr)? } else { expr };
+
+        let fields = expr.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(CronError::FieldsCount(fields.len()));
+        }
+
+        Ok(Self {
+            minutes: Field::parse(fields[0], &MINUTES)?,
+            hours: Field::parse(fields[1], &HOURS)?,
+            days: Field::parse(fields[2], &DAYS)?,
+            months: Field::parse(fields[3], &MONTHS)?,
+            weekdays: Field::parse(fields[4], &WEEKDAYS)?,
+            time_zone: TimeZone::UTC,
+        })
+    }
+
+    /// Sets IANA time zone in which the schedule is evaluated.
+    pub fn with_time_zone(mut self, time_zone: Option<&str>) -> Result<Self, CronError> {
+        if let Some(name) = time_zone {
+            self.time_zone = TimeZone::get(name).map_err(|_| CronError::UnknownTimeZone(name.to_owned()))?;
+        }
+
+        Ok(self)
+    }
+
+    /// Returns time zone in which the schedule is evaluated.
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.time_zone
+    }
+
+    /// Returns the first time the schedule fires strictly after the provided `time`.\
+    /// **Note** that it returns `None` if the schedule never fires.
+    pub fn next_after(&self, time: Timestamp) -> Option<Zoned> {
+        let start = time.to_zoned(self.time_zone.clone()).datetime();
+        let mut current = start
+            .with()
+            .second(0)
+            .subsec_nanosecond(0)
+            .build()
+            .ok()?
+            .checked_add(1.minute())
+            .ok()?;
+        let max_year = start.year().saturating_add(MAX_SEARCH_YEARS);
+
+        while current.year() <= max_year {
+            if !self.months.matches(current.month()) {
+                let next_month = current.date().first_of_month().checked_add(1.month()).ok()?;
+                current = DateTime::from_parts(next_month, Time::midnight());
+            } else if !self.day_matches(current) {
+                current = DateTime::from_parts(current.date().tomorrow().ok()?, Time::midnight());
+            } else if !self.hours.matches(current.hour()) {
+                current = current.with().minute(0).build().ok()?.checked_add(1.hour()).ok()?;
+            } else if !self.minutes.matches(current.minute()) {
+                current = current.checked_add(1.minute()).ok()?;
+            } else {
+                let zoned = self.time_zone.to_ambiguous_zoned(current).compatible().ok()?;
+                if zoned.timestamp() > time {
+                    return Some(zoned);
+                }
+
+                current = current.checked_add(1.minute()).ok()?;
+            }
+        }
+
+        None
+    }
+
+    /// Returns up to `count` next times the schedule fires after the provided `time`.
+    pub fn next_runs(&self, time: Timestamp, count: usize) -> Vec<Zoned> {
+        let mut result = Vec::with_capacity(count);
+        let mut time = time;
+        while result.len() < count {
+            let Some(next) = self.next_after(time) else {
+                break;
+            };
+
+            time = next.timestamp();
+            result.push(next);
+        }
+
+        result
+    }
+
+    /// Returns plain English explanation of the schedule.
+    pub fn describe(&self) -> String {
+        let mut text = self.describe_time();
+
+        if let Some(days) = self.describe_days() {
+            text.push_str(", ");
+            text.push_str(&days);
+        }
+
+        if !self.months.is_any {
+            text.push_str(", ");
+            text.push_str(&describe_field(&self.months.expr, &MONTHS, "in "));
+        }
+
+        text
+    }
+
+    fn day_matches(&self, time: DateTime) -> bool {
+        let day = self.days.matches(time.day());
+        let weekday = self.weekdays.matches(time.weekday().to_sunday_zero_offset());
+        if self.days.is_any || self.weekdays.is_any {
+            day && weekday
+        } else {
+            day || weekday
+        }
+    }
+
+    fn describe_time(&self) -> String {
+        let minute = single_value(&self.minutes.expr);
+        let hour = single_value(&self.hours.expr);
+        match (minute, hour) {
+            (Some(minute), Some(hour)) => format!("At {hour:0>2}:{minute:0>2}"),
+            (Some(minute), None) if self.hours.is_any => format!("At minute {minute} past every hour"),
+            _ => {
+                let mut text = if self.minutes.is_any {
+                    "Every minute".to_owned()
+                } else {
+                    capitalize(&describe_field(&self.minutes.expr, &MINUTES, "at "))
+                };
+
+                if !self.hours.is_any {
+                    text.push_str(", ");
+                    text.push_str(&describe_field(&self.hours.expr, &HOURS, "during "));
+                }
+
+                text
+            },
+        }
+    }
+
+    fn describe_days(&self) -> Option<String> {
+        let days = (!self.days.is_any).then(|| describe_field(&self.days.expr, &DAYS, "on "));
+        let weekdays = (!self.weekdays.is_any).then(|| describe_field(&self.weekdays.expr, &WEEKDAYS, "on "));
+
+        match (days, weekdays) {
+            (Some(days), Some(weekdays)) => Some(format!("{days} or {weekdays}")),
+            (days, weekdays) => days.or(weekdays),
+        }
+    }
+}
+
+fn expand_macro(expr: &str) -> Result<&'static str, CronError> {
+    match expr.to_ascii_lowercase().as_str() {
+        "@yearly" | "@annually" => Ok("0 0 1 1 *"),
+        "@monthly" => Ok("0 0 1 * *"),
+        "@weekly" => Ok("0 0 * * 0"),
+        "@daily" | "@midnight" => Ok("0 0 * * *"),
+        "@hourly" => Ok("0 * * * *"),
+        _ => Err(CronError::UnknownMacro(expr.to_owned())),
+    }
+}
+
+fn parse_item(item: &str, kind: &FieldKind) -> Result<u64, CronError> {
+    let (range, step) = match item.split_once('/') {
+        Some((range, step)) => {
+            let step = step.parse::<u8>().ok().filter(|s| *s > 0).ok_or_else(|| kind.invalid(item))?;
+            (range, Some(step))
+        },
+        None => (item, None),
+    };
+
+    let (start, end) = if range == "*" || range == "?" {
+        (kind.min, if kind.max == 7 { 6 } else { kind.max })
+    } else if let Some((start, end)) = range.split_once('-') {
+        (kind.parse_value(start)?, kind.parse_value(end)?)
+    } else {
+        let start = kind.parse_value(range)?;
+        (start, if step.is_some() { kind.max } else { start })
+    };
+
+    if start > end {
+        return Err(kind.invalid(item));
+    }
+
+    let step = usize::from(step.unwrap_or(1));
+    Ok((start..=end).step_by(step).fold(0, |bits, value| bits | (1 << value)))
+}
+
+fn single_value(expr: &str) -> Option<u8> {
+    expr.parse::<u8>().ok()
+}
+
+fn describe_field(expr: &str, kind: &FieldKind, prefix: &str) -> String {
+    let plural = kind.plural;
+    let items = expr
+        .split(',')
+        .map(|item| match item.split_once('/') {
+            Some(("*" | "?", step)) => format!("every {step} {plural}"),
+            Some((range, step)) => match range.split_once('-') {
+                Some((start, end)) => format!("every {step} {plural} from {} through {}", kind.label(start), kind.label(end)),
+                None => format!("every {step} {plural} starting at {}", kind.label(range)),
+            },
+            None => match item.split_once('-') {
+                Some((start, end)) => format!("{} through {}", kind.label(start), kind.label(end)),
+                None => kind.label(item),
+            },
+        })
+        .collect::<Vec<_>>();
+
+    let is_plain = !expr.contains('/');
+    let mut text = String::from(if is_plain { prefix } else { "" });
+    if is_plain && kind.labels.is_empty() {
+        text.push_str(if items.len() > 1 || expr.contains('-') {
+            plural
+        } else {
+            kind.name
+        });
+        text.push(' ');
+    }
+
+    text.push_str(&join_items(&items));
+    text
+}
+
+fn join_items(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
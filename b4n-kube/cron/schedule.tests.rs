@@ -0,0 +1,135 @@
+use super::*;
+
+fn ts(time: &str) -> Timestamp {
+    time.parse().unwrap()
+}
+
+fn next(expr: &str, after: &str) -> Option<String> {
+    CronSchedule::parse(expr)
+        .unwrap()
+        .next_after(ts(after))
+        .map(|t| t.timestamp().to_string())
+}
+
+#[test]
+fn parse_errors_test() {
+    assert_eq!(CronError::FieldsCount(4), CronSchedule::parse("* * * *").unwrap_err());
+    assert_eq!(
+        CronError::UnknownMacro("@reboot".to_owned()),
+        CronSchedule::parse("@reboot").unwrap_err()
+    );
+    assert!(matches!(
+        CronSchedule::parse("60 * * * *").unwrap_err(),
+        CronError::OutOfRange { value: 60, .. }
+    ));
+    assert!(matches!(
+        CronSchedule::parse("* * * foo *").unwrap_err(),
+        CronError::InvalidValue { field: "month", .. }
+    ));
+    assert!(matches!(
+        CronSchedule::parse("*/0 * * * *").unwrap_err(),
+        CronError::InvalidValue { .. }
+    ));
+    assert!(matches!(
+        CronSchedule::parse("5-1 * * * *").unwrap_err(),
+        CronError::InvalidValue { .. }
+    ));
+    assert!(matches!(
+        CronSchedule::parse("* * * * *").unwrap().with_time_zone(Some("Mars/Olympus")),
+        Err(CronError::UnknownTimeZone(_))
+    ));
+}
+
+#[test]
+fn next_after_test() {
+    assert_eq!(
+        Some("2026-10-18T12:01:00Z".to_owned()),
+        next("* * * * *", "2026-10-18T12:00:00Z")
+    );
+    assert_eq!(
+        Some("2026-10-18T12:15:00Z".to_owned()),
+        next("*/15 * * * *", "2026-10-18T12:00:30Z")
+    );
+    assert_eq!(
+        Some("2026-10-19T00:00:00Z".to_owned()),
+        next("@daily", "2026-10-18T12:00:00Z")
+    );
+    assert_eq!(
+        Some("2026-11-01T00:00:00Z".to_owned()),
+        next("@monthly", "2026-10-18T12:00:00Z")
+    );
+    assert_eq!(
+        Some("2027-01-01T00:00:00Z".to_owned()),
+        next("@yearly", "2026-10-18T12:00:00Z")
+    );
+    assert_eq!(
+        Some("2026-10-19T09:30:00Z".to_owned()),
+        next("30 9 * * mon-fri", "2026-10-17T10:00:00Z")
+    );
+    assert_eq!(
+        Some("2026-10-25T00:00:00Z".to_owned()),
+        next("0 0 * * 7", "2026-10-18T12:00:00Z")
+    );
+    assert_eq!(
+        Some("2028-02-29T00:00:00Z".to_owned()),
+        next("0 0 29 2 *", "2026-10-18T12:00:00Z")
+    );
+    assert_eq!(None, next("0 0 30 2 *", "2026-10-18T12:00:00Z"));
+}
+
+#[test]
+fn next_after_day_of_month_or_week_test() {
+    // when both day fields are restricted, the schedule fires if any of them matches
+    assert_eq!(
+        Some("2026-10-19T00:00:00Z".to_owned()),
+        next("0 0 1 * mon", "2026-10-18T12:00:00Z")
+    );
+    assert_eq!(
+        Some("2026-11-01T00:00:00Z".to_owned()),
+        next("0 0 1 * mon", "2026-10-26T12:00:00Z")
+    );
+}
+
+#[test]
+fn next_after_time_zone_test() {
+    let schedule = CronSchedule::parse("0 9 * * *")
+        .unwrap()
+        .with_time_zone(Some("Europe/Warsaw"))
+        .unwrap();
+
+    let next = schedule.next_after(ts("2026-10-18T12:00:00Z")).unwrap();
+    assert_eq!("2026-10-19T07:00:00Z", next.timestamp().to_string());
+
+    let next = schedule.next_after(ts("2026-10-25T12:00:00Z")).unwrap();
+    assert_eq!("2026-10-26T08:00:00Z", next.timestamp().to_string());
+}
+
+#[test]
+fn next_runs_test() {
+    let runs = CronSchedule::parse("0 */6 * * *")
+        .unwrap()
+        .next_runs(ts("2026-10-18T12:00:00Z"), 3);
+    let runs = runs.iter().map(|r| r.timestamp().to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        vec!["2026-10-18T18:00:00Z", "2026-10-19T00:00:00Z", "2026-10-19T06:00:00Z"],
+        runs
+    );
+}
+
+#[test]
+fn describe_test() {
+    let describe = |expr: &str| CronSchedule::parse(expr).unwrap().describe();
+
+    assert_eq!("Every minute", describe("* * * * *"));
+    assert_eq!("At 00:00", describe("@daily"));
+    assert_eq!("At minute 0 past every hour", describe("@hourly"));
+    assert_eq!("At 00:00, on Sunday", describe("@weekly"));
+    assert_eq!("At 00:00, on day of month 1, in January", describe("@yearly"));
+    assert_eq!(
+        "Every 15 minutes, during hours 9 through 17, on Monday through Friday",
+        describe("*/15 9-17 * * 1-5")
+    );
+    assert_eq!("At minutes 0 and 30", describe("0,30 * * * *"));
+    assert_eq!("At 02:05, on day of month 1 or on Monday", describe("5 2 1 * MON"));
+    assert_eq!("At 12:00, every 3 months", describe("0 12 ? */3 *"));
+}
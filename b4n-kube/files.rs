@@ -6,6 +6,7 @@ pub struct TransferContext {
     pub to: String,
     pub container: String,
     pub overwrite_files: bool,
+    pub verify_checksum: bool,
 }
 
 impl TransferContext {
@@ -17,6 +18,7 @@ impl TransferContext {
             to,
             container,
             overwrite_files,
+            verify_checksum: false,
         }
     }
 
@@ -28,6 +30,13 @@ impl TransferContext {
             to,
             container,
             overwrite_files,
+            verify_checksum: false,
         }
     }
+
+    /// Sets if the SHA-256 checksum should be verified after the transfer.
+    pub fn with_checksum_verification(mut self, verify_checksum: bool) -> Self {
+        self.verify_checksum = verify_checksum;
+        self
+    }
 }
@@ -0,0 +1,5 @@
+pub use self::release::{
+    HELM_RELEASE, HelmRelease, HelmReleaseError, HelmReleaseView, get_release_revision, get_release_stub, release_labels,
+};
+
+mod release;
@@ -0,0 +1,208 @@
+use base64::{Engine, engine};
+use flate2::read::GzDecoder;
+use jiff::Timestamp;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, Time};
+use k8s_openapi::serde_json::{self, Value, json};
+use kube::api::DynamicObject;
+use std::io::Read;
+
+#[cfg(test)]
+#[path = "./release.tests.rs"]
+mod release_tests;
+
+pub const HELM_RELEASE: &str = "HelmRelease";
+
+const HELM_OWNER_LABEL: &str = "owner=helm";
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Possible errors from decoding helm release.
+#[derive(thiserror::Error, Debug)]
+pub enum HelmReleaseError {
+    /// Secret does not contain release data.
+    #[error("secret does not contain release data")]
+    MissingData,
+
+    /// Release data is not a valid base64 string.
+    #[error("cannot decode release data")]
+    DecodeError(#[from] base64::DecodeError),
+
+    /// Release data cannot be decompressed.
+    #[error("cannot decompress release data")]
+    DecompressError(#[from] std::io::Error),
+
+    /// Release data is not a valid JSON.
+    #[error("cannot parse release data")]
+    ParseError(#[from] serde_json::Error),
+}
+
+/// Part of the helm release that can be viewed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HelmReleaseView {
+    Values,
+    Manifest,
+    Notes,
+    History,
+}
+
+impl HelmReleaseView {
+    /// Returns title for the helm release view.
+    pub fn title(self) -> &'static str {
+        match self {
+            HelmReleaseView::Values => "values",
+            HelmReleaseView::Manifest => "manifest",
+            HelmReleaseView::Notes => "notes",
+            HelmReleaseView::History => "history",
+        }
+    }
+}
+
+/// Helm release decoded from the `sh.helm.release.v1.*` secret.
+#[derive(Debug, Clone)]
+pub struct HelmRelease {
+    pub name: String,
+    pub namespace: String,
+    pub revision: i64,
+    pub status: String,
+    pub chart: String,
+    pub chart_version: String,
+    pub app_version: Option<String>,
+    pub first_deployed: Option<Timestamp>,
+    pub last_deployed: Option<Timestamp>,
+    pub description: String,
+    pub notes: String,
+    pub values: Value,
+    pub manifest: String,
+}
+
+impl HelmRelease {
+    /// Decodes [`HelmRelease`] from the helm release secret.
+    pub fn from_secret(secret: &DynamicObject) -> Result<Self, HelmReleaseError> {
+        let data = secret.data["data"]["release"].as_str().ok_or(HelmReleaseError::MissingData)?;
+        Self::decode(data)
+    }
+
+    /// Decodes [`HelmRelease`] from the `release` value of the helm release secret.\
+    /// **Note** that helm encodes gzipped release JSON with base64 before it is stored in a secret,
+    /// so the value is base64 encoded twice.
+    pub fn decode(data: &str) -> Result<Self, HelmReleaseError> {
+        let engine = engine::general_purpose::STANDARD;
+        let data = engine.decode(engine.decode(data)?)?;
+
+        let json = if data.starts_with(&GZIP_MAGIC) {
+            let mut json = Vec::new();
+            GzDecoder::new(data.as_slice()).read_to_end(&mut json)?;
+            json
+        } else {
+            data
+        };
+
+        Ok(Self::from_value(&serde_json::from_slice(&json)?))
+    }
+
+    /// Returns unique identifier of the helm release.
+    pub fn uid(&self) -> String {
+        format!("{}/{}", self.namespace, self.name)
+    }
+
+    /// Returns chart name together with its version.
+    pub fn chart_with_version(&self) -> String {
+        if self.chart_version.is_empty() {
+            self.chart.clone()
+        } else {
+            format!("{}-{}", self.chart, self.chart_version)
+        }
+    }
+
+    /// Returns [`DynamicObject`] that represents helm release on the resources list.\
+    /// **Note** that it holds only summary of the release, without values and manifest.
+    pub fn to_object(&self) -> DynamicObject {
+        DynamicObject {
+            types: None,
+            metadata: ObjectMeta {
+                name: Some(self.name.clone()),
+                namespace: Some(self.namespace.clone()),
+                uid: Some(self.uid()),
+                creation_timestamp: self.first_deployed.map(Time),
+                ..Default::default()
+            },
+            data: json!({
+                "revision": self.revision,
+                "chart": self.chart_with_version(),
+                "appVersion": self.app_version,
+                "status": self.status,
+                "updated": self.last_deployed.map(|t| t.to_string()),
+                "description": self.description,
+            }),
+        }
+    }
+
+    /// Returns history entry for the helm release revision.
+    pub fn to_history_entry(&self) -> Value {
+        json!({
+            "revision": self.revision,
+            "updated": self.last_deployed.map(|t| t.to_string()),
+            "status": self.status,
+            "chart": self.chart_with_version(),
+            "appVersion": self.app_version,
+            "description": self.description,
+        })
+    }
+
+    fn from_value(release: &Value) -> Self {
+        let info = &release["info"];
+        let metadata = &release["chart"]["metadata"];
+
+        Self {
+            name: release["name"].as_str().unwrap_or_default().to_owned(),
+            namespace: release["namespace"].as_str().unwrap_or_default().to_owned(),
+            revision: release["version"].as_i64().unwrap_or_default(),
+            status: info["status"].as_str().unwrap_or_default().to_owned(),
+            chart: metadata["name"].as_str().unwrap_or_default().to_owned(),
+            chart_version: metadata["version"].as_str().unwrap_or_default().to_owned(),
+            app_version: metadata["appVersion"].as_str().map(String::from),
+            first_deployed: info["first_deployed"].as_str().and_then(|t| t.parse().ok()),
+            last_deployed: info["last_deployed"].as_str().and_then(|t| t.parse().ok()),
+            description: info["description"].as_str().unwrap_or_default().to_owned(),
+            notes: info["notes"].as_str().unwrap_or_default().to_owned(),
+            values: release["config"].clone(),
+            manifest: release["manifest"].as_str().unwrap_or_default().to_owned(),
+        }
+    }
+}
+
+/// Returns helm release unique identifier and its revision read from the release secret labels.
+pub fn get_release_revision(secret: &DynamicObject) -> Option<(String, i64)> {
+    let labels = secret.metadata.labels.as_ref()?;
+    let name = labels.get("name")?;
+    let revision = labels.get("version")?.parse().ok()?;
+    let namespace = secret.metadata.namespace.as_deref().unwrap_or_default();
+
+    Some((format!("{namespace}/{name}"), revision))
+}
+
+/// Returns [`DynamicObject`] that identifies helm release of the release `secret` on the resources list.\
+/// **Note** that it is built from the secret labels, so the release can be removed from the list without decoding it.
+pub fn get_release_stub(secret: &DynamicObject) -> Option<DynamicObject> {
+    let name = secret.metadata.labels.as_ref()?.get("name")?;
+    let namespace = secret.metadata.namespace.as_deref().unwrap_or_default();
+
+    Some(DynamicObject {
+        types: None,
+        metadata: ObjectMeta {
+            name: Some(name.clone()),
+            namespace: Some(namespace.to_owned()),
+            uid: Some(format!("{namespace}/{name}")),
+            ..Default::default()
+        },
+        data: Value::Null,
+    })
+}
+
+/// Returns labels selector for helm release secrets.\
+/// **Note** that if `name` is provided, only secrets for the specified release are selected.
+pub fn release_labels(name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{HELM_OWNER_LABEL},name={name}"),
+        None => HELM_OWNER_LABEL.to_owned(),
+    }
+}
@@ -0,0 +1,111 @@
+use flate2::{Compression, write::GzEncoder};
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use super::*;
+
+const RELEASE: &str = r#"{
+    "name": "web",
+    "namespace": "apps",
+    "version": 3,
+    "info": {
+        "first_deployed": "2026-10-01T10:00:00.123456789+02:00",
+        "last_deployed": "2026-10-18T12:00:00Z",
+        "description": "Upgrade complete",
+        "status": "deployed",
+        "notes": "Visit http://web.local"
+    },
+    "chart": { "metadata": { "name": "nginx", "version": "1.2.3", "appVersion": "1.27.0" } },
+    "config": { "replicaCount": 2 },
+    "manifest": "---\nkind: Service\n"
+}"#;
+
+fn encode(data: &[u8], gzip: bool) -> String {
+    let engine = engine::general_purpose::STANDARD;
+    let data = if gzip {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    } else {
+        data.to_vec()
+    };
+
+    engine.encode(engine.encode(data))
+}
+
+#[test]
+fn decode_test() {
+    for gzip in [true, false] {
+        let release = HelmRelease::decode(&encode(RELEASE.as_bytes(), gzip)).unwrap();
+
+        assert_eq!("web", release.name);
+        assert_eq!("apps", release.namespace);
+        assert_eq!(3, release.revision);
+        assert_eq!("deployed", release.status);
+        assert_eq!("nginx-1.2.3", release.chart_with_version());
+        assert_eq!(Some("1.27.0"), release.app_version.as_deref());
+        assert_eq!("2026-10-01T08:00:00.123456789Z", release.first_deployed.unwrap().to_string());
+        assert_eq!("2026-10-18T12:00:00Z", release.last_deployed.unwrap().to_string());
+        assert_eq!("Visit http://web.local", release.notes);
+        assert_eq!(2, release.values["replicaCount"].as_i64().unwrap());
+        assert_eq!("---\nkind: Service\n", release.manifest);
+    }
+}
+
+#[test]
+fn decode_errors_test() {
+    let secret = DynamicObject {
+        types: None,
+        metadata: ObjectMeta::default(),
+        data: json!({ "data": {} }),
+    };
+
+    assert!(matches!(
+        HelmRelease::from_secret(&secret),
+        Err(HelmReleaseError::MissingData)
+    ));
+    assert!(matches!(
+        HelmRelease::decode("not base64!"),
+        Err(HelmReleaseError::DecodeError(_))
+    ));
+    assert!(matches!(
+        HelmRelease::decode(&encode(b"{ not json", true)),
+        Err(HelmReleaseError::ParseError(_))
+    ));
+}
+
+#[test]
+fn to_object_test() {
+    let object = HelmRelease::decode(&encode(RELEASE.as_bytes(), true)).unwrap().to_object();
+
+    assert_eq!(Some("web"), object.metadata.name.as_deref());
+    assert_eq!(Some("apps/web"), object.metadata.uid.as_deref());
+    assert_eq!(3, object.data["revision"].as_i64().unwrap());
+    assert_eq!("nginx-1.2.3", object.data["chart"].as_str().unwrap());
+    assert_eq!("2026-10-18T12:00:00Z", object.data["updated"].as_str().unwrap());
+}
+
+#[test]
+fn get_release_revision_test() {
+    let labels = BTreeMap::from([
+        ("name".to_owned(), "web".to_owned()),
+        ("owner".to_owned(), "helm".to_owned()),
+        ("version".to_owned(), "12".to_owned()),
+    ]);
+    let secret = DynamicObject {
+        types: None,
+        metadata: ObjectMeta {
+            namespace: Some("apps".to_owned()),
+            labels: Some(labels),
+            ..Default::default()
+        },
+        data: Value::Null,
+    };
+
+    assert_eq!(Some(("apps/web".to_owned(), 12)), get_release_revision(&secret));
+    let stub = get_release_stub(&secret).unwrap();
+    assert_eq!(Some("web"), stub.metadata.name.as_deref());
+    assert_eq!(Some("apps/web"), stub.metadata.uid.as_deref());
+    assert_eq!("owner=helm,name=web", release_labels(Some("web")));
+    assert_eq!("owner=helm", release_labels(None));
+}
@@ -1,18 +1,22 @@
 pub use self::core::{
-    ALL_NAMESPACES, CONTAINERS, CORE_VERSION, CRDS, DAEMON_SETS, DEPLOYMENTS, EVENTS, JOBS, NAMESPACES, NODES, PODS, PV, PVC,
-    REPLICA_SETS, SECRETS, SERVICES, STATEFUL_SETS,
+    ALL_NAMESPACES, CONFIG_MAPS, CONTAINERS, CORE_VERSION, CRDS, CRON_JOBS, DAEMON_SETS, DEPLOYMENTS, EVENTS, HELM_RELEASES, HPA,
+    JOBS, NAMESPACES, NAMESPACES_SEPARATOR, NODES, PDB, PODS, PV, PVC, REPLICA_SETS, SECRETS, SERVICE_ACCOUNTS, SERVICES,
+    STATEFUL_SETS, TLS_CERTIFICATES,
 };
 pub use self::core::{
-    ContainerRef, ContainerType, Kind, Namespace, Port, PortProtocol, PropagationPolicy, ResourceRef, ResourceRefFilter,
-    ResourceTag, is_builtin_api_group,
+    ContainerRef, ContainerType, Kind, MetadataChanges, MetadataChangesError, Namespace, Port, PortProtocol, PropagationPolicy,
+    ResourceRef, ResourceRefFilter, ResourceTag, is_builtin_api_group, parse_metadata_changes,
 };
 pub use self::discovery::{BgDiscovery, DiscoveryList, convert_to_vector};
 pub use self::watcher::{BgObserver, BgObserverError, BgObserverState, InitData, ObserverResult};
 pub use kube::discovery::Scope;
 
+pub mod certs;
 pub mod client;
 pub mod crds;
+pub mod cron;
 pub mod files;
+pub mod helm;
 pub mod plugins;
 pub mod stats;
 pub mod status;
@@ -7,6 +7,10 @@ use kube::discovery::ApiCapabilities;
 
 use crate::{DiscoveryList, Kind, ResourceTag};
 
+#[cfg(test)]
+#[path = "./utils.tests.rs"]
+mod utils_tests;
+
 /// Serializes kubernetes resource to YAML.
 pub fn serialize_resource(resource: &mut DynamicObject) -> Result<String, serde_saphyr::ser::Error> {
     resource.managed_fields_mut().clear();
@@ -19,6 +23,47 @@ pub fn serialize_resource(resource: &mut DynamicObject) -> Result<String, serde_
     Ok(yaml)
 }
 
+/// Annotation that holds the last configuration applied by `kubectl apply`.
+pub const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+/// Removes all server populated fields from the kubernetes resource, so it can be applied again.\
+/// **Note** that for services it also drops cluster IPs that were assigned by the cluster.
+pub fn clean_manifest(resource: &mut DynamicObject) {
+    let metadata = &mut resource.metadata;
+    metadata.creation_timestamp = None;
+    metadata.deletion_grace_period_seconds = None;
+    metadata.deletion_timestamp = None;
+    metadata.generation = None;
+    metadata.managed_fields = None;
+    metadata.owner_references = None;
+    metadata.resource_version = None;
+    metadata.self_link = None;
+    metadata.uid = None;
+
+    if let Some(annotations) = &mut metadata.annotations {
+        annotations.remove(LAST_APPLIED_ANNOTATION);
+        if annotations.is_empty() {
+            metadata.annotations = None;
+        }
+    }
+
+    if let Value::Object(map) = &mut resource.data {
+        map.remove("status");
+    }
+
+    let is_service = resource
+        .types
+        .as_ref()
+        .is_some_and(|t| t.kind == "Service" && t.api_version == "v1");
+    if is_service
+        && let Some(Value::Object(spec)) = resource.data.get_mut("spec")
+        && spec.get("clusterIP").and_then(Value::as_str).is_some_and(|ip| ip != "None")
+    {
+        spec.remove("clusterIP");
+        spec.remove("clusterIPs");
+    }
+}
+
 /// Encodes `data` property in the provided resource.
 pub fn encode_secret_data(data: &mut Value) {
     if let Value::Object(data) = data {
@@ -90,7 +135,22 @@ pub fn format_datetime(time: &Timestamp) -> String {
     let now = Timestamp::now();
     let duration = now.duration_since(*time);
 
-    let total_secs = duration.as_secs();
+    format_duration(duration.as_secs())
+}
+
+/// Formats time left to the datetime to a human-readable string, e.g. `in 5m02s` or `5m02s ago` if it has passed.
+pub fn format_time_left(time: &Timestamp) -> String {
+    let now = Timestamp::now();
+    let duration = time.duration_since(now);
+
+    if duration.is_negative() {
+        format!("{} ago", format_duration(duration.abs().as_secs()))
+    } else {
+        format!("in {}", format_duration(duration.as_secs()))
+    }
+}
+
+fn format_duration(total_secs: i64) -> String {
     let days = total_secs / 86_400;
     let hours = (total_secs % 86_400) / 3_600;
     let minutes = (total_secs % 3_600) / 60;
@@ -0,0 +1,79 @@
+use k8s_openapi::jiff::SignedDuration;
+use k8s_openapi::serde_json::{self, json};
+
+use super::*;
+
+fn object(value: Value) -> DynamicObject {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn clean_manifest_test() {
+    let mut deployment = object(json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": {
+            "name": "web",
+            "namespace": "apps",
+            "uid": "9b1c4a5e",
+            "resourceVersion": "1234",
+            "generation": 3,
+            "creationTimestamp": "2026-10-18T12:00:00Z",
+            "managedFields": [{ "manager": "kubectl" }],
+            "labels": { "app": "web" },
+            "annotations": { "kubectl.kubernetes.io/last-applied-configuration": "{}" }
+        },
+        "spec": { "replicas": 2 },
+        "status": { "readyReplicas": 2 }
+    }));
+
+    clean_manifest(&mut deployment);
+
+    assert_eq!(
+        json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": {
+                "name": "web",
+                "namespace": "apps",
+                "labels": { "app": "web" }
+            },
+            "spec": { "replicas": 2 }
+        }),
+        serde_json::to_value(&deployment).unwrap()
+    );
+}
+
+#[test]
+fn clean_manifest_service_test() {
+    let mut service = object(json!({
+        "apiVersion": "v1",
+        "kind": "Service",
+        "metadata": { "name": "web", "annotations": { "team": "a" } },
+        "spec": { "clusterIP": "10.0.0.12", "clusterIPs": ["10.0.0.12"], "ports": [{ "port": 80 }] }
+    }));
+    let mut headless = object(json!({
+        "apiVersion": "v1",
+        "kind": "Service",
+        "metadata": { "name": "db" },
+        "spec": { "clusterIP": "None", "clusterIPs": ["None"] }
+    }));
+
+    clean_manifest(&mut service);
+    clean_manifest(&mut headless);
+
+    assert_eq!(json!({ "ports": [{ "port": 80 }] }), service.data["spec"]);
+    assert_eq!(Some("a"), service.annotations().get("team").map(String::as_str));
+    assert_eq!(json!({ "clusterIP": "None", "clusterIPs": ["None"] }), headless.data["spec"]);
+}
+
+#[test]
+fn format_time_left_test() {
+    let future = Timestamp::now() + SignedDuration::from_secs(5 * 60 + 30);
+    let past = Timestamp::now() - SignedDuration::from_secs(5 * 60 + 30);
+
+    assert!(format_time_left(&future).starts_with("in 5m"));
+    assert!(format_time_left(&past).starts_with("5m"));
+    assert!(format_time_left(&past).ends_with(" ago"));
+    assert!(format_datetime(&past).starts_with("5m"));
+}
@@ -9,10 +9,12 @@ use tokio_util::sync::CancellationToken;
 
 use crate::utils::get_object_uid;
 use crate::watcher::client::FallbackNamespace;
+use crate::watcher::namespaces::NamespaceResults;
 use crate::watcher::state::BgObserverHealth;
 use crate::watcher::{client::ResourceClient, result::ObserverResultSender, utils};
 use crate::{BgObserverState, InitData, ObserverResult};
 
+#[derive(Clone)]
 pub struct ListInput {
     pub init_data: InitData,
     pub context_tx: ObserverResultSender,
@@ -26,6 +28,7 @@ pub struct ListInput {
 pub async fn list(
     mut client: ResourceClient,
     input: ListInput,
+    mut namespace_results: Option<NamespaceResults>,
     fields: Option<String>,
     labels: Option<String>,
     stop_on_access_error: bool,
@@ -44,9 +47,17 @@ pub async fn list(
         let resources = client.get_api().list(&params).await;
         match resources {
             Ok(objects) => {
-                results = Some(emit_results(objects, results, &input.init_data, &input.context_tx));
+                let is_ready = if let Some(namespace_results) = namespace_results.as_mut() {
+                    emit_namespace_results(objects, namespace_results, &input.context_tx)
+                } else {
+                    results = Some(emit_results(objects, results, &input.init_data, &input.context_tx));
+                    true
+                };
+
+                if is_ready {
+                    input.state.store(BgObserverState::Ready.into(), Ordering::Relaxed);
+                }
 
-                input.state.store(BgObserverState::Ready.into(), Ordering::Relaxed);
                 input.health.store(BgObserverHealth::Good.into(), Ordering::Relaxed);
                 input.has_access.store(true, Ordering::Relaxed);
             },
@@ -119,3 +130,18 @@ fn emit_results(
 
     result
 }
+
+/// Emits listed objects of a single namespace from the observed namespaces set.\
+/// **Note** that it returns `true` if all namespaces of the set were listed at least once.
+fn emit_namespace_results(
+    objects: ObjectList<DynamicObject>,
+    results: &mut NamespaceResults,
+    context_tx: &ObserverResultSender,
+) -> bool {
+    results.init(context_tx);
+    for object in objects {
+        results.send(object, false, context_tx);
+    }
+
+    results.init_done(context_tx)
+}
@@ -4,6 +4,7 @@ pub use self::state::BgObserverState;
 
 mod client;
 mod list;
+mod namespaces;
 mod observer;
 mod result;
 mod state;
@@ -0,0 +1,196 @@
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::Namespace as NamespaceObject;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use k8s_openapi::serde_json::Value;
+use kube::api::{DynamicObject, ListParams, PartialObjectMeta};
+use kube::runtime::WatchStreamExt;
+use kube::runtime::watcher::{self, Event, watcher};
+use kube::{Api, Client};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::utils::get_object_uid;
+use crate::watcher::result::ObserverResultSender;
+use crate::{InitData, Namespace, ObserverResult};
+
+#[cfg(test)]
+#[path = "./namespaces.tests.rs"]
+mod namespaces_tests;
+
+/// Merges initial lists of the observers started for each namespace of the observed namespaces set.
+pub struct InitMerger {
+    init_data: InitData,
+    is_started: AtomicBool,
+    remaining: AtomicUsize,
+}
+
+impl InitMerger {
+    /// Creates new [`InitMerger`] instance for the `count` observed namespaces.
+    pub fn new(init_data: InitData, count: usize) -> Self {
+        Self {
+            init_data,
+            is_started: AtomicBool::new(false),
+            remaining: AtomicUsize::new(count),
+        }
+    }
+
+    /// Sends `Init` result if it was not already sent by any of the namespace observers.
+    fn start(&self, context_tx: &ObserverResultSender) {
+        if !self.is_started.swap(true, Ordering::Relaxed) {
+            let _ = context_tx.send(Box::new(ObserverResult::Init(Box::new(self.init_data.clone()))));
+        }
+    }
+
+    /// Sends `InitDone` result if all namespace observers received their initial lists.
+    fn finish(&self, context_tx: &ObserverResultSender) {
+        if self.remaining.fetch_sub(1, Ordering::Relaxed) == 1 {
+            let _ = context_tx.send(Box::new(ObserverResult::InitDone));
+        }
+    }
+
+    /// Returns `true` if all namespace observers received their initial lists.
+    fn is_done(&self) -> bool {
+        self.remaining.load(Ordering::Relaxed) == 0
+    }
+}
+
+/// Sends results of the observer started for a single namespace of the observed namespaces set.\
+/// **Note** that the namespace can be listed again at any time, so objects that are not listed anymore are sent as deleted,
+/// as `Init` and `InitDone` results are sent only once for the whole set. Only minimal stubs of the sent objects are kept
+/// for that.
+pub struct NamespaceResults {
+    merger: Arc<InitMerger>,
+    known: HashMap<String, DynamicObject>,
+    listed: Option<HashSet<String>>,
+    is_synced: bool,
+}
+
+impl NamespaceResults {
+    /// Creates new [`NamespaceResults`] instance.
+    pub fn new(merger: Arc<InitMerger>) -> Self {
+        Self {
+            merger,
+            known: HashMap::new(),
+            listed: None,
+            is_synced: false,
+        }
+    }
+
+    /// Starts receiving the (initial) list of objects for the namespace.
+    pub fn init(&mut self, context_tx: &ObserverResultSender) {
+        if !self.is_synced {
+            self.merger.start(context_tx);
+        }
+
+        self.listed = Some(HashSet::new());
+    }
+
+    /// Finishes receiving the list of objects for the namespace and sends objects that were not listed as deleted.\
+    /// **Note** that it returns `true` if all namespaces of the set received their initial lists.
+    pub fn init_done(&mut self, context_tx: &ObserverResultSender) -> bool {
+        if let Some(listed) = self.listed.take() {
+            let known = std::mem::take(&mut self.known);
+            for (uid, object) in known {
+                if listed.contains(&uid) {
+                    self.known.insert(uid, object);
+                } else {
+                    let _ = context_tx.send(Box::new(ObserverResult::new(object, true)));
+                }
+            }
+        }
+
+        if !self.is_synced {
+            self.is_synced = true;
+            self.merger.finish(context_tx);
+        }
+
+        self.merger.is_done()
+    }
+
+    /// Remembers the `object` and sends it as a result.
+    pub fn send(&mut self, object: DynamicObject, is_delete: bool, context_tx: &ObserverResultSender) {
+        let uid = get_object_uid(&object);
+        if is_delete {
+            self.known.remove(&uid);
+        } else {
+            if let Some(listed) = self.listed.as_mut() {
+                listed.insert(uid.clone());
+            }
+
+            self.known.insert(uid, deletion_stub(&object));
+        }
+
+        let _ = context_tx.send(Box::new(ObserverResult::new(object, is_delete)));
+    }
+}
+
+/// Returns concrete namespaces of the observed namespaces set.\
+/// **Note** that glob patterns are resolved against the namespaces list, so they match only namespaces that exist now,
+/// use [`wait_for_namespaces_change`] to know when they should be resolved again.
+pub async fn resolve_namespaces(client: Client, namespace: &Namespace) -> Result<Vec<String>, kube::Error> {
+    if !namespace.has_patterns() {
+        return Ok(namespace.resolve(&[]));
+    }
+
+    let api = Api::<NamespaceObject>::all(client);
+    let names = api
+        .list_metadata(&ListParams::default())
+        .await?
+        .items
+        .into_iter()
+        .filter_map(|ns| ns.metadata.name)
+        .collect::<Vec<_>>();
+
+    Ok(namespace.resolve(&names))
+}
+
+/// Waits until glob patterns of the observed namespaces set match other namespaces than the `resolved` ones.\
+/// **Note** that it never returns if the set has no glob patterns or namespaces cannot be watched.
+pub async fn wait_for_namespaces_change(client: Client, namespace: &Namespace, resolved: &[String]) {
+    if !namespace.has_patterns() {
+        return futures::future::pending().await;
+    }
+
+    let resolved = resolved.iter().collect::<HashSet<_>>();
+    let api = Api::<PartialObjectMeta<NamespaceObject>>::all(client);
+    let mut events = watcher(api, watcher::Config::default()).default_backoff().boxed();
+    let mut names = HashSet::new();
+    let mut is_synced = false;
+
+    while let Some(event) = events.next().await {
+        match event {
+            Ok(Event::Init) => names.clear(),
+            Ok(Event::InitApply(ns) | Event::Apply(ns)) => names.extend(ns.metadata.name),
+            Ok(Event::InitDone) => is_synced = true,
+            Ok(Event::Delete(ns)) => {
+                if let Some(name) = ns.metadata.name {
+                    names.remove(&name);
+                }
+            },
+            Err(_) => continue,
+        }
+
+        if is_synced {
+            let known = names.iter().cloned().collect::<Vec<_>>();
+            if namespace.resolve(&known).iter().collect::<HashSet<_>>() != resolved {
+                return;
+            }
+        }
+    }
+}
+
+/// Returns minimal copy of the `object` that is enough to send it as deleted.
+fn deletion_stub(object: &DynamicObject) -> DynamicObject {
+    DynamicObject {
+        types: object.types.clone(),
+        metadata: ObjectMeta {
+            name: object.metadata.name.clone(),
+            namespace: object.metadata.namespace.clone(),
+            uid: object.metadata.uid.clone(),
+            labels: object.metadata.labels.clone(),
+            ..Default::default()
+        },
+        data: Value::Null,
+    }
+}
@@ -0,0 +1,65 @@
+use k8s_openapi::serde_json::{self, json};
+use tokio::sync::mpsc;
+
+use crate::ResourceRef;
+
+use super::*;
+
+fn pod(name: &str, namespace: &str) -> DynamicObject {
+    serde_json::from_value(json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": { "name": name, "namespace": namespace, "uid": name },
+        "spec": { "containers": [{ "name": "app", "image": "nginx" }] },
+    }))
+    .unwrap()
+}
+
+fn init_data() -> InitData {
+    let resource = ResourceRef::new("pods".into(), Namespace::from("team-a,team-b"));
+    InitData::simple(resource, "Pod".to_owned(), "pods".to_owned())
+}
+
+#[test]
+fn namespace_results_merge_initial_lists_test() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let merger = Arc::new(InitMerger::new(init_data(), 2));
+    let mut team_a = NamespaceResults::new(Arc::clone(&merger));
+    let mut team_b = NamespaceResults::new(merger);
+
+    team_a.init(&tx);
+    team_a.send(pod("web", "team-a"), false, &tx);
+    assert!(!team_a.init_done(&tx));
+    team_b.init(&tx);
+    assert!(team_b.init_done(&tx));
+
+    assert!(matches!(*rx.try_recv().unwrap(), ObserverResult::Init(_)));
+    assert!(matches!(*rx.try_recv().unwrap(), ObserverResult::Apply(_)));
+    assert!(matches!(*rx.try_recv().unwrap(), ObserverResult::InitDone));
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn namespace_results_send_not_listed_objects_as_deleted_test() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut results = NamespaceResults::new(Arc::new(InitMerger::new(init_data(), 1)));
+
+    results.init(&tx);
+    results.send(pod("web", "team-a"), false, &tx);
+    results.send(pod("api", "team-a"), false, &tx);
+    results.init_done(&tx);
+    while rx.try_recv().is_ok() {}
+
+    results.init(&tx);
+    results.send(pod("web", "team-a"), false, &tx);
+    results.init_done(&tx);
+
+    assert!(matches!(*rx.try_recv().unwrap(), ObserverResult::Apply(_)));
+    let ObserverResult::Delete(deleted) = *rx.try_recv().unwrap() else {
+        panic!("expected deleted object");
+    };
+    assert_eq!(Some("api"), deleted.metadata.name.as_deref());
+    assert_eq!(Some("team-a"), deleted.metadata.namespace.as_deref());
+    assert_eq!(Value::Null, deleted.data);
+    assert!(rx.try_recv().is_err());
+}
@@ -1,4 +1,5 @@
 use b4n_common::NotificationSink;
+use futures::FutureExt;
 use kube::Client;
 use kube::api::{ApiResource, DynamicObject};
 use kube::discovery::{ApiCapabilities, Scope, verbs};
@@ -9,10 +10,14 @@ use tokio::sync::mpsc::{self};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
+use crate::certs::TLS_SECRET_TYPE;
+use crate::helm::release_labels;
 use crate::watcher::client::{FallbackNamespace, ResourceClient};
 use crate::watcher::list::{ListInput, list};
+use crate::watcher::namespaces::{InitMerger, NamespaceResults, resolve_namespaces, wait_for_namespaces_change};
 use crate::watcher::result::{ObserverResultReceiver, ObserverResultSender};
 use crate::watcher::state::BgObserverHealth;
+use crate::watcher::utils;
 use crate::watcher::watch::{WatchInput, watch};
 use crate::{BgObserverState, InitData, Kind, Namespace, ObserverResult, ResourceRef};
 
@@ -291,13 +296,20 @@ impl BgObserver {
         let init_data = InitData::new(&self.resource, &ar, &cap, None, false);
         self.kind_singular = Some(init_data.kind.clone());
 
-        let api_client = ResourceClient::new(client, ar, cap.clone(), self.resource.namespace.clone());
-        let task = if cap.supports_operation(verbs::WATCH) {
-            self.spawn_watch_task(api_client, init_data, cancellation_token.clone())
-        } else if cap.supports_operation(verbs::LIST) {
-            self.spawn_list_task(api_client, init_data, cancellation_token.clone())
-        } else {
+        let supports_watch = cap.supports_operation(verbs::WATCH);
+        if !supports_watch && !cap.supports_operation(verbs::LIST) {
             return Err(BgObserverError::UnsupportedOperation);
+        }
+
+        let task = if self.scope == Scope::Namespaced && self.resource.namespace.is_set() {
+            self.spawn_namespaces_task(client, (ar, cap), init_data, supports_watch, cancellation_token.clone())
+        } else {
+            let api_client = ResourceClient::new(client, ar, cap, self.resource.namespace.clone());
+            if supports_watch {
+                self.spawn_watch_task(api_client, init_data, cancellation_token.clone())
+            } else {
+                self.spawn_list_task(api_client, init_data, cancellation_token.clone())
+            }
         };
 
         self.cancellation_token = Some(cancellation_token);
@@ -338,6 +350,7 @@ impl BgObserver {
                     health: Arc::clone(&self.health),
                     has_access: Arc::clone(&self.has_access),
                 },
+                None,
                 build_fields_filter(&self.resource),
                 build_labels_filter(&self.resource),
                 self.stop_on_access_error,
@@ -364,6 +377,7 @@ impl BgObserver {
                     health: Arc::clone(&self.health),
                     has_access: Arc::clone(&self.has_access),
                 },
+                None,
                 build_fields_filter(&self.resource),
                 build_labels_filter(&self.resource),
                 self.stop_on_access_error,
@@ -371,6 +385,100 @@ impl BgObserver {
             )
         })
     }
+
+    /// Spawns task that observes each namespace of the observed namespaces set separately and merges their results.\
+    /// **Note** that namespaces are observed one by one, as listing all namespaces requires cluster wide access.
+    /// Observing is restarted when glob patterns of the set start to match other namespaces.
+    fn spawn_namespaces_task(
+        &mut self,
+        client: Client,
+        discovery: (ApiResource, ApiCapabilities),
+        init_data: InitData,
+        supports_watch: bool,
+        cancellation_token: CancellationToken,
+    ) -> JoinHandle<()> {
+        let (ar, cap) = discovery;
+        let namespace = self.resource.namespace.clone();
+        let fields = build_fields_filter(&self.resource);
+        let labels = build_labels_filter(&self.resource);
+        let stop_on_access_error = self.stop_on_access_error;
+        let input = WatchInput {
+            init_data,
+            context_tx: self.context_tx.clone(),
+            footer_tx: self.footer_tx.clone(),
+            fallback: None,
+            state: Arc::clone(&self.state),
+            health: Arc::clone(&self.health),
+            has_access: Arc::clone(&self.has_access),
+        };
+
+        self.runtime.spawn(async move {
+            // observing is restarted each time glob patterns of the namespaces set start to match other namespaces
+            while !cancellation_token.is_cancelled() {
+                let namespaces = resolve_namespaces(client.clone(), &namespace).await.unwrap_or_else(|error| {
+                    utils::log_error_message(
+                        format!("Cannot resolve namespaces {namespace}: {error}"),
+                        input.footer_tx.as_ref(),
+                    );
+                    namespace.resolve(&[])
+                });
+
+                let merger = Arc::new(InitMerger::new(input.init_data.clone(), namespaces.len()));
+                if namespaces.is_empty() {
+                    let mut results = NamespaceResults::new(Arc::clone(&merger));
+                    results.init(&input.context_tx);
+                    input.state.store(BgObserverState::Ready.into(), Ordering::Relaxed);
+                    let _ = input.context_tx.send(Box::new(ObserverResult::InitDone));
+                }
+
+                let round_token = cancellation_token.child_token();
+                let tasks = namespaces
+                    .iter()
+                    .map(|name| {
+                        let client = ResourceClient::new(client.clone(), ar.clone(), cap.clone(), Namespace::from(name.as_str()));
+                        let results = Some(NamespaceResults::new(Arc::clone(&merger)));
+                        let (fields, labels, round_token) = (fields.clone(), labels.clone(), round_token.clone());
+                        if supports_watch {
+                            watch(
+                                client,
+                                input.clone(),
+                                results,
+                                fields,
+                                labels,
+                                stop_on_access_error,
+                                round_token,
+                            )
+                            .boxed()
+                        } else {
+                            let input = ListInput {
+                                init_data: input.init_data.clone(),
+                                context_tx: input.context_tx.clone(),
+                                footer_tx: input.footer_tx.clone(),
+                                fallback: None,
+                                state: Arc::clone(&input.state),
+                                health: Arc::clone(&input.health),
+                                has_access: Arc::clone(&input.has_access),
+                            };
+                            list(client, input, results, fields, labels, stop_on_access_error, round_token).boxed()
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let observe = async {
+                    if tasks.is_empty() {
+                        futures::future::pending().await
+                    } else {
+                        futures::future::join_all(tasks).await;
+                    }
+                };
+
+                tokio::select! {
+                    () = cancellation_token.cancelled() => return,
+                    () = observe => return,
+                    () = wait_for_namespaces_change(client.clone(), &namespace, &namespaces) => round_token.cancel(),
+                }
+            }
+        })
+    }
 }
 
 impl Drop for BgObserver {
@@ -380,6 +488,16 @@ impl Drop for BgObserver {
 }
 
 fn build_fields_filter(rt: &ResourceRef) -> Option<String> {
+    let fields = build_resource_fields_filter(rt);
+    if rt.kind.is_tls_certificates() {
+        let tls = format!("type={TLS_SECRET_TYPE}");
+        Some(fields.map_or(tls.clone(), |fields| format!("{tls},{fields}")))
+    } else {
+        fields
+    }
+}
+
+fn build_resource_fields_filter(rt: &ResourceRef) -> Option<String> {
     match (&rt.name, &rt.filter) {
         (Some(name), Some(filter)) => match &filter.fields {
             Some(data) => Some(format!("metadata.name={name},{data}")),
@@ -393,5 +511,11 @@ fn build_fields_filter(rt: &ResourceRef) -> Option<String> {
 }
 
 fn build_labels_filter(rt: &ResourceRef) -> Option<String> {
-    rt.filter.as_ref()?.labels.clone()
+    let labels = rt.filter.as_ref().and_then(|f| f.labels.as_deref());
+    if rt.kind.is_helm_releases() {
+        let helm = release_labels(None);
+        Some(labels.map_or(helm.clone(), |labels| format!("{helm},{labels}")))
+    } else {
+        labels.map(String::from)
+    }
 }
@@ -3,8 +3,10 @@ use kube::api::{ApiResource, DynamicObject};
 use kube::discovery::{ApiCapabilities, Scope, verbs};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
+use crate::certs::TLS_CERTIFICATE;
 use crate::crds::CrdColumns;
-use crate::{CONTAINERS, ResourceRef};
+use crate::helm::HELM_RELEASE;
+use crate::{CONTAINERS, HELM_RELEASES, ResourceRef, TLS_CERTIFICATES};
 
 pub type ObserverResultSender = UnboundedSender<Box<ObserverResult<DynamicObject>>>;
 pub type ObserverResultReceiver = UnboundedReceiver<Box<ObserverResult<DynamicObject>>>;
@@ -67,8 +69,16 @@ impl Default for InitData {
 impl InitData {
     /// Creates new initial data for [`ObserverResult`].
     pub fn new(rt: &ResourceRef, ar: &ApiResource, cap: &ApiCapabilities, crd: Option<CrdColumns>, has_metrics: bool) -> Self {
-        let kind = if rt.is_container() { "Container" } else { ar.kind.as_str() };
-        let kind_plural = if rt.is_container() { CONTAINERS } else { ar.plural.as_str() };
+        let is_virtual = rt.kind.is_virtual();
+        let (kind, kind_plural) = if rt.is_container() {
+            ("Container", CONTAINERS)
+        } else if rt.kind.is_helm_releases() {
+            (HELM_RELEASE, HELM_RELEASES)
+        } else if rt.kind.is_tls_certificates() {
+            (TLS_CERTIFICATE, TLS_CERTIFICATES)
+        } else {
+            (ar.kind.as_str(), ar.plural.as_str())
+        };
         Self {
             uuid: random_uuid(),
             resource: rt.clone(),
@@ -79,9 +89,9 @@ impl InitData {
             scope: cap.scope.clone(),
             crd,
             has_metrics,
-            is_editable: cap.supports_operation(verbs::PATCH),
-            is_creatable: cap.supports_operation(verbs::CREATE),
-            is_deletable: cap.supports_operation(verbs::DELETE),
+            is_editable: !is_virtual && cap.supports_operation(verbs::PATCH),
+            is_creatable: !is_virtual && cap.supports_operation(verbs::CREATE),
+            is_deletable: !is_virtual && cap.supports_operation(verbs::DELETE),
         }
     }
 
@@ -8,12 +8,14 @@ use std::time::Instant;
 use tokio_util::sync::CancellationToken;
 
 use crate::watcher::client::FallbackNamespace;
+use crate::watcher::namespaces::NamespaceResults;
 use crate::watcher::state::BgObserverHealth;
 use crate::watcher::{client::ResourceClient, result::ObserverResultSender, stream_backoff::StreamBackoff, utils};
 use crate::{BgObserverState, InitData, ObserverResult};
 
 const WATCH_ERROR_TIMEOUT_SECS: u64 = 120;
 
+#[derive(Clone)]
 pub struct WatchInput {
     pub init_data: InitData,
     pub context_tx: ObserverResultSender,
@@ -27,6 +29,7 @@ pub struct WatchInput {
 pub async fn watch(
     mut client: ResourceClient,
     input: WatchInput,
+    results: Option<NamespaceResults>,
     fields: Option<String>,
     labels: Option<String>,
     stop_on_access_error: bool,
@@ -35,6 +38,7 @@ pub async fn watch(
     let mut processor = EventsProcessor {
         init_data: input.init_data,
         context_tx: input.context_tx,
+        results,
         footer_tx: input.footer_tx,
         stop_on_access_error: stop_on_access_error || input.fallback.is_some(),
         state: input.state,
@@ -92,6 +96,7 @@ enum ProcessorResult {
 struct EventsProcessor {
     init_data: InitData,
     context_tx: ObserverResultSender,
+    results: Option<NamespaceResults>,
     footer_tx: Option<NotificationSink>,
     stop_on_access_error: bool,
     state: Arc<AtomicU8>,
@@ -112,10 +117,7 @@ impl EventsProcessor {
                         self.state.store(BgObserverState::Connected.into(), Ordering::Relaxed);
                         self.send_init_result();
                     },
-                    Some(Event::InitDone) => {
-                        self.state.store(BgObserverState::Ready.into(), Ordering::Relaxed);
-                        let _ = self.context_tx.send(Box::new(ObserverResult::InitDone));
-                    },
+                    Some(Event::InitDone) => self.send_init_done_result(),
                     Some(Event::InitApply(o) | Event::Apply(o)) => self.send_result(o, false),
                     Some(Event::Delete(o)) => self.send_result(o, true),
                     None => (),
@@ -175,13 +177,34 @@ impl EventsProcessor {
         ProcessorResult::Continue
     }
 
-    fn send_init_result(&self) {
-        let _ = self
-            .context_tx
-            .send(Box::new(ObserverResult::Init(Box::new(self.init_data.clone()))));
+    fn send_init_result(&mut self) {
+        if let Some(results) = self.results.as_mut() {
+            results.init(&self.context_tx);
+        } else {
+            let _ = self
+                .context_tx
+                .send(Box::new(ObserverResult::Init(Box::new(self.init_data.clone()))));
+        }
     }
 
-    fn send_result(&self, object: DynamicObject, is_delete: bool) {
-        let _ = self.context_tx.send(Box::new(ObserverResult::new(object, is_delete)));
+    fn send_init_done_result(&mut self) {
+        let is_ready = if let Some(results) = self.results.as_mut() {
+            results.init_done(&self.context_tx)
+        } else {
+            let _ = self.context_tx.send(Box::new(ObserverResult::InitDone));
+            true
+        };
+
+        if is_ready {
+            self.state.store(BgObserverState::Ready.into(), Ordering::Relaxed);
+        }
+    }
+
+    fn send_result(&mut self, object: DynamicObject, is_delete: bool) {
+        if let Some(results) = self.results.as_mut() {
+            results.send(object, is_delete, &self.context_tx);
+        } else {
+            let _ = self.context_tx.send(Box::new(ObserverResult::new(object, is_delete)));
+        }
     }
 }
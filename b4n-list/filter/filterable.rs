@@ -9,6 +9,13 @@ pub trait Filterable<Fc: FilterContext> {
     /// Builds [`FilterContext`] object that can be used to filter an item.
     fn get_context(pattern: &str, settings: Option<&str>) -> Fc;
 
+    /// Builds [`FilterContext`] object that can also reference list columns by their `columns` names.\
+    /// **Note** that by default columns are ignored.
+    fn get_context_with_columns(pattern: &str, settings: Option<&str>, columns: &[String]) -> Fc {
+        let _ = columns;
+        Self::get_context(pattern, settings)
+    }
+
     /// Checks if an item match a filter using the provided context.
     fn is_matching(&self, context: &mut Fc) -> bool;
 }
@@ -42,6 +49,7 @@ impl From<&str> for BasicFilterContext {
 pub struct FilterData<Fc: FilterContext> {
     pattern: Option<String>,
     settings: Option<String>,
+    columns: Vec<String>,
     context: Option<Fc>,
 }
 
@@ -50,6 +58,7 @@ impl<Fc: FilterContext> Default for FilterData<Fc> {
         Self {
             pattern: None,
             settings: None,
+            columns: Vec::new(),
             context: None,
         }
     }
@@ -89,6 +98,23 @@ impl<Fc: FilterContext> FilterData<Fc> {
         }
     }
 
+    /// Gets columns names that can be referenced by the pattern.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Sets columns names that can be referenced by the pattern. Returns `true` if columns were updated.\
+    /// **Note** that it clears filter context.
+    pub fn set_columns(&mut self, columns: Vec<String>) -> bool {
+        if self.columns == columns {
+            false
+        } else {
+            self.columns = columns;
+            self.context = None;
+            true
+        }
+    }
+
     /// Gets pattern for [`Filterable`] item.
     pub fn pattern(&self) -> Option<&str> {
         self.pattern.as_deref()
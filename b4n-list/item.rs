@@ -103,6 +103,11 @@ impl<T: Row + Filterable<Fc>, Fc: FilterContext> Filterable<Fc> for Item<T, Fc>
         T::get_context(pattern, settings)
     }
 
+    #[inline]
+    fn get_context_with_columns(pattern: &str, settings: Option<&str>, columns: &[String]) -> Fc {
+        T::get_context_with_columns(pattern, settings, columns)
+    }
+
     #[inline]
     fn is_matching(&self, context: &mut Fc) -> bool {
         self.data.is_matching(context)
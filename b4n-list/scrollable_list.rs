@@ -245,6 +245,18 @@ impl<T: Row + Filterable<Fc>, Fc: FilterContext> ScrollableList<T, Fc> {
         true
     }
 
+    /// Returns columns names that can be referenced by the filter.
+    pub fn filter_columns(&self) -> &[String] {
+        self.filter.columns()
+    }
+
+    /// Sets columns names that can be referenced by the filter.
+    pub fn set_filter_columns(&mut self, columns: Vec<String>) {
+        if self.filter.set_columns(columns) {
+            self.apply_filter();
+        }
+    }
+
     /// Returns filter settings for the list.
     pub fn filter_settings(&self) -> Option<&str> {
         self.filter.settings()
@@ -580,7 +592,7 @@ impl<T: Row + Filterable<Fc>, Fc: FilterContext> ScrollableList<T, Fc> {
             context.restart();
             self.items.filter(context);
         } else if let Some(filter) = self.filter.pattern() {
-            let mut context = T::get_context(filter, self.filter.settings());
+            let mut context = T::get_context_with_columns(filter, self.filter.settings(), self.filter.columns());
             self.items.filter(&mut context);
             self.filter.set_context(Some(context));
         }
@@ -0,0 +1,188 @@
+use b4n_kube::{ContainerRef, Namespace};
+use k8s_openapi::api::core::v1::{Container, Pod};
+use kube::api::{DeleteParams, ObjectMeta};
+use kube::runtime::wait::await_condition;
+use kube::{Api, Client};
+use std::time::Duration;
+
+use crate::commands::node_shell::{get_start_failure, is_pod_running};
+use crate::commands::{CommandResult, SetNewResourceYamlCommand, SetNewResourceYamlError, SetNewResourceYamlOptions};
+
+const POD_RUNNING_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Possible errors from creating debug copy of the pod.
+#[derive(thiserror::Error, Debug)]
+pub enum CopyPodError {
+    /// Unable to get the source pod.
+    #[error("unable to get pod '{pod_name}': {source}")]
+    KubeError {
+        pod_name: String,
+        #[source]
+        source: Box<kube::Error>,
+    },
+
+    /// Source pod has no containers.
+    #[error("pod '{0}' has no containers")]
+    NoContainers(String),
+
+    /// Command for the debug container cannot be parsed.
+    #[error("invalid command '{0}': unbalanced quotes")]
+    InvalidCommand(String),
+
+    /// Failed to serialize pod copy to YAML.
+    #[error("failed to serialize pod copy: {0}")]
+    SerializationError(#[from] serde_saphyr::ser::Error),
+
+    /// Failed to create pod copy.
+    #[error("{0}")]
+    CreateError(#[from] SetNewResourceYamlError),
+
+    /// Pod copy has not started in time.
+    #[error("pod '{pod_name}' has not started in {seconds} seconds")]
+    Timeout { pod_name: String, seconds: u64 },
+
+    /// Pod copy cannot start.
+    #[error("pod '{pod_name}' cannot start: {reason}")]
+    StartFailed { pod_name: String, reason: String },
+}
+
+/// Configuration for the debug copy of the pod.
+pub struct PodCopyConfig {
+    pub name: String,
+    pub container: Option<String>,
+    pub image: String,
+    pub command: String,
+}
+
+/// Command that creates debug copy of the specified pod.
+pub struct CopyPodCommand {
+    name: String,
+    namespace: Namespace,
+    client: Client,
+    config: PodCopyConfig,
+}
+
+impl CopyPodCommand {
+    /// Creates new [`CopyPodCommand`] instance.
+    pub fn new(name: String, namespace: Namespace, client: Client, config: PodCopyConfig) -> Self {
+        Self {
+            name,
+            namespace,
+            client,
+            config,
+        }
+    }
+
+    /// Creates debug copy of the pod and waits until it is running.\
+    /// **Note** that the copy is deleted if it cannot start.
+    pub async fn execute(self) -> Option<CommandResult> {
+        Some(CommandResult::CopyPod(self.copy_pod().await))
+    }
+
+    async fn copy_pod(self) -> Result<ContainerRef, CopyPodError> {
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), self.namespace.as_str());
+        let pod = api.get(&self.name).await.map_err(|e| CopyPodError::KubeError {
+            pod_name: self.name.clone(),
+            source: Box::new(e),
+        })?;
+
+        let command = if self.config.command.is_empty() {
+            None
+        } else {
+            let command = shlex::split(&self.config.command);
+            Some(command.ok_or_else(|| CopyPodError::InvalidCommand(self.config.command.clone()))?)
+        };
+
+        let (copy, container) =
+            build_pod_copy(pod, &self.config, command).ok_or_else(|| CopyPodError::NoContainers(self.name.clone()))?;
+        let yaml = serde_saphyr::to_string(&copy)?;
+        let options = SetNewResourceYamlOptions {
+            encode: false,
+            patch_status: false,
+        };
+
+        let pod_name = SetNewResourceYamlCommand::new(yaml, self.client.clone(), options)
+            .create_resource(self.client)
+            .await?;
+        let is_started = |pod: Option<&Pod>| pod.is_some_and(|p| is_pod_running(p) || get_start_failure(p).is_some());
+        let started = tokio::time::timeout(POD_RUNNING_TIMEOUT, await_condition(api.clone(), &pod_name, is_started)).await;
+        let error = match started {
+            Ok(Ok(Some(pod))) => match get_start_failure(&pod) {
+                Some(reason) => CopyPodError::StartFailed {
+                    pod_name: pod_name.clone(),
+                    reason,
+                },
+                None => return Ok(ContainerRef::simple(pod_name, self.namespace, Some(container))),
+            },
+            _ => CopyPodError::Timeout {
+                pod_name: pod_name.clone(),
+                seconds: POD_RUNNING_TIMEOUT.as_secs(),
+            },
+        };
+
+        let params = DeleteParams::default().grace_period(0);
+        if let Err(err) = api.delete(&pod_name, &params).await {
+            tracing::warn!("Cannot delete pod copy '{}': {}", pod_name, err);
+        }
+
+        Err(error)
+    }
+}
+
+/// Builds debug copy of the pod, returns it together with the name of the container to open shell in.\
+/// **Note** that owner references, labels, probes and ephemeral containers are not copied.
+fn build_pod_copy(pod: Pod, config: &PodCopyConfig, command: Option<Vec<String>>) -> Option<(Pod, String)> {
+    let mut spec = pod.spec?;
+    let target = config
+        .container
+        .as_deref()
+        .filter(|name| spec.containers.iter().any(|c| c.name == *name))
+        .or_else(|| spec.containers.first().map(|c| c.name.as_str()))?
+        .to_owned();
+
+    spec.node_name = None;
+    spec.ephemeral_containers = None;
+    for container in spec.containers.iter_mut() {
+        strip_probes(container);
+        if container.name == target {
+            override_container(container, &config.image, command.clone());
+        }
+    }
+
+    if let Some(init_containers) = spec.init_containers.as_mut() {
+        init_containers.iter_mut().for_each(strip_probes);
+    }
+
+    let copy = Pod {
+        metadata: ObjectMeta {
+            name: Some(config.name.clone()),
+            namespace: pod.metadata.namespace,
+            annotations: pod.metadata.annotations,
+            ..Default::default()
+        },
+        spec: Some(spec),
+        status: None,
+    };
+
+    Some((copy, target))
+}
+
+fn strip_probes(container: &mut Container) {
+    container.liveness_probe = None;
+    container.readiness_probe = None;
+    container.startup_probe = None;
+}
+
+fn override_container(container: &mut Container, image: &str, command: Option<Vec<String>>) {
+    if !image.is_empty() {
+        container.image = Some(image.to_owned());
+    }
+
+    if command.is_some() {
+        container.command = command;
+        container.args = None;
+    }
+
+    container.stdin = Some(true);
+    container.tty = Some(true);
+}
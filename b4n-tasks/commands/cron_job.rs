@@ -0,0 +1,184 @@
+use b4n_common::{NotificationSink, random_uuid, truncate};
+use b4n_kube::{JOBS, Namespace, ResourceRef};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+use k8s_openapi::serde_json::json;
+use kube::api::{ObjectMeta, Patch, PatchParams, PostParams};
+use kube::{Api, Client};
+use tokio::task::JoinSet;
+
+use crate::commands::CommandResult;
+
+#[cfg(test)]
+#[path = "./cron_job.tests.rs"]
+mod cron_job_tests;
+
+/// Annotation that marks jobs created manually from a cron job.
+pub const CRON_JOB_INSTANTIATE_ANNOTATION: &str = "cronjob.kubernetes.io/instantiate";
+
+/// Maximum length of the cron job name part in the generated job name.\
+/// **Note** that together with `-manual-` and 5 characters suffix it must fit in the 63 characters label value.
+const MAX_JOB_NAME_PREFIX_LEN: usize = 50;
+
+/// Possible errors from triggering cron job.
+#[derive(thiserror::Error, Debug)]
+pub enum TriggerCronJobError {
+    /// Unable to get the cron job.
+    #[error("unable to get cron job '{name}': {source}")]
+    GetError {
+        name: String,
+        #[source]
+        source: Box<kube::Error>,
+    },
+
+    /// Cron job has no UID.
+    #[error("cron job '{0}' has no UID")]
+    NoUid(String),
+
+    /// Unable to create the job.
+    #[error("unable to create job '{name}': {source}")]
+    CreateError {
+        name: String,
+        #[source]
+        source: Box<kube::Error>,
+    },
+}
+
+/// Command that creates a job from the cron job's template, the same way as `kubectl create job --from=cronjob/` does.
+pub struct TriggerCronJobCommand {
+    name: String,
+    namespace: Namespace,
+    client: Client,
+}
+
+impl TriggerCronJobCommand {
+    /// Creates new [`TriggerCronJobCommand`] instance.
+    pub fn new(name: String, namespace: Namespace, client: Client) -> Self {
+        Self { name, namespace, client }
+    }
+
+    /// Creates a new job from the cron job and returns reference to it.
+    pub async fn execute(self) -> Option<CommandResult> {
+        Some(CommandResult::TriggerCronJob(self.trigger().await))
+    }
+
+    async fn trigger(self) -> Result<ResourceRef, TriggerCronJobError> {
+        let cron_jobs: Api<CronJob> = Api::namespaced(self.client.clone(), self.namespace.as_str());
+        let cron_job = cron_jobs.get(&self.name).await.map_err(|e| TriggerCronJobError::GetError {
+            name: self.name.clone(),
+            source: Box::new(e),
+        })?;
+
+        let job = build_job(cron_job, &self.namespace).ok_or_else(|| TriggerCronJobError::NoUid(self.name.clone()))?;
+        let job_name = job.metadata.name.clone().unwrap_or_default();
+        let jobs: Api<Job> = Api::namespaced(self.client, self.namespace.as_str());
+        jobs.create(&PostParams::default(), &job)
+            .await
+            .map_err(|e| TriggerCronJobError::CreateError {
+                name: job_name.clone(),
+                source: Box::new(e),
+            })?;
+
+        Ok(ResourceRef::named(JOBS.into(), self.namespace, job_name))
+    }
+}
+
+/// Command that suspends or resumes all named cron jobs in the provided namespace.
+pub struct SuspendCronJobsCommand {
+    names: Vec<String>,
+    namespace: Namespace,
+    suspend: bool,
+    client: Client,
+    footer_tx: NotificationSink,
+}
+
+impl SuspendCronJobsCommand {
+    /// Creates new [`SuspendCronJobsCommand`] instance.
+    pub fn new(names: Vec<String>, namespace: Namespace, suspend: bool, client: Client, footer_tx: NotificationSink) -> Self {
+        Self {
+            names,
+            namespace,
+            suspend,
+            client,
+            footer_tx,
+        }
+    }
+
+    /// Patches `spec.suspend` for all cron jobs.\
+    /// **Note** that results are reported for each cron job separately in the footer.
+    pub async fn execute(self) -> Option<CommandResult> {
+        let api: Api<CronJob> = Api::namespaced(self.client, self.namespace.as_str());
+        let (action, done) = if self.suspend {
+            ("suspend", "suspended")
+        } else {
+            ("resume", "resumed")
+        };
+        let patch = json!({ "spec": { "suspend": self.suspend } });
+        let mut set = JoinSet::new();
+
+        for name in self.names {
+            let api = api.clone();
+            let patch = patch.clone();
+            let footer_tx = self.footer_tx.clone();
+
+            set.spawn(async move {
+                if let Err(err) = api.patch(&name, &PatchParams::default(), &Patch::Merge(&patch)).await {
+                    let msg = format!("Cannot {action} cron job {name}: {err}");
+                    tracing::error!("{}", msg);
+                    footer_tx.show_error(msg, 0);
+                } else {
+                    let msg = format!("Cron job {name} {done}");
+                    tracing::info!("{}", msg);
+                    footer_tx.show_info(msg, 0);
+                }
+            });
+        }
+
+        while let Some(res) = set.join_next().await {
+            if let Err(err) = res {
+                tracing::error!("Suspend cron job task failed to complete: {}", err);
+            }
+        }
+
+        None
+    }
+}
+
+/// Builds a job from the cron job's template with an owner reference pointing to the cron job.
+fn build_job(cron_job: CronJob, namespace: &Namespace) -> Option<Job> {
+    let name = cron_job.metadata.name?;
+    let uid = cron_job.metadata.uid?;
+    let template = cron_job.spec.job_template;
+    let metadata = template.metadata.unwrap_or_default();
+
+    let mut annotations = metadata.annotations.unwrap_or_default();
+    annotations.insert(CRON_JOB_INSTANTIATE_ANNOTATION.to_owned(), "manual".to_owned());
+
+    let job_name = get_job_name(&name, &random_uuid());
+
+    Some(Job {
+        metadata: ObjectMeta {
+            name: Some(job_name),
+            namespace: Some(namespace.as_str().to_owned()),
+            labels: metadata.labels,
+            annotations: Some(annotations),
+            owner_references: Some(vec![OwnerReference {
+                api_version: "batch/v1".to_owned(),
+                kind: "CronJob".to_owned(),
+                name,
+                uid,
+                controller: Some(true),
+                block_owner_deletion: Some(true),
+            }]),
+            ..Default::default()
+        },
+        spec: template.spec,
+        status: None,
+    })
+}
+
+/// Returns name for the job triggered manually from the cron job with the specified `name`.
+fn get_job_name(name: &str, suffix: &str) -> String {
+    let prefix = truncate(name, MAX_JOB_NAME_PREFIX_LEN).trim_end_matches('-');
+    format!("{prefix}-manual-{}", truncate(suffix, 5))
+}
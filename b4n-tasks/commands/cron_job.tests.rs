@@ -0,0 +1,18 @@
+use super::*;
+
+#[test]
+fn job_name_fits_in_label_value_test() {
+    let name = "a".repeat(64);
+    let job_name = get_job_name(&name, &random_uuid());
+
+    assert!(job_name.len() <= 63);
+    assert!(job_name.starts_with(&"a".repeat(50)));
+}
+
+#[test]
+fn job_name_has_no_double_dash_test() {
+    let name = format!("{}-{}", "a".repeat(49), "b".repeat(20));
+
+    assert_eq!(format!("{}-manual-12345", "a".repeat(49)), get_job_name(&name, "1234567890"));
+    assert_eq!("backup-manual-12345", get_job_name("backup", "1234567890"));
+}
@@ -0,0 +1,166 @@
+use b4n_common::{DEFAULT_ERROR_DURATION, DEFAULT_MESSAGE_DURATION, NotificationSink};
+use b4n_kube::ResourceRef;
+use b4n_kube::utils::{clean_manifest, serialize_resource};
+use kube::ResourceExt;
+use kube::api::{ApiResource, DynamicObject};
+use kube::discovery::{ApiCapabilities, verbs};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::commands::CommandResult;
+
+const MAX_CONCURRENT_GETS: usize = 8;
+
+/// Command that exports resources as clean manifests that can be applied again.\
+/// **Note** that if the `path` has a YAML extension all manifests are written to that single file as separate documents,
+/// otherwise a `<namespace>/<kind>/<name>.yaml` directory tree is created under the `path`.
+pub struct ExportResourcesCommand {
+    resources: Vec<ResourceRef>,
+    discovery: Option<(ApiResource, ApiCapabilities)>,
+    client: kube::Client,
+    path: PathBuf,
+    footer_tx: NotificationSink,
+}
+
+impl ExportResourcesCommand {
+    /// Creates new [`ExportResourcesCommand`] instance.
+    pub fn new(
+        resources: Vec<ResourceRef>,
+        discovery: Option<(ApiResource, ApiCapabilities)>,
+        client: kube::Client,
+        path: PathBuf,
+        footer_tx: NotificationSink,
+    ) -> Self {
+        Self {
+            resources,
+            discovery,
+            client,
+            path,
+            footer_tx,
+        }
+    }
+
+    /// Fetches, cleans and saves all resources.\
+    /// **Note** that errors are reported for each resource separately in the footer.
+    pub async fn execute(mut self) -> Option<CommandResult> {
+        let (ar, cap) = self.discovery.take()?;
+        if !cap.supports_operation(verbs::GET) {
+            self.footer_tx
+                .show_error(format!("Export is not supported for {}", ar.plural), DEFAULT_ERROR_DURATION);
+            return None;
+        }
+
+        let mut resources = self.fetch_resources(&ar, &cap).await;
+        if resources.is_empty() {
+            return None;
+        }
+
+        resources.sort_by_cached_key(|r| (r.namespace(), r.name_any()));
+
+        let count = resources.len();
+        let result = if is_single_file(&self.path) {
+            self.write_single_file(resources).await
+        } else {
+            self.write_directory_tree(resources, &ar.plural).await
+        };
+
+        match result {
+            Ok(()) => {
+                let msg = format!("Exported {} resource(s) to: {}", count, self.path.display());
+                tracing::info!("{}", msg);
+                self.footer_tx.show_info(msg, DEFAULT_MESSAGE_DURATION);
+            },
+            Err(msg) => {
+                tracing::error!("{}", msg);
+                self.footer_tx.show_error(msg, DEFAULT_ERROR_DURATION);
+            },
+        }
+
+        None
+    }
+
+    async fn fetch_resources(&self, ar: &ApiResource, cap: &ApiCapabilities) -> Vec<DynamicObject> {
+        let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_GETS));
+        let mut set = JoinSet::new();
+
+        for resource in &self.resources {
+            let Some(name) = resource.name.clone() else {
+                continue;
+            };
+
+            let namespace = resource.namespace.as_option();
+            let api = b4n_kube::client::get_dynamic_api(ar, cap, self.client.clone(), namespace, namespace.is_none());
+            let semaphore = Arc::clone(&semaphore);
+            set.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                api.get(&name).await.map_err(|err| (name, err))
+            });
+        }
+
+        let mut resources = Vec::with_capacity(self.resources.len());
+        while let Some(res) = set.join_next().await {
+            match res {
+                Ok(Ok(resource)) => resources.push(resource),
+                Ok(Err((name, err))) => {
+                    let msg = format!("Cannot export resource {name}: {err}");
+                    tracing::error!("{}", msg);
+                    self.footer_tx.show_error(msg, 0);
+                },
+                Err(err) => tracing::error!("Export task failed to complete: {}", err),
+            }
+        }
+
+        resources
+    }
+
+    async fn write_single_file(&self, resources: Vec<DynamicObject>) -> Result<(), String> {
+        let mut content = String::new();
+        for mut resource in resources {
+            content.push_str("---\n");
+            content.push_str(&to_manifest(&mut resource)?);
+        }
+
+        write_file(&self.path, content).await
+    }
+
+    async fn write_directory_tree(&self, resources: Vec<DynamicObject>, plural: &str) -> Result<(), String> {
+        for mut resource in resources {
+            let mut path = self.path.clone();
+            if let Some(namespace) = resource.namespace() {
+                path.push(namespace);
+            }
+
+            path.push(plural);
+            path.push(format!("{}.yaml", resource.name_any()));
+
+            write_file(&path, to_manifest(&mut resource)?).await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn is_single_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("yaml") || e.eq_ignore_ascii_case("yml"))
+}
+
+fn to_manifest(resource: &mut DynamicObject) -> Result<String, String> {
+    clean_manifest(resource);
+    serialize_resource(resource).map_err(|err| format!("Cannot serialize resource {}: {}", resource.name_any(), err))
+}
+
+async fn write_file(path: &Path, content: String) -> Result<(), String> {
+    if let Some(parent) = path.parent()
+        && let Err(error) = fs::create_dir_all(parent).await
+    {
+        return Err(format!("Cannot create directories for {}: {}", path.display(), error));
+    }
+
+    fs::write(path, content)
+        .await
+        .map_err(|error| format!("Cannot write content to {}: {}", path.display(), error))
+}
@@ -198,7 +198,11 @@ fn build_resource(
     if cap.scope == Scope::Namespaced {
         meta.insert(
             "namespace".into(),
-            Value::String(if namespace.is_all() { String::new() } else { namespace.into() }),
+            Value::String(if namespace.is_multiple() {
+                String::new()
+            } else {
+                namespace.into()
+            }),
         );
     }
 
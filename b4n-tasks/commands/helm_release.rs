@@ -0,0 +1,145 @@
+use b4n_config::themes::YamlSyntaxColors;
+use b4n_kube::helm::{HELM_RELEASE, HelmRelease, HelmReleaseError, HelmReleaseView, release_labels};
+use b4n_kube::{HELM_RELEASES, Namespace};
+use k8s_openapi::api::core::v1::Secret;
+use k8s_openapi::serde_json::Value;
+use kube::api::{ApiResource, DynamicObject, ListParams};
+use kube::{Api, Client};
+use ratatui_core::style::Style;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::commands::{CommandResult, ResourceYamlResult};
+use crate::{HighlightRequest, HighlightResourceError, highlight_yaml};
+
+/// Possible errors from fetching helm release.
+#[derive(thiserror::Error, Debug)]
+pub enum GetHelmReleaseError {
+    /// Helm release was not found.
+    #[error("helm release not found")]
+    NotFound,
+
+    /// Unable to list helm release secrets.
+    #[error("unable to list helm release secrets")]
+    ListError(#[from] kube::Error),
+
+    /// Cannot decode helm release.
+    #[error("cannot decode helm release")]
+    DecodeError(#[from] HelmReleaseError),
+
+    /// Cannot serialize helm release data to YAML.
+    #[error("cannot serialize helm release data")]
+    SerializationError(#[from] serde_saphyr::ser::Error),
+
+    /// Cannot highlight helm release data.
+    #[error("cannot highlight helm release data")]
+    HighlighterError(#[from] HighlightResourceError),
+}
+
+/// Command that decodes helm release from its secrets and returns the requested part of it.
+pub struct GetHelmReleaseCommand {
+    name: String,
+    namespace: Namespace,
+    view: HelmReleaseView,
+    client: Client,
+    highlighter: UnboundedSender<HighlightRequest>,
+    colors: YamlSyntaxColors,
+}
+
+impl GetHelmReleaseCommand {
+    /// Creates new [`GetHelmReleaseCommand`] instance.
+    pub fn new(
+        name: String,
+        namespace: Namespace,
+        view: HelmReleaseView,
+        client: Client,
+        highlighter: UnboundedSender<HighlightRequest>,
+        colors: YamlSyntaxColors,
+    ) -> Self {
+        Self {
+            name,
+            namespace,
+            view,
+            client,
+            highlighter,
+            colors,
+        }
+    }
+
+    /// Returns styled helm release values, manifest, notes or revision history.
+    pub async fn execute(self) -> Option<CommandResult> {
+        Some(CommandResult::GetHelmRelease(self.get_release().await))
+    }
+
+    async fn get_release(&self) -> Result<ResourceYamlResult, GetHelmReleaseError> {
+        let ar = ApiResource::erase::<Secret>(&());
+        let api: Api<DynamicObject> = Api::namespaced_with(self.client.clone(), self.namespace.as_str(), &ar);
+        let params = ListParams::default().labels(&release_labels(Some(&self.name)));
+
+        let secrets = api.list(&params).await?.items;
+        let mut releases = Vec::with_capacity(secrets.len());
+        let mut last_error = None;
+        for secret in &secrets {
+            match HelmRelease::from_secret(secret) {
+                Ok(release) => releases.push(release),
+                Err(error) => {
+                    let secret_name = secret.metadata.name.as_deref().unwrap_or_default();
+                    tracing::warn!("Cannot decode helm release secret '{}': {}", secret_name, error);
+                    last_error = Some(error);
+                },
+            }
+        }
+
+        if releases.is_empty()
+            && let Some(error) = last_error
+        {
+            return Err(error.into());
+        }
+
+        releases.sort_unstable_by_key(|r| r.revision);
+
+        let latest = releases.last().ok_or(GetHelmReleaseError::NotFound)?;
+        let (yaml, styled) = match self.view {
+            HelmReleaseView::Values => self.highlight(values_to_yaml(&latest.values)?).await?,
+            HelmReleaseView::Manifest => self.highlight(latest.manifest.clone()).await?,
+            HelmReleaseView::Notes => self.plain(&latest.notes),
+            HelmReleaseView::History => {
+                let history = releases.iter().rev().map(HelmRelease::to_history_entry).collect();
+                self.highlight(serde_saphyr::to_string(&Value::Array(history))?).await?
+            },
+        };
+
+        Ok(ResourceYamlResult {
+            name: self.name.clone(),
+            namespace: self.namespace.clone(),
+            kind: HELM_RELEASES.into(),
+            singular: HELM_RELEASE.to_owned(),
+            yaml,
+            styled,
+            is_decoded: false,
+            is_editable: false,
+            can_patch_status: false,
+        })
+    }
+
+    async fn highlight(&self, yaml: String) -> Result<(Vec<String>, Vec<Vec<(Style, String)>>), GetHelmReleaseError> {
+        let response = highlight_yaml(&self.highlighter, yaml).await?;
+        Ok((response.plain, response.styled))
+    }
+
+    fn plain(&self, text: &str) -> (Vec<String>, Vec<Vec<(Style, String)>>) {
+        let plain = text.lines().map(String::from).collect::<Vec<_>>();
+        let styled = plain
+            .iter()
+            .map(|l| vec![((&self.colors.string).into(), l.clone())])
+            .collect::<Vec<_>>();
+        (plain, styled)
+    }
+}
+
+fn values_to_yaml(values: &Value) -> Result<String, serde_saphyr::ser::Error> {
+    if values.as_object().is_none_or(|v| v.is_empty()) {
+        Ok("{}".to_owned())
+    } else {
+        serde_saphyr::to_string(values)
+    }
+}
@@ -1,34 +1,54 @@
-use b4n_config::{Config, History};
-use b4n_kube::{Port, ResourceRef};
+use b4n_config::{Bookmarks, Config, History};
+use b4n_kube::{ContainerRef, Port, ResourceRef};
 use kube::config::NamedContext;
 use std::path::PathBuf;
 
+pub use self::copy_pod::{CopyPodCommand, CopyPodError, PodCopyConfig};
+pub use self::cron_job::{CRON_JOB_INSTANTIATE_ANNOTATION, SuspendCronJobsCommand, TriggerCronJobCommand, TriggerCronJobError};
 pub use self::delete_resources::{DeleteResourcesCommand, DeleteResourcesOptions};
+pub use self::export_resources::ExportResourcesCommand;
 pub use self::get_new_yaml::{GetNewResourceYamlCommand, GetNewResourceYamlError, GetNewResourceYamlResult};
 pub use self::get_yaml::{GetResourceYamlCommand, ResourceYamlError, ResourceYamlResult};
+pub use self::helm_release::{GetHelmReleaseCommand, GetHelmReleaseError};
 pub use self::inject_container::{EphemeralContainerConfig, InjectContainerCommand, InjectContainerError, SecurityProfile};
 pub use self::list_contexts::ListKubeContextsCommand;
 pub use self::list_resource_ports::ListResourcePortsCommand;
 pub use self::list_themes::ListThemesCommand;
 pub use self::new_kubernetes_client::{KubernetesClientError, KubernetesClientResult, NewKubernetesClientCommand};
+pub use self::node_shell::{NODE_SHELL_COMMAND, NODE_SHELL_CONTAINER, NodeShellCommand, NodeShellError};
+pub use self::patch_metadata::PatchMetadataCommand;
+pub use self::query_resources::{QueryResourcesCommand, QueryResourcesError};
+pub use self::resource_relations::{GetResourceRelationsCommand, RelationNode, RelationState, ResourceRelationsError};
+pub use self::resource_users::{GetResourceUsersCommand, ResourceUser, ResourceUsersError};
 pub use self::run_plugin::{RunPluginCommand, RunPluginError, RunPluginOutput};
 pub use self::save_configuration::SaveConfigurationCommand;
 pub use self::save_content::SaveContentCommand;
+pub use self::search_index::{BuildSearchIndexCommand, SearchIndexItem};
 pub use self::set_new_yaml::{SetNewResourceYamlCommand, SetNewResourceYamlError, SetNewResourceYamlOptions};
 pub use self::set_yaml::{SetResourceYamlAction, SetResourceYamlCommand, SetResourceYamlError, SetResourceYamlOptions};
-pub use self::transfer_file::{TransferFileCommand, TransferFileError, TransferFileResult};
+pub use self::transfer_file::{ChecksumStatus, TransferFileCommand, TransferFileError, TransferFileResult};
 
+mod copy_pod;
+mod cron_job;
 mod delete_resources;
+mod export_resources;
 mod get_new_yaml;
 mod get_yaml;
+mod helm_release;
 mod inject_container;
 mod list_contexts;
 mod list_resource_ports;
 mod list_themes;
 mod new_kubernetes_client;
+mod node_shell;
+mod patch_metadata;
+mod query_resources;
+mod resource_relations;
+mod resource_users;
 mod run_plugin;
 mod save_configuration;
 mod save_content;
+mod search_index;
 mod set_new_yaml;
 mod set_yaml;
 mod transfer_file;
@@ -41,8 +61,10 @@ pub enum Command {
     NewKubernetesClient(Box<NewKubernetesClientCommand>),
     SaveConfig(Box<SaveConfigurationCommand<Config>>),
     SaveHistory(Box<SaveConfigurationCommand<History>>),
+    SaveBookmarks(Box<SaveConfigurationCommand<Bookmarks>>),
     SaveContent(Box<SaveContentCommand>),
     DeleteResource(Box<DeleteResourcesCommand>),
+    ExportResources(Box<ExportResourcesCommand>),
     GetNewYaml(Box<GetNewResourceYamlCommand>),
     GetYaml(Box<GetResourceYamlCommand>),
     SetNewYaml(Box<SetNewResourceYamlCommand>),
@@ -50,12 +72,25 @@ pub enum Command {
     RunPlugin(Box<RunPluginCommand>),
     InjectContainer(Box<InjectContainerCommand>),
     TransferFile(Box<TransferFileCommand>),
+    NodeShell(Box<NodeShellCommand>),
+    CopyPod(Box<CopyPodCommand>),
+    TriggerCronJob(Box<TriggerCronJobCommand>),
+    SuspendCronJobs(Box<SuspendCronJobsCommand>),
+    GetHelmRelease(Box<GetHelmReleaseCommand>),
+    QueryResources(Box<QueryResourcesCommand>),
+    PatchMetadata(Box<PatchMetadataCommand>),
+    BuildSearchIndex(Box<BuildSearchIndexCommand>),
+    GetResourceRelations(Box<GetResourceRelationsCommand>),
+    GetResourceUsers(Box<GetResourceUsersCommand>),
 }
 
 impl Command {
     /// Returns `true` if this command must be executed sequentially.
     pub fn is_sequential(&self) -> bool {
-        matches!(self, Command::SaveConfig(_) | Command::SaveHistory(_))
+        matches!(
+            self,
+            Command::SaveConfig(_) | Command::SaveHistory(_) | Command::SaveBookmarks(_)
+        )
     }
 }
 
@@ -67,9 +102,17 @@ pub enum CommandResult {
     KubernetesClient(Result<KubernetesClientResult, KubernetesClientError>),
     GetNewResourceYaml(Result<GetNewResourceYamlResult, GetNewResourceYamlError>),
     GetResourceYaml(Result<ResourceYamlResult, ResourceYamlError>),
+    GetHelmRelease(Result<ResourceYamlResult, GetHelmReleaseError>),
+    QueryResources(Result<ResourceYamlResult, QueryResourcesError>),
     SetNewResourceYaml(Result<String, SetNewResourceYamlError>),
     SetResourceYaml(Result<String, SetResourceYamlError>),
     RunPluginOutput(Result<RunPluginOutput, RunPluginError>),
     InjectedContainer(Result<ResourceRef, InjectContainerError>),
     TransferFile(Result<TransferFileResult, TransferFileError>),
+    NodeShell(Result<ContainerRef, NodeShellError>),
+    CopyPod(Result<ContainerRef, CopyPodError>),
+    TriggerCronJob(Result<ResourceRef, TriggerCronJobError>),
+    SearchIndex(Vec<SearchIndexItem>),
+    ResourceRelations(Result<RelationNode, ResourceRelationsError>),
+    ResourceUsers(Result<Vec<ResourceUser>, ResourceUsersError>),
 }
@@ -0,0 +1,227 @@
+use b4n_common::random_uuid;
+use b4n_config::APP_NAME;
+use b4n_kube::{ContainerRef, Namespace};
+use k8s_openapi::api::core::v1::{
+    Container, HostPathVolumeSource, Pod, PodSpec, SecurityContext, Toleration, Volume, VolumeMount,
+};
+use kube::api::{DeleteParams, ObjectMeta, PostParams};
+use kube::runtime::wait::await_condition;
+use kube::{Api, Client};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::commands::CommandResult;
+
+/// Name of the container in the node shell pod.
+pub const NODE_SHELL_CONTAINER: &str = "shell";
+
+/// Command that enters the host namespaces from the node shell pod.
+pub const NODE_SHELL_COMMAND: [&str; 12] = [
+    "nsenter",
+    "--target",
+    "1",
+    "--mount",
+    "--uts",
+    "--ipc",
+    "--net",
+    "--pid",
+    "--",
+    "sh",
+    "-c",
+    "command -v bash >/dev/null 2>&1 && exec bash -l || exec sh -l",
+];
+
+const POD_RUNNING_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Maximum lifetime of the node shell pod, it is stopped by kubernetes even if b4n could not delete it.
+const POD_ACTIVE_DEADLINE_SECONDS: i64 = 8 * 60 * 60;
+
+/// Container waiting reasons the started pod will not recover from.
+const TERMINAL_WAITING_REASONS: [&str; 8] = [
+    "ErrImagePull",
+    "ImagePullBackOff",
+    "ErrImageNeverPull",
+    "InvalidImageName",
+    "CreateContainerConfigError",
+    "CreateContainerError",
+    "RunContainerError",
+    "CrashLoopBackOff",
+];
+
+/// Possible errors from creating node shell pod.
+#[derive(thiserror::Error, Debug)]
+pub enum NodeShellError {
+    /// Unable to create node shell pod.
+    #[error("unable to create node shell pod on '{node_name}': {source}")]
+    KubeError {
+        node_name: String,
+        #[source]
+        source: Box<kube::Error>,
+    },
+
+    /// Node shell pod has not started in time.
+    #[error("node shell pod '{pod_name}' has not started in {seconds} seconds")]
+    Timeout { pod_name: String, seconds: u64 },
+
+    /// Node shell pod cannot start.
+    #[error("node shell pod '{pod_name}' cannot start: {reason}")]
+    StartFailed { pod_name: String, reason: String },
+}
+
+/// Command that creates a short-lived privileged pod pinned to the specified node.
+pub struct NodeShellCommand {
+    node_name: String,
+    namespace: Namespace,
+    image: String,
+    client: Client,
+}
+
+impl NodeShellCommand {
+    /// Creates new [`NodeShellCommand`] instance.
+    pub fn new(node_name: String, namespace: Namespace, image: String, client: Client) -> Self {
+        Self {
+            node_name,
+            namespace,
+            image,
+            client,
+        }
+    }
+
+    /// Creates node shell pod and waits until it is running.
+    pub async fn execute(self) -> Option<CommandResult> {
+        let api: Api<Pod> = Api::namespaced(self.client, self.namespace.as_str());
+        Some(CommandResult::NodeShell(
+            create_node_shell_pod(api, self.node_name, self.image).await,
+        ))
+    }
+}
+
+async fn create_node_shell_pod(api: Api<Pod>, node_name: String, image: String) -> Result<ContainerRef, NodeShellError> {
+    let pod = build_node_shell_pod(&node_name, image);
+    let pod_name = pod.metadata.name.clone().unwrap_or_default();
+
+    api.create(&PostParams::default(), &pod)
+        .await
+        .map_err(|e| NodeShellError::KubeError {
+            node_name: node_name.clone(),
+            source: Box::new(e),
+        })?;
+
+    let is_started = |pod: Option<&Pod>| pod.is_some_and(|p| is_pod_running(p) || get_start_failure(p).is_some());
+    let started = tokio::time::timeout(POD_RUNNING_TIMEOUT, await_condition(api.clone(), &pod_name, is_started)).await;
+    let error = match started {
+        Ok(Ok(Some(pod))) => match get_start_failure(&pod) {
+            Some(reason) => NodeShellError::StartFailed {
+                pod_name: pod_name.clone(),
+                reason,
+            },
+            None => {
+                return Ok(ContainerRef::simple(
+                    pod_name,
+                    api.namespace().into(),
+                    Some(NODE_SHELL_CONTAINER.to_owned()),
+                ));
+            },
+        },
+        _ => NodeShellError::Timeout {
+            pod_name: pod_name.clone(),
+            seconds: POD_RUNNING_TIMEOUT.as_secs(),
+        },
+    };
+
+    let params = DeleteParams::default().grace_period(0);
+    if let Err(err) = api.delete(&pod_name, &params).await {
+        tracing::warn!("Cannot delete node shell pod '{}': {}", pod_name, err);
+    }
+
+    Err(error)
+}
+
+/// Returns `true` if the pod is running.
+pub(crate) fn is_pod_running(pod: &Pod) -> bool {
+    pod.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Running")
+}
+
+/// Returns the reason why the pod cannot start if it is in a state it will not recover from.
+pub(crate) fn get_start_failure(pod: &Pod) -> Option<String> {
+    let status = pod.status.as_ref()?;
+    if let Some(phase @ ("Failed" | "Succeeded")) = status.phase.as_deref() {
+        let reason = status.reason.as_deref().unwrap_or(phase);
+        return Some(match status.message.as_deref() {
+            Some(message) => format!("{reason}: {message}"),
+            None => reason.to_owned(),
+        });
+    }
+
+    status
+        .container_statuses
+        .iter()
+        .flatten()
+        .filter_map(|s| s.state.as_ref().and_then(|s| s.waiting.as_ref()))
+        .find_map(|waiting| {
+            let reason = waiting.reason.as_deref().filter(|r| TERMINAL_WAITING_REASONS.contains(r))?;
+            Some(match waiting.message.as_deref() {
+                Some(message) => format!("{reason}: {message}"),
+                None => reason.to_owned(),
+            })
+        })
+}
+
+fn build_node_shell_pod(node_name: &str, image: String) -> Pod {
+    let suffix = random_uuid().chars().take(5).collect::<String>();
+    let node = node_name.replace('.', "-").chars().take(40).collect::<String>();
+    let name = format!("{APP_NAME}-node-shell-{}-{suffix}", node.trim_end_matches('-'));
+    let labels = BTreeMap::from([
+        ("app.kubernetes.io/managed-by".to_owned(), APP_NAME.to_owned()),
+        ("app.kubernetes.io/component".to_owned(), "node-shell".to_owned()),
+    ]);
+
+    Pod {
+        metadata: ObjectMeta {
+            name: Some(name),
+            labels: Some(labels),
+            ..Default::default()
+        },
+        spec: Some(PodSpec {
+            node_name: Some(node_name.to_owned()),
+            host_pid: Some(true),
+            host_network: Some(true),
+            host_ipc: Some(true),
+            restart_policy: Some("Never".to_owned()),
+            termination_grace_period_seconds: Some(0),
+            active_deadline_seconds: Some(POD_ACTIVE_DEADLINE_SECONDS),
+            tolerations: Some(vec![Toleration {
+                operator: Some("Exists".to_owned()),
+                ..Default::default()
+            }]),
+            containers: vec![Container {
+                name: NODE_SHELL_CONTAINER.to_owned(),
+                image: Some(image),
+                // keeps pod alive, it is deleted when the shell session ends or stopped after the active deadline
+                command: Some(vec!["sleep".to_owned(), POD_ACTIVE_DEADLINE_SECONDS.to_string()]),
+                stdin: Some(true),
+                tty: Some(true),
+                security_context: Some(SecurityContext {
+                    privileged: Some(true),
+                    ..Default::default()
+                }),
+                volume_mounts: Some(vec![VolumeMount {
+                    name: "host-root".to_owned(),
+                    mount_path: "/host".to_owned(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }],
+            volumes: Some(vec![Volume {
+                name: "host-root".to_owned(),
+                host_path: Some(HostPathVolumeSource {
+                    path: "/".to_owned(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,105 @@
+use b4n_common::{DEFAULT_ERROR_DURATION, NotificationSink};
+use b4n_kube::{MetadataChanges, ResourceRef};
+use kube::api::{ApiResource, DynamicObject, Patch, PatchParams};
+use kube::discovery::{ApiCapabilities, verbs};
+use kube::{Api, Client};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+
+use crate::commands::CommandResult;
+
+/// Command that adds, overwrites or removes labels and annotations of the specified resources.\
+/// **Note** that if `overwrite` is not set, resources that already have different values for the changed keys are skipped.
+pub struct PatchMetadataCommand {
+    resources: Vec<ResourceRef>,
+    changes: MetadataChanges,
+    overwrite: bool,
+    discovery: Option<(ApiResource, ApiCapabilities)>,
+    client: Client,
+    footer_tx: NotificationSink,
+}
+
+impl PatchMetadataCommand {
+    /// Creates new [`PatchMetadataCommand`] instance.
+    pub fn new(
+        resources: Vec<ResourceRef>,
+        changes: MetadataChanges,
+        overwrite: bool,
+        discovery: Option<(ApiResource, ApiCapabilities)>,
+        client: Client,
+        footer_tx: NotificationSink,
+    ) -> Self {
+        Self {
+            resources,
+            changes,
+            overwrite,
+            discovery,
+            client,
+            footer_tx,
+        }
+    }
+
+    /// Applies metadata changes as a merge patch to each resource.\
+    /// **Note** that results are reported for each resource separately in the footer.
+    pub async fn execute(mut self) -> Option<CommandResult> {
+        let (ar, cap) = self.discovery.take()?;
+        if !cap.supports_operation(verbs::PATCH) {
+            self.footer_tx
+                .show_error(format!("Patch is not supported for {}", ar.plural), DEFAULT_ERROR_DURATION);
+            return None;
+        }
+
+        let changes = Arc::new(self.changes);
+        let mut set = JoinSet::new();
+
+        for resource in self.resources {
+            let Some(name) = resource.name else {
+                continue;
+            };
+
+            let namespace = resource.namespace.as_option();
+            let api = b4n_kube::client::get_dynamic_api(&ar, &cap, self.client.clone(), namespace, namespace.is_none());
+            let changes = Arc::clone(&changes);
+            let footer_tx = self.footer_tx.clone();
+            let overwrite = self.overwrite;
+
+            set.spawn(async move {
+                match patch_metadata(&api, &name, &changes, overwrite).await {
+                    Ok(()) => {
+                        let msg = format!("Updated labels and annotations of {name}");
+                        tracing::info!("{}", msg);
+                        footer_tx.show_info(msg, 0);
+                    },
+                    Err(err) => {
+                        let msg = format!("Cannot update labels and annotations of {name}: {err}");
+                        tracing::error!("{}", msg);
+                        footer_tx.show_error(msg, 0);
+                    },
+                }
+            });
+        }
+
+        while let Some(res) = set.join_next().await {
+            if let Err(err) = res {
+                tracing::error!("Patch metadata task failed to complete: {}", err);
+            }
+        }
+
+        None
+    }
+}
+
+async fn patch_metadata(api: &Api<DynamicObject>, name: &str, changes: &MetadataChanges, overwrite: bool) -> Result<(), String> {
+    if !overwrite {
+        let resource = api.get(name).await.map_err(|err| err.to_string())?;
+        let conflicts = changes.conflicts(&resource.metadata);
+        if !conflicts.is_empty() {
+            return Err(format!("'{}' already set to a different value", conflicts.join("', '")));
+        }
+    }
+
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&changes.to_patch()))
+        .await
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
@@ -0,0 +1,304 @@
+use b4n_config::themes::YamlSyntaxColors;
+use b4n_kube::{Namespace, ResourceRef};
+use jsonpath_rust::parser::errors::JsonPathError;
+use jsonpath_rust::parser::model::JpQuery;
+use jsonpath_rust::parser::parse_json_path;
+use jsonpath_rust::query::js_path_process;
+use k8s_openapi::serde_json::{self, Value};
+use kube::api::{ApiResource, DynamicObject};
+use kube::discovery::{ApiCapabilities, verbs};
+use kube::{Client, ResourceExt};
+use ratatui_core::style::Style;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::task::JoinSet;
+
+use crate::commands::{CommandResult, ResourceYamlResult};
+use crate::{HighlightRequest, HighlightResourceError, highlight_yaml};
+
+const TABLE_COLUMNS_SEPARATOR: &str = "   ";
+const MAX_CONCURRENT_GETS: usize = 8;
+
+/// Possible errors from querying resources.
+#[derive(thiserror::Error, Debug)]
+pub enum QueryResourcesError {
+    /// Get is not supported for the specified resource.
+    #[error("get is not supported for the specified resource")]
+    GetNotSupported,
+
+    /// There are no resources to query.
+    #[error("there are no resources to query")]
+    NoResources,
+
+    /// Provided JSONPath expression is not valid.
+    #[error("invalid JSONPath expression: {0}")]
+    InvalidExpression(#[from] JsonPathError),
+
+    /// Unable to retrieve resource.
+    #[error("unable to retrieve resource")]
+    GetError(#[from] kube::Error),
+
+    /// Cannot serialize query results to YAML.
+    #[error("cannot serialize query results")]
+    SerializationError(#[from] serde_saphyr::ser::Error),
+
+    /// Cannot highlight query results.
+    #[error("cannot highlight query results")]
+    HighlighterError(#[from] HighlightResourceError),
+}
+
+/// Command that evaluates JSONPath expression against the specified resources.
+pub struct QueryResourcesCommand {
+    resources: Vec<ResourceRef>,
+    expression: String,
+    as_table: bool,
+    discovery: Option<(ApiResource, ApiCapabilities)>,
+    client: Client,
+    highlighter: UnboundedSender<HighlightRequest>,
+    colors: YamlSyntaxColors,
+}
+
+impl QueryResourcesCommand {
+    /// Creates new [`QueryResourcesCommand`] instance.
+    pub fn new(
+        resources: Vec<ResourceRef>,
+        expression: String,
+        as_table: bool,
+        discovery: Option<(ApiResource, ApiCapabilities)>,
+        client: Client,
+        highlighter: UnboundedSender<HighlightRequest>,
+        colors: YamlSyntaxColors,
+    ) -> Self {
+        Self {
+            resources,
+            expression,
+            as_table,
+            discovery,
+            client,
+            highlighter,
+            colors,
+        }
+    }
+
+    /// Returns query results styled either as a table or as YAML.
+    pub async fn execute(mut self) -> Option<CommandResult> {
+        Some(CommandResult::QueryResources(self.query().await))
+    }
+
+    async fn query(&mut self) -> Result<ResourceYamlResult, QueryResourcesError> {
+        let (ar, cap) = self.discovery.take().ok_or(QueryResourcesError::GetNotSupported)?;
+        if !cap.supports_operation(verbs::GET) {
+            return Err(QueryResourcesError::GetNotSupported);
+        }
+
+        let first = self.resources.first().ok_or(QueryResourcesError::NoResources)?;
+        let query = parse_json_path(&self.expression)?;
+        let kind = first.kind.clone();
+        let namespace = if self.resources.len() == 1 {
+            first.namespace.clone()
+        } else {
+            Namespace::all()
+        };
+
+        let objects = self.fetch_resources(&ar, &cap).await?;
+        let results = objects
+            .into_iter()
+            .map(|o| match o {
+                Ok(object) => evaluate(&query, &object),
+                Err(error) => Ok(error),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (yaml, styled) = if self.as_table {
+            self.table(&results)
+        } else {
+            let response = highlight_yaml(&self.highlighter, to_yaml(&results)?).await?;
+            (response.plain, response.styled)
+        };
+
+        Ok(ResourceYamlResult {
+            name: self.expression.clone(),
+            namespace,
+            kind,
+            singular: ar.kind,
+            yaml,
+            styled,
+            is_decoded: false,
+            is_editable: false,
+            can_patch_status: false,
+        })
+    }
+
+    /// Fetches all resources, failed ones are returned as [`QueryResult`] with the error.\
+    /// **Note** that it returns the first error if none of the resources can be fetched.
+    async fn fetch_resources(
+        &self,
+        ar: &ApiResource,
+        cap: &ApiCapabilities,
+    ) -> Result<Vec<Result<DynamicObject, QueryResult>>, QueryResourcesError> {
+        let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_GETS));
+        let mut set = JoinSet::new();
+
+        for (index, resource) in self.resources.iter().enumerate() {
+            let Some(name) = resource.name.clone() else {
+                continue;
+            };
+
+            let namespace = resource.namespace.as_option();
+            let api = b4n_kube::client::get_dynamic_api(ar, cap, self.client.clone(), namespace, namespace.is_none());
+            let namespace = namespace.map(String::from).unwrap_or_default();
+            let semaphore = Arc::clone(&semaphore);
+            set.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                (index, api.get(&name).await.map_err(|err| (namespace, name, err)))
+            });
+        }
+
+        let mut objects = Vec::with_capacity(self.resources.len());
+        let mut first_error = None;
+        while let Some(res) = set.join_next().await {
+            match res {
+                Ok((index, Ok(object))) => objects.push((index, Ok(object))),
+                Ok((index, Err((namespace, name, err)))) => {
+                    tracing::error!("Cannot query resource {}: {}", name, err);
+                    objects.push((index, Err(QueryResult::error(namespace, name, &err))));
+                    first_error.get_or_insert(err);
+                },
+                Err(err) => tracing::error!("Query task failed to complete: {}", err),
+            }
+        }
+
+        if let Some(err) = first_error
+            && objects.iter().all(|(_, o)| o.is_err())
+        {
+            return Err(err.into());
+        }
+
+        objects.sort_unstable_by_key(|(index, _)| *index);
+        Ok(objects.into_iter().map(|(_, o)| o).collect())
+    }
+
+    fn table(&self, results: &[QueryResult]) -> (Vec<String>, Vec<Vec<(Style, String)>>) {
+        let has_namespace = results.iter().any(|r| !r.namespace.is_empty());
+        let rows = results
+            .iter()
+            .map(|r| (r.namespace.as_str(), r.name.as_str(), r.values_to_string()))
+            .collect::<Vec<_>>();
+        let ns_width = rows.iter().map(|r| r.0.chars().count()).max().unwrap_or_default().max(9);
+        let name_width = rows.iter().map(|r| r.1.chars().count()).max().unwrap_or_default().max(4);
+
+        let header = if has_namespace {
+            format!("{:<ns_width$}{TABLE_COLUMNS_SEPARATOR}", "NAMESPACE")
+        } else {
+            String::new()
+        };
+        let header = format!("{header}{:<name_width$}{TABLE_COLUMNS_SEPARATOR}RESULT", "NAME");
+
+        let mut plain = Vec::with_capacity(rows.len() + 1);
+        let mut styled = Vec::with_capacity(rows.len() + 1);
+        styled.push(vec![((&self.colors.property).into(), header.clone())]);
+        plain.push(header);
+
+        for (namespace, name, values) in rows {
+            let key = if has_namespace {
+                format!("{namespace:<ns_width$}{TABLE_COLUMNS_SEPARATOR}{name:<name_width$}{TABLE_COLUMNS_SEPARATOR}")
+            } else {
+                format!("{name:<name_width$}{TABLE_COLUMNS_SEPARATOR}")
+            };
+
+            plain.push(format!("{key}{values}"));
+            styled.push(vec![
+                ((&self.colors.normal).into(), key),
+                ((&self.colors.string).into(), values),
+            ]);
+        }
+
+        (plain, styled)
+    }
+}
+
+/// Values matched by the JSONPath expression for a single resource.
+struct QueryResult {
+    namespace: String,
+    name: String,
+    values: Vec<Value>,
+    error: Option<String>,
+}
+
+impl QueryResult {
+    /// Creates new [`QueryResult`] instance for the resource that cannot be fetched.
+    fn error(namespace: String, name: String, error: &kube::Error) -> Self {
+        Self {
+            namespace,
+            name,
+            values: Vec::new(),
+            error: Some(error.to_string()),
+        }
+    }
+
+    /// Returns all matched values as a single line of text.
+    fn values_to_string(&self) -> String {
+        if let Some(error) = &self.error {
+            return format!("<error: {error}>");
+        }
+
+        if self.values.is_empty() {
+            return "<none>".to_owned();
+        }
+
+        self.values
+            .iter()
+            .map(|v| match v {
+                Value::String(s) => s.clone(),
+                Value::Null => "null".to_owned(),
+                _ => serde_json::to_string(v).unwrap_or_default(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Returns matched value or the list of values if there is more than one match.
+    fn to_value(&self) -> Value {
+        if self.values.len() == 1 {
+            self.values[0].clone()
+        } else {
+            Value::Array(self.values.clone())
+        }
+    }
+}
+
+fn evaluate(query: &JpQuery, object: &DynamicObject) -> Result<QueryResult, QueryResourcesError> {
+    let value = serde_json::to_value(object).unwrap_or_default();
+    let values = js_path_process(query, &value)?.into_iter().map(|r| r.val.clone()).collect();
+
+    Ok(QueryResult {
+        namespace: object.namespace().unwrap_or_default(),
+        name: object.name_any(),
+        values,
+        error: None,
+    })
+}
+
+fn to_yaml(results: &[QueryResult]) -> Result<String, serde_saphyr::ser::Error> {
+    if results.len() == 1 && results[0].error.is_none() {
+        return serde_saphyr::to_string(&results[0].to_value());
+    }
+
+    let mut yaml = String::new();
+    for result in results {
+        if result.namespace.is_empty() {
+            yaml.push_str(&format!("---\n# {}\n", result.name));
+        } else {
+            yaml.push_str(&format!("---\n# {}/{}\n", result.namespace, result.name));
+        }
+
+        if let Some(error) = &result.error {
+            yaml.push_str(&format!("# error: {error}\n"));
+        } else {
+            yaml.push_str(&serde_saphyr::to_string(&result.to_value())?);
+        }
+    }
+
+    Ok(yaml)
+}
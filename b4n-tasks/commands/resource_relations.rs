@@ -0,0 +1,472 @@
+use b4n_kube::utils::get_resource;
+use b4n_kube::{
+    CONFIG_MAPS, CRON_JOBS, DAEMON_SETS, DEPLOYMENTS, DiscoveryList, JOBS, Kind, PODS, PVC, REPLICA_SETS, ResourceRef, SECRETS,
+    SERVICE_ACCOUNTS, SERVICES, STATEFUL_SETS,
+};
+use k8s_openapi::serde_json::Value;
+use kube::api::{ApiResource, DynamicObject, ListParams};
+use kube::discovery::{ApiCapabilities, verbs};
+use kube::{Client, ResourceExt};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::pin::Pin;
+
+use crate::commands::CommandResult;
+
+#[cfg(test)]
+#[path = "./resource_relations.tests.rs"]
+mod resource_relations_tests;
+
+const MAX_OWNERS_DEPTH: usize = 8;
+const INGRESSES: &str = "ingresses";
+
+/// Possible errors from building the relations tree.
+#[derive(thiserror::Error, Debug)]
+pub enum ResourceRelationsError {
+    /// Get is not supported for the specified resource.
+    #[error("get is not supported for the specified resource")]
+    GetNotSupported,
+
+    /// Unable to retrieve resource.
+    #[error("unable to retrieve resource")]
+    GetError(#[from] kube::Error),
+}
+
+/// State of the resource in the relations tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelationState {
+    Ready,
+    InProgress,
+    Completed,
+    Failed,
+    Missing,
+}
+
+/// Single resource in the relations tree.
+#[derive(Debug, Clone)]
+pub struct RelationNode {
+    pub kind: Kind,
+    pub singular: String,
+    pub namespace: Option<String>,
+    pub name: String,
+    pub uid: Option<String>,
+    pub relation: Option<&'static str>,
+    pub status: String,
+    pub state: RelationState,
+    pub is_target: bool,
+    pub children: Vec<RelationNode>,
+}
+
+/// Command that builds the relations tree for the specified resource.\
+/// **Note** that the tree starts at the top-most owner and goes down through dependents and referenced resources.
+pub struct GetResourceRelationsCommand {
+    resource: ResourceRef,
+    discovery: DiscoveryList,
+    client: Client,
+    lists: HashMap<String, Vec<DynamicObject>>,
+}
+
+impl GetResourceRelationsCommand {
+    /// Creates new [`GetResourceRelationsCommand`] instance.
+    pub fn new(resource: ResourceRef, discovery: DiscoveryList, client: Client) -> Self {
+        Self {
+            resource,
+            discovery,
+            client,
+            lists: HashMap::new(),
+        }
+    }
+
+    /// Returns relations tree for the resource.
+    pub async fn execute(mut self) -> Option<CommandResult> {
+        Some(CommandResult::ResourceRelations(self.get_relations().await))
+    }
+
+    async fn get_relations(&mut self) -> Result<RelationNode, ResourceRelationsError> {
+        let (ar, cap) =
+            get_resource(Some(&self.discovery), &self.resource.kind).ok_or(ResourceRelationsError::GetNotSupported)?;
+        if !cap.supports_operation(verbs::GET) {
+            return Err(ResourceRelationsError::GetNotSupported);
+        }
+
+        let name = self.resource.name.as_deref().ok_or(ResourceRelationsError::GetNotSupported)?;
+        let namespace = self.resource.namespace.as_option();
+        let target = b4n_kube::client::get_dynamic_api(&ar, &cap, self.client.clone(), namespace, namespace.is_none())
+            .get(name)
+            .await?;
+        let target_uid = target.uid().unwrap_or_default();
+
+        let mut chain = vec![(ar, target)];
+        while chain.len() < MAX_OWNERS_DEPTH
+            && let Some(owner) = self.get_owner(&chain[chain.len() - 1].1).await
+        {
+            chain.push(owner);
+        }
+
+        let (ar, root) = chain.pop().ok_or(ResourceRelationsError::GetNotSupported)?;
+        chain.reverse();
+        Ok(self.build_node(ar, root, chain, &target_uid, false).await)
+    }
+
+    /// Returns controller (or the first) owner of the `object`.
+    async fn get_owner(&self, object: &DynamicObject) -> Option<(ApiResource, DynamicObject)> {
+        let owners = object.owner_references();
+        let owner = owners.iter().find(|o| o.controller == Some(true)).or(owners.first())?;
+        let kind = Kind::from_api_version(&owner.kind, &owner.api_version);
+        let (ar, cap) = get_resource(Some(&self.discovery), &kind)?;
+        let namespace = object.namespace();
+        let api = b4n_kube::client::get_dynamic_api(&ar, &cap, self.client.clone(), namespace.as_deref(), namespace.is_none());
+        let owner = api.get(&owner.name).await.ok()?;
+        Some((ar, owner))
+    }
+
+    /// Builds node for the `object` together with its dependents and referenced resources.\
+    /// **Note** that `chain` is the path from this node to the target resource that must be present in the tree.
+    fn build_node<'a>(
+        &'a mut self,
+        ar: ApiResource,
+        object: DynamicObject,
+        mut chain: Vec<(ApiResource, DynamicObject)>,
+        target_uid: &'a str,
+        has_pod_spec_parent: bool,
+    ) -> Pin<Box<dyn Future<Output = RelationNode> + Send + 'a>> {
+        Box::pin(async move {
+            let mut node = new_node(&ar, &object, None);
+            node.is_target = object.uid().is_some_and(|uid| uid == target_uid);
+
+            let uid = object.uid().unwrap_or_default();
+            let namespace = object.namespace();
+            let pod_spec = get_pod_spec(&ar.plural, &object.data);
+            let has_pod_spec = pod_spec.is_some() || has_pod_spec_parent;
+            let next = if chain.is_empty() { None } else { Some(chain.remove(0)) };
+            let mut next_chain = Some(chain);
+
+            for kind in get_dependent_kinds(&ar.plural) {
+                let Some((dependent_ar, dependent_cap)) = get_resource(Some(&self.discovery), &Kind::from(*kind)) else {
+                    continue;
+                };
+
+                let mut dependents = self
+                    .list(&dependent_ar, &dependent_cap, namespace.as_deref())
+                    .await
+                    .into_iter()
+                    .filter(|o| o.owner_references().iter().any(|r| r.uid == uid))
+                    .collect::<Vec<_>>();
+                dependents.sort_by_key(|o| o.name_any());
+
+                for dependent in dependents {
+                    let chain = if next.as_ref().is_some_and(|n| n.1.uid() == dependent.uid()) {
+                        next_chain.take().unwrap_or_default()
+                    } else {
+                        Vec::new()
+                    };
+                    let child = self
+                        .build_node(dependent_ar.clone(), dependent, chain, target_uid, has_pod_spec)
+                        .await;
+                    node.children.push(child);
+                }
+            }
+
+            if let Some((next_ar, next_object)) = next
+                && let Some(chain) = next_chain
+            {
+                let child = self.build_node(next_ar, next_object, chain, target_uid, has_pod_spec).await;
+                node.children.push(child);
+            }
+
+            let mut references = Vec::new();
+            if let Some(spec) = pod_spec
+                && !has_pod_spec_parent
+            {
+                references.extend(get_pod_spec_references(spec));
+            }
+
+            if ar.plural == SERVICES {
+                node.children.extend(self.get_selected_pods(&object).await);
+            } else if ar.plural == INGRESSES {
+                references.extend(get_ingress_references(&object.data));
+            }
+
+            let mut seen = HashSet::new();
+            references.retain(|(kind, name, _)| seen.insert((*kind, name.clone())));
+            for (kind, name, relation) in references {
+                if let Some(child) = self.get_reference(kind, &name, namespace.as_deref(), relation).await {
+                    node.children.push(child);
+                }
+            }
+
+            node
+        })
+    }
+
+    /// Returns node for the referenced resource, it is marked as missing if it does not exist.
+    async fn get_reference(
+        &self,
+        kind: &str,
+        name: &str,
+        namespace: Option<&str>,
+        relation: &'static str,
+    ) -> Option<RelationNode> {
+        let (ar, cap) = get_resource(Some(&self.discovery), &Kind::from(kind))?;
+        let api = b4n_kube::client::get_dynamic_api(&ar, &cap, self.client.clone(), namespace, namespace.is_none());
+        match api.get_opt(name).await {
+            Ok(Some(object)) => Some(new_node(&ar, &object, Some(relation))),
+            Ok(None) => Some(RelationNode {
+                kind: Kind::new(&ar.plural, &ar.group, ""),
+                singular: ar.kind.clone(),
+                namespace: namespace.map(String::from),
+                name: name.to_owned(),
+                uid: None,
+                relation: Some(relation),
+                status: "Missing".to_owned(),
+                state: RelationState::Missing,
+                is_target: false,
+                children: Vec::new(),
+            }),
+            Err(err) => {
+                tracing::warn!("Cannot get referenced {} '{}': {}", kind, name, err);
+                None
+            },
+        }
+    }
+
+    /// Returns nodes for pods matching the service selector.
+    async fn get_selected_pods(&mut self, service: &DynamicObject) -> Vec<RelationNode> {
+        let Some(selector) = service.data["spec"]["selector"].as_object().filter(|s| !s.is_empty()) else {
+            return Vec::new();
+        };
+        let Some((ar, cap)) = get_resource(Some(&self.discovery), &Kind::from(PODS)) else {
+            return Vec::new();
+        };
+
+        let mut pods = self
+            .list(&ar, &cap, service.namespace().as_deref())
+            .await
+            .into_iter()
+            .filter(|pod| is_selected(selector, pod.labels()))
+            .collect::<Vec<_>>();
+        pods.sort_by_key(|o| o.name_any());
+        pods.iter().map(|pod| new_node(&ar, pod, Some("selector"))).collect()
+    }
+
+    /// Lists resources in the `namespace`, results are cached for the lifetime of the command.
+    async fn list(&mut self, ar: &ApiResource, cap: &ApiCapabilities, namespace: Option<&str>) -> Vec<DynamicObject> {
+        let key = format!("{}.{}/{}", ar.plural, ar.group, namespace.unwrap_or_default());
+        if let Some(list) = self.lists.get(&key) {
+            return list.clone();
+        }
+
+        if !cap.supports_operation(verbs::LIST) {
+            return Vec::new();
+        }
+
+        let api = b4n_kube::client::get_dynamic_api(ar, cap, self.client.clone(), namespace, namespace.is_none());
+        let list = match api.list(&ListParams::default()).await {
+            Ok(list) => list.items,
+            Err(err) => {
+                tracing::warn!("Cannot list {} for relations: {}", ar.plural, err);
+                Vec::new()
+            },
+        };
+
+        self.lists.insert(key, list.clone());
+        list
+    }
+}
+
+fn new_node(ar: &ApiResource, object: &DynamicObject, relation: Option<&'static str>) -> RelationNode {
+    let (state, status) = get_state(&ar.plural, &object.data);
+    RelationNode {
+        kind: Kind::new(&ar.plural, &ar.group, ""),
+        singular: ar.kind.clone(),
+        namespace: object.namespace(),
+        name: object.name_any(),
+        uid: object.uid(),
+        relation,
+        status,
+        state,
+        is_target: false,
+        children: Vec::new(),
+    }
+}
+
+/// Returns kinds of resources that can be owned by the resource of the specified kind.
+fn get_dependent_kinds(plural: &str) -> &'static [&'static str] {
+    match plural {
+        DEPLOYMENTS => &["replicasets.apps"],
+        REPLICA_SETS | STATEFUL_SETS | DAEMON_SETS | JOBS => &[PODS],
+        CRON_JOBS => &["jobs.batch"],
+        _ => &[],
+    }
+}
+
+/// Returns pod spec of the pod or of the workload's pod template.
+pub(crate) fn get_pod_spec<'a>(plural: &str, data: &'a Value) -> Option<&'a Value> {
+    let spec = match plural {
+        PODS => &data["spec"],
+        DEPLOYMENTS | REPLICA_SETS | STATEFUL_SETS | DAEMON_SETS | JOBS => &data["spec"]["template"]["spec"],
+        CRON_JOBS => &data["spec"]["jobTemplate"]["spec"]["template"]["spec"],
+        _ => return None,
+    };
+
+    spec.is_object().then_some(spec)
+}
+
+/// Returns config maps, secrets, persistent volume claims and service account referenced in the pod `spec`.\
+/// **Note** that pods without explicitly set service account use the `default` one.
+pub(crate) fn get_pod_spec_references(spec: &Value) -> Vec<(&'static str, String, &'static str)> {
+    let mut result = Vec::new();
+    let mut add = |kind: &'static str, name: &Value, relation: &'static str| {
+        if let Some(name) = name.as_str()
+            && !result.iter().any(|(k, n, r)| *k == kind && n == name && *r == relation)
+        {
+            result.push((kind, name.to_owned(), relation));
+        }
+    };
+
+    let service_account = spec["serviceAccountName"].as_str().or(spec["serviceAccount"].as_str());
+    add(
+        SERVICE_ACCOUNTS,
+        &Value::from(service_account.unwrap_or("default")),
+        "service account",
+    );
+
+    for volume in iter(&spec["volumes"]) {
+        add(CONFIG_MAPS, &volume["configMap"]["name"], "volume");
+        add(SECRETS, &volume["secret"]["secretName"], "volume");
+        add(PVC, &volume["persistentVolumeClaim"]["claimName"], "volume");
+        for source in iter(&volume["projected"]["sources"]) {
+            add(CONFIG_MAPS, &source["configMap"]["name"], "volume");
+            add(SECRETS, &source["secret"]["name"], "volume");
+        }
+    }
+
+    for container in iter(&spec["initContainers"]).chain(iter(&spec["containers"])) {
+        for env in iter(&container["envFrom"]) {
+            add(CONFIG_MAPS, &env["configMapRef"]["name"], "env");
+            add(SECRETS, &env["secretRef"]["name"], "env");
+        }
+
+        for env in iter(&container["env"]) {
+            add(CONFIG_MAPS, &env["valueFrom"]["configMapKeyRef"]["name"], "env");
+            add(SECRETS, &env["valueFrom"]["secretKeyRef"]["name"], "env");
+        }
+    }
+
+    for secret in iter(&spec["imagePullSecrets"]) {
+        add(SECRETS, &secret["name"], "image pull");
+    }
+
+    result
+}
+
+/// Returns backend services and TLS secrets referenced by the ingress.
+fn get_ingress_references(data: &Value) -> Vec<(&'static str, String, &'static str)> {
+    let mut result: Vec<(&'static str, String, &'static str)> = Vec::new();
+    let backends = std::iter::once(&data["spec"]["defaultBackend"]).chain(
+        iter(&data["spec"]["rules"])
+            .flat_map(|rule| iter(&rule["http"]["paths"]))
+            .map(|path| &path["backend"]),
+    );
+
+    for backend in backends {
+        if let Some(name) = backend["service"]["name"].as_str()
+            && !result.iter().any(|(_, n, _)| n == name)
+        {
+            result.push((SERVICES, name.to_owned(), "backend"));
+        }
+    }
+
+    for tls in iter(&data["spec"]["tls"]) {
+        if let Some(name) = tls["secretName"].as_str() {
+            result.push((SECRETS, name.to_owned(), "tls"));
+        }
+    }
+
+    result
+}
+
+/// Returns state and short status text for the resource.
+fn get_state(plural: &str, data: &Value) -> (RelationState, String) {
+    let status = &data["status"];
+    match plural {
+        PODS => get_pod_state(data),
+        DEPLOYMENTS | REPLICA_SETS | STATEFUL_SETS => {
+            let desired = data["spec"]["replicas"].as_u64().unwrap_or(1);
+            get_replicas_state(status["readyReplicas"].as_u64().unwrap_or_default(), desired)
+        },
+        DAEMON_SETS => get_replicas_state(
+            status["numberReady"].as_u64().unwrap_or_default(),
+            status["desiredNumberScheduled"].as_u64().unwrap_or_default(),
+        ),
+        JOBS => {
+            if iter(&status["conditions"]).any(|c| c["type"] == "Failed" && c["status"] == "True") {
+                (RelationState::Failed, "Failed".to_owned())
+            } else if status["succeeded"].as_u64().unwrap_or_default() >= data["spec"]["completions"].as_u64().unwrap_or(1) {
+                (RelationState::Completed, "Complete".to_owned())
+            } else {
+                (RelationState::InProgress, "Running".to_owned())
+            }
+        },
+        CRON_JOBS => {
+            if data["spec"]["suspend"].as_bool().unwrap_or_default() {
+                (RelationState::Completed, "Suspended".to_owned())
+            } else {
+                let active = status["active"].as_array().map(Vec::len).unwrap_or_default();
+                (RelationState::Ready, format!("Active: {active}"))
+            }
+        },
+        PVC => match status["phase"].as_str() {
+            Some("Bound") => (RelationState::Ready, "Bound".to_owned()),
+            Some("Lost") => (RelationState::Failed, "Lost".to_owned()),
+            phase => (RelationState::InProgress, phase.unwrap_or("Pending").to_owned()),
+        },
+        _ => (RelationState::Ready, String::new()),
+    }
+}
+
+fn get_pod_state(data: &Value) -> (RelationState, String) {
+    if data["metadata"]["deletionTimestamp"].is_string() {
+        return (RelationState::Failed, "Terminating".to_owned());
+    }
+
+    let statuses = &data["status"]["containerStatuses"];
+    if let Some(reason) = iter(statuses).find_map(|s| s["state"]["waiting"]["reason"].as_str()) {
+        let state = if reason == "ContainerCreating" || reason == "PodInitializing" {
+            RelationState::InProgress
+        } else {
+            RelationState::Failed
+        };
+        return (state, reason.to_owned());
+    }
+
+    let phase = data["status"]["phase"].as_str().unwrap_or("Unknown");
+    let state = match phase {
+        "Running" if iter(statuses).all(|s| s["ready"].as_bool().unwrap_or_default()) => RelationState::Ready,
+        "Succeeded" => RelationState::Completed,
+        "Failed" | "Unknown" => RelationState::Failed,
+        _ => RelationState::InProgress,
+    };
+
+    (state, phase.to_owned())
+}
+
+fn get_replicas_state(ready: u64, desired: u64) -> (RelationState, String) {
+    let state = if ready >= desired {
+        RelationState::Ready
+    } else {
+        RelationState::InProgress
+    };
+
+    (state, format!("{ready}/{desired}"))
+}
+
+/// Returns `true` if all `selector` labels are present in the `labels`.
+fn is_selected(selector: &k8s_openapi::serde_json::Map<String, Value>, labels: &BTreeMap<String, String>) -> bool {
+    selector
+        .iter()
+        .all(|(key, value)| labels.get(key).is_some_and(|l| value.as_str() == Some(l.as_str())))
+}
+
+pub(crate) fn iter(value: &Value) -> impl Iterator<Item = &Value> {
+    value.as_array().into_iter().flatten()
+}
@@ -0,0 +1,177 @@
+use k8s_openapi::serde_json::json;
+
+use super::*;
+
+fn assert_state(plural: &str, data: &Value, expected: (RelationState, &str)) {
+    let (state, status) = get_state(plural, data);
+    assert_eq!(expected, (state, status.as_str()));
+}
+
+#[test]
+fn get_dependent_kinds_test() {
+    assert_eq!(&["replicasets.apps"], get_dependent_kinds(DEPLOYMENTS));
+    assert_eq!(&[PODS], get_dependent_kinds(REPLICA_SETS));
+    assert_eq!(&[PODS], get_dependent_kinds(JOBS));
+    assert_eq!(&["jobs.batch"], get_dependent_kinds(CRON_JOBS));
+    assert!(get_dependent_kinds(PODS).is_empty());
+    assert!(get_dependent_kinds(SERVICES).is_empty());
+}
+
+#[test]
+fn get_pod_spec_test() {
+    let pod = json!({ "spec": { "containers": [] } });
+    let deployment = json!({ "spec": { "template": { "spec": { "containers": [] } } } });
+    let cron_job = json!({ "spec": { "jobTemplate": { "spec": { "template": { "spec": { "containers": [] } } } } } });
+
+    assert_eq!(Some(&pod["spec"]), get_pod_spec(PODS, &pod));
+    assert_eq!(
+        Some(&deployment["spec"]["template"]["spec"]),
+        get_pod_spec(DEPLOYMENTS, &deployment)
+    );
+    assert_eq!(
+        Some(&deployment["spec"]["template"]["spec"]),
+        get_pod_spec(STATEFUL_SETS, &deployment)
+    );
+    assert_eq!(
+        Some(&cron_job["spec"]["jobTemplate"]["spec"]["template"]["spec"]),
+        get_pod_spec(CRON_JOBS, &cron_job)
+    );
+    assert_eq!(None, get_pod_spec(DEPLOYMENTS, &pod));
+    assert_eq!(None, get_pod_spec(SERVICES, &pod));
+}
+
+#[test]
+fn get_pod_spec_references_test() {
+    let spec = json!({
+        "serviceAccount": "legacy",
+        "volumes": [
+            { "configMap": { "name": "config" } },
+            { "projected": { "sources": [{ "configMap": { "name": "config" } }, { "secret": { "name": "token" } }] } },
+            { "persistentVolumeClaim": { "claimName": "data" } },
+        ],
+        "containers": [
+            { "envFrom": [{ "secretRef": { "name": "env" } }] },
+            { "env": [{ "valueFrom": { "configMapKeyRef": { "name": "config" } } }] },
+        ],
+        "imagePullSecrets": [{ "name": "registry" }],
+    });
+
+    let expected = vec![
+        (SERVICE_ACCOUNTS, "legacy".to_owned(), "service account"),
+        (CONFIG_MAPS, "config".to_owned(), "volume"),
+        (SECRETS, "token".to_owned(), "volume"),
+        (PVC, "data".to_owned(), "volume"),
+        (SECRETS, "env".to_owned(), "env"),
+        (CONFIG_MAPS, "config".to_owned(), "env"),
+        (SECRETS, "registry".to_owned(), "image pull"),
+    ];
+    assert_eq!(expected, get_pod_spec_references(&spec));
+
+    let default = get_pod_spec_references(&json!({ "containers": [] }));
+    assert_eq!(vec![(SERVICE_ACCOUNTS, "default".to_owned(), "service account")], default);
+}
+
+#[test]
+fn get_ingress_references_test() {
+    let ingress = json!({
+        "spec": {
+            "defaultBackend": { "service": { "name": "fallback" } },
+            "rules": [
+                { "http": { "paths": [{ "backend": { "service": { "name": "web" } } }] } },
+                { "http": { "paths": [{ "backend": { "service": { "name": "web" } } }, { "backend": { "resource": {} } }] } },
+            ],
+            "tls": [{ "secretName": "web-tls" }, { "hosts": ["example.com"] }],
+        },
+    });
+
+    let expected = vec![
+        (SERVICES, "fallback".to_owned(), "backend"),
+        (SERVICES, "web".to_owned(), "backend"),
+        (SECRETS, "web-tls".to_owned(), "tls"),
+    ];
+    assert_eq!(expected, get_ingress_references(&ingress));
+    assert!(get_ingress_references(&json!({})).is_empty());
+}
+
+#[test]
+fn get_workloads_state_test() {
+    let deployment = json!({ "spec": { "replicas": 3 }, "status": { "readyReplicas": 2 } });
+    assert_state(DEPLOYMENTS, &deployment, (RelationState::InProgress, "2/3"));
+    let deployment = json!({ "spec": {}, "status": { "readyReplicas": 1 } });
+    assert_state(DEPLOYMENTS, &deployment, (RelationState::Ready, "1/1"));
+
+    let daemon_set = json!({ "status": { "numberReady": 4, "desiredNumberScheduled": 4 } });
+    assert_state(DAEMON_SETS, &daemon_set, (RelationState::Ready, "4/4"));
+
+    let job = json!({ "spec": { "completions": 2 }, "status": { "succeeded": 1 } });
+    assert_state(JOBS, &job, (RelationState::InProgress, "Running"));
+    let job = json!({ "spec": { "completions": 2 }, "status": { "succeeded": 2 } });
+    assert_state(JOBS, &job, (RelationState::Completed, "Complete"));
+    let job = json!({ "status": { "conditions": [{ "type": "Failed", "status": "True" }] } });
+    assert_state(JOBS, &job, (RelationState::Failed, "Failed"));
+
+    let cron_job = json!({ "spec": { "suspend": true } });
+    assert_state(CRON_JOBS, &cron_job, (RelationState::Completed, "Suspended"));
+    let cron_job = json!({ "spec": {}, "status": { "active": [{}, {}] } });
+    assert_state(CRON_JOBS, &cron_job, (RelationState::Ready, "Active: 2"));
+
+    assert_state(
+        PVC,
+        &json!({ "status": { "phase": "Bound" } }),
+        (RelationState::Ready, "Bound"),
+    );
+    assert_state(
+        PVC,
+        &json!({ "status": { "phase": "Lost" } }),
+        (RelationState::Failed, "Lost"),
+    );
+    assert_state(PVC, &json!({}), (RelationState::InProgress, "Pending"));
+    assert_state(CONFIG_MAPS, &json!({}), (RelationState::Ready, ""));
+}
+
+#[test]
+fn get_pod_state_test() {
+    let pod = |phase: &str, statuses: Value| json!({ "status": { "phase": phase, "containerStatuses": statuses } });
+
+    assert_state(
+        PODS,
+        &pod("Running", json!([{ "ready": true }])),
+        (RelationState::Ready, "Running"),
+    );
+    assert_state(
+        PODS,
+        &pod("Running", json!([{ "ready": true }, { "ready": false }])),
+        (RelationState::InProgress, "Running"),
+    );
+    let waiting = |reason: &str| json!([{ "state": { "waiting": { "reason": reason } } }]);
+    assert_state(
+        PODS,
+        &pod("Running", waiting("CrashLoopBackOff")),
+        (RelationState::Failed, "CrashLoopBackOff"),
+    );
+    assert_state(
+        PODS,
+        &pod("Pending", waiting("ContainerCreating")),
+        (RelationState::InProgress, "ContainerCreating"),
+    );
+    assert_state(PODS, &pod("Succeeded", json!([])), (RelationState::Completed, "Succeeded"));
+    assert_state(PODS, &json!({}), (RelationState::Failed, "Unknown"));
+
+    let terminating = json!({ "metadata": { "deletionTimestamp": "2026-10-18T12:00:00Z" }, "status": { "phase": "Running" } });
+    assert_state(PODS, &terminating, (RelationState::Failed, "Terminating"));
+}
+
+#[test]
+fn is_selected_test() {
+    let labels = BTreeMap::from([
+        ("app".to_owned(), "web".to_owned()),
+        ("tier".to_owned(), "frontend".to_owned()),
+    ]);
+    let selector = |value: Value| value.as_object().cloned().unwrap();
+
+    assert!(is_selected(&selector(json!({ "app": "web" })), &labels));
+    assert!(is_selected(&selector(json!({ "app": "web", "tier": "frontend" })), &labels));
+    assert!(is_selected(&selector(json!({})), &labels));
+    assert!(!is_selected(&selector(json!({ "app": "api" })), &labels));
+    assert!(!is_selected(&selector(json!({ "app": "web", "env": "prod" })), &labels));
+}
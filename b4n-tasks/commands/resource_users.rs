@@ -0,0 +1,153 @@
+use b4n_kube::utils::get_resource;
+use b4n_kube::{CRON_JOBS, DAEMON_SETS, DEPLOYMENTS, DiscoveryList, JOBS, Kind, PODS, REPLICA_SETS, ResourceRef, STATEFUL_SETS};
+use kube::api::{DynamicObject, ListParams};
+use kube::discovery::verbs;
+use kube::{Client, ResourceExt};
+use std::collections::HashMap;
+
+use crate::commands::CommandResult;
+use crate::commands::resource_relations::{get_pod_spec, get_pod_spec_references};
+
+#[cfg(test)]
+#[path = "./resource_users.tests.rs"]
+mod resource_users_tests;
+
+const USER_KINDS: [&str; 7] = [PODS, DEPLOYMENTS, STATEFUL_SETS, DAEMON_SETS, REPLICA_SETS, JOBS, CRON_JOBS];
+
+/// Possible errors from looking up resource users.
+#[derive(thiserror::Error, Debug)]
+pub enum ResourceUsersError {
+    /// Unable to list resources.
+    #[error("unable to list {0}")]
+    ListError(String, #[source] kube::Error),
+}
+
+/// Pod or workload that references the resource.
+#[derive(Debug, Clone)]
+pub struct ResourceUser {
+    pub kind: Kind,
+    pub singular: String,
+    pub namespace: Option<String>,
+    pub name: String,
+    pub uid: String,
+    pub target: String,
+    pub references: Vec<&'static str>,
+}
+
+/// Command that finds pods and workloads which reference the specified config maps, secrets,
+/// persistent volume claims or service accounts.\
+/// **Note** that replica sets and jobs owned by other workloads are skipped, as their owners are already listed.
+/// Kinds that cannot be listed are skipped too, the lookup fails only if none of them can be listed.
+pub struct GetResourceUsersCommand {
+    resources: Vec<ResourceRef>,
+    discovery: DiscoveryList,
+    client: Client,
+}
+
+impl GetResourceUsersCommand {
+    /// Creates new [`GetResourceUsersCommand`] instance.
+    pub fn new(resources: Vec<ResourceRef>, discovery: DiscoveryList, client: Client) -> Self {
+        Self {
+            resources,
+            discovery,
+            client,
+        }
+    }
+
+    /// Returns pods and workloads that use the resources.
+    pub async fn execute(self) -> Option<CommandResult> {
+        Some(CommandResult::ResourceUsers(self.get_users().await))
+    }
+
+    async fn get_users(&self) -> Result<Vec<ResourceUser>, ResourceUsersError> {
+        let mut targets: HashMap<&str, Vec<(&str, &str)>> = HashMap::new();
+        for resource in &self.resources {
+            if let Some(name) = resource.name.as_deref() {
+                targets
+                    .entry(resource.namespace.as_str())
+                    .or_default()
+                    .push((resource.kind.name(), name));
+            }
+        }
+
+        let mut users = Vec::new();
+        let mut is_listed = false;
+        let mut last_error = None;
+        for (namespace, targets) in targets {
+            for kind in USER_KINDS {
+                let Some((ar, cap)) = get_resource(Some(&self.discovery), &Kind::from(kind)) else {
+                    continue;
+                };
+
+                if !cap.supports_operation(verbs::LIST) {
+                    continue;
+                }
+
+                let api = b4n_kube::client::get_dynamic_api(&ar, &cap, self.client.clone(), Some(namespace), false);
+                let list = match api.list(&ListParams::default()).await {
+                    Ok(list) => list,
+                    Err(error) => {
+                        tracing::warn!(
+                            "Cannot list {} in '{}' to find resource users: {}",
+                            ar.plural,
+                            namespace,
+                            error
+                        );
+                        last_error = Some(ResourceUsersError::ListError(ar.plural.clone(), error));
+                        continue;
+                    },
+                };
+
+                is_listed = true;
+                for object in list.items {
+                    users.extend(get_object_users(&ar.plural, &ar.group, &ar.kind, &object, &targets));
+                }
+            }
+        }
+
+        match last_error {
+            Some(error) if !is_listed => Err(error),
+            _ => Ok(users),
+        }
+    }
+}
+
+/// Returns one [`ResourceUser`] for every target referenced by the `object`.\
+/// **Note** that replica sets and jobs owned by other workloads have no users, as their owners are returned instead.
+fn get_object_users(
+    plural: &str,
+    group: &str,
+    singular: &str,
+    object: &DynamicObject,
+    targets: &[(&str, &str)],
+) -> Vec<ResourceUser> {
+    if (plural == REPLICA_SETS || plural == JOBS) && object.owner_references().iter().any(|o| o.controller == Some(true)) {
+        return Vec::new();
+    }
+
+    let Some(spec) = get_pod_spec(plural, &object.data) else {
+        return Vec::new();
+    };
+
+    let references = get_pod_spec_references(spec);
+    targets
+        .iter()
+        .filter_map(|(kind, name)| {
+            let references = references
+                .iter()
+                .filter(|(k, n, _)| k == kind && n == name)
+                .map(|(_, _, relation)| *relation)
+                .collect::<Vec<_>>();
+
+            (!references.is_empty()).then(|| ResourceUser {
+                kind: Kind::new(plural, group, ""),
+                singular: singular.to_owned(),
+                namespace: object.namespace(),
+                name: object.name_any(),
+                uid: object.uid().unwrap_or_default(),
+                target: (*name).to_owned(),
+                references,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,119 @@
+use b4n_kube::{CONFIG_MAPS, PVC, SECRETS, SERVICE_ACCOUNTS};
+use k8s_openapi::serde_json::{self, Value, json};
+
+use super::*;
+
+fn object(kind: &str, name: &str, spec: Value, owner: Option<&str>) -> DynamicObject {
+    let owners = owner.map_or_else(
+        || json!([]),
+        |owner| json!([{ "apiVersion": "apps/v1", "kind": "Deployment", "name": owner, "uid": owner, "controller": true }]),
+    );
+    serde_json::from_value(json!({
+        "apiVersion": "apps/v1",
+        "kind": kind,
+        "metadata": { "name": name, "namespace": "apps", "uid": name, "ownerReferences": owners },
+        "spec": spec,
+    }))
+    .unwrap()
+}
+
+fn pod_spec() -> Value {
+    json!({
+        "serviceAccountName": "web",
+        "volumes": [
+            { "name": "config", "configMap": { "name": "web-config" } },
+            { "name": "certs", "secret": { "secretName": "web-tls" } },
+            { "name": "data", "persistentVolumeClaim": { "claimName": "web-data" } },
+            { "name": "all", "projected": { "sources": [
+                { "configMap": { "name": "shared-config" } },
+                { "secret": { "name": "shared-secret" } },
+            ] } },
+        ],
+        "initContainers": [{ "name": "init", "envFrom": [{ "secretRef": { "name": "web-env" } }] }],
+        "containers": [{
+            "name": "app",
+            "envFrom": [{ "configMapRef": { "name": "web-env" } }],
+            "env": [
+                { "name": "A", "valueFrom": { "configMapKeyRef": { "name": "web-config", "key": "a" } } },
+                { "name": "B", "valueFrom": { "secretKeyRef": { "name": "db-credentials", "key": "b" } } },
+            ],
+        }],
+        "imagePullSecrets": [{ "name": "registry" }],
+    })
+}
+
+fn references(plural: &str, object: &DynamicObject, target: (&str, &str)) -> Vec<&'static str> {
+    let users = get_object_users(plural, "apps", "Deployment", object, &[target]);
+    users.into_iter().flat_map(|u| u.references).collect()
+}
+
+#[test]
+fn pod_users_test() {
+    let pod = object("Pod", "web-1", pod_spec(), None);
+
+    assert_eq!(vec!["volume", "env"], references(PODS, &pod, (CONFIG_MAPS, "web-config")));
+    assert_eq!(vec!["volume"], references(PODS, &pod, (SECRETS, "web-tls")));
+    assert_eq!(vec!["volume"], references(PODS, &pod, (PVC, "web-data")));
+    assert_eq!(vec!["volume"], references(PODS, &pod, (CONFIG_MAPS, "shared-config")));
+    assert_eq!(vec!["volume"], references(PODS, &pod, (SECRETS, "shared-secret")));
+    assert_eq!(vec!["env"], references(PODS, &pod, (CONFIG_MAPS, "web-env")));
+    assert_eq!(vec!["env"], references(PODS, &pod, (SECRETS, "web-env")));
+    assert_eq!(vec!["env"], references(PODS, &pod, (SECRETS, "db-credentials")));
+    assert_eq!(vec!["image pull"], references(PODS, &pod, (SECRETS, "registry")));
+    assert_eq!(vec!["service account"], references(PODS, &pod, (SERVICE_ACCOUNTS, "web")));
+    assert!(references(PODS, &pod, (SERVICE_ACCOUNTS, "default")).is_empty());
+    assert!(references(PODS, &pod, (SECRETS, "web-config")).is_empty());
+}
+
+#[test]
+fn workload_users_test() {
+    let deployment = object("Deployment", "web", json!({ "template": { "spec": pod_spec() } }), None);
+    let users = get_object_users(
+        DEPLOYMENTS,
+        "apps",
+        "Deployment",
+        &deployment,
+        &[(SECRETS, "registry"), (PVC, "web-data")],
+    );
+
+    assert_eq!(2, users.len());
+    assert_eq!("deployments.apps", users[0].kind.as_str());
+    assert_eq!(Some("apps"), users[0].namespace.as_deref());
+    assert_eq!("registry", users[0].target);
+    assert_eq!("web-data", users[1].target);
+
+    let cron_job = object(
+        "CronJob",
+        "backup",
+        json!({ "jobTemplate": { "spec": { "template": { "spec": { "containers": [] } } } } }),
+        None,
+    );
+    assert_eq!(
+        vec!["service account"],
+        references(CRON_JOBS, &cron_job, (SERVICE_ACCOUNTS, "default"))
+    );
+}
+
+#[test]
+fn owned_workloads_are_skipped_test() {
+    let spec = json!({ "template": { "spec": pod_spec() } });
+    let target = (SECRETS, "registry");
+
+    assert!(
+        references(
+            REPLICA_SETS,
+            &object("ReplicaSet", "web-7f9", spec.clone(), Some("web")),
+            target
+        )
+        .is_empty()
+    );
+    assert!(references(JOBS, &object("Job", "backup-123", spec.clone(), Some("backup")), target).is_empty());
+    assert_eq!(
+        vec!["image pull"],
+        references(REPLICA_SETS, &object("ReplicaSet", "web-7f9", spec.clone(), None), target)
+    );
+    assert_eq!(
+        vec!["image pull"],
+        references(JOBS, &object("Job", "backup-123", spec, None), target)
+    );
+}
@@ -0,0 +1,95 @@
+use b4n_kube::{DiscoveryList, EVENTS, Kind};
+use kube::api::{ApiResource, ListParams};
+use kube::discovery::{ApiCapabilities, Scope, verbs};
+use kube::{Client, ResourceExt};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::commands::CommandResult;
+
+const MAX_CONCURRENT_LISTS: usize = 8;
+const LIST_PAGE_SIZE: u32 = 500;
+
+/// Single resource name kept in the search index.
+pub struct SearchIndexItem {
+    pub kind: Kind,
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+/// Command that builds an index of resource names across all listable kinds.\
+/// **Note** that only metadata is fetched and events are skipped as they are short-lived and numerous.
+pub struct BuildSearchIndexCommand {
+    discovery: DiscoveryList,
+    client: Client,
+}
+
+impl BuildSearchIndexCommand {
+    /// Creates new [`BuildSearchIndexCommand`] instance.
+    pub fn new(discovery: DiscoveryList, client: Client) -> Self {
+        Self { discovery, client }
+    }
+
+    /// Lists metadata of all resources and returns their names.\
+    /// **Note** that kinds that cannot be listed are silently skipped.
+    pub async fn execute(self) -> Option<CommandResult> {
+        let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_LISTS));
+        let mut set = JoinSet::new();
+
+        for (ar, cap) in self.discovery {
+            if !cap.supports_operation(verbs::LIST) || ar.plural == EVENTS {
+                continue;
+            }
+
+            let client = self.client.clone();
+            let semaphore = Arc::clone(&semaphore);
+            set.spawn(async move {
+                let _permit = semaphore.acquire_owned().await.ok()?;
+                list_names(&ar, &cap, client).await
+            });
+        }
+
+        let mut items = Vec::new();
+        while let Some(result) = set.join_next().await {
+            match result {
+                Ok(Some(mut names)) => items.append(&mut names),
+                Ok(None) => (),
+                Err(err) => tracing::error!("Search index task failed to complete: {}", err),
+            }
+        }
+
+        Some(CommandResult::SearchIndex(items))
+    }
+}
+
+async fn list_names(ar: &ApiResource, cap: &ApiCapabilities, client: Client) -> Option<Vec<SearchIndexItem>> {
+    let api = b4n_kube::client::get_dynamic_api(ar, cap, client, None, true);
+    let kind = Kind::new(&ar.plural, &ar.group, "");
+    let is_namespaced = cap.scope == Scope::Namespaced;
+
+    let mut items = Vec::new();
+    let mut params = ListParams::default().limit(LIST_PAGE_SIZE);
+    loop {
+        let list = match api.list_metadata(&params).await {
+            Ok(list) => list,
+            Err(err) => {
+                tracing::warn!("Cannot index {}: {}", ar.plural, err);
+                return if items.is_empty() { None } else { Some(items) };
+            },
+        };
+
+        items.extend(list.items.iter().map(|resource| SearchIndexItem {
+            kind: kind.clone(),
+            namespace: if is_namespaced { resource.namespace() } else { None },
+            name: resource.name_any(),
+        }));
+
+        match list.metadata.continue_ {
+            Some(token) if !token.is_empty() => params = params.continue_token(&token),
+            _ => break,
+        }
+    }
+
+    Some(items)
+}
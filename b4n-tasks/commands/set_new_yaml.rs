@@ -66,7 +66,8 @@ impl SetNewResourceYamlCommand {
         }
     }
 
-    async fn create_resource(self, client: Client) -> Result<String, SetNewResourceYamlError> {
+    /// Creates resource from the YAML and returns its name.
+    pub(crate) async fn create_resource(self, client: Client) -> Result<String, SetNewResourceYamlError> {
         let mut resource = serde_saphyr::from_str::<DynamicObject>(&self.yaml)?;
         if self.options.encode
             && let Some(data) = resource.data.get_mut("data")
@@ -2,8 +2,11 @@ use b4n_common::{IconKind, NotificationSink};
 use b4n_kube::{ContainerRef, files::TransferContext};
 use k8s_openapi::api::core::v1::Pod;
 use kube::{Api, Client, api::AttachParams};
+use sha2::{Digest, Sha256};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{Duration, Instant};
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
 use tokio::runtime::Handle;
 use tokio::task::JoinHandle;
@@ -12,6 +15,7 @@ use crate::commands::CommandResult;
 
 static COUNTER: AtomicU8 = AtomicU8::new(0);
 const CHUNK_SIZE: usize = 128 * 1024;
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
 
 /// Possible file transfer errors.
 #[derive(thiserror::Error, Debug)]
@@ -45,6 +49,22 @@ pub enum TransferFileError {
 
     #[error("destination path already exists: {0}")]
     DestinationExists(String),
+
+    #[error("checksum mismatch (local: {local}, remote: {remote})")]
+    ChecksumMismatch { local: String, remote: String },
+}
+
+/// Checksum verification status of the transferred file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChecksumStatus {
+    /// Verification was not requested.
+    Skipped,
+
+    /// Checksum could not be computed, e.g. `sha256sum` is missing in the container or a directory was transferred.
+    Unavailable,
+
+    /// Local and remote SHA-256 checksums are equal.
+    Verified(String),
 }
 
 /// Result from the file transfer command.
@@ -53,6 +73,7 @@ pub struct TransferFileResult {
     pub pod: String,
     pub container: String,
     pub file: String,
+    pub checksum: ChecksumStatus,
 }
 
 /// File transfer command that sends/downloads file from/to a pod's container.
@@ -87,13 +108,18 @@ impl TransferFileCommand {
         let transfer_id = format!("200_{}", COUNTER.fetch_add(1, Ordering::Relaxed));
         let sink = self.footer.clone();
 
+        // clears progress text also when the command is cancelled and its future dropped
+        let _progress_guard = ProgressTextGuard {
+            sink: self.footer,
+            text_id: transfer_id.clone(),
+        };
+
         let result = if self.context.is_download {
             download_file(self.runtime, pods, self.resource, self.context, sink, &transfer_id).await
         } else {
             upload_file(self.runtime, pods, self.resource, self.context, sink, &transfer_id).await
         };
 
-        self.footer.set_text(&transfer_id, None::<String>, IconKind::Default);
         Some(CommandResult::TransferFile(result))
     }
 }
@@ -114,6 +140,8 @@ async fn download_file(
     let source = Path::new(&remote_from);
     let (dir, file) = split_path(source)?;
 
+    let total = remote_file_size(&pods, &resource, &context.container, &remote_from).await;
+
     let attach_params = build_attach_params(&context.container).stderr(true);
     let mut attached = pods
         .exec(&resource.name, ["tar", "cf", "-", "-C", dir, file], &attach_params)
@@ -126,7 +154,7 @@ async fn download_file(
 
     let mut tar_data = Vec::new();
     let mut buf = vec![0u8; CHUNK_SIZE];
-    let mut transferred = 0;
+    let mut progress = TransferProgress::new('󰶡', total);
     loop {
         let n = stdout.read(&mut buf).await?;
         if n == 0 {
@@ -134,9 +162,9 @@ async fn download_file(
         }
 
         tar_data.extend_from_slice(&buf[..n]);
-        transferred += n;
-
-        sink.set_text(text_id, Some(format_size('󰶡', transferred)), IconKind::Default);
+        if let Some(text) = progress.advance(n) {
+            sink.set_text(text_id, Some(text), IconKind::Default);
+        }
     }
 
     check_stderr(stderr_task).await?;
@@ -154,11 +182,19 @@ async fn download_file(
         })
         .await??;
 
+    let checksum = if context.verify_checksum {
+        let local_path = Path::new(&context.to).join(file);
+        verify_checksum(&runtime, &pods, &resource, &context.container, local_path, &remote_from).await?
+    } else {
+        ChecksumStatus::Skipped
+    };
+
     Ok(TransferFileResult {
         is_download: true,
         pod: resource.name,
         container: context.container,
         file: file.to_owned(),
+        checksum,
     })
 }
 
@@ -198,12 +234,12 @@ async fn upload_file(
     let stderr = attached.stderr().ok_or(TransferFileError::MissingStderr)?;
     let stderr_task = runtime.spawn(read_to_string(stderr));
 
-    let mut transferred = 0;
+    let mut progress = TransferProgress::new('󰶣', Some(tar_buffer.len()));
     for chunk in tar_buffer.chunks(CHUNK_SIZE) {
         stdin.write_all(chunk).await?;
-        transferred += chunk.len();
-
-        sink.set_text(text_id, Some(format_size('󰶣', transferred)), IconKind::Default);
+        if let Some(text) = progress.advance(chunk.len()) {
+            sink.set_text(text_id, Some(text), IconKind::Default);
+        }
     }
 
     stdin.shutdown().await?;
@@ -217,11 +253,20 @@ async fn upload_file(
         .await
         .map_err(|err| TransferFileError::RemoteProcessError(err.to_string()))?;
 
+    let checksum = if context.verify_checksum {
+        let remote_path = format!("{}/{}", remote_to.trim_end_matches('/'), file_name);
+        let local_path = PathBuf::from(&context.from);
+        verify_checksum(&runtime, &pods, &resource, &context.container, local_path, &remote_path).await?
+    } else {
+        ChecksumStatus::Skipped
+    };
+
     Ok(TransferFileResult {
         is_download: false,
         pod: resource.name,
         container: context.container,
         file: file_name,
+        checksum,
     })
 }
 
@@ -237,6 +282,64 @@ fn build_tar_blocking(path: String, name: String) -> Result<Vec<u8>, TransferFil
     Ok(buffer)
 }
 
+async fn verify_checksum(
+    runtime: &Handle,
+    pods: &Api<Pod>,
+    resource: &ContainerRef,
+    container: &str,
+    local_path: PathBuf,
+    remote_path: &str,
+) -> Result<ChecksumStatus, TransferFileError> {
+    if !tokio::fs::metadata(&local_path).await?.is_file() {
+        return Ok(ChecksumStatus::Unavailable);
+    }
+
+    let command = format!(
+        "command -v sha256sum >/dev/null 2>&1 && sha256sum {} || true",
+        quote_path(remote_path)
+    );
+    let output = exec_capture_stdout(pods, resource, container, &command).await?;
+    let Some(remote) = output.split_whitespace().next().map(str::to_ascii_lowercase) else {
+        return Ok(ChecksumStatus::Unavailable);
+    };
+
+    let local = runtime.spawn_blocking(move || calculate_sha256(&local_path)).await??;
+    if local == remote {
+        Ok(ChecksumStatus::Verified(local))
+    } else {
+        Err(TransferFileError::ChecksumMismatch { local, remote })
+    }
+}
+
+fn calculate_sha256(path: &Path) -> Result<String, std::io::Error> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+
+        hasher.update(&buf[..n]);
+    }
+
+    Ok(format!("{:x}", base16ct::HexDisplay(&hasher.finalize())))
+}
+
+/// Returns size of the remote file or `None` if it cannot be checked, e.g. in containers without shell.
+async fn remote_file_size(pods: &Api<Pod>, resource: &ContainerRef, container: &str, path: &str) -> Option<usize> {
+    let quoted = quote_path(path);
+    let command = format!("[ -f {quoted} ] && (stat -L -c %s {quoted} 2>/dev/null || wc -c < {quoted}) || true");
+    match exec_capture_stdout(pods, resource, container, &command).await {
+        Ok(output) => output.parse::<usize>().ok(),
+        Err(err) => {
+            tracing::warn!("Cannot get size of the remote file '{}': {}", path, err);
+            None
+        },
+    }
+}
+
 async fn resolve_remote_tilde(
     pods: &Api<Pod>,
     resource: &ContainerRef,
@@ -261,7 +364,7 @@ async fn remote_path_exists(
     container: &str,
     path: &str,
 ) -> Result<bool, TransferFileError> {
-    let command = format!("test -e '{}' && echo 1 || echo 0", path.replace('\'', "'\\''"));
+    let command = format!("test -e {} && echo 1 || echo 0", quote_path(path));
     let output = exec_capture_stdout(pods, resource, container, &command).await?;
 
     Ok(output == "1")
@@ -302,6 +405,10 @@ async fn check_process_status(attached: &mut kube::api::AttachedProcess) -> Resu
     Ok(())
 }
 
+fn quote_path(path: &str) -> String {
+    format!("'{}'", path.replace('\'', "'\\''"))
+}
+
 fn split_path(path: &Path) -> Result<(&str, &str), TransferFileError> {
     let dir = path
         .parent()
@@ -356,15 +463,110 @@ fn get_file_name(path: &str) -> String {
         .to_string()
 }
 
-fn format_size(icon: char, bytes: usize) -> String {
+/// Removes transfer progress text from the footer when dropped.
+struct ProgressTextGuard {
+    sink: NotificationSink,
+    text_id: String,
+}
+
+impl Drop for ProgressTextGuard {
+    fn drop(&mut self) {
+        self.sink.set_text(&self.text_id, None::<String>, IconKind::Default);
+    }
+}
+
+/// Tracks transferred bytes and builds progress text with rate and ETA.
+struct TransferProgress {
+    icon: char,
+    total: Option<usize>,
+    transferred: usize,
+    started: Instant,
+    last_update: Option<Instant>,
+}
+
+impl TransferProgress {
+    /// Creates new [`TransferProgress`] instance.\
+    /// **Note** that `total` is optional, ETA is not shown when it is unknown.
+    fn new(icon: char, total: Option<usize>) -> Self {
+        Self {
+            icon,
+            total,
+            transferred: 0,
+            started: Instant::now(),
+            last_update: None,
+        }
+    }
+
+    /// Adds `bytes` to the transferred ones and returns new progress text if it should be refreshed.
+    fn advance(&mut self, bytes: usize) -> Option<String> {
+        self.transferred += bytes;
+
+        let now = Instant::now();
+        if self
+            .last_update
+            .is_some_and(|last| now.duration_since(last) < PROGRESS_INTERVAL)
+        {
+            return None;
+        }
+
+        self.last_update = Some(now);
+        Some(self.text(now.duration_since(self.started)))
+    }
+
+    fn text(&self, elapsed: Duration) -> String {
+        let rate = if elapsed.as_secs_f64() > 0.0 {
+            self.transferred as f64 / elapsed.as_secs_f64()
+        } else {
+            0.0
+        };
+
+        match self.total {
+            Some(total) => {
+                // tar stream is a bit larger than the file itself
+                let transferred = self.transferred.min(total);
+                let eta = if rate > 0.0 {
+                    format_eta((total - transferred) as f64 / rate)
+                } else {
+                    "--".to_owned()
+                };
+
+                format!(
+                    "{}/{} {}/s {}{}",
+                    format_size(transferred),
+                    format_size(total),
+                    format_size(rate as usize),
+                    eta,
+                    self.icon
+                )
+            },
+            None => format!(
+                "{} {}/s{}",
+                format_size(self.transferred),
+                format_size(rate as usize),
+                self.icon
+            ),
+        }
+    }
+}
+
+fn format_eta(seconds: f64) -> String {
+    let seconds = seconds.ceil() as u64;
+    if seconds >= 3_600 {
+        format!("{}:{:02}:{:02}", seconds / 3_600, (seconds % 3_600) / 60, seconds % 60)
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+fn format_size(bytes: usize) -> String {
     const KB: usize = 1_024;
     const MB: usize = 1_024 * KB;
     const GB: usize = 1_024 * MB;
 
     match bytes {
-        b if b < KB => format!("{b}B{icon}"),
-        b if b < MB => format!("{:.1}KB{}", b as f64 / KB as f64, icon),
-        b if b < GB => format!("{:.1}MB{}", b as f64 / MB as f64, icon),
-        b => format!("{:.1}GB{}", b as f64 / GB as f64, icon),
+        b if b < KB => format!("{b}B"),
+        b if b < MB => format!("{:.1}KB", b as f64 / KB as f64),
+        b if b < GB => format!("{:.1}MB", b as f64 / MB as f64),
+        b => format!("{:.1}GB", b as f64 / GB as f64),
     }
 }
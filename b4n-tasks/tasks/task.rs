@@ -105,8 +105,10 @@ pub async fn run_command(command: Command) -> Option<CommandResult> {
         Command::NewKubernetesClient(command) => command.execute().await,
         Command::SaveConfig(command) => command.execute().await,
         Command::SaveHistory(command) => command.execute().await,
+        Command::SaveBookmarks(command) => command.execute().await,
         Command::SaveContent(command) => command.execute().await,
         Command::DeleteResource(command) => command.execute().await,
+        Command::ExportResources(command) => command.execute().await,
         Command::GetNewYaml(command) => command.execute().await,
         Command::GetYaml(command) => command.execute().await,
         Command::SetNewYaml(command) => command.execute().await,
@@ -114,5 +116,15 @@ pub async fn run_command(command: Command) -> Option<CommandResult> {
         Command::RunPlugin(command) => command.execute().await,
         Command::InjectContainer(command) => command.execute().await,
         Command::TransferFile(command) => command.execute().await,
+        Command::NodeShell(command) => command.execute().await,
+        Command::CopyPod(command) => command.execute().await,
+        Command::TriggerCronJob(command) => command.execute().await,
+        Command::SuspendCronJobs(command) => command.execute().await,
+        Command::GetHelmRelease(command) => command.execute().await,
+        Command::QueryResources(command) => command.execute().await,
+        Command::PatchMetadata(command) => command.execute().await,
+        Command::BuildSearchIndex(command) => command.execute().await,
+        Command::GetResourceRelations(command) => command.execute().await,
+        Command::GetResourceUsers(command) => command.execute().await,
     }
 }
@@ -1,7 +1,8 @@
-use b4n_config::PluginRef;
+use b4n_config::{Bookmark, PluginRef};
 use b4n_kube::files::TransferContext;
+use b4n_kube::helm::HelmReleaseView;
 use b4n_kube::plugins::PluginContext;
-use b4n_kube::{ContainerRef, PropagationPolicy, ResourceRef, ResourceRefFilter, ResourceTag, Scope};
+use b4n_kube::{ContainerRef, MetadataChanges, PropagationPolicy, ResourceRef, ResourceRefFilter, ResourceTag, Scope};
 
 use crate::TuiEvent;
 
@@ -62,6 +63,15 @@ pub struct EphemeralContainer {
     pub target: Option<String>,
 }
 
+/// Debug copy of a pod data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PodCopy {
+    pub name: String,
+    pub container: Option<String>,
+    pub image: String,
+    pub command: String,
+}
+
 /// Terminal UI Response Event.
 #[derive(Default, Debug, Clone, PartialEq)]
 pub enum ResponseEvent {
@@ -81,10 +91,15 @@ pub enum ResponseEvent {
     ChangeKind(String),
     ChangeKindAndSelect(String, ToSelectData),
     ChangeNamespace(String),
+    ChangeServerFilter(Option<ResourceRefFilter>),
     ChangeContext(String, Option<String>),
     ChangeTheme(String),
+    OpenBookmark(String),
+    SaveBookmark(Box<Bookmark>),
 
     ViewPreviousResource,
+    ViewHistoryBack,
+    ViewHistoryForward,
     ViewContainers(String, String),
     ViewInvolved(String, String, ToSelectData),
     ViewScoped(String, Option<String>, ToSelectData, ScopeData),
@@ -98,17 +113,34 @@ pub enum ResponseEvent {
 
     AskDeleteResources,
     DeleteResources(PropagationPolicy, bool, bool),
+    ExportResources(Vec<ResourceRef>, String),
+    QueryResources(Vec<ResourceRef>, String, bool),
+    PatchMetadata(Vec<ResourceRef>, MetadataChanges, bool),
 
     NewYaml(ResourceRef, bool),
     ViewYaml(ResourceRef, bool, bool),
+    ViewHelmRelease(ResourceRef, HelmReleaseView),
     ViewLogs(ResourceRef, Option<Vec<ResourceTag>>),
     ViewPreviousLogs(ResourceRef, Option<Vec<ResourceTag>>),
     Describe(ResourceRef),
+    ViewRelations(ResourceRef),
 
     InjectContainer(ResourceRef, EphemeralContainer),
+    CopyPod(ResourceRef, PodCopy),
+    ExecCommand(Vec<ContainerRef>, String),
     AttachContainer(ResourceRef),
     OpenShell(ResourceRef),
+    OpenNodeShell(String, String, String),
+    TriggerCronJob(ResourceRef),
+    SuspendCronJobs(Vec<ResourceRef>, bool),
+    SendToBackground,
+    ListSessions,
+    ResumeSession(usize),
     ShowPortForwards,
+    ShowEventStream,
+    JumpToAlert,
+    ShowResourcesSearch,
+    TogglePreview,
     PortForward(ResourceRef, u16, u16, String),
 
     PluginAction(PluginRef),
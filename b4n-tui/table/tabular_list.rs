@@ -90,8 +90,12 @@ impl<T: Row + Filterable<Fc>, Fc: FilterContext> Responsive for TabularList<T, F
 impl<T: Row + Filterable<Fc>, Fc: FilterContext> TabularList<T, Fc> {
     /// Creates new [`TabularList`] instance.
     pub fn new(header: Header) -> Self {
+        let mut list = ScrollableList::default();
+        list.set_filter_columns(header.get_names());
+
         Self {
             header,
+            list,
             ..Default::default()
         }
     }
@@ -101,6 +105,7 @@ impl<T: Row + Filterable<Fc>, Fc: FilterContext> TabularList<T, Fc> {
     pub fn update_header(&mut self, new_header: Header) {
         self.header = new_header;
         self.list.clear();
+        self.list.set_filter_columns(self.header.get_names());
         self.offset = 0;
     }
 
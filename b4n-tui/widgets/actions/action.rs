@@ -1,4 +1,4 @@
-use b4n_common::{random_uuid, truncate};
+use b4n_common::{fuzzy_score, random_uuid, truncate};
 use b4n_kube::Port;
 use b4n_list::{BasicFilterContext, Filterable, Row};
 use std::{borrow::Cow, path::PathBuf};
@@ -21,6 +21,7 @@ pub struct ActionItem {
     icon: Option<&'static str>,
     aliases: Option<Vec<String>>,
     key: Option<String>,
+    is_fuzzy: bool,
 }
 
 impl ActionItem {
@@ -117,6 +118,17 @@ impl ActionItem {
         }
     }
 
+    /// Enables or disables fuzzy matching of the action name and aliases.
+    pub fn set_fuzzy(&mut self, is_fuzzy: bool) {
+        self.is_fuzzy = is_fuzzy;
+    }
+
+    /// Returns the best fuzzy match score of the `pattern` in the action name or its aliases.
+    pub fn fuzzy_score(&self, pattern: &str) -> Option<usize> {
+        let aliases = self.aliases.iter().flatten().filter_map(|a| fuzzy_score(pattern, a));
+        fuzzy_score(pattern, &self.name).into_iter().chain(aliases).max()
+    }
+
     /// Sets key name for this action.
     pub fn set_key(&mut self, key: Option<String>) {
         self.key = key;
@@ -298,6 +310,10 @@ impl Filterable<BasicFilterContext> for ActionItem {
     }
 
     fn is_matching(&self, context: &mut BasicFilterContext) -> bool {
-        self.contains(&context.pattern)
+        if self.is_fuzzy {
+            self.fuzzy_score(&context.pattern).is_some()
+        } else {
+            self.contains(&context.pattern)
+        }
     }
 }
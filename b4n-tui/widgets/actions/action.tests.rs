@@ -24,3 +24,15 @@ fn get_name_test() {
     let action = ActionItem::menu(9, " logs ␝previous␝", "");
     assert_eq!(" logs ␝previous␝".to_owned(), action.get_name(15));
 }
+
+#[test]
+fn fuzzy_matching_test() {
+    let mut action = ActionItem::new("something-billing").with_aliases(["configmaps/something-billing"]);
+    let mut context = BasicFilterContext::from("smbill");
+    assert!(!action.is_matching(&mut context));
+
+    action.set_fuzzy(true);
+    assert!(action.is_matching(&mut context));
+    assert!(action.fuzzy_score("cm/bill").is_some());
+    assert!(action.fuzzy_score("secret").is_none());
+}
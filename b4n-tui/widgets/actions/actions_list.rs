@@ -1,3 +1,4 @@
+use b4n_config::Bookmarks;
 use b4n_config::keys::{KeyBindings, KeyCommand};
 use b4n_kube::{Port, PortProtocol};
 use b4n_list::{BasicFilterContext, Row, ScrollableList};
@@ -14,6 +15,7 @@ pub struct ActionsList {
     pub list: ScrollableList<ActionItem, BasicFilterContext>,
     header: String,
     width: usize,
+    is_fuzzy: bool,
 }
 
 impl ActionsList {
@@ -26,6 +28,25 @@ impl ActionsList {
             .unwrap_or_default()
     }
 
+    /// Sorts actions by the fuzzy match score of the `pattern`, the best matches first.
+    fn rank_by_score(&mut self, pattern: Option<&str>) {
+        let Some(pattern) = pattern else {
+            self.list.sort(1, false);
+            return;
+        };
+
+        let scores = self
+            .list
+            .iter()
+            .map(|i| (i.data.uid.clone(), i.data.fuzzy_score(pattern).unwrap_or_default()))
+            .collect::<HashMap<_, _>>();
+        self.list.sort_by(|a, b| {
+            let score_a = scores.get(&a.data.uid).copied().unwrap_or_default();
+            let score_b = scores.get(&b.data.uid).copied().unwrap_or_default();
+            score_b.cmp(&score_a).then_with(|| a.data.name.len().cmp(&b.data.name.len()))
+        });
+    }
+
     /// Highlights element on list by index.
     pub fn highlight_item_by_index(&mut self, index: usize) -> bool {
         self.list.highlight_item_by_index(index)
@@ -44,7 +65,6 @@ impl Table for ActionsList {
             fn clear(&mut self);
             fn len(&self) -> usize;
             fn is_filtered(&self) -> bool;
-            fn set_filter(&mut self, filter: Option<String>);
             fn filter(&self) -> Option<&str>;
             fn sort(&mut self, column_no: usize, is_descending: bool);
             fn is_anything_highlighted(&self) -> bool;
@@ -53,7 +73,6 @@ impl Table for ActionsList {
             fn get_highlighted_item_uid(&self) -> Option<&str>;
             fn get_highlighted_item_line_no(&self) -> Option<u16>;
             fn highlight_item_by_name(&mut self, name: &str) -> bool;
-            fn highlight_item_by_name_start(&mut self, text: &str) -> bool;
             fn highlight_item_by_uid(&mut self, uid: &str) -> bool;
             fn highlight_item_by_line(&mut self, line_no: u16) -> bool;
             fn highlight_first_item(&mut self) -> bool;
@@ -70,6 +89,22 @@ impl Table for ActionsList {
         }
     }
 
+    /// Highlights the best match for fuzzy ranked actions, otherwise the first action that starts with `text`.
+    fn highlight_item_by_name_start(&mut self, text: &str) -> bool {
+        if self.is_fuzzy {
+            self.list.highlight_first_item()
+        } else {
+            self.list.highlight_item_by_name_start(text)
+        }
+    }
+
+    fn set_filter(&mut self, filter: Option<String>) {
+        if self.list.set_filter(filter) && self.is_fuzzy {
+            let pattern = self.list.filter().map(String::from);
+            self.rank_by_score(pattern.as_deref());
+        }
+    }
+
     fn get_column_at_position(&self, position: usize) -> Option<usize> {
         if position < self.width { Some(0) } else { None }
     }
@@ -96,13 +131,18 @@ impl Table for ActionsList {
 pub struct ActionsListBuilder {
     actions: Vec<ActionItem>,
     commands: Vec<Option<KeyCommand>>,
+    is_fuzzy: bool,
 }
 
 impl ActionsListBuilder {
     /// Creates a new [`ActionsListBuilder`] instance.
     pub fn new(actions: Vec<ActionItem>) -> Self {
         let commands = vec![None; actions.len()];
-        Self { actions, commands }
+        Self {
+            actions,
+            commands,
+            is_fuzzy: false,
+        }
     }
 
     /// Creates new [`ActionsListBuilder`] instance from the list of string slices.\
@@ -115,29 +155,23 @@ impl ActionsListBuilder {
                 ActionItem::raw(idx.to_string(), "items".to_owned(), item.as_ref().to_string(), None).with_sort_id(idx)
             })
             .collect();
-        let commands = vec![None; items.len()];
-        Self { actions, commands }
+        Self::new(actions)
     }
 
     /// Creates new [`ActionsListBuilder`] instance from the list of [`PathBuf`]s.
     pub fn from_paths(themes: Vec<PathBuf>) -> Self {
-        let commands = vec![None; themes.len()];
-        Self {
-            actions: themes.into_iter().map(ActionItem::from).collect(),
-            commands,
-        }
+        Self::new(themes.into_iter().map(ActionItem::from).collect())
     }
 
     /// Creates new [`ActionsListBuilder`] instance from the list of [`Port`]s.
     pub fn from_resource_ports(ports: &[Port]) -> Self {
-        Self {
-            actions: ports
+        Self::new(
+            ports
                 .iter()
                 .filter(|p| p.protocol == PortProtocol::TCP)
                 .map(ActionItem::from)
                 .collect(),
-            commands: vec![None; ports.len()],
-        }
+        )
     }
 
     /// Builds the [`ActionsList`] instance.\
@@ -147,6 +181,10 @@ impl ActionsListBuilder {
             self.update_key_bindings(key_bindings);
         }
 
+        if self.is_fuzzy {
+            self.actions.iter_mut().for_each(|a| a.set_fuzzy(true));
+        }
+
         let has_ids = self.actions.iter().any(|a| a.id.is_some());
         let mut list = ScrollableList::from(self.actions);
 
@@ -158,10 +196,17 @@ impl ActionsListBuilder {
 
         ActionsList {
             list,
+            is_fuzzy: self.is_fuzzy,
             ..Default::default()
         }
     }
 
+    /// Enables fuzzy matching of actions, the list is then ordered by the match score when filtered.
+    pub fn with_fuzzy_ranking(mut self) -> Self {
+        self.is_fuzzy = true;
+        self
+    }
+
     /// Adds aliases to the existing actions.
     pub fn with_aliases(mut self, aliases: &HashMap<String, String>) -> Self {
         for action in &mut self.actions {
@@ -314,6 +359,83 @@ impl ActionsListBuilder {
         self
     }
 
+    /// Adds `show event stream` action.
+    pub fn with_event_stream(mut self) -> Self {
+        self.actions.push(
+            ActionItem::new("show event stream")
+                .with_description("shows grouped events from all namespaces")
+                .with_aliases(["stream", "incident"])
+                .with_response(ResponseEvent::ShowEventStream),
+        );
+        self.commands.push(Some(KeyCommand::EventStreamOpen));
+        self
+    }
+
+    /// Adds `jump to alert` action.
+    pub fn with_alert_jump(mut self) -> Self {
+        self.actions.push(
+            ActionItem::new("jump to alert")
+                .with_description("shows the resource of the last raised alert")
+                .with_aliases(["alert", "alerts"])
+                .with_response(ResponseEvent::JumpToAlert),
+        );
+        self.commands.push(Some(KeyCommand::AlertJump));
+        self
+    }
+
+    /// Adds `show sessions` action.
+    pub fn with_sessions(mut self) -> Self {
+        self.actions.push(
+            ActionItem::new("show sessions")
+                .with_description("shows shell sessions running in the background")
+                .with_aliases(["sessions", "background", "bg"])
+                .with_response(ResponseEvent::ListSessions),
+        );
+        self.commands.push(Some(KeyCommand::SessionsOpen));
+        self
+    }
+
+    /// Adds `search` action.
+    pub fn with_search(mut self) -> Self {
+        self.actions.push(
+            ActionItem::new("search")
+                .with_description("searches resources of all kinds by name")
+                .with_aliases(["find", "global"])
+                .with_response(ResponseEvent::ShowResourcesSearch),
+        );
+        self.commands.push(Some(KeyCommand::GlobalSearchOpen));
+        self
+    }
+
+    /// Adds `preview` action.
+    pub fn with_preview(mut self) -> Self {
+        self.actions.push(
+            ActionItem::new("preview")
+                .with_description("shows or hides the preview pane for the highlighted resource")
+                .with_aliases(["split", "pane"])
+                .with_response(ResponseEvent::TogglePreview),
+        );
+        self.commands.push(Some(KeyCommand::PreviewToggle));
+        self
+    }
+
+    /// Adds an action for each bookmark, the first ones are bound to the `bookmark.N` key commands.\
+    /// **Note** that bookmarks can be also filtered by `bm <name>` or `bookmark <name>`.
+    pub fn with_bookmarks(mut self, bookmarks: &Bookmarks) -> Self {
+        for (index, bookmark) in bookmarks.bookmarks.iter().enumerate() {
+            self.actions.push(
+                ActionItem::new(&bookmark.name)
+                    .with_description(&bookmark.description())
+                    .with_aliases([format!("bm {}", bookmark.name), format!("bookmark {}", bookmark.name)])
+                    .with_icon(Some(""))
+                    .with_response(ResponseEvent::OpenBookmark(bookmark.name.clone())),
+            );
+            self.commands.push(KeyCommand::BOOKMARKS.get(index).copied());
+        }
+
+        self
+    }
+
     /// Adds custom action.
     pub fn add_action(&mut self, action: ActionItem, command: Option<KeyCommand>) {
         self.actions.push(action);
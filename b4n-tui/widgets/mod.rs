@@ -3,6 +3,7 @@ pub use self::footer::Footer;
 pub use self::input::{ErrorHighlightMode, Input, SharedClipboard};
 pub use self::list::{List, ListWidget};
 pub use self::modal::{Button, CheckBox, Control, ControlsGroup, Dialog, Selector, TextBox};
+pub use self::palette_command::{PaletteCommand, PaletteCompletions, PaletteVerb};
 pub use self::select::Select;
 pub use self::spinner::Spinner;
 pub use self::validator::{InputValidator, ValidatorKind};
@@ -13,6 +14,7 @@ mod history;
 mod input;
 mod list;
 mod modal;
+mod palette_command;
 mod select;
 mod spinner;
 mod validator;
@@ -122,6 +122,11 @@ impl Dialog {
         self.controls.selector(id)
     }
 
+    /// Sets new dialog message.
+    pub fn set_message(&mut self, message: String) {
+        self.message = message;
+    }
+
     /// Marks [`Dialog`] as a visible.
     pub fn show(&mut self) {
         self.is_visible = true;
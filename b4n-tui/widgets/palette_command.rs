@@ -0,0 +1,270 @@
+#[cfg(test)]
+#[path = "./palette_command.tests.rs"]
+mod palette_command_tests;
+
+const CONTEXT_VERBS: [&str; 2] = ["ctx", "context"];
+const NAMESPACE_VERBS: [&str; 2] = ["ns", "namespace"];
+const LOGS_VERBS: [&str; 2] = ["logs", "log"];
+const YAML_VERBS: [&str; 1] = ["yaml"];
+const DESCRIBE_VERBS: [&str; 2] = ["describe", "desc"];
+const FILTER_PREFIXES: [&str; 6] = ["ns", "n", "a", "l", "f", "c"];
+const MIN_KIND_PREFIX_LEN: usize = 3;
+
+/// Kind of the command typed into the command palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteVerb {
+    Context,
+    Namespace,
+    Logs,
+    Yaml,
+    Describe,
+    Resources,
+}
+
+impl PaletteVerb {
+    /// Returns [`PaletteVerb`] for the specified command `word`.\
+    /// **Note** that any of the `kinds` (or its prefix of at least 3 characters, e.g. `deploy`) is treated as
+    /// the [`PaletteVerb::Resources`] command.
+    pub fn from_word<S: AsRef<str>>(word: &str, kinds: &[S]) -> Option<Self> {
+        let word = word.to_ascii_lowercase();
+        if CONTEXT_VERBS.contains(&word.as_str()) {
+            Some(Self::Context)
+        } else if NAMESPACE_VERBS.contains(&word.as_str()) {
+            Some(Self::Namespace)
+        } else if LOGS_VERBS.contains(&word.as_str()) {
+            Some(Self::Logs)
+        } else if YAML_VERBS.contains(&word.as_str()) {
+            Some(Self::Yaml)
+        } else if DESCRIBE_VERBS.contains(&word.as_str()) {
+            Some(Self::Describe)
+        } else if kinds.iter().any(|k| {
+            let kind = k.as_ref().to_ascii_lowercase();
+            kind == word || (word.len() >= MIN_KIND_PREFIX_LEN && kind.starts_with(&word))
+        }) {
+            Some(Self::Resources)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the command requires a positional argument.
+    pub fn requires_target(self) -> bool {
+        self != Self::Resources
+    }
+
+    fn accepts_namespace(self) -> bool {
+        self != Self::Namespace
+    }
+}
+
+/// Command with arguments typed into the command palette, e.g. `pods -n kube-system /crash`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteCommand {
+    pub verb: PaletteVerb,
+    pub name: String,
+    pub target: Option<String>,
+    pub namespace: Option<String>,
+    pub all_namespaces: bool,
+    pub container: Option<String>,
+    pub previous: bool,
+    pub filter: Option<String>,
+}
+
+impl PaletteCommand {
+    /// Parses palette `input` using `shlex` rules, `kinds` are accepted as command names next to the built-in verbs.\
+    /// **Note** that it returns `Ok(None)` if the input is not a command with arguments, so it can be treated as a plain
+    /// action search, and the returned error is the character position of the invalid argument.
+    pub fn parse<S: AsRef<str>>(input: &str, kinds: &[S]) -> Result<Option<Self>, usize> {
+        let Some(word) = input.trim_start().trim_start_matches(':').split_whitespace().next() else {
+            return Ok(None);
+        };
+        let Some(verb) = PaletteVerb::from_word(word, kinds) else {
+            return Ok(None);
+        };
+
+        let tokens = tokenize(input)?;
+        if tokens.len() < 2 {
+            return Ok(None);
+        }
+
+        let mut command = Self {
+            verb,
+            name: word.to_owned(),
+            target: None,
+            namespace: None,
+            all_namespaces: false,
+            container: None,
+            previous: false,
+            filter: None,
+        };
+        let mut filters = Vec::new();
+        let mut iter = tokens.into_iter().skip(1);
+        while let Some((position, token)) = iter.next() {
+            match token.as_str() {
+                "-n" | "--namespace" if verb.accepts_namespace() => {
+                    command.namespace = Some(iter.next().ok_or(position)?.1);
+                },
+                "-A" | "--all-namespaces" if verb == PaletteVerb::Resources => command.all_namespaces = true,
+                "-c" | "--container" if verb == PaletteVerb::Logs => command.container = Some(iter.next().ok_or(position)?.1),
+                "-p" | "--previous" if verb == PaletteVerb::Logs => command.previous = true,
+                _ => {
+                    if let Some(namespace) = token.strip_prefix("--namespace=")
+                        && verb.accepts_namespace()
+                    {
+                        command.namespace = Some(namespace.to_owned());
+                    } else if token.starts_with('-') {
+                        return Err(position);
+                    } else if verb == PaletteVerb::Resources && is_filter(&token) {
+                        filters.push(get_filter_term(&token).to_owned());
+                    } else if command.target.is_none() {
+                        command.target = Some(token);
+                    } else {
+                        return Err(position);
+                    }
+                },
+            }
+        }
+
+        if verb.requires_target() && command.target.is_none() {
+            return Err(0);
+        }
+
+        filters.retain(|f| !f.is_empty());
+        if !filters.is_empty() {
+            command.filter = Some(filters.join(" & "));
+        }
+
+        Ok(Some(command))
+    }
+}
+
+/// Sources of values used to complete arguments of the palette commands.
+#[derive(Default)]
+pub struct PaletteCompletions {
+    pub kinds: Vec<String>,
+    pub namespaces: Vec<String>,
+    pub names: Vec<String>,
+    pub contexts: Vec<String>,
+}
+
+impl PaletteCompletions {
+    /// Completes the last word of the palette `input`.\
+    /// **Note** that it returns `None` if there is nothing to complete or the last word is ambiguous.
+    pub fn complete(&self, input: &str) -> Option<String> {
+        let start = input.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+        let (head, word) = input.split_at(start);
+        let previous = head.split_whitespace().last();
+        let verb = head
+            .trim_start()
+            .trim_start_matches(':')
+            .split_whitespace()
+            .next()
+            .and_then(|w| PaletteVerb::from_word(w, &self.kinds));
+
+        let (prefix, word) = match word.split_once('/') {
+            Some((kind, name)) if !kind.is_empty() => (&word[..=kind.len()], name),
+            _ => ("", word),
+        };
+
+        let candidates = match previous {
+            None => &self.kinds,
+            Some("-n" | "--namespace") => &self.namespaces,
+            Some(_) if word.starts_with('-') || (prefix.is_empty() && is_filter(word)) => return None,
+            Some(_) => match verb? {
+                PaletteVerb::Context => &self.contexts,
+                PaletteVerb::Namespace => &self.namespaces,
+                _ => &self.names,
+            },
+        };
+
+        let completed = complete_word(word, candidates)?;
+        Some(format!("{head}{prefix}{completed}"))
+    }
+}
+
+/// Splits `input` into `shlex` tokens together with their character positions.
+fn tokenize(input: &str) -> Result<Vec<(usize, String)>, usize> {
+    let Some(tokens) = shlex::split(&keep_backslashes(input.trim_start().trim_start_matches(':'))) else {
+        return Err(input.rfind(['"', '\'']).map_or(0, |i| input[..i].chars().count()));
+    };
+
+    let mut offset = 0;
+    let mut result = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        let mut position = offset;
+        if let Some(index) = input[offset..].find(token.as_str()) {
+            position += index;
+            offset = position + token.len();
+        }
+
+        result.push((input[..position].chars().count(), token));
+    }
+
+    Ok(result)
+}
+
+/// Escapes unquoted backslashes that do not escape anything for `shlex`, so regex terms like `/^api-\d+/` keep them.
+fn keep_backslashes(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut quote = None;
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        result.push(c);
+        match (quote, c) {
+            (None | Some('"'), '\\') => match chars.peek() {
+                Some(&next) if quote.is_some() || next.is_whitespace() || matches!(next, '\\' | '"' | '\'') => {
+                    result.push(next);
+                    chars.next();
+                },
+                Some(_) if quote.is_none() => result.push('\\'),
+                _ => (),
+            },
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            _ => (),
+        }
+    }
+
+    result
+}
+
+/// Returns `true` if `token` is a filter, e.g. `/text`, `/^api-\d+/`, `ns:kube-system` or `l:app=web`.
+fn is_filter(token: &str) -> bool {
+    token.starts_with('/')
+        || token
+            .split_once(':')
+            .is_some_and(|(prefix, _)| FILTER_PREFIXES.iter().any(|p| prefix.eq_ignore_ascii_case(p)))
+}
+
+/// Returns filter term for the filter `token`.\
+/// **Note** that the leading slash is stripped only from the plain text, as `/^api-\d+/` is a regex term.
+fn get_filter_term(token: &str) -> &str {
+    match token.strip_prefix('/') {
+        Some(text) if !text.ends_with('/') => text,
+        _ => token,
+    }
+}
+
+/// Returns the only candidate that starts with `word` or the longest common prefix of all of them.
+fn complete_word(word: &str, candidates: &[String]) -> Option<String> {
+    let mut matching = candidates.iter().filter(|c| c.starts_with(word));
+    let first = matching.next()?;
+    let mut common = first.len();
+    let mut is_single = true;
+    for candidate in matching {
+        is_single = false;
+        common = first
+            .char_indices()
+            .zip(candidate.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(common.min(candidate.len()), |((i, _), _)| common.min(i));
+    }
+
+    if is_single {
+        Some(format!("{first} "))
+    } else if common > word.len() {
+        Some(first[..common].to_owned())
+    } else {
+        None
+    }
+}
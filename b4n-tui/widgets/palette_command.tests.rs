@@ -0,0 +1,98 @@
+use super::*;
+
+const KINDS: [&str; 3] = ["pods", "deployments", "deploy"];
+
+#[test]
+fn parse_plain_search_test() {
+    assert_eq!(Ok(None), PaletteCommand::parse("", &KINDS));
+    assert_eq!(Ok(None), PaletteCommand::parse("pods", &KINDS));
+    assert_eq!(Ok(None), PaletteCommand::parse(":ctx", &KINDS));
+    assert_eq!(Ok(None), PaletteCommand::parse("show port forwards", &KINDS));
+}
+
+#[test]
+fn parse_resources_command_test() {
+    let command = PaletteCommand::parse(":pods -n kube-system /crash", &KINDS).unwrap().unwrap();
+    assert_eq!(PaletteVerb::Resources, command.verb);
+    assert_eq!("pods", command.name);
+    assert_eq!(Some("kube-system"), command.namespace.as_deref());
+    assert_eq!(Some("crash"), command.filter.as_deref());
+
+    let command = PaletteCommand::parse("deploy l:app=web -A", &KINDS).unwrap().unwrap();
+    assert_eq!(Some("l:app=web"), command.filter.as_deref());
+    assert!(command.all_namespaces);
+
+    let command = PaletteCommand::parse("pods \"/crash loop\" l:app=web", &KINDS)
+        .unwrap()
+        .unwrap();
+    assert_eq!(Some("crash loop & l:app=web"), command.filter.as_deref());
+}
+
+#[test]
+fn parse_resources_filters_test() {
+    let command = PaletteCommand::parse(r":pods /^api-\d+/", &KINDS).unwrap().unwrap();
+    assert_eq!(Some(r"/^api-\d+/"), command.filter.as_deref());
+    assert_eq!(None, command.target);
+
+    let command = PaletteCommand::parse(":pods ns:kube-system n:/^dns/ a:team=core", &KINDS)
+        .unwrap()
+        .unwrap();
+    assert_eq!(Some("ns:kube-system & n:/^dns/ & a:team=core"), command.filter.as_deref());
+    assert_eq!(None, command.target);
+
+    let command = PaletteCommand::parse(":pods f:status.phase=Running C:restarts>0 web", &KINDS)
+        .unwrap()
+        .unwrap();
+    assert_eq!(Some("f:status.phase=Running & C:restarts>0"), command.filter.as_deref());
+    assert_eq!(Some("web"), command.target.as_deref());
+}
+
+#[test]
+fn parse_verbs_test() {
+    let command = PaletteCommand::parse(":ctx prod", &KINDS).unwrap().unwrap();
+    assert_eq!(PaletteVerb::Context, command.verb);
+    assert_eq!(Some("prod"), command.target.as_deref());
+
+    let command = PaletteCommand::parse(":logs api-7f9 -c sidecar --previous", &KINDS)
+        .unwrap()
+        .unwrap();
+    assert_eq!(PaletteVerb::Logs, command.verb);
+    assert_eq!(Some("api-7f9"), command.target.as_deref());
+    assert_eq!(Some("sidecar"), command.container.as_deref());
+    assert!(command.previous);
+
+    let command = PaletteCommand::parse(":yaml secret/foo --namespace=dev", &KINDS)
+        .unwrap()
+        .unwrap();
+    assert_eq!(PaletteVerb::Yaml, command.verb);
+    assert_eq!(Some("secret/foo"), command.target.as_deref());
+    assert_eq!(Some("dev"), command.namespace.as_deref());
+}
+
+#[test]
+fn parse_errors_test() {
+    assert_eq!(Err(10), PaletteCommand::parse(":logs api --all", &KINDS));
+    assert_eq!(Err(10), PaletteCommand::parse(":logs api -c", &KINDS));
+    assert_eq!(Err(8), PaletteCommand::parse(":ns dev prod", &KINDS));
+    assert_eq!(Err(6), PaletteCommand::parse(":pods -c app", &KINDS));
+    assert_eq!(Err(0), PaletteCommand::parse(":ctx -n dev", &KINDS));
+    assert_eq!(Err(6), PaletteCommand::parse(":pods \"/crash", &KINDS));
+}
+
+#[test]
+fn complete_test() {
+    let completions = PaletteCompletions {
+        kinds: vec!["pods".to_owned(), "deployments".to_owned()],
+        namespaces: vec!["kube-system".to_owned(), "kube-public".to_owned(), "default".to_owned()],
+        names: vec!["api-7f9".to_owned(), "web-1".to_owned()],
+        contexts: vec!["prod".to_owned(), "staging".to_owned()],
+    };
+
+    assert_eq!(Some("pods -n kube-".to_owned()), completions.complete("pods -n ku"));
+    assert_eq!(Some("pods -n default ".to_owned()), completions.complete("pods -n d"));
+    assert_eq!(Some(":ctx prod ".to_owned()), completions.complete(":ctx p"));
+    assert_eq!(Some(":logs api-7f9 ".to_owned()), completions.complete(":logs a"));
+    assert_eq!(Some("yaml secret/web-1 ".to_owned()), completions.complete("yaml secret/w"));
+    assert_eq!(None, completions.complete("pods /w"));
+    assert_eq!(None, completions.complete("pods -n kube-"));
+}
@@ -1,6 +1,10 @@
+use b4n_kube::{NAMESPACES_SEPARATOR, parse_metadata_changes};
 use http::uri::Authority;
+use jsonpath_rust::parser::parse_json_path;
 use std::{net::IpAddr, str::FromStr};
 
+use crate::widgets::PaletteCommand;
+
 #[cfg(test)]
 #[path = "./validator.tests.rs"]
 mod validator_tests;
@@ -14,8 +18,14 @@ pub enum ValidatorKind {
     StringOneOf(Vec<String>),
     ShellCommand,
     DockerImage,
+    OptionalDockerImage,
     IpAddr,
     DnsLabel,
+    Namespaces,
+    JsonPath,
+    Labels,
+    Annotations,
+    PaletteCommand(Vec<String>),
 }
 
 pub struct InputValidator {
@@ -52,8 +62,15 @@ impl InputValidator {
             ValidatorKind::StringOneOf(one_of) => validate_sting_one_of(input, one_of),
             ValidatorKind::ShellCommand => validate_shell_command(input),
             ValidatorKind::DockerImage => validate_docker_image(input),
+            ValidatorKind::OptionalDockerImage if input.is_empty() => Ok(()),
+            ValidatorKind::OptionalDockerImage => validate_docker_image(input),
             ValidatorKind::IpAddr => validate_ip_address(input),
             ValidatorKind::DnsLabel => validate_dns_label(input),
+            ValidatorKind::Namespaces => validate_namespaces(input),
+            ValidatorKind::JsonPath => validate_json_path(input),
+            ValidatorKind::Labels => parse_metadata_changes(input, true).map(|_| ()),
+            ValidatorKind::Annotations => parse_metadata_changes(input, false).map(|_| ()),
+            ValidatorKind::PaletteCommand(kinds) => PaletteCommand::parse(input, kinds).map(|_| ()),
             ValidatorKind::None => Ok(()),
         };
 
@@ -137,6 +154,27 @@ fn validate_dns_label(input: &str) -> Result<(), usize> {
     Ok(())
 }
 
+/// Validates a comma separated list of namespaces, each of them can be a glob pattern with `*` and `?` wildcards.
+fn validate_namespaces(input: &str) -> Result<(), usize> {
+    let mut offset = 0;
+    for namespace in input.split(NAMESPACES_SEPARATOR) {
+        if namespace.contains(['*', '?']) {
+            if let Some(i) = namespace
+                .chars()
+                .position(|ch| !ch.is_ascii_lowercase() && !ch.is_ascii_digit() && !matches!(ch, '-' | '*' | '?'))
+            {
+                return Err(offset + i);
+            }
+        } else {
+            validate_dns_label(namespace).map_err(|i| offset + i)?;
+        }
+
+        offset += namespace.len() + 1;
+    }
+
+    Ok(())
+}
+
 /// Validates a docker container image name.\
 /// Format: `[registry/][namespace/]name[:tag][@digest]`
 fn validate_docker_image(input: &str) -> Result<(), usize> {
@@ -170,6 +208,15 @@ fn validate_shell_command(input: &str) -> Result<(), usize> {
     if shlex::split(input).is_some() { Ok(()) } else { Err(0) }
 }
 
+/// Validates JSONPath expression using `jsonpath-rust` crate.
+fn validate_json_path(input: &str) -> Result<(), usize> {
+    if input.is_empty() || parse_json_path(input).is_ok() {
+        Ok(())
+    } else {
+        Err(0)
+    }
+}
+
 /// Format: `[registry/][namespace/]name[:tag]`.
 fn validate_image_and_tag(image: &str) -> Result<(), usize> {
     let after_slash = image.rfind('/').map_or(image, |i| &image[i + 1..]);
@@ -33,3 +33,58 @@ fn test_invalid_images() {
     assert!(validator.validate("my::image").is_err());
     assert!(validator.validate("MyImage").is_err());
 }
+
+#[test]
+fn test_optional_images() {
+    let mut validator = InputValidator::new(ValidatorKind::OptionalDockerImage);
+
+    assert!(validator.validate("").is_ok());
+    assert!(validator.validate("ubuntu:22.04").is_ok());
+    assert!(validator.validate("ubuntu:").is_err());
+    assert!(validator.validate("my image").is_err());
+
+    let mut validator = InputValidator::new(ValidatorKind::DockerImage);
+    assert!(validator.validate("").is_err());
+}
+
+#[test]
+fn test_json_path() {
+    let mut validator = InputValidator::new(ValidatorKind::JsonPath);
+
+    assert!(validator.validate("").is_ok());
+    assert!(validator.validate("$.metadata.name").is_ok());
+    assert!(validator.validate("$.spec.containers[*].image").is_ok());
+    assert!(validator.validate("$.status.conditions[?@.type == 'Ready'].status").is_ok());
+    assert!(validator.validate("$..image").is_ok());
+    assert!(validator.validate("metadata.name").is_err());
+    assert!(validator.validate("$.spec[").is_err());
+}
+
+#[test]
+fn test_metadata_changes() {
+    let mut validator = InputValidator::new(ValidatorKind::Labels);
+
+    assert!(validator.validate("").is_ok());
+    assert!(validator.validate("app=web tier- example.com/team=a").is_ok());
+    assert_eq!(Err(8), validator.validate("app=web tier"));
+    assert_eq!(Err(0), validator.validate("app=web!"));
+
+    let mut validator = InputValidator::new(ValidatorKind::Annotations);
+
+    assert!(validator.validate("app=web!").is_ok());
+    assert!(validator.validate("note=\"hello, world\"").is_ok());
+    assert_eq!(Err(5), validator.validate("note=\"hello"));
+}
+
+#[test]
+fn test_namespaces() {
+    let mut validator = InputValidator::new(ValidatorKind::Namespaces);
+
+    assert!(validator.validate("").is_ok());
+    assert!(validator.validate("kube-system").is_ok());
+    assert!(validator.validate("team-a,team-b,").is_ok());
+    assert!(validator.validate("team-*,kube-?ublic").is_ok());
+    assert_eq!(Err(7), validator.validate("team-a,Team-b"));
+    assert_eq!(Err(11), validator.validate("team-a,team_*"));
+    assert_eq!(Err(4), validator.validate("team-"));
+}
@@ -2,10 +2,11 @@ use anyhow::Result;
 use b4n_common::{DEFAULT_ERROR_DURATION, DEFAULT_MESSAGE_DURATION, IconKind};
 use b4n_config::keys::{KeyBindings, KeyCommand};
 use b4n_config::themes::Theme;
-use b4n_config::{Config, ConfigError, ConfigWatcher, History, PluginsWatcher, SyntaxData};
-use b4n_kube::{Kind, NAMESPACES, Namespace, ResourceRef};
+use b4n_config::{Bookmark, Bookmarks, Config, ConfigError, ConfigWatcher, History, PluginsWatcher, SyntaxData};
+use b4n_kube::{Kind, NAMESPACES, Namespace, ResourceRef, ResourceRefFilter};
 use b4n_tasks::commands::{
     Command, CommandResult, KubernetesClientError, KubernetesClientResult, ListKubeContextsCommand, ListThemesCommand,
+    SearchIndexItem, TriggerCronJobError,
 };
 use b4n_tui::widgets::Footer;
 use b4n_tui::{ResponseEvent, ScopeData, ToSelectData, Tui, TuiEvent};
@@ -16,9 +17,10 @@ use std::rc::Rc;
 use tokio::runtime::Handle;
 
 use crate::core::{
-    AppData, BgWorker, BgWorkerError, KubernetesClientManager, SharedAppData, SharedAppDataExt, SharedBgWorker, ViewsManager,
+    AlertsManager, AppData, BgWorker, BgWorkerError, DetailView, KubernetesClientManager, NavigationEntry, SharedAppData,
+    SharedAppDataExt, SharedBgWorker, ViewsManager,
 };
-use crate::ui::views::ResourcesView;
+use crate::ui::views::{NextRefreshActions, ResourcesView};
 
 /// Application execution flow.
 #[derive(Clone, Debug, PartialEq)]
@@ -41,19 +43,29 @@ pub struct App {
     worker: SharedBgWorker,
     config_watcher: ConfigWatcher<Config>,
     history_watcher: ConfigWatcher<History>,
+    bookmarks_watcher: ConfigWatcher<Bookmarks>,
     theme_watcher: ConfigWatcher<Theme>,
     plugins_watcher: PluginsWatcher,
     client_manager: KubernetesClientManager,
+    alerts_manager: AlertsManager,
     views_manager: ViewsManager,
+    is_search_requested: bool,
 }
 
 impl App {
     /// Creates new [`App`] instance.
-    pub fn new(runtime: Handle, config: Config, history: History, theme: Theme, allow_insecure: bool) -> Result<Self> {
+    pub fn new(
+        runtime: Handle,
+        config: Config,
+        history: History,
+        bookmarks: Bookmarks,
+        theme: Theme,
+        allow_insecure: bool,
+    ) -> Result<Self> {
         let is_mouse_enabled = config.mouse;
         let theme_path = config.theme_path();
         let syntax_data = SyntaxData::new(&theme);
-        let data = Rc::new(RefCell::new(AppData::new(config, history, theme)));
+        let data = Rc::new(RefCell::new(AppData::new(config, history, bookmarks, theme)));
         let footer = Footer::default();
         let worker = Rc::new(RefCell::new(BgWorker::new(
             runtime.clone(),
@@ -63,6 +75,7 @@ impl App {
         let resources = ResourcesView::new(Rc::clone(&data), Rc::clone(&worker), footer.get_transmitter());
         let client_manager =
             KubernetesClientManager::new(Rc::clone(&data), Rc::clone(&worker), footer.get_transmitter(), allow_insecure);
+        let alerts_manager = AlertsManager::new(Rc::clone(&data), Rc::clone(&worker), footer.get_transmitter());
         let mut views_manager = ViewsManager::new(Rc::clone(&data), Rc::clone(&worker), resources, footer);
         views_manager.set_message_history_hint();
 
@@ -72,10 +85,13 @@ impl App {
             worker,
             config_watcher: Config::watcher(runtime.clone()),
             history_watcher: History::watcher(runtime.clone()),
+            bookmarks_watcher: Bookmarks::watcher(runtime.clone()),
             theme_watcher: ConfigWatcher::new(runtime.clone(), theme_path),
             plugins_watcher: PluginsWatcher::new(runtime, Config::plugins_dir()),
             client_manager,
+            alerts_manager,
             views_manager,
+            is_search_requested: false,
         })
     }
 
@@ -87,6 +103,7 @@ impl App {
             .process_context_change(context, namespace, String::default(), Scope::Cluster);
         self.config_watcher.start()?;
         self.history_watcher.start()?;
+        self.bookmarks_watcher.start()?;
         self.theme_watcher.start()?;
         self.plugins_watcher.start()?;
         self.tui.enter_terminal()?;
@@ -97,9 +114,11 @@ impl App {
 
     /// Cancels all app tasks.
     pub fn cancel(&mut self) {
+        self.alerts_manager.stop();
         self.worker.borrow_mut().cancel_all();
         self.config_watcher.cancel();
         self.history_watcher.cancel();
+        self.bookmarks_watcher.cancel();
         self.theme_watcher.cancel();
         self.plugins_watcher.cancel();
         self.tui.cancel();
@@ -107,9 +126,11 @@ impl App {
 
     /// Stops app.
     pub fn stop(&mut self) -> Result<()> {
+        self.alerts_manager.stop();
         self.worker.borrow_mut().stop_all();
         self.config_watcher.stop();
         self.history_watcher.stop();
+        self.bookmarks_watcher.stop();
         self.theme_watcher.stop();
         self.plugins_watcher.stop();
         self.tui.exit_terminal()?;
@@ -142,12 +163,17 @@ impl App {
             }
 
             self.views_manager.set_message_history_hint();
+            self.alerts_manager.update_rules();
         }
 
         if let Some(Ok(history)) = self.history_watcher.try_next() {
             self.data.borrow_mut().history = history;
         }
 
+        if let Some(Ok(bookmarks)) = self.bookmarks_watcher.try_next() {
+            self.data.borrow_mut().bookmarks = bookmarks;
+        }
+
         match self.theme_watcher.try_next() {
             Some(Ok(theme)) => {
                 self.worker.borrow_mut().update_syntax_data(SyntaxData::new(&theme));
@@ -172,6 +198,7 @@ impl App {
         self.process_commands_results();
         self.process_connection_events();
         self.views_manager.update_lists();
+        self.alerts_manager.process_ticks();
         if self.views_manager.process_ticks() == ResponseEvent::ExitApplication {
             return Ok(ExecutionFlow::Stop);
         }
@@ -211,6 +238,11 @@ impl App {
             return Ok(ResponseEvent::Handled);
         }
 
+        if self.data.has_binding(event, KeyCommand::AlertJump) && self.alerts_manager.last_alert().is_some() {
+            self.jump_to_alert()?;
+            return Ok(ResponseEvent::Handled);
+        }
+
         match self.views_manager.process_event(event) {
             ResponseEvent::ExitApplication => return Ok(ResponseEvent::ExitApplication),
             ResponseEvent::Change(kind, namespace) => {
@@ -225,6 +257,7 @@ impl App {
             ResponseEvent::ChangeKind(kind) => self.change_kind(kind.into(), ToSelectData::None)?,
             ResponseEvent::ChangeKindAndSelect(kind, to_select) => self.change_kind(kind.into(), to_select)?,
             ResponseEvent::ChangeNamespace(namespace) => self.change_namespace(namespace.into())?,
+            ResponseEvent::ChangeServerFilter(filter) => self.change_server_filter(filter)?,
             ResponseEvent::ViewContainers(pod_name, pod_namespace) => self.view_containers(pod_name, pod_namespace.into())?,
             ResponseEvent::ViewInvolved(kind, namespace, to_select) => {
                 self.view_involved(kind.into(), namespace.into(), to_select)?;
@@ -236,28 +269,54 @@ impl App {
                 self.view_scoped(kind.into(), namespace.into(), to_select, scope, TrackFlow::Nothing)?;
             },
             ResponseEvent::ViewNamespaces => self.view_namespaces()?,
+            ResponseEvent::ViewHistoryBack => self.navigate_history(true)?,
+            ResponseEvent::ViewHistoryForward => self.navigate_history(false)?,
             ResponseEvent::ListKubeContexts => self.list_kube_contexts(),
             ResponseEvent::ListThemes => self.list_app_themes(),
             ResponseEvent::ListNamespaces => self.views_manager.show_namespaces_list(),
             ResponseEvent::ListResourcePorts(resource) => self.worker.borrow_mut().list_resource_ports(resource),
             ResponseEvent::ChangeContext(context, namespace) => self.request_kubernetes_client(context, namespace.as_deref()),
             ResponseEvent::ChangeTheme(theme) => self.process_theme_change(theme),
+            ResponseEvent::OpenBookmark(name) => self.open_bookmark(&name)?,
+            ResponseEvent::SaveBookmark(bookmark) => self.save_bookmark(*bookmark),
             ResponseEvent::AskDeleteResources => self.views_manager.ask_delete_resources(),
             ResponseEvent::DeleteResources(policy, force, detach) => self.views_manager.delete_resources(policy, force, detach),
+            ResponseEvent::ExportResources(resources, path) => self.views_manager.export_resources(resources, &path),
+            ResponseEvent::PatchMetadata(resources, changes, overwrite) => {
+                self.views_manager.patch_metadata(resources, changes, overwrite);
+            },
             ResponseEvent::NewYaml(resource, is_full) => self.request_yaml_template(resource, is_full),
             ResponseEvent::ViewYaml(resource, decode, edit) => self.request_yaml(resource, decode, edit),
+            ResponseEvent::ViewHelmRelease(resource, view) => self.views_manager.show_helm_release(resource, view),
+            ResponseEvent::QueryResources(resources, expression, as_table) => {
+                self.views_manager.query_resources(resources, expression, as_table);
+            },
             ResponseEvent::ViewLogs(resource, containers) => self.views_manager.show_logs(&resource, containers, false),
             ResponseEvent::ViewPreviousLogs(resource, containers) => self.views_manager.show_logs(&resource, containers, true),
             ResponseEvent::Describe(resource) => self.views_manager.describe(resource),
+            ResponseEvent::ViewRelations(resource) => self.views_manager.show_relations(resource),
             ResponseEvent::AttachContainer(container) => self.views_manager.open_shell(container, true),
             ResponseEvent::OpenShell(container) => self.views_manager.open_shell(container, false),
+            ResponseEvent::OpenNodeShell(node, namespace, image) => self.views_manager.create_node_shell(node, namespace, image),
+            ResponseEvent::TriggerCronJob(resource) => self.views_manager.trigger_cron_job(resource),
+            ResponseEvent::SuspendCronJobs(resources, suspend) => self.views_manager.suspend_cron_jobs(resources, suspend),
+            ResponseEvent::ListSessions => self.views_manager.show_sessions_list(),
+            ResponseEvent::ResumeSession(id) => self.views_manager.resume_session(id),
             ResponseEvent::ShowPortForwards => self.views_manager.show_port_forwards(),
+            ResponseEvent::ShowEventStream => self.views_manager.show_event_stream(),
+            ResponseEvent::JumpToAlert => self.jump_to_alert()?,
+            ResponseEvent::ShowResourcesSearch => self.show_resources_search(),
+            ResponseEvent::TogglePreview => self.views_manager.toggle_preview(),
             ResponseEvent::PortForward(resource, to, from, address) => self.port_forward(resource, to, from, &address),
             ResponseEvent::RunPlugin(id, context) => self.views_manager.run_plugin(&id, context),
             ResponseEvent::InjectContainer(resource, container) => {
                 self.worker.borrow_mut().inject_container(&resource, container);
             },
-            ResponseEvent::TransferFile(container, context) => self.worker.borrow_mut().transfer_file(container, context),
+            ResponseEvent::ExecCommand(containers, command) => self.views_manager.exec_command(containers, command),
+            ResponseEvent::CopyPod(resource, copy) => self.views_manager.copy_pod(&resource, copy),
+            ResponseEvent::TransferFile(container, context) => {
+                self.worker.borrow_mut().transfer_file(container, context);
+            },
             _ => (),
         }
 
@@ -272,6 +331,8 @@ impl App {
                 CommandResult::KubernetesClient(result) => self.change_client(&command.id, result),
                 CommandResult::GetNewResourceYaml(result) => self.views_manager.new_yaml_result(&command.id, result),
                 CommandResult::GetResourceYaml(result) => self.views_manager.show_yaml_result(&command.id, result),
+                CommandResult::GetHelmRelease(result) => self.views_manager.show_helm_release_result(&command.id, result),
+                CommandResult::QueryResources(result) => self.views_manager.show_query_result(&command.id, result),
                 CommandResult::SetNewResourceYaml(result) => self.views_manager.create_yaml_result(&command.id, result),
                 CommandResult::SetResourceYaml(result) => self.views_manager.edit_yaml_result(&command.id, result),
                 CommandResult::ContextsList(list) => self.views_manager.show_contexts_list(&list),
@@ -279,7 +340,59 @@ impl App {
                 CommandResult::ResourcePortsList(list) => self.views_manager.show_ports_list(&list),
                 CommandResult::RunPluginOutput(result) => self.views_manager.show_plugin_output(&command.id, result),
                 CommandResult::InjectedContainer(result) => self.views_manager.show_inject_result(result),
-                CommandResult::TransferFile(result) => self.views_manager.show_transfer_file_result(result),
+                CommandResult::NodeShell(result) => self.views_manager.open_node_shell(result),
+                CommandResult::CopyPod(result) => self.views_manager.open_pod_copy(result),
+                CommandResult::TriggerCronJob(result) => self.show_triggered_job(result),
+                CommandResult::SearchIndex(items) => self.process_search_index(&command.id, items),
+                CommandResult::ResourceRelations(result) => self.views_manager.show_relations_result(&command.id, result),
+                CommandResult::ResourceUsers(result) => self.views_manager.show_resource_users(&command.id, result),
+                CommandResult::TransferFile(result) => {
+                    self.worker.borrow_mut().finish_transfer(&command.id);
+                    self.views_manager.show_transfer_file_result(result);
+                },
+            }
+        }
+    }
+
+    /// Changes observed resources to the job created from the cron job and highlights it.
+    fn show_triggered_job(&mut self, result: Result<ResourceRef, TriggerCronJobError>) {
+        let Some(job) = self.views_manager.show_trigger_cron_job_result(result) else {
+            return;
+        };
+
+        let to_select = ToSelectData::new(job.name.unwrap_or_default(), Some(job.namespace.as_str()));
+        if let Err(error) = self.view_involved(job.kind, job.namespace, to_select) {
+            tracing::warn!("Cannot show triggered job: {}", error);
+        }
+    }
+
+    /// Shows resources search palette, builds the search index first if needed.\
+    /// **Note** that an outdated index is still shown while a new one is being built.
+    fn show_resources_search(&mut self) {
+        if !self.data.borrow().is_connected() {
+            return;
+        }
+
+        let is_stale = self.worker.borrow().is_search_index_stale();
+        if is_stale {
+            self.worker.borrow_mut().build_search_index();
+        }
+
+        if let Some(items) = self.worker.borrow().search_index() {
+            self.views_manager.show_search_list(items);
+        } else {
+            self.is_search_requested = true;
+            let msg = "Indexing resources of all kinds, search will open when ready…";
+            self.views_manager.footer().show_info(msg, DEFAULT_MESSAGE_DURATION);
+        }
+    }
+
+    /// Stores the new search index and opens the search palette if it was requested.
+    fn process_search_index(&mut self, command_id: &str, items: Vec<SearchIndexItem>) {
+        if self.worker.borrow_mut().set_search_index(command_id, items) && self.is_search_requested {
+            self.is_search_requested = false;
+            if let Some(items) = self.worker.borrow().search_index() {
+                self.views_manager.show_search_list(items);
             }
         }
     }
@@ -306,22 +419,30 @@ impl App {
             || !self.data.borrow().current.is_kind_equal(&kind)
             || self.data.borrow().current.resource.filter.is_some()
         {
+            self.record_navigation();
             if track == TrackFlow::Add {
                 self.views_manager.remember_current_resource();
             } else if track == TrackFlow::Clear {
                 self.data.borrow_mut().previous.clear();
             }
 
-            self.views_manager.handle_kind_change(to_select);
-            self.views_manager.handle_namespace_change(namespace.clone());
-            let resource = ResourceRef::new(kind.clone(), namespace.clone());
-            let scope = self.worker.borrow_mut().restart(resource)?;
-            self.process_resources_change(Some(kind.into()), Some(namespace.into()), &scope);
+            self.restart_resources(kind, namespace, to_select)?;
         }
 
         Ok(())
     }
 
+    /// Restarts observed resources with the new kind and namespace, optionally selects one of them.
+    fn restart_resources(&mut self, kind: Kind, namespace: Namespace, to_select: ToSelectData) -> Result<(), BgWorkerError> {
+        self.views_manager.handle_kind_change(to_select);
+        self.views_manager.handle_namespace_change(namespace.clone());
+        let resource = ResourceRef::new(kind.clone(), namespace.clone());
+        let scope = self.worker.borrow_mut().restart(resource)?;
+        self.process_resources_change(Some(kind.into()), Some(namespace.into()), &scope);
+
+        Ok(())
+    }
+
     /// Changes observed resources kind, optionally selects one of them.\
     /// **Note** that it selects current namespace if the resource kind is `namespaces`.
     fn change_kind(&mut self, kind: Kind, to_select: ToSelectData) -> Result<(), BgWorkerError> {
@@ -329,6 +450,7 @@ impl App {
         if (!self.data.borrow().current.is_kind_equal(&kind) || self.data.borrow().current.resource.filter.is_some())
             && (!kind.is_namespaces() || self.worker.borrow().namespaces.has_access())
         {
+            self.record_navigation();
             let namespace = self.data.borrow().current.get_namespace();
             if kind.as_str() == NAMESPACES {
                 let to_select = ToSelectData::Some(namespace.as_str().to_owned(), String::new());
@@ -358,6 +480,7 @@ impl App {
                     self.change(kind, namespace, name, TrackFlow::Clear)?;
                 }
             } else {
+                self.record_navigation();
                 self.update_history_data(None, Some(namespace.clone().into()));
                 self.views_manager.handle_namespace_change(namespace.clone());
                 if self.data.borrow().current.scope == Scope::Namespaced {
@@ -372,9 +495,27 @@ impl App {
         Ok(())
     }
 
+    /// Restarts observed resources with the new server-side `filter`, or without it if `None` is provided.
+    fn change_server_filter(&mut self, filter: Option<ResourceRefFilter>) -> Result<(), BgWorkerError> {
+        let current = self.data.borrow().current.resource.clone();
+        let resource = match filter {
+            Some(filter) => ResourceRef::filtered(current.kind, current.namespace, filter),
+            None => ResourceRef::new(current.kind, current.namespace),
+        };
+        self.worker.borrow_mut().restart(resource)?;
+
+        Ok(())
+    }
+
     /// Changes observed resources to `containers` for a specified `pod`.
     fn view_containers(&mut self, pod_name: String, pod_namespace: Namespace) -> Result<(), BgWorkerError> {
+        self.record_navigation();
         self.views_manager.remember_current_resource();
+        self.restart_containers(pod_name, pod_namespace)
+    }
+
+    /// Restarts observed resources with `containers` for a specified `pod`.
+    fn restart_containers(&mut self, pod_name: String, pod_namespace: Namespace) -> Result<(), BgWorkerError> {
         self.views_manager.cache_page_data();
         self.views_manager
             .restore_page_data(Some(&pod_name), Some(pod_namespace.as_str()), &Scope::Namespaced, true, None);
@@ -390,6 +531,20 @@ impl App {
         self.change(kind, namespace, to_select, TrackFlow::Add)
     }
 
+    /// Closes the current view and highlights the resource of the last raised alert.
+    fn jump_to_alert(&mut self) -> Result<(), BgWorkerError> {
+        let Some(alert) = self.alerts_manager.last_alert().cloned() else {
+            self.views_manager
+                .footer()
+                .show_info("No alerts raised yet", DEFAULT_MESSAGE_DURATION);
+            return Ok(());
+        };
+
+        self.views_manager.close_view();
+        let to_select = ToSelectData::new(alert.name, alert.namespace.as_option());
+        self.view_involved(alert.kind, alert.namespace, to_select)
+    }
+
     /// Changes observed resource to the scoped one.
     fn view_scoped(
         &mut self,
@@ -400,30 +555,44 @@ impl App {
         track: TrackFlow,
     ) -> Result<(), BgWorkerError> {
         if !self.data.borrow().current.is_kind_equal(&kind) {
+            self.record_navigation();
             if track == TrackFlow::Add {
                 self.views_manager.remember_current_resource();
             }
 
-            let is_all_namespaces = namespace.is_all();
-            self.views_manager.handle_kind_change(to_select);
-            self.views_manager.cache_page_data();
-            self.views_manager.restore_page_data(
-                Some(kind.as_str()),
-                Some(namespace.as_str()),
-                &scope.list,
-                false,
-                Some(&scope.filter),
-            );
-            self.views_manager.set_page_view(&scope.list, is_all_namespaces);
-            self.views_manager.force_header_scope(Some(scope.header));
-
-            let resource = ResourceRef::filtered(kind, namespace, scope.filter);
-            self.worker.borrow_mut().restart(resource)?;
+            self.restart_scoped(kind, namespace, to_select, scope)?;
         }
 
         Ok(())
     }
 
+    /// Restarts observed resources with the scoped ones.
+    fn restart_scoped(
+        &mut self,
+        kind: Kind,
+        namespace: Namespace,
+        to_select: ToSelectData,
+        scope: ScopeData,
+    ) -> Result<(), BgWorkerError> {
+        let is_all_namespaces = namespace.is_multiple();
+        self.views_manager.handle_kind_change(to_select);
+        self.views_manager.cache_page_data();
+        self.views_manager.restore_page_data(
+            Some(kind.as_str()),
+            Some(namespace.as_str()),
+            &scope.list,
+            false,
+            Some(&scope.filter),
+        );
+        self.views_manager.set_page_view(&scope.list, is_all_namespaces);
+        self.views_manager.force_header_scope(Some(scope.header));
+
+        let resource = ResourceRef::filtered(kind, namespace, scope.filter);
+        self.worker.borrow_mut().restart(resource)?;
+
+        Ok(())
+    }
+
     /// Changes observed resources kind to `namespaces`.
     fn view_namespaces(&mut self) -> Result<(), BgWorkerError> {
         self.change_kind(NAMESPACES.into(), ToSelectData::None)
@@ -451,6 +620,7 @@ impl App {
 
             let scope = self.worker.borrow_mut().start(result.client, result.discovery, resource);
             if let Ok(scope) = scope {
+                self.alerts_manager.restart();
                 self.views_manager
                     .process_context_change(context, result.namespace.clone(), version, scope.clone());
                 self.process_resources_change(Some(result.kind.into()), Some(result.namespace.into()), &scope);
@@ -464,7 +634,7 @@ impl App {
         let is_all_namespaces = namespace
             .as_deref()
             .map_or_else(|| self.data.borrow().current.get_namespace(), Namespace::from)
-            .is_all();
+            .is_multiple();
         self.views_manager.cache_page_data();
         self.views_manager
             .restore_page_data(kind.as_deref(), namespace.as_deref(), scope, false, None);
@@ -504,10 +674,18 @@ impl App {
             return;
         }
 
+        let (kind, namespace) = self.data.borrow().get_namespaced_resource_from_config(&context, namespace);
+        self.record_navigation();
+        self.switch_context(context, kind, namespace);
+    }
+
+    /// Stops observing the current context and requests new kubernetes client for the specified one.
+    fn switch_context(&mut self, context: String, kind: Kind, namespace: Namespace) {
+        self.is_search_requested = false;
         self.client_manager.erase_request(true);
+        self.alerts_manager.stop();
         self.worker.borrow_mut().stop();
 
-        let (kind, namespace) = self.data.borrow().get_namespaced_resource_from_config(&context, namespace);
         self.views_manager.reset();
         self.views_manager
             .process_context_change(context.clone(), namespace.clone(), String::default(), Scope::Cluster);
@@ -515,6 +693,110 @@ impl App {
         self.client_manager.request_new_client(context, kind, namespace);
     }
 
+    /// Opens the bookmarked view, switching the kube context if needed.
+    fn open_bookmark(&mut self, name: &str) -> Result<(), BgWorkerError> {
+        let Some(bookmark) = self.data.borrow().bookmarks.get(name).cloned() else {
+            self.show_error(format!("Bookmark '{name}' not found"));
+            return Ok(());
+        };
+
+        let to_select = bookmark.highlighted.as_ref().map_or(ToSelectData::None, |item| {
+            ToSelectData::Some(item.name.clone(), item.group.clone())
+        });
+        self.views_manager.set_next_refresh(NextRefreshActions {
+            highlight_item: bookmark.highlighted.map(|item| (item.name, item.group)),
+            apply_filter: bookmark.filter,
+            sort_info: bookmark.sort_column.map(|column| (column, bookmark.sort_descending)),
+            clear_header_scope: true,
+            ..Default::default()
+        });
+
+        self.record_navigation();
+        let namespace = Namespace::from(bookmark.namespace);
+        let is_same_context = self.data.borrow().current.context == bookmark.context;
+        if is_same_context && self.data.borrow().is_connected() {
+            let kind = self.worker.borrow().ensure_kind_is_plural(bookmark.kind.into());
+            self.data.borrow_mut().previous.clear();
+            self.restart_resources(kind, namespace, to_select)?;
+        } else {
+            self.switch_context(bookmark.context, bookmark.kind.into(), namespace);
+        }
+
+        Ok(())
+    }
+
+    /// Remembers the current location in the navigation history.
+    fn record_navigation(&mut self) {
+        if self.data.borrow().current.context.is_empty() {
+            return;
+        }
+
+        let entry = self.views_manager.get_navigation_entry();
+        self.data.borrow_mut().navigation.push(entry);
+    }
+
+    /// Moves back or forward in the navigation history.
+    fn navigate_history(&mut self, is_back: bool) -> Result<(), BgWorkerError> {
+        if self.data.borrow().current.context.is_empty() {
+            return Ok(());
+        }
+
+        let current = self.views_manager.get_navigation_entry();
+        let entry = if is_back {
+            self.data.borrow_mut().navigation.back(current)
+        } else {
+            self.data.borrow_mut().navigation.forward(current)
+        };
+
+        match entry {
+            Some(entry) => self.restore_navigation_entry(entry),
+            None => Ok(()),
+        }
+    }
+
+    /// Restores location saved in the navigation history, including the detail view that was open there.
+    fn restore_navigation_entry(&mut self, entry: NavigationEntry) -> Result<(), BgWorkerError> {
+        let NavigationEntry { context, data, view } = entry;
+        self.views_manager.close_view();
+        self.data.borrow_mut().previous.clear();
+        self.views_manager.set_next_refresh(NextRefreshActions::from_previous(&data));
+
+        let is_same_context = self.data.borrow().current.context == context;
+        if !is_same_context || !self.data.borrow().is_connected() {
+            self.switch_context(context, data.resource.kind, data.namespace);
+        } else if data.resource.is_container() {
+            let pod_name = data.resource.name.unwrap_or_default();
+            self.restart_containers(pod_name, data.namespace)?;
+        } else if let Some(filter) = data.resource.filter {
+            let scope = ScopeData {
+                list: data.list,
+                header: data.header,
+                filter,
+            };
+            self.restart_scoped(data.resource.kind, data.namespace, data.highlighted, scope)?;
+        } else {
+            self.restart_resources(data.resource.kind, data.namespace, data.highlighted)?;
+        }
+
+        match view {
+            Some(DetailView::Describe(resource)) => self.views_manager.describe(resource),
+            Some(DetailView::Relations(resource)) => self.views_manager.show_relations(resource),
+            Some(DetailView::EventStream) => self.views_manager.show_event_stream(),
+            None => (),
+        }
+
+        Ok(())
+    }
+
+    /// Adds or replaces the bookmark and saves all bookmarks to a file.
+    fn save_bookmark(&mut self, bookmark: Bookmark) {
+        let msg = format!("Bookmark '{}' saved", bookmark.name);
+        self.data.borrow_mut().bookmarks.put(bookmark);
+        self.bookmarks_watcher.skip_next();
+        self.worker.borrow_mut().save_bookmarks(self.data.borrow().bookmarks.clone());
+        self.views_manager.footer().show_info(msg, DEFAULT_MESSAGE_DURATION);
+    }
+
     /// Sends command to fetch resource's YAML template to the background executor.
     fn request_yaml_template(&mut self, resource: ResourceRef, is_full: bool) {
         let command_id = self.worker.borrow_mut().get_yaml_template(
@@ -1,7 +1,7 @@
 use arboard::Clipboard;
 use b4n_common::NotificationSink;
 use b4n_config::keys::{KeyBindings, KeyCombination, KeyCommand};
-use b4n_config::{Config, History, themes::Theme};
+use b4n_config::{Bookmarks, Config, History, themes::Theme};
 use b4n_config::{PluginInput, PluginRef, Plugins};
 use b4n_kube::{CONTAINERS, InitData, Kind, Namespace, ResourceRef};
 use b4n_tui::widgets::SharedClipboard;
@@ -10,6 +10,7 @@ use kube::discovery::Scope;
 use std::borrow::Cow;
 use std::{cell::RefCell, collections::HashSet, rc::Rc};
 
+use crate::core::NavigationHistory;
 use crate::kube::kinds::KindItem;
 
 pub type SharedAppData = Rc<RefCell<AppData>>;
@@ -135,6 +136,9 @@ pub struct AppData {
     /// Application history data.
     pub history: History,
 
+    /// Named bookmarks.
+    pub bookmarks: Bookmarks,
+
     /// Current application theme.
     pub theme: Theme,
 
@@ -145,6 +149,9 @@ pub struct AppData {
     pub current: ResourcesInfo,
     pub previous: Vec<PreviousData>,
 
+    /// Back and forward history of the visited resources lists.
+    pub navigation: NavigationHistory,
+
     /// Filter that should be applied to all resources.
     pub pinned_filter: Option<String>,
     pub is_pinned: bool,
@@ -154,6 +161,9 @@ pub struct AppData {
     /// Holds all discovered kinds.
     pub kinds: Option<Vec<KindItem>>,
 
+    /// Holds names of all discovered namespaces.
+    pub namespaces: Vec<String>,
+
     /// Holds clipboard object.
     pub clipboard: Option<SharedClipboard>,
 
@@ -163,12 +173,13 @@ pub struct AppData {
 
 impl AppData {
     /// Creates new [`AppData`] instance.
-    pub fn new(config: Config, history: History, theme: Theme) -> Self {
+    pub fn new(config: Config, history: History, bookmarks: Bookmarks, theme: Theme) -> Self {
         let key_bindings = KeyBindings::default_with(config.key_bindings.clone());
         Self {
             config,
             key_bindings,
             history,
+            bookmarks,
             theme,
             clipboard: Clipboard::new().ok().map(|clipboard| Rc::new(RefCell::new(clipboard))),
             state: ConnectionState::Connecting,
@@ -0,0 +1,133 @@
+use b4n_common::{DEFAULT_ERROR_DURATION, NotificationSink};
+use b4n_config::AlertRule;
+use b4n_config::keys::KeyCommand;
+use b4n_kube::utils::get_resource;
+use b4n_kube::{BgObserver, Kind, Namespace, ObserverResult, ResourceRef};
+use kube::discovery::Scope;
+use std::io::Write;
+
+use crate::core::{SharedAppData, SharedAppDataExt, SharedBgWorker};
+use crate::kube::alerts::{Alert, AlertWatch};
+
+/// Background observer together with the alert rule evaluated on its results.
+struct RuleObserver {
+    watch: AlertWatch,
+    observer: BgObserver,
+}
+
+/// Alerts manager that evaluates alert rules from the configuration on the observed resources.
+pub struct AlertsManager {
+    app_data: SharedAppData,
+    worker: SharedBgWorker,
+    footer_tx: NotificationSink,
+    rules: Vec<AlertRule>,
+    observers: Vec<RuleObserver>,
+    last_alert: Option<Alert>,
+}
+
+impl AlertsManager {
+    /// Creates new [`AlertsManager`] instance.
+    pub fn new(app_data: SharedAppData, worker: SharedBgWorker, footer_tx: NotificationSink) -> Self {
+        Self {
+            app_data,
+            worker,
+            footer_tx,
+            rules: Vec::new(),
+            observers: Vec::new(),
+            last_alert: None,
+        }
+    }
+
+    /// Starts observers for all alert rules from the configuration.\
+    /// **Note** that it must be called each time the kubernetes client changes.
+    pub fn restart(&mut self) {
+        self.observers.clear();
+        self.last_alert = None;
+        self.rules = self.app_data.borrow().config.alerts.clone();
+
+        let worker = self.worker.borrow();
+        let Some(client) = worker.kubernetes_client() else {
+            return;
+        };
+
+        for rule in &self.rules {
+            let kind = worker.ensure_kind_is_plural(Kind::from(rule.kind.as_str()));
+            let discovery = get_resource(worker.discovery_list(), &kind);
+            let namespace = match rule.namespace.as_deref() {
+                Some(namespace) if discovery.as_ref().is_some_and(|(_, cap)| cap.scope == Scope::Namespaced) => {
+                    Namespace::from(namespace)
+                },
+                _ => Namespace::all(),
+            };
+
+            let mut observer = BgObserver::new(worker.runtime_handle().clone(), None);
+            let resource = ResourceRef::new(kind.clone(), namespace);
+            match observer.start(client.get_client(), resource, discovery, None, false) {
+                Ok(_) => self.observers.push(RuleObserver {
+                    watch: AlertWatch::new(rule.clone(), kind),
+                    observer,
+                }),
+                Err(error) => {
+                    let msg = format!("Cannot watch '{}' for the '{}' alert rule: {}", rule.kind, rule.name(), error);
+                    self.footer_tx.show_error(msg, DEFAULT_ERROR_DURATION);
+                },
+            }
+        }
+    }
+
+    /// Restarts observers if alert rules in the configuration have changed.
+    pub fn update_rules(&mut self) {
+        if self.rules != self.app_data.borrow().config.alerts {
+            self.restart();
+        }
+    }
+
+    /// Stops all observers.
+    pub fn stop(&mut self) {
+        self.observers.clear();
+        self.last_alert = None;
+    }
+
+    /// Evaluates alert rules on the new data from observers and shows raised alerts in the footer.
+    pub fn process_ticks(&mut self) {
+        let mut alerts = Vec::new();
+        for rule in &mut self.observers {
+            while let Some(result) = rule.observer.try_next() {
+                match *result {
+                    ObserverResult::Init(init) => rule.watch.init(&init),
+                    ObserverResult::InitDone => rule.watch.init_done(),
+                    ObserverResult::Apply(object) => alerts.extend(rule.watch.apply(&object)),
+                    ObserverResult::Delete(object) => rule.watch.delete(&object),
+                }
+            }
+        }
+
+        if alerts.is_empty() {
+            return;
+        }
+
+        let key = self.app_data.get_key_name(KeyCommand::AlertJump).to_ascii_uppercase();
+        for alert in &alerts {
+            let msg = format!("{}, press {key} to jump", alert.message);
+            self.footer_tx.show_error(msg, DEFAULT_ERROR_DURATION);
+        }
+
+        if alerts.iter().any(|a| a.bell) {
+            ring_bell();
+        }
+
+        self.last_alert = alerts.pop();
+    }
+
+    /// Returns the last raised alert.
+    pub fn last_alert(&self) -> Option<&Alert> {
+        self.last_alert.as_ref()
+    }
+}
+
+/// Rings the terminal bell.
+fn ring_bell() {
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
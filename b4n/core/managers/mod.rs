@@ -1,5 +1,11 @@
+pub use self::alerts::*;
 pub use self::client::*;
+pub use self::preview::*;
+pub use self::sessions::*;
 pub use self::views::*;
 
+mod alerts;
 mod client;
+mod preview;
+mod sessions;
 mod views;
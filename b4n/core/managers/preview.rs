@@ -0,0 +1,349 @@
+use b4n_common::NotificationSink;
+use b4n_kube::{PODS, ResourceRef, ResourceTag};
+use b4n_tasks::commands::CommandResult;
+use b4n_tui::{MouseEventKind, ResponseEvent, TuiEvent};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::core::{SharedAppData, SharedBgWorker};
+use crate::ui::views::{DescribeView, LogsView, View, YamlView, get_logs_containers};
+
+#[cfg(test)]
+#[path = "./preview.tests.rs"]
+mod preview_tests;
+
+const PREVIEW_DELAY: Duration = Duration::from_millis(250);
+const DEFAULT_LIST_RATIO: u16 = 50;
+const MIN_LIST_RATIO: u16 = 20;
+const MAX_LIST_RATIO: u16 = 80;
+const LIST_RATIO_STEP: u16 = 5;
+const MIN_WIDTH_FOR_COLUMNS: u16 = 140;
+
+/// Content displayed in the preview pane.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewMode {
+    #[default]
+    Yaml,
+    Describe,
+    Logs,
+}
+
+impl PreviewMode {
+    /// Returns the mode that follows this one.
+    pub fn next(self) -> Self {
+        match self {
+            PreviewMode::Yaml => PreviewMode::Describe,
+            PreviewMode::Describe => PreviewMode::Logs,
+            PreviewMode::Logs => PreviewMode::Yaml,
+        }
+    }
+
+    /// Returns the mode name displayed to the user.
+    pub fn title(self) -> &'static str {
+        match self {
+            PreviewMode::Yaml => "YAML",
+            PreviewMode::Describe => "describe",
+            PreviewMode::Logs => "logs",
+        }
+    }
+}
+
+/// Resource highlighted on the resources list that should be previewed.
+#[derive(Debug, Clone)]
+pub struct PreviewTarget {
+    pub resource: ResourceRef,
+    pub containers: Option<Vec<ResourceTag>>,
+}
+
+/// Pane that shows YAML, describe or logs of the resource highlighted on the resources list.
+pub struct PreviewPane {
+    pub is_visible: bool,
+    app_data: SharedAppData,
+    worker: SharedBgWorker,
+    mode: PreviewMode,
+    list_ratio: u16,
+    view: Option<Box<dyn View>>,
+    shown: Option<ResourceRef>,
+    is_built: bool,
+    pending: Option<(Option<PreviewTarget>, Instant)>,
+    area: Rect,
+    divider: Rect,
+    workspace: Rect,
+    is_dragging: bool,
+}
+
+impl PreviewPane {
+    /// Creates new [`PreviewPane`] instance.
+    pub fn new(app_data: SharedAppData, worker: SharedBgWorker) -> Self {
+        Self {
+            is_visible: false,
+            app_data,
+            worker,
+            mode: PreviewMode::default(),
+            list_ratio: DEFAULT_LIST_RATIO,
+            view: None,
+            shown: None,
+            is_built: false,
+            pending: None,
+            area: Rect::default(),
+            divider: Rect::default(),
+            workspace: Rect::default(),
+            is_dragging: false,
+        }
+    }
+
+    /// Shows or hides the preview pane.
+    pub fn toggle(&mut self) {
+        self.is_visible = !self.is_visible;
+        if !self.is_visible {
+            self.clear();
+        }
+    }
+
+    /// Switches the preview pane to the next mode.
+    pub fn next_mode(&mut self) {
+        self.mode = self.mode.next();
+        self.drop_view();
+        self.is_built = false;
+    }
+
+    /// Makes the preview pane bigger or smaller, resizing the resources list part of the workspace accordingly.
+    pub fn resize(&mut self, grow_preview: bool) {
+        self.list_ratio = get_resized_ratio(self.list_ratio, grow_preview);
+    }
+
+    /// Drops the previewed content, it will be rebuilt for the next highlighted resource.
+    pub fn clear(&mut self) {
+        self.drop_view();
+        self.shown = None;
+        self.is_built = false;
+        self.pending = None;
+        self.is_dragging = false;
+    }
+
+    /// Follows the highlighted resource, the preview is rebuilt when the highlight stays on it for a while.
+    pub fn follow(&mut self, target: Option<PreviewTarget>) {
+        if !self.is_visible {
+            return;
+        }
+
+        let resource = target.as_ref().map(|t| &t.resource);
+        if self.is_built && self.shown.as_ref() == resource {
+            self.pending = None;
+            return;
+        }
+
+        match &self.pending {
+            Some((pending, since)) if pending.as_ref().map(|t| &t.resource) == resource => {
+                if since.elapsed() >= PREVIEW_DELAY {
+                    self.pending = None;
+                    self.build(target);
+                }
+            },
+            _ => self.pending = Some((target, Instant::now())),
+        }
+    }
+
+    /// Allows the previewed view to do some computations on every app tick.
+    pub fn process_tick(&mut self) {
+        if self
+            .view
+            .as_mut()
+            .is_some_and(|v| v.process_tick() == ResponseEvent::Cancelled)
+        {
+            self.drop_view();
+        }
+    }
+
+    /// Returns `true` if provided command ID match the one associated with the previewed view.
+    pub fn command_id_match(&self, command_id: &str) -> bool {
+        self.view.as_ref().is_some_and(|v| v.command_id_match(command_id))
+    }
+
+    /// Passes command result to the previewed view.
+    pub fn process_command_result(&mut self, result: CommandResult) {
+        if let Some(view) = &mut self.view {
+            view.process_command_result(result);
+        }
+    }
+
+    /// Drops the previewed view after its command failed.
+    pub fn process_command_error(&mut self) {
+        self.drop_view();
+    }
+
+    /// Processes mouse events for the divider and scrolling of the preview pane.\
+    /// **Note** that it returns [`ResponseEvent::NotHandled`] for all other events.
+    pub fn process_event(&mut self, event: &TuiEvent) -> ResponseEvent {
+        if !self.is_visible {
+            return ResponseEvent::NotHandled;
+        }
+
+        if event.is_in(MouseEventKind::LeftClick, self.divider) {
+            self.is_dragging = true;
+            return ResponseEvent::Handled;
+        }
+
+        if self.is_dragging {
+            if event.is_mouse(MouseEventKind::LeftUp) {
+                self.is_dragging = false;
+                return ResponseEvent::Handled;
+            }
+
+            if event.is_mouse(MouseEventKind::LeftDrag)
+                && let Some(position) = event.position()
+            {
+                self.drag_divider(position.x, position.y);
+                return ResponseEvent::Handled;
+            }
+        }
+
+        if (event.is_in(MouseEventKind::ScrollUp, self.area) || event.is_in(MouseEventKind::ScrollDown, self.area))
+            && let Some(view) = &mut self.view
+        {
+            view.process_event(event);
+            return ResponseEvent::Handled;
+        }
+
+        ResponseEvent::NotHandled
+    }
+
+    /// Draws the preview pane on the part of the `area` and returns the remaining area for the resources list.
+    pub fn draw(&mut self, frame: &mut Frame<'_>, area: Rect) -> Rect {
+        self.workspace = area;
+        let is_side_by_side = area.width >= MIN_WIDTH_FOR_COLUMNS;
+        let layout = Layout::default()
+            .direction(if is_side_by_side {
+                Direction::Horizontal
+            } else {
+                Direction::Vertical
+            })
+            .constraints([
+                Constraint::Percentage(self.list_ratio),
+                Constraint::Length(1),
+                Constraint::Fill(1),
+            ])
+            .split(area);
+        self.divider = layout[1];
+        self.area = layout[2];
+
+        let style = {
+            let colors = &self.app_data.borrow().theme.colors;
+            Style::default().fg(colors.text.dim).bg(colors.text.bg)
+        };
+        let borders = if is_side_by_side { Borders::LEFT } else { Borders::TOP };
+        frame.render_widget(Block::new().borders(borders).border_style(style), self.divider);
+
+        if let Some(view) = &mut self.view {
+            view.draw(frame, self.area, false);
+        } else {
+            let text = if self.shown.is_some() {
+                format!(" {} preview is not available for the highlighted resource", self.mode.title())
+            } else {
+                String::new()
+            };
+            frame.render_widget(Paragraph::new(text).block(Block::new().style(style)), self.area);
+        }
+
+        layout[0]
+    }
+
+    fn drag_divider(&mut self, x: u16, y: u16) {
+        let (position, start, size) = if self.workspace.width >= MIN_WIDTH_FOR_COLUMNS {
+            (x, self.workspace.x, self.workspace.width)
+        } else {
+            (y, self.workspace.y, self.workspace.height)
+        };
+
+        if let Some(ratio) = get_dragged_ratio(position, start, size) {
+            self.list_ratio = ratio;
+        }
+    }
+
+    fn build(&mut self, target: Option<PreviewTarget>) {
+        self.drop_view();
+        self.shown = target.as_ref().map(|t| t.resource.clone());
+        self.is_built = true;
+        self.view = target.and_then(|t| self.create_view(t));
+    }
+
+    fn create_view(&self, target: PreviewTarget) -> Option<Box<dyn View>> {
+        let resource = target.resource;
+        match self.mode {
+            PreviewMode::Yaml => {
+                let name = resource.name.clone()?;
+                let command_id =
+                    self.worker
+                        .borrow_mut()
+                        .get_yaml(name, resource.namespace.clone(), resource.kind.clone(), false)?;
+                Some(Box::new(YamlView::new(
+                    Rc::clone(&self.app_data),
+                    Rc::clone(&self.worker),
+                    Some(command_id),
+                    resource,
+                    NotificationSink::muted(),
+                    false,
+                    self.area,
+                )))
+            },
+            PreviewMode::Describe => {
+                DescribeView::new(&self.worker, Rc::clone(&self.app_data), resource, NotificationSink::muted())
+                    .map(|view| Box::new(view) as Box<dyn View>)
+            },
+            PreviewMode::Logs => {
+                if resource.kind.name() != PODS {
+                    return None;
+                }
+
+                let worker = self.worker.borrow();
+                let client = worker.kubernetes_client()?;
+                let containers = get_logs_containers(&resource, target.containers);
+                LogsView::new(
+                    Rc::clone(&self.app_data),
+                    Rc::clone(&self.worker),
+                    client,
+                    containers,
+                    false,
+                    NotificationSink::muted(),
+                    self.area,
+                )
+                .ok()
+                .map(|view| Box::new(view) as Box<dyn View>)
+            },
+        }
+    }
+
+    fn drop_view(&mut self) {
+        if let Some(view) = self.view.take() {
+            self.worker.borrow_mut().cancel_command(view.command_id());
+        }
+    }
+}
+
+/// Returns resources list ratio after growing or shrinking the preview pane by one step.
+fn get_resized_ratio(list_ratio: u16, grow_preview: bool) -> u16 {
+    if grow_preview {
+        list_ratio.saturating_sub(LIST_RATIO_STEP)
+    } else {
+        list_ratio + LIST_RATIO_STEP
+    }
+    .clamp(MIN_LIST_RATIO, MAX_LIST_RATIO)
+}
+
+/// Returns resources list ratio for the divider dragged to the `position` in the workspace of the `size` starting at `start`.
+fn get_dragged_ratio(position: u16, start: u16, size: u16) -> Option<u16> {
+    if size == 0 {
+        return None;
+    }
+
+    let ratio = u32::from(position.saturating_sub(start)) * 100 / u32::from(size);
+    Some(
+        u16::try_from(ratio)
+            .unwrap_or(MAX_LIST_RATIO)
+            .clamp(MIN_LIST_RATIO, MAX_LIST_RATIO),
+    )
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn resize_preview_test() {
+    assert_eq!(45, get_resized_ratio(DEFAULT_LIST_RATIO, true));
+    assert_eq!(55, get_resized_ratio(DEFAULT_LIST_RATIO, false));
+    assert_eq!(MIN_LIST_RATIO, get_resized_ratio(MIN_LIST_RATIO, true));
+    assert_eq!(MIN_LIST_RATIO, get_resized_ratio(MIN_LIST_RATIO + 2, true));
+    assert_eq!(MAX_LIST_RATIO, get_resized_ratio(MAX_LIST_RATIO, false));
+    assert_eq!(MAX_LIST_RATIO, get_resized_ratio(MAX_LIST_RATIO - 2, false));
+}
+
+#[test]
+fn drag_divider_test() {
+    assert_eq!(Some(50), get_dragged_ratio(60, 10, 100));
+    assert_eq!(Some(35), get_dragged_ratio(45, 10, 100));
+    assert_eq!(Some(MIN_LIST_RATIO), get_dragged_ratio(12, 10, 100));
+    assert_eq!(Some(MIN_LIST_RATIO), get_dragged_ratio(5, 10, 100));
+    assert_eq!(Some(MAX_LIST_RATIO), get_dragged_ratio(105, 10, 100));
+    assert_eq!(Some(MAX_LIST_RATIO), get_dragged_ratio(u16::MAX, 0, 1));
+    assert_eq!(None, get_dragged_ratio(50, 0, 0));
+}
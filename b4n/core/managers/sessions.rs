@@ -0,0 +1,97 @@
+use b4n_kube::utils::format_datetime;
+use b4n_tui::ResponseEvent;
+use b4n_tui::widgets::{ActionItem, ActionsListBuilder};
+
+use crate::ui::views::{SessionInfo, View};
+
+/// View session that is kept running in the background.
+struct BackgroundSession {
+    id: usize,
+    view: Box<dyn View>,
+}
+
+/// Keeps shell, attach and plugin sessions alive when they are not displayed.
+#[derive(Default)]
+pub struct SessionsManager {
+    sessions: Vec<BackgroundSession>,
+    next_id: usize,
+}
+
+impl SessionsManager {
+    /// Returns number of the background sessions.
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    /// Returns `true` if there are no background sessions.
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    /// Moves `view` to the background if it has a running session, otherwise drops it.\
+    /// **Note** that it returns `true` if the view was kept.
+    pub fn push(&mut self, mut view: Box<dyn View>) -> bool {
+        if view.session().is_none_or(|s| !s.is_running) {
+            return false;
+        }
+
+        view.set_foreground(false);
+        self.next_id += 1;
+        self.sessions.push(BackgroundSession { id: self.next_id, view });
+        true
+    }
+
+    /// Removes session with the specified `id` and returns its view.
+    pub fn take(&mut self, id: usize) -> Option<Box<dyn View>> {
+        let index = self.sessions.iter().position(|s| s.id == id)?;
+        Some(self.sessions.remove(index).view)
+    }
+
+    /// Processes tick for all background sessions, e.g. to let them fall back to another shell.\
+    /// **Note** that sessions that have ended are dropped later by [`SessionsManager::remove_finished`].
+    pub fn process_ticks(&mut self) {
+        for session in &mut self.sessions {
+            session.view.process_tick();
+        }
+    }
+
+    /// Drops all sessions that are no longer running and returns their details.
+    pub fn remove_finished(&mut self) -> Vec<SessionInfo> {
+        let mut finished = Vec::new();
+        self.sessions.retain(|s| match s.view.session() {
+            Some(session) if session.is_running => true,
+            Some(session) => {
+                finished.push(session);
+                false
+            },
+            None => false,
+        });
+
+        finished
+    }
+
+    /// Builds actions list builder with all background sessions.
+    pub fn to_actions(&self) -> ActionsListBuilder {
+        let actions = self
+            .sessions
+            .iter()
+            .filter_map(|s| s.view.session().map(|session| (s.id, session)))
+            .enumerate()
+            .map(|(idx, (id, session))| {
+                let mut name = format!("{}/{}", session.namespace.as_str(), session.name.as_deref().unwrap_or("n/a"));
+                if let Some(container) = &session.container {
+                    name.push('/');
+                    name.push_str(container);
+                }
+
+                let description = format!("{} idle {}", session.title, format_datetime(&session.last_activity));
+                ActionItem::raw(id.to_string(), "session".to_owned(), name, None)
+                    .with_description(&description)
+                    .with_response(ResponseEvent::ResumeSession(id))
+                    .with_sort_id(idx)
+            })
+            .collect();
+
+        ActionsListBuilder::new(actions)
+    }
+}
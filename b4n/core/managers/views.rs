@@ -2,27 +2,38 @@ use anyhow::Result;
 use b4n_common::{DEFAULT_ERROR_DURATION, DEFAULT_MESSAGE_DURATION, IconKind, NotificationSink};
 use b4n_config::keys::KeyCommand;
 use b4n_kube::client::KubernetesClient;
+use b4n_kube::helm::HelmReleaseView;
 use b4n_kube::plugins::PluginContext;
 use b4n_kube::{
-    ALL_NAMESPACES, ContainerRef, Namespace, PODS, Port, PropagationPolicy, ResourceRef, ResourceRefFilter, ResourceTag,
+    ALL_NAMESPACES, ContainerRef, MetadataChanges, NAMESPACES_SEPARATOR, Namespace, PODS, Port, PropagationPolicy, ResourceRef,
+    ResourceRefFilter, ResourceTag,
 };
 use b4n_tasks::commands::{
-    CommandResult, DeleteResourcesOptions, GetNewResourceYamlError, GetNewResourceYamlResult, InjectContainerError,
-    ResourceYamlError, ResourceYamlResult, RunPluginError, RunPluginOutput, SetNewResourceYamlError, SetResourceYamlError,
-    TransferFileError, TransferFileResult,
+    ChecksumStatus, CommandResult, CopyPodError, DeleteResourcesOptions, GetHelmReleaseError, GetNewResourceYamlError,
+    GetNewResourceYamlResult, InjectContainerError, NodeShellError, QueryResourcesError, RelationNode, ResourceRelationsError,
+    ResourceUser, ResourceUsersError, ResourceYamlError, ResourceYamlResult, RunPluginError, RunPluginOutput, SearchIndexItem,
+    SetNewResourceYamlError, SetResourceYamlError, TransferFileError, TransferFileResult, TriggerCronJobError,
 };
-use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, ToSelectData, TuiEvent};
+use b4n_tui::{MouseEventKind, PodCopy, ResponseEvent, Responsive, ToSelectData, TuiEvent};
 use b4n_tui::{table::Table, table::ViewType, widgets::Footer};
 use kube::{config::NamedContext, discovery::Scope};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::rc::Rc;
 
-use crate::core::{SharedAppData, SharedAppDataExt, SharedBgWorker};
+use crate::core::{
+    DetailView, NavigationEntry, PreviewPane, PreviewTarget, SessionsManager, SharedAppData, SharedAppDataExt, SharedBgWorker,
+};
 use crate::kube::resources::{ResourceItem, build_cache_key};
 use crate::kube::{kinds::KindsList, resources::ResourcesList};
-use crate::ui::views::{CmdView, DescribeView, ForwardsView, LogsView, ResourcesView, ShellView, View, YamlView};
-use crate::ui::widgets::{Position, SideSelect};
+use crate::ui::views::{
+    CmdView, DescribeView, EventStreamView, ExecView, ForwardsView, LogsView, NextRefreshActions, ResourcesView, ShellMode,
+    ShellView, View, XrayView, YamlView, get_logs_containers,
+};
+use crate::ui::widgets::{CommandPalette, Position, SideSelect};
+
+const QUERY_HISTORY_SIZE: usize = 20;
 
 pub struct ViewsManager {
     app_data: SharedAppData,
@@ -31,6 +42,10 @@ pub struct ViewsManager {
     ns_selector: SideSelect<ResourcesList>,
     res_selector: SideSelect<KindsList>,
     view: Option<Box<dyn View>>,
+    preview: PreviewPane,
+    closed_view: Option<DetailView>,
+    sessions: SessionsManager,
+    sessions_list: CommandPalette,
     footer: Footer,
     workspace: Rect,
     areas: Vec<Rect>,
@@ -41,12 +56,14 @@ impl ViewsManager {
         let ns_selector = SideSelect::new(Rc::clone(&app_data), ResourcesList::default(), Position::Left, 30)
             .with_name("NAMESPACE", "NAMESPACES")
             .with_result(ResponseEvent::ChangeNamespace)
-            .with_quick_highlight(ALL_NAMESPACES);
+            .with_quick_highlight(ALL_NAMESPACES)
+            .with_multiple_selection(NAMESPACES_SEPARATOR);
         let res_selector = SideSelect::new(Rc::clone(&app_data), KindsList::default(), Position::Right, 40)
             .with_name("RESOURCE", "RESOURCES")
             .with_result(ResponseEvent::ChangeKind)
             .with_quick_highlight(PODS);
         set_command_palette_hint(footer.transmitter(), &app_data);
+        let preview = PreviewPane::new(Rc::clone(&app_data), Rc::clone(&worker));
 
         Self {
             app_data,
@@ -55,6 +72,10 @@ impl ViewsManager {
             ns_selector,
             res_selector,
             view: None,
+            preview,
+            closed_view: None,
+            sessions: SessionsManager::default(),
+            sessions_list: CommandPalette::default(),
             footer,
             workspace: Rect::default(),
             areas: vec![Rect::default(), Rect::default()],
@@ -94,8 +115,14 @@ impl ViewsManager {
             self.resources.update_error_state(worker.resources.has_api_error());
         }
 
+        let mut namespaces_updated = false;
         while let Some(update_result) = { self.worker.borrow_mut().namespaces.try_next() } {
             self.ns_selector.select.items.update(*update_result);
+            namespaces_updated = true;
+        }
+
+        if namespaces_updated {
+            self.app_data.borrow_mut().namespaces = self.ns_selector.select.items.get_names();
         }
 
         while let Some(update_result) = { self.worker.borrow_mut().resources.try_next() } {
@@ -112,15 +139,21 @@ impl ViewsManager {
         self.footer.show_breadcrumb_trail(self.view.is_none());
         self.footer.draw(frame, layout[1], &self.app_data.borrow().theme);
 
-        let has_focus =
-            !self.footer.is_message_history_visible() && !self.ns_selector.is_visible() && !self.res_selector.is_visible();
+        let has_focus = !self.footer.is_message_history_visible()
+            && !self.ns_selector.is_visible()
+            && !self.res_selector.is_visible()
+            && !self.sessions_list.is_visible;
         if let Some(view) = &mut self.view {
             view.draw(frame, layout[0], has_focus);
+        } else if self.preview.is_visible {
+            let area = self.preview.draw(frame, layout[0]);
+            self.resources.draw(frame, area, has_focus);
         } else {
             self.resources.draw(frame, layout[0], has_focus);
         }
 
         self.draw_selectors(frame, layout[0]);
+        self.sessions_list.draw(frame, frame.area());
         self.footer.draw_history(frame, layout[0], &self.app_data.borrow().theme);
     }
 
@@ -144,6 +177,7 @@ impl ViewsManager {
 
     /// Processes single TUI event.
     pub fn process_event(&mut self, event: &TuiEvent) -> ResponseEvent {
+        self.closed_view = None;
         if self.footer.is_message_history_visible() {
             if self.app_data.has_binding(event, KeyCommand::HistoryOpen)
                 || self.app_data.has_binding(event, KeyCommand::NavigateBack)
@@ -159,6 +193,18 @@ impl ViewsManager {
             return self.footer.process_event(event);
         }
 
+        if self.sessions_list.is_visible {
+            let result = self.sessions_list.process_event(event);
+            if let ResponseEvent::ResumeSession(id) = result {
+                self.resume_session(id);
+                return ResponseEvent::Handled;
+            }
+
+            if result != ResponseEvent::NotHandled {
+                return result;
+            }
+        }
+
         if self.ns_selector.is_visible() {
             let result = self.ns_selector.process_event(event);
             if let Some(view) = &mut self.view {
@@ -193,6 +239,23 @@ impl ViewsManager {
                 return ResponseEvent::Handled;
             }
 
+            if self.app_data.has_binding(event, KeyCommand::SessionsOpen) {
+                self.show_sessions_list();
+                return ResponseEvent::Handled;
+            }
+
+            if self.app_data.has_binding(event, KeyCommand::NavigateHistoryBack) {
+                return ResponseEvent::ViewHistoryBack;
+            }
+
+            if self.app_data.has_binding(event, KeyCommand::NavigateHistoryForward) {
+                return ResponseEvent::ViewHistoryForward;
+            }
+
+            if self.view.is_none() && self.process_preview_keys(event) {
+                return ResponseEvent::Handled;
+            }
+
             return self.footer.process_event(event);
         }
 
@@ -210,7 +273,28 @@ impl ViewsManager {
             return ResponseEvent::NotHandled;
         };
 
+        // detail views use arrow keys for scrolling, so navigation history keys must be checked first
+        if view.detail_view().is_some() {
+            if self.app_data.has_binding(event, KeyCommand::NavigateHistoryBack) {
+                return ResponseEvent::ViewHistoryBack;
+            }
+
+            if self.app_data.has_binding(event, KeyCommand::NavigateHistoryForward) {
+                return ResponseEvent::ViewHistoryForward;
+            }
+        }
+
         let response = view.process_event(event);
+        if response == ResponseEvent::SendToBackground {
+            self.send_view_to_background();
+            return ResponseEvent::Handled;
+        }
+
+        if matches!(response, ResponseEvent::ViewInvolved(..) | ResponseEvent::ViewScoped(..)) {
+            self.closed_view = self.take_view();
+            return response;
+        }
+
         if response == ResponseEvent::Cancelled || response == ResponseEvent::ExitApplication {
             self.view = None;
             if response == ResponseEvent::Cancelled {
@@ -229,9 +313,49 @@ impl ViewsManager {
             return ResponseEvent::Handled;
         }
 
+        if self.preview.process_event(event) != ResponseEvent::NotHandled {
+            return ResponseEvent::Handled;
+        }
+
         self.resources.process_event(event)
     }
 
+    fn process_preview_keys(&mut self, event: &TuiEvent) -> bool {
+        if self.app_data.has_binding(event, KeyCommand::PreviewToggle) {
+            self.toggle_preview();
+        } else if self.preview.is_visible && self.app_data.has_binding(event, KeyCommand::PreviewMode) {
+            self.preview.next_mode();
+        } else if self.preview.is_visible && self.app_data.has_binding(event, KeyCommand::PreviewShrink) {
+            self.preview.resize(false);
+        } else if self.preview.is_visible && self.app_data.has_binding(event, KeyCommand::PreviewGrow) {
+            self.preview.resize(true);
+        } else {
+            return false;
+        }
+
+        true
+    }
+
+    /// Shows or hides the preview pane for the highlighted resource.
+    pub fn toggle_preview(&mut self) {
+        self.preview.toggle();
+        self.follow_highlighted_resource();
+    }
+
+    fn follow_highlighted_resource(&mut self) {
+        let table = &self.resources.table;
+        let target = table.get_resource_ref(false).map(|resource| PreviewTarget {
+            resource,
+            containers: table
+                .list
+                .table
+                .get_highlighted_resource()
+                .and_then(|r| r.data.as_ref())
+                .map(|d| d.tags.to_vec()),
+        });
+        self.preview.follow(target);
+    }
+
     fn process_selectors_event(
         &mut self,
         event: &TuiEvent,
@@ -279,6 +403,9 @@ impl ViewsManager {
 
     /// Allows all views to do some computations on every app tick.
     pub fn process_ticks(&mut self) -> ResponseEvent {
+        self.sessions.process_ticks();
+        self.remove_finished_sessions();
+
         if let Some(view_result) = self.view.as_mut().map(|view| view.process_tick()) {
             if view_result == ResponseEvent::Cancelled {
                 self.view = None;
@@ -287,6 +414,11 @@ impl ViewsManager {
 
             view_result
         } else {
+            if self.preview.is_visible {
+                self.follow_highlighted_resource();
+                self.preview.process_tick();
+            }
+
             self.resources.process_tick()
         }
     }
@@ -301,6 +433,7 @@ impl ViewsManager {
             self.res_selector.hide();
 
             self.resources.process_disconnection();
+            self.preview.clear();
             if let Some(view) = &mut self.view {
                 view.process_disconnection();
             }
@@ -325,11 +458,46 @@ impl ViewsManager {
         }
     }
 
+    /// Remembers actions that will be applied for next resources list refresh.
+    pub fn set_next_refresh(&mut self, actions: NextRefreshActions) {
+        self.resources.set_next_refresh(actions);
+    }
+
     /// Adds current resource to the previous resources stack.
     pub fn remember_current_resource(&mut self) {
         self.resources.remember_current_resource();
     }
 
+    /// Returns navigation history entry for the current location.\
+    /// **Note** that it includes the detail view that was just closed to navigate away from it.
+    pub fn get_navigation_entry(&mut self) -> NavigationEntry {
+        let closed_view = self.closed_view.take();
+        NavigationEntry {
+            context: self.app_data.borrow().current.context.clone(),
+            data: self.resources.get_previous_data(),
+            view: self.view.as_ref().and_then(|v| v.detail_view()).or(closed_view),
+        }
+    }
+
+    /// Closes the current view, keeping it in the background if it has a running session.
+    pub fn close_view(&mut self) {
+        self.closed_view = None;
+        self.take_view();
+        self.resources.process_external_view_close();
+    }
+
+    /// Removes the current view, keeping it in the background if it has a running session.\
+    /// **Note** that it returns the detail view that was shown in the removed view.
+    fn take_view(&mut self) -> Option<DetailView> {
+        let view = self.view.take()?;
+        let detail_view = view.detail_view();
+        if self.sessions.push(view) {
+            self.update_sessions_footer();
+        }
+
+        detail_view
+    }
+
     /// Processes context change that app is connected to.
     pub fn process_context_change(&mut self, context: String, namespace: Namespace, version: String, scope: Scope) {
         if self.app_data.borrow().current.context != context {
@@ -427,11 +595,51 @@ impl ViewsManager {
             .show_info("Selected resources marked for deletion", 3_000);
     }
 
+    /// Exports provided resources as clean manifests to the specified `path`.
+    pub fn export_resources(&mut self, resources: Vec<ResourceRef>, path: &str) {
+        let count = resources.len();
+        if self
+            .worker
+            .borrow_mut()
+            .export_resources(resources, PathBuf::from(path))
+            .is_some()
+        {
+            self.footer
+                .transmitter()
+                .show_info(format!("Exporting {count} resource(s)…"), DEFAULT_MESSAGE_DURATION);
+        }
+
+        self.resources.deselect_all();
+    }
+
+    /// Applies labels and annotations `changes` to all provided resources.
+    pub fn patch_metadata(&mut self, resources: Vec<ResourceRef>, changes: MetadataChanges, overwrite: bool) {
+        let count = resources.len();
+        if self
+            .worker
+            .borrow_mut()
+            .patch_metadata(resources, changes, overwrite)
+            .is_some()
+        {
+            self.footer.transmitter().show_info(
+                format!("Updating labels and annotations of {count} resource(s)…"),
+                DEFAULT_MESSAGE_DURATION,
+            );
+        }
+
+        self.resources.deselect_all();
+    }
+
     /// Displays a list of available contexts to choose from.
     pub fn show_contexts_list(&mut self, list: &[NamedContext]) {
         self.resources.show_contexts_list(list);
     }
 
+    /// Displays a palette that searches the indexed resources of all kinds.
+    pub fn show_search_list(&mut self, items: &[SearchIndexItem]) {
+        self.resources.show_search_list(items);
+    }
+
     /// Displays a list of available themes to choose from.
     pub fn show_themes_list(&mut self, list: Vec<std::path::PathBuf>) {
         self.resources.show_themes_list(list);
@@ -449,24 +657,7 @@ impl ViewsManager {
             return;
         };
 
-        let pods = match containers {
-            Some(containers) if !containers.is_empty() => containers
-                .into_iter()
-                .map(|container| {
-                    ContainerRef::new(
-                        resource.name.clone().unwrap_or_default(),
-                        resource.namespace.clone(),
-                        Some(container),
-                    )
-                })
-                .collect(),
-            _ => vec![ContainerRef::simple(
-                resource.name.clone().unwrap_or_default(),
-                resource.namespace.clone(),
-                resource.container.clone(),
-            )],
-        };
-
+        let pods = get_logs_containers(resource, containers);
         let view = LogsView::new(
             Rc::clone(&self.app_data),
             Rc::clone(&self.worker),
@@ -477,8 +668,32 @@ impl ViewsManager {
             self.workspace,
         );
 
+        drop(worker);
         if let Ok(view) = view {
-            self.view = Some(Box::new(view));
+            self.open_view(Box::new(view));
+        }
+    }
+
+    /// Executes command in the specified containers and opens view with the aggregated output.
+    pub fn exec_command(&mut self, containers: Vec<ContainerRef>, command: String) {
+        let worker = self.worker.borrow();
+        let Some(client) = worker.kubernetes_client() else {
+            return;
+        };
+
+        let view = ExecView::new(
+            Rc::clone(&self.app_data),
+            Rc::clone(&self.worker),
+            client,
+            containers,
+            command,
+            self.footer.get_transmitter(),
+            self.workspace,
+        );
+
+        drop(worker);
+        if let Some(view) = view {
+            self.open_view(Box::new(view));
         }
     }
 
@@ -497,7 +712,7 @@ impl ViewsManager {
             view.switch_to_edit();
         }
 
-        self.view = Some(Box::new(view));
+        self.open_view(Box::new(view));
     }
 
     /// Shows returned resource's template YAML in an already opened YAML view.
@@ -510,6 +725,79 @@ impl ViewsManager {
         self.handle_yaml_result(command_id, result, CommandResult::GetResourceYaml, "View YAML", true);
     }
 
+    /// Sends command to fetch helm release data to the background executor and opens empty YAML view.
+    pub fn show_helm_release(&mut self, resource: ResourceRef, view: HelmReleaseView) {
+        let colors = self.app_data.borrow().theme.colors.syntax.yaml.clone();
+        let command_id = self.worker.borrow_mut().get_helm_release(
+            resource.name.clone().unwrap_or_default(),
+            resource.namespace.clone(),
+            view,
+            colors,
+        );
+        let yaml = YamlView::new(
+            Rc::clone(&self.app_data),
+            Rc::clone(&self.worker),
+            command_id,
+            resource,
+            self.footer.get_transmitter(),
+            false,
+            self.workspace,
+        );
+
+        self.open_view(Box::new(yaml.with_title(view.title(), '󰠳')));
+    }
+
+    /// Shows returned helm release data in an already opened YAML view.
+    pub fn show_helm_release_result(&mut self, command_id: &str, result: Result<ResourceYamlResult, GetHelmReleaseError>) {
+        self.handle_yaml_result(command_id, result, CommandResult::GetHelmRelease, "Helm release", true);
+    }
+
+    /// Evaluates JSONPath `expression` against provided resources and shows results in the YAML view.
+    pub fn query_resources(&mut self, resources: Vec<ResourceRef>, expression: String, as_table: bool) {
+        let Some(first) = resources.first() else {
+            return;
+        };
+
+        let namespace = if resources.len() == 1 {
+            first.namespace.clone()
+        } else {
+            Namespace::all()
+        };
+        let resource = ResourceRef::named(first.kind.clone(), namespace, expression.clone());
+
+        {
+            let mut data = self.app_data.borrow_mut();
+            let context = data.current.context.clone();
+            data.history
+                .put_query_history_item(&context, expression.as_str().into(), QUERY_HISTORY_SIZE);
+        }
+
+        let history = self.app_data.borrow().history.clone();
+        self.worker.borrow_mut().save_history(history);
+
+        let colors = self.app_data.borrow().theme.colors.syntax.yaml.clone();
+        let command_id = self
+            .worker
+            .borrow_mut()
+            .query_resources(resources, expression, as_table, colors);
+        let yaml = YamlView::new(
+            Rc::clone(&self.app_data),
+            Rc::clone(&self.worker),
+            command_id,
+            resource,
+            self.footer.get_transmitter(),
+            false,
+            self.workspace,
+        );
+
+        self.open_view(Box::new(yaml.with_title("query", '󰘦')));
+    }
+
+    /// Shows returned query results in an already opened YAML view.
+    pub fn show_query_result(&mut self, command_id: &str, result: Result<ResourceYamlResult, QueryResourcesError>) {
+        self.handle_yaml_result(command_id, result, CommandResult::QueryResources, "Query", true);
+    }
+
     /// Process YAML patch result.
     pub fn create_yaml_result(&mut self, command_id: &str, result: Result<String, SetNewResourceYamlError>) {
         self.handle_yaml_result(command_id, result, CommandResult::SetNewResourceYaml, "Create YAML", false);
@@ -525,6 +813,18 @@ impl ViewsManager {
         E: std::fmt::Display,
         F: FnOnce(Result<R, E>) -> CommandResult,
     {
+        if self.preview.command_id_match(command_id) {
+            match result {
+                Ok(result) => self.preview.process_command_result(wrap(Ok(result))),
+                Err(error) => {
+                    tracing::warn!("Preview {error_msg} error: {error}");
+                    self.preview.process_command_error();
+                },
+            }
+
+            return;
+        }
+
         if self.view.as_ref().is_some_and(|v| !v.command_id_match(command_id)) {
             return;
         }
@@ -549,24 +849,233 @@ impl ViewsManager {
             resource,
             self.footer.get_transmitter(),
         ) {
-            self.view = Some(Box::new(view));
+            self.open_view(Box::new(view));
+        }
+    }
+
+    /// Opens xray view with relations of the specified resource.
+    pub fn show_relations(&mut self, resource: ResourceRef) {
+        let view = XrayView::new(
+            Rc::clone(&self.app_data),
+            Rc::clone(&self.worker),
+            resource,
+            self.footer.get_transmitter(),
+        );
+        self.open_view(Box::new(view));
+    }
+
+    /// Passes relations tree to the xray view.
+    pub fn show_relations_result(&mut self, command_id: &str, result: Result<RelationNode, ResourceRelationsError>) {
+        self.handle_yaml_result(command_id, result, CommandResult::ResourceRelations, "Xray", true);
+    }
+
+    /// Passes pods and workloads that use a resource to the delete dialog or describe view.\
+    /// **Note** that lookup errors are only logged, as the "used by" data is supplementary.
+    pub fn show_resource_users(&mut self, command_id: &str, result: Result<Vec<ResourceUser>, ResourceUsersError>) {
+        if self.resources.process_delete_users(command_id, &result) {
+            return;
+        }
+
+        if let Err(error) = &result {
+            tracing::warn!("Used by lookup error: {error}");
+        }
+
+        if self.preview.command_id_match(command_id) {
+            self.preview.process_command_result(CommandResult::ResourceUsers(result));
+        } else if let Some(view) = &mut self.view
+            && view.command_id_match(command_id)
+        {
+            view.process_command_result(CommandResult::ResourceUsers(result));
         }
     }
 
     /// Opens shell / attach to the main process of the specified container.
     pub fn open_shell(&mut self, resource: ResourceRef, is_attach: bool) {
-        if let Some(client) = self.worker.borrow().kubernetes_client().map(KubernetesClient::get_client) {
-            self.footer().hide_hint();
-            let view = ShellView::new(
-                Rc::clone(&self.app_data),
-                Rc::clone(&self.worker),
-                client,
-                resource.into(),
-                is_attach,
-                self.footer.get_transmitter(),
-                self.workspace,
-            );
-            self.view = Some(Box::new(view));
+        let mode = if is_attach { ShellMode::Attach } else { ShellMode::Shell };
+        self.open_shell_view(resource.into(), mode);
+    }
+
+    /// Creates node shell pod on the specified node, the shell view is opened when the pod is running.
+    pub fn create_node_shell(&mut self, node_name: String, namespace: String, image: String) {
+        let msg = format!("Creating node shell pod on '{node_name}'…");
+        if self
+            .worker
+            .borrow_mut()
+            .create_node_shell(node_name, Namespace::from(namespace), image)
+            .is_some()
+        {
+            self.footer.transmitter().show_info(msg, DEFAULT_MESSAGE_DURATION);
+        }
+    }
+
+    /// Creates debug copy of the specified pod, the shell view is opened when the copy is running.
+    pub fn copy_pod(&mut self, resource: &ResourceRef, copy: PodCopy) {
+        let msg = format!("Creating debug copy '{}'…", copy.name);
+        if self.worker.borrow_mut().copy_pod(resource, copy).is_some() {
+            self.footer.transmitter().show_info(msg, DEFAULT_MESSAGE_DURATION);
+        }
+    }
+
+    /// Creates a new job from the cron job in the background.
+    pub fn trigger_cron_job(&mut self, resource: ResourceRef) {
+        let Some(name) = resource.name else {
+            return;
+        };
+
+        let msg = format!("Triggering cron job '{name}'…");
+        if self.worker.borrow_mut().trigger_cron_job(name, resource.namespace).is_some() {
+            self.footer.transmitter().show_info(msg, DEFAULT_MESSAGE_DURATION);
+        }
+    }
+
+    /// Shows result of the cron job trigger and returns the created job if successful.
+    pub fn show_trigger_cron_job_result(&mut self, result: Result<ResourceRef, TriggerCronJobError>) -> Option<ResourceRef> {
+        match result {
+            Ok(job) => {
+                let msg = format!("Job '{}' created from cron job", job.name.as_deref().unwrap_or_default());
+                tracing::info!("{}", msg);
+                self.footer.transmitter().show_info(msg, DEFAULT_MESSAGE_DURATION);
+                Some(job)
+            },
+            Err(error) => {
+                let msg = format!("Trigger cron job error: {error}");
+                tracing::warn!("{}", msg);
+                self.footer.transmitter().show_error(msg, DEFAULT_ERROR_DURATION);
+                None
+            },
+        }
+    }
+
+    /// Suspends or resumes provided cron jobs.
+    pub fn suspend_cron_jobs(&mut self, resources: Vec<ResourceRef>, suspend: bool) {
+        let mut grouped: HashMap<String, Vec<String>> = HashMap::new();
+        for resource in resources {
+            if let Some(name) = resource.name {
+                grouped.entry(resource.namespace.as_str().to_owned()).or_default().push(name);
+            }
+        }
+
+        for (namespace, names) in grouped {
+            self.worker.borrow_mut().suspend_cron_jobs(names, namespace.into(), suspend);
+        }
+
+        self.resources.deselect_all();
+    }
+
+    /// Opens shell view for the debug copy of the pod.
+    pub fn open_pod_copy(&mut self, result: Result<ContainerRef, CopyPodError>) {
+        match result {
+            Ok(pod) => {
+                let msg = format!("Debug copy '{}' successfully created", pod.name);
+                tracing::info!("{}", msg);
+                self.footer.transmitter().show_info(msg, DEFAULT_MESSAGE_DURATION);
+                self.open_shell_view(pod, ShellMode::Shell);
+            },
+            Err(error) => {
+                let msg = format!("Debug copy error: {error}");
+                tracing::warn!("{}", msg);
+                self.footer.transmitter().show_error(msg, DEFAULT_ERROR_DURATION);
+            },
+        }
+    }
+
+    /// Opens shell view for the node shell pod.
+    pub fn open_node_shell(&mut self, result: Result<ContainerRef, NodeShellError>) {
+        match result {
+            Ok(pod) => self.open_shell_view(pod, ShellMode::Node),
+            Err(error) => {
+                let msg = format!("Node shell error: {error}");
+                tracing::warn!("{}", msg);
+                self.footer.transmitter().show_error(msg, DEFAULT_ERROR_DURATION);
+            },
+        }
+    }
+
+    fn open_shell_view(&mut self, pod: ContainerRef, mode: ShellMode) {
+        let Some(client) = self.worker.borrow().kubernetes_client().map(KubernetesClient::get_client) else {
+            return;
+        };
+
+        self.footer().hide_hint();
+        let view = ShellView::new(
+            Rc::clone(&self.app_data),
+            Rc::clone(&self.worker),
+            client,
+            pod,
+            mode,
+            self.footer.get_transmitter(),
+            self.workspace,
+        );
+        self.open_view(Box::new(view));
+    }
+
+    /// Displays a list of sessions running in the background to choose from.
+    pub fn show_sessions_list(&mut self) {
+        if self.sessions.is_empty() {
+            self.footer()
+                .show_info("There are no sessions running in the background", DEFAULT_MESSAGE_DURATION);
+            return;
+        }
+
+        let actions = self.sessions.to_actions().build(None);
+        self.sessions_list = CommandPalette::new(Rc::clone(&self.app_data), actions, 80)
+            .with_header(" Resume background session:")
+            .with_prompt("session")
+            .with_first_highlighted();
+        self.sessions_list.show();
+    }
+
+    /// Brings session with the specified `id` back to the foreground.
+    pub fn resume_session(&mut self, id: usize) {
+        if let Some(view) = self.sessions.take(id) {
+            self.open_view(view);
+            self.update_sessions_footer();
+        }
+    }
+
+    /// Sets new view as the current one.\
+    /// **Note** that the previous view is kept in the background if it has a running session.
+    fn open_view(&mut self, mut view: Box<dyn View>) {
+        self.take_view();
+        view.set_foreground(true);
+        self.view = Some(view);
+    }
+
+    fn send_view_to_background(&mut self) {
+        if let Some(view) = self.view.take()
+            && self.sessions.push(view)
+        {
+            let key = self.app_data.get_key_name(KeyCommand::SessionsOpen).to_ascii_uppercase();
+            let msg = format!("Session moved to the background, press ␝{key}␝ to resume it");
+            self.footer().show_info(msg, DEFAULT_MESSAGE_DURATION);
+            self.update_sessions_footer();
+        }
+
+        self.resources.process_external_view_close();
+    }
+
+    fn remove_finished_sessions(&mut self) {
+        let finished = self.sessions.remove_finished();
+        if finished.is_empty() {
+            return;
+        }
+
+        for session in finished {
+            let name = session.name.as_deref().unwrap_or(&session.title);
+            let msg = format!("Background {} session '{}' has ended", session.title, name);
+            tracing::info!("{}", msg);
+            self.footer().show_info(msg, DEFAULT_MESSAGE_DURATION);
+        }
+
+        self.update_sessions_footer();
+    }
+
+    fn update_sessions_footer(&self) {
+        if self.sessions.is_empty() {
+            self.footer().reset("200_sessions");
+        } else {
+            let text = format!(" {}", self.sessions.len());
+            self.footer().set_text("200_sessions", Some(text), IconKind::Default);
         }
     }
 
@@ -589,7 +1098,14 @@ impl ViewsManager {
             view.set_filter(filter);
         }
 
-        self.view = Some(Box::new(view));
+        self.open_view(Box::new(view));
+    }
+
+    /// Opens event stream view with grouped events from all namespaces.
+    pub fn show_event_stream(&mut self) {
+        if let Some(view) = EventStreamView::new(Rc::clone(&self.app_data), &self.worker, self.footer.get_transmitter()) {
+            self.open_view(Box::new(view));
+        }
     }
 
     /// Runs plugin with the specified `id` and `context`.
@@ -613,7 +1129,7 @@ impl ViewsManager {
             )
             .with_header_data(index.map(|i| &context.resources[i]));
 
-            self.view = Some(Box::new(view));
+            self.open_view(Box::new(view));
         } else {
             let resource = context.resources.first().cloned().unwrap_or_default();
             let title = plugin.name.clone();
@@ -630,7 +1146,7 @@ impl ViewsManager {
                     false,
                     self.workspace,
                 );
-                self.view = Some(Box::new(view.with_title(title, '')));
+                self.open_view(Box::new(view.with_title(title, '')));
             }
         }
     }
@@ -678,6 +1194,11 @@ impl ViewsManager {
                     result.container,
                     result.pod,
                 );
+                let msg = match result.checksum {
+                    ChecksumStatus::Skipped => msg,
+                    ChecksumStatus::Unavailable => format!("{msg} (checksum not verified)"),
+                    ChecksumStatus::Verified(hash) => format!("{msg} (sha256 {} verified)", &hash[..hash.len().min(12)]),
+                };
                 tracing::info!("{}", msg);
                 self.footer().show_info(msg, DEFAULT_MESSAGE_DURATION);
             },
@@ -1,9 +1,11 @@
 pub use self::app::*;
 pub use self::data::*;
 pub use self::managers::*;
+pub use self::navigation::*;
 pub use self::worker::*;
 
 mod app;
 mod data;
 mod managers;
+mod navigation;
 mod worker;
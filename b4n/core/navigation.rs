@@ -0,0 +1,107 @@
+use b4n_kube::{CONTAINERS, ResourceRef};
+
+use crate::core::PreviousData;
+
+#[cfg(test)]
+#[path = "./navigation.tests.rs"]
+mod navigation_tests;
+
+/// Maximum number of entries kept in each direction of the navigation history.
+const NAVIGATION_HISTORY_SIZE: usize = 50;
+
+/// Detail view that was open on top of the resources list.
+#[derive(Clone, PartialEq)]
+pub enum DetailView {
+    Describe(ResourceRef),
+    Relations(ResourceRef),
+    EventStream,
+}
+
+/// Single location in the navigation history.
+pub struct NavigationEntry {
+    pub context: String,
+    pub data: PreviousData,
+    pub view: Option<DetailView>,
+}
+
+impl NavigationEntry {
+    /// Returns short label for this entry, e.g. to show it in the breadcrumbs.\
+    /// **Note** that the context name is included only if it is different from `current_context`.
+    pub fn label(&self, current_context: &str) -> String {
+        let kind = if self.data.resource.is_container() {
+            CONTAINERS
+        } else {
+            self.data.resource.kind.name()
+        };
+
+        if self.context == current_context {
+            kind.to_owned()
+        } else {
+            format!("{kind}@{}", self.context)
+        }
+    }
+
+    /// Returns `true` if this entry points to the same list as the `other` one.
+    fn is_same_location(&self, other: &NavigationEntry) -> bool {
+        self.context == other.context
+            && self.data.namespace == other.data.namespace
+            && self.data.resource == other.data.resource
+            && self.data.filter == other.data.filter
+            && self.view == other.view
+    }
+}
+
+/// Back and forward navigation history of the visited resources lists.
+#[derive(Default)]
+pub struct NavigationHistory {
+    back: Vec<NavigationEntry>,
+    forward: Vec<NavigationEntry>,
+}
+
+impl NavigationHistory {
+    /// Remembers the location that is being left and clears the forward history.
+    pub fn push(&mut self, entry: NavigationEntry) {
+        self.forward.clear();
+        if self.back.last().is_some_and(|last| last.is_same_location(&entry)) {
+            self.back.pop();
+        }
+
+        push_limited(&mut self.back, entry);
+    }
+
+    /// Moves `current` location to the forward history and returns the previous one.
+    pub fn back(&mut self, current: NavigationEntry) -> Option<NavigationEntry> {
+        let entry = self.back.pop()?;
+        push_limited(&mut self.forward, current);
+        Some(entry)
+    }
+
+    /// Moves `current` location to the back history and returns the next one.
+    pub fn forward(&mut self, current: NavigationEntry) -> Option<NavigationEntry> {
+        let entry = self.forward.pop()?;
+        push_limited(&mut self.back, current);
+        Some(entry)
+    }
+
+    /// Returns `true` if there is a location to go back to.
+    pub fn has_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    /// Returns iterator over the back history, starting from the most recent entry.
+    pub fn back_entries(&self) -> impl Iterator<Item = &NavigationEntry> {
+        self.back.iter().rev()
+    }
+
+    /// Returns the entry that [`NavigationHistory::forward`] would return.
+    pub fn next_entry(&self) -> Option<&NavigationEntry> {
+        self.forward.last()
+    }
+}
+
+fn push_limited(list: &mut Vec<NavigationEntry>, entry: NavigationEntry) {
+    list.push(entry);
+    if list.len() > NAVIGATION_HISTORY_SIZE {
+        list.remove(0);
+    }
+}
@@ -0,0 +1,78 @@
+use b4n_kube::{Namespace, ResourceRef};
+use b4n_tui::ToSelectData;
+use kube::discovery::Scope;
+
+use super::*;
+
+fn entry(context: &str, kind: &str) -> NavigationEntry {
+    NavigationEntry {
+        context: context.to_owned(),
+        data: PreviousData {
+            list: Scope::Namespaced,
+            header: Scope::Namespaced,
+            namespace: Namespace::from("default"),
+            resource: ResourceRef::new(kind.into(), "default".into()),
+            highlighted: ToSelectData::None,
+            filter: None,
+            sort_info: (1, false),
+            offset: 0,
+        },
+        view: None,
+    }
+}
+
+#[test]
+fn back_and_forward_test() {
+    let mut history = NavigationHistory::default();
+    assert!(history.back(entry("prod", "pods")).is_none());
+
+    history.push(entry("prod", "pods"));
+    history.push(entry("prod", "deployments"));
+
+    let previous = history.back(entry("prod", "services")).unwrap();
+    assert_eq!(previous.label("prod"), "deployments");
+    assert_eq!(history.next_entry().unwrap().label("prod"), "services");
+
+    let previous = history.back(entry("prod", "deployments")).unwrap();
+    assert_eq!(previous.label("prod"), "pods");
+    assert!(!history.has_back());
+
+    let next = history.forward(entry("prod", "pods")).unwrap();
+    assert_eq!(next.label("prod"), "deployments");
+    let next = history.forward(entry("prod", "deployments")).unwrap();
+    assert_eq!(next.label("prod"), "services");
+    assert!(history.next_entry().is_none());
+}
+
+#[test]
+fn push_clears_forward_test() {
+    let mut history = NavigationHistory::default();
+    history.push(entry("prod", "pods"));
+    history.back(entry("prod", "deployments"));
+    assert!(history.next_entry().is_some());
+
+    history.push(entry("prod", "pods"));
+    assert!(history.next_entry().is_none());
+}
+
+#[test]
+fn push_replaces_same_location_test() {
+    let mut history = NavigationHistory::default();
+    history.push(entry("prod", "pods"));
+    history.push(entry("prod", "pods"));
+    history.push(entry("dev", "pods"));
+
+    let labels = history.back_entries().map(|e| e.label("prod")).collect::<Vec<_>>();
+    assert_eq!(labels, ["pods@dev", "pods"]);
+}
+
+#[test]
+fn push_limits_history_size_test() {
+    let mut history = NavigationHistory::default();
+    for i in 0..NAVIGATION_HISTORY_SIZE + 5 {
+        history.push(entry("prod", &format!("kind{i}")));
+    }
+
+    assert_eq!(history.back_entries().count(), NAVIGATION_HISTORY_SIZE);
+    assert_eq!(history.back_entries().last().unwrap().label("prod"), "kind5");
+}
@@ -1,24 +1,32 @@
 use anyhow::Result;
 use b4n_common::NotificationSink;
 use b4n_config::themes::YamlSyntaxColors;
-use b4n_config::{Config, History, Plugin, SyntaxData};
+use b4n_config::{Bookmarks, Config, History, Plugin, SyntaxData};
 use b4n_kube::client::KubernetesClient;
 use b4n_kube::crds::{CrdObserver, SharedCrdsList};
 use b4n_kube::files::TransferContext;
+use b4n_kube::helm::HelmReleaseView;
 use b4n_kube::plugins::PluginContext;
 use b4n_kube::stats::BgStatistics;
 use b4n_kube::utils::{get_plural, get_resource};
-use b4n_kube::{BgDiscovery, BgObserverError, CRDS, ContainerRef, DiscoveryList, Kind, NAMESPACES, Namespace, PODS, ResourceRef};
+use b4n_kube::{
+    BgDiscovery, BgObserverError, CORE_VERSION, CRDS, ContainerRef, DiscoveryList, HELM_RELEASES, Kind, MetadataChanges,
+    NAMESPACES, Namespace, PODS, PropagationPolicy, ResourceRef, SECRETS, TLS_CERTIFICATES,
+};
 use b4n_tasks::commands::{
-    Command, DeleteResourcesCommand, DeleteResourcesOptions, EphemeralContainerConfig, GetNewResourceYamlCommand,
-    GetResourceYamlCommand, InjectContainerCommand, ListResourcePortsCommand, RunPluginCommand, SaveConfigurationCommand,
-    SaveContentCommand, SetNewResourceYamlCommand, SetNewResourceYamlOptions, SetResourceYamlCommand, SetResourceYamlOptions,
-    TransferFileCommand,
+    BuildSearchIndexCommand, Command, CopyPodCommand, DeleteResourcesCommand, DeleteResourcesOptions, EphemeralContainerConfig,
+    ExportResourcesCommand, GetHelmReleaseCommand, GetNewResourceYamlCommand, GetResourceRelationsCommand,
+    GetResourceUsersCommand, GetResourceYamlCommand, InjectContainerCommand, ListResourcePortsCommand, NodeShellCommand,
+    PatchMetadataCommand, PodCopyConfig, QueryResourcesCommand, RunPluginCommand, SaveConfigurationCommand, SaveContentCommand,
+    SearchIndexItem, SetNewResourceYamlCommand, SetNewResourceYamlOptions, SetResourceYamlCommand, SetResourceYamlOptions,
+    SuspendCronJobsCommand, TransferFileCommand, TriggerCronJobCommand,
 };
 use b4n_tasks::{BgExecutor, TaskResult};
 use b4n_tasks::{BgHighlighter, HighlightRequest, PortForwarder};
-use b4n_tui::EphemeralContainer;
-use kube::discovery::{Scope, verbs};
+use b4n_tui::{EphemeralContainer, PodCopy};
+use kube::api::ApiResource;
+use kube::discovery::{ApiCapabilities, Scope, verbs};
+use std::time::{Duration, Instant};
 use std::{cell::RefCell, collections::HashMap, net::SocketAddr, path::PathBuf, rc::Rc};
 use tokio::{runtime::Handle, sync::mpsc::UnboundedSender};
 
@@ -29,6 +37,9 @@ use crate::ui::views::PortForwardItem;
 
 pub type SharedBgWorker = Rc<RefCell<BgWorker>>;
 
+/// Time after which the search index is rebuilt on next use.
+const SEARCH_INDEX_TTL: Duration = Duration::from_secs(300);
+
 /// Possible errors from [`BgWorkerError`].
 #[derive(thiserror::Error, Debug)]
 pub enum BgWorkerError {
@@ -56,6 +67,9 @@ pub struct BgWorker {
     discovery_list: Option<DiscoveryList>,
     client: Option<KubernetesClient>,
     footer_tx: NotificationSink,
+    transfers: Vec<String>,
+    search_index: Option<(Instant, Vec<SearchIndexItem>)>,
+    search_index_command: Option<String>,
     is_crds_list_ready: bool,
 }
 
@@ -83,6 +97,9 @@ impl BgWorker {
             discovery_list: None,
             client: None,
             footer_tx,
+            transfers: Vec::new(),
+            search_index: None,
+            search_index_command: None,
             is_crds_list_ready: false,
         }
     }
@@ -95,6 +112,7 @@ impl BgWorker {
         resource: ResourceRef,
     ) -> Result<Scope, BgWorkerError> {
         self.is_crds_list_ready = false;
+        self.reset_search_index();
 
         self.discovery_list = Some(initial_discovery_list);
         self.discovery.start(&client);
@@ -104,7 +122,7 @@ impl BgWorker {
         self.namespaces
             .start(&client, ResourceRef::new(namespaces, Namespace::default()), discovery, true)?;
 
-        let discovery = get_resource(self.discovery_list.as_ref(), &resource.kind);
+        let discovery = get_observed_resource(self.discovery_list.as_ref(), &resource.kind);
         let scope = self.resources.start(&client, resource, discovery, false)?;
 
         let discovery = get_resource(self.discovery_list.as_ref(), &Kind::from(CRDS));
@@ -123,7 +141,7 @@ impl BgWorker {
         if let Some(client) = &self.client {
             self.statistics
                 .change_namespace(client, self.discovery_list.as_ref(), &resource.namespace);
-            let discovery = get_resource(self.discovery_list.as_ref(), &resource.kind);
+            let discovery = get_observed_resource(self.discovery_list.as_ref(), &resource.kind);
             Ok(self.resources.restart(client, resource, discovery, false)?)
         } else {
             Err(BgWorkerError::NoKubernetesClient)
@@ -135,7 +153,7 @@ impl BgWorker {
         if let Some(client) = &self.client {
             self.statistics
                 .change_namespace(client, self.discovery_list.as_ref(), &last_namespace);
-            let discovery = get_resource(self.discovery_list.as_ref(), &kind);
+            let discovery = get_observed_resource(self.discovery_list.as_ref(), &kind);
             Ok(self
                 .resources
                 .restart_new_kind(client, kind, last_namespace, discovery, false)?)
@@ -149,7 +167,7 @@ impl BgWorker {
         if let Some(client) = &self.client {
             self.statistics
                 .change_namespace(client, self.discovery_list.as_ref(), &resource_namespace);
-            let discovery = get_resource(self.discovery_list.as_ref(), self.resources.observed_kind())
+            let discovery = get_observed_resource(self.discovery_list.as_ref(), self.resources.observed_kind())
                 .or_else(|| get_resource(self.discovery_list.as_ref(), &PODS.into()));
             Ok(self
                 .resources
@@ -175,6 +193,7 @@ impl BgWorker {
 
     /// Stops all background tasks except the executor one.
     pub fn stop(&mut self) {
+        self.reset_search_index();
         self.namespaces.stop();
         self.resources.stop();
         self.discovery.stop();
@@ -243,6 +262,13 @@ impl BgWorker {
                     .or_insert_with(|| vec![(&item.0.group, &item.0.version)]);
             }
 
+            // virtual kinds are counted too, as their names can clash with the names of custom resources
+            if grouped.contains_key(SECRETS) {
+                for virtual_kind in [HELM_RELEASES, TLS_CERTIFICATES] {
+                    grouped.entry(virtual_kind).or_default().push(("", CORE_VERSION));
+                }
+            }
+
             let mut all = Vec::<KindItem>::with_capacity(discovery.len());
             for key in grouped.keys() {
                 let count = grouped[key].len();
@@ -262,7 +288,7 @@ impl BgWorker {
         self.forwarder
             .tasks()
             .iter()
-            .filter(|t| namespace.is_all() || t.resource.namespace == *namespace)
+            .filter(|t| namespace.contains(t.resource.namespace.as_str()))
             .map(PortForwardItem::from)
             .collect()
     }
@@ -274,7 +300,7 @@ impl BgWorker {
         self.forwarder
             .tasks()
             .iter()
-            .filter(|t| namespace.is_all() || t.resource.namespace == *namespace)
+            .filter(|t| namespace.contains(t.resource.namespace.as_str()))
             .map(|f| &f.resource)
             .collect()
     }
@@ -388,6 +414,12 @@ impl BgWorker {
             .run_task(Command::SaveHistory(Box::new(SaveConfigurationCommand::new(history))));
     }
 
+    /// Saves the provided bookmarks to a file.
+    pub fn save_bookmarks(&mut self, bookmarks: Bookmarks) {
+        self.executor
+            .run_task(Command::SaveBookmarks(Box::new(SaveConfigurationCommand::new(bookmarks))));
+    }
+
     /// Saves provided text content to the specified file.
     pub fn save_content(&mut self, path: PathBuf, text: String) {
         let command = SaveContentCommand::new(path, text, self.footer_tx.clone());
@@ -417,6 +449,73 @@ impl BgWorker {
         }
     }
 
+    /// Sends [`ExportResourcesCommand`] to the background executor.
+    pub fn export_resources(&mut self, resources: Vec<ResourceRef>, path: PathBuf) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let discovery = get_resource(self.discovery_list.as_ref(), &resources.first()?.kind);
+        let command = ExportResourcesCommand::new(resources, discovery, client.get_client(), path, self.footer_tx.clone());
+        Some(self.executor.run_task(Command::ExportResources(Box::new(command))))
+    }
+
+    /// Sends [`BuildSearchIndexCommand`] to the background executor.\
+    /// **Note** that it does nothing if the index is already being built.
+    pub fn build_search_index(&mut self) {
+        if self.search_index_command.is_some() {
+            return;
+        }
+
+        if let Some(client) = &self.client
+            && let Some(discovery) = &self.discovery_list
+        {
+            let command = BuildSearchIndexCommand::new(discovery.clone(), client.get_client());
+            self.search_index_command = Some(self.executor.run_task(Command::BuildSearchIndex(Box::new(command))));
+        }
+    }
+
+    /// Returns search index if it was already built.
+    pub fn search_index(&self) -> Option<&[SearchIndexItem]> {
+        self.search_index.as_ref().map(|(_, items)| items.as_slice())
+    }
+
+    /// Returns `true` if the search index was not built yet or it is outdated.
+    pub fn is_search_index_stale(&self) -> bool {
+        self.search_index
+            .as_ref()
+            .is_none_or(|(built, _)| built.elapsed() > SEARCH_INDEX_TTL)
+    }
+
+    /// Sets the search index if it is a result of the last build command.\
+    /// Returns `true` if the index was updated.
+    pub fn set_search_index(&mut self, command_id: &str, items: Vec<SearchIndexItem>) -> bool {
+        if self.search_index_command.as_deref() != Some(command_id) {
+            return false;
+        }
+
+        self.search_index_command = None;
+        self.search_index = Some((Instant::now(), items));
+        true
+    }
+
+    fn reset_search_index(&mut self) {
+        self.search_index = None;
+        self.search_index_command = None;
+    }
+
+    /// Sends [`PatchMetadataCommand`] to the background executor.
+    pub fn patch_metadata(&mut self, resources: Vec<ResourceRef>, changes: MetadataChanges, overwrite: bool) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let discovery = get_resource(self.discovery_list.as_ref(), &resources.first()?.kind);
+        let command = PatchMetadataCommand::new(
+            resources,
+            changes,
+            overwrite,
+            discovery,
+            client.get_client(),
+            self.footer_tx.clone(),
+        );
+        Some(self.executor.run_task(Command::PatchMetadata(Box::new(command))))
+    }
+
     /// Sends [`ListResourcePortsCommand`] to the background executor.
     pub fn list_resource_ports(&mut self, resource: ResourceRef) {
         if let Some(client) = &self.client {
@@ -462,6 +561,30 @@ impl BgWorker {
         }
     }
 
+    /// Sends [`GetResourceRelationsCommand`] to the background executor.
+    pub fn get_relations(&mut self, resource: ResourceRef) -> Option<String> {
+        if let Some(client) = &self.client
+            && let Some(discovery) = &self.discovery_list
+        {
+            let command = GetResourceRelationsCommand::new(resource, discovery.clone(), client.get_client());
+            Some(self.executor.run_task(Command::GetResourceRelations(Box::new(command))))
+        } else {
+            None
+        }
+    }
+
+    /// Sends [`GetResourceUsersCommand`] to the background executor.
+    pub fn get_resource_users(&mut self, resources: Vec<ResourceRef>) -> Option<String> {
+        if let Some(client) = &self.client
+            && let Some(discovery) = &self.discovery_list
+        {
+            let command = GetResourceUsersCommand::new(resources, discovery.clone(), client.get_client());
+            Some(self.executor.run_task(Command::GetResourceUsers(Box::new(command))))
+        } else {
+            None
+        }
+    }
+
     /// Sends [`SetNewResourceYamlCommand`] to the background executor.
     pub fn set_new_yaml(&mut self, yaml: String, options: SetNewResourceYamlOptions) -> Option<String> {
         if let Some(client) = &self.client {
@@ -543,13 +666,117 @@ impl BgWorker {
         Some(self.executor.run_task(Command::InjectContainer(Box::new(command))))
     }
 
+    /// Creates debug copy of the specified pod.
+    pub fn copy_pod(&mut self, resource: &ResourceRef, copy: PodCopy) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let name = resource.name.as_deref()?;
+        let config = PodCopyConfig {
+            name: copy.name,
+            container: copy.container,
+            image: copy.image,
+            command: copy.command,
+        };
+        let command = CopyPodCommand::new(name.to_owned(), resource.namespace.clone(), client.get_client(), config);
+        Some(self.executor.run_task(Command::CopyPod(Box::new(command))))
+    }
+
+    /// Creates a new job from the specified cron job in the background task.
+    pub fn trigger_cron_job(&mut self, name: String, namespace: Namespace) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let command = TriggerCronJobCommand::new(name, namespace, client.get_client());
+        Some(self.executor.run_task(Command::TriggerCronJob(Box::new(command))))
+    }
+
+    /// Suspends or resumes all named cron jobs in the background task.
+    pub fn suspend_cron_jobs(&mut self, names: Vec<String>, namespace: Namespace, suspend: bool) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let command = SuspendCronJobsCommand::new(names, namespace, suspend, client.get_client(), self.footer_tx.clone());
+        Some(self.executor.run_task(Command::SuspendCronJobs(Box::new(command))))
+    }
+
+    /// Sends [`GetHelmReleaseCommand`] to the background executor.
+    pub fn get_helm_release(
+        &mut self,
+        name: String,
+        namespace: Namespace,
+        view: HelmReleaseView,
+        colors: YamlSyntaxColors,
+    ) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let sender = self.highlighter.get_sender()?;
+        let command = GetHelmReleaseCommand::new(name, namespace, view, client.get_client(), sender, colors);
+        Some(self.executor.run_task(Command::GetHelmRelease(Box::new(command))))
+    }
+
+    /// Sends [`QueryResourcesCommand`] to the background executor.
+    pub fn query_resources(
+        &mut self,
+        resources: Vec<ResourceRef>,
+        expression: String,
+        as_table: bool,
+        colors: YamlSyntaxColors,
+    ) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let sender = self.highlighter.get_sender()?;
+        let discovery = get_resource(self.discovery_list.as_ref(), &resources.first()?.kind);
+        let command = QueryResourcesCommand::new(
+            resources,
+            expression,
+            as_table,
+            discovery,
+            client.get_client(),
+            sender,
+            colors,
+        );
+        Some(self.executor.run_task(Command::QueryResources(Box::new(command))))
+    }
+
+    /// Creates privileged pod pinned to the specified node that can be used to open node shell.
+    pub fn create_node_shell(&mut self, node_name: String, namespace: Namespace, image: String) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let command = NodeShellCommand::new(node_name, namespace, image, client.get_client());
+        Some(self.executor.run_task(Command::NodeShell(Box::new(command))))
+    }
+
+    /// Deletes node shell pod immediately.
+    pub fn delete_node_shell(&mut self, pod: &ContainerRef) {
+        let options = DeleteResourcesOptions {
+            propagation_policy: PropagationPolicy::Background,
+            terminate_immediately: true,
+            detach_finalizers: false,
+        };
+        let resources = vec![(pod.name.clone(), String::new())];
+        self.delete_resources(resources, pod.namespace.clone(), &Kind::from(PODS), options);
+    }
+
     /// Transfers file from/to a pod's container in the background task.
-    pub fn transfer_file(&mut self, container: ContainerRef, context: TransferContext) {
-        if let Some(client) = &self.client {
-            let runtime = self.runtime.clone();
-            let command = TransferFileCommand::new(runtime, container, context, client.get_client(), self.footer_tx.clone());
-            self.executor.run_task(Command::TransferFile(Box::new(command)));
+    pub fn transfer_file(&mut self, container: ContainerRef, context: TransferContext) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let runtime = self.runtime.clone();
+        let command = TransferFileCommand::new(runtime, container, context, client.get_client(), self.footer_tx.clone());
+        let id = self.executor.run_task(Command::TransferFile(Box::new(command)));
+        self.transfers.push(id.clone());
+        Some(id)
+    }
+
+    /// Returns `true` if there is at least one file transfer in progress.
+    pub fn has_active_transfers(&self) -> bool {
+        !self.transfers.is_empty()
+    }
+
+    /// Removes finished file transfer from the list of active ones.
+    pub fn finish_transfer(&mut self, command_id: &str) {
+        self.transfers.retain(|id| id != command_id);
+    }
+
+    /// Cancels all file transfers that are in progress, returns the number of cancelled transfers.
+    pub fn cancel_transfers(&mut self) -> usize {
+        let transfers = std::mem::take(&mut self.transfers);
+        for id in &transfers {
+            self.cancel_command(Some(id));
         }
+
+        transfers.len()
     }
 }
 
@@ -558,3 +785,13 @@ impl Drop for BgWorker {
         self.cancel_all();
     }
 }
+
+/// Returns discovery data for the observed `kind`.\
+/// **Note** that helm releases and TLS certificates are observed through the secrets they are stored in.
+fn get_observed_resource(list: Option<&DiscoveryList>, kind: &Kind) -> Option<(ApiResource, ApiCapabilities)> {
+    if kind.is_virtual() {
+        get_resource(list, &SECRETS.into())
+    } else {
+        get_resource(list, kind)
+    }
+}
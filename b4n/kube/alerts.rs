@@ -0,0 +1,212 @@
+use b4n_config::{AlertCondition, AlertRule};
+use b4n_kube::stats::Statistics;
+use b4n_kube::{InitData, Kind, Namespace, status};
+use b4n_list::{Filterable, Row};
+use k8s_openapi::serde_json::Value;
+use kube::{ResourceExt, api::DynamicObject};
+use std::collections::HashMap;
+
+use crate::kube::resources::{ColumnsLayout, ResourceItem};
+
+#[cfg(test)]
+#[path = "./alerts.tests.rs"]
+mod alerts_tests;
+
+const WARNING_TYPE: &str = "Warning";
+const STATUS_COLUMN: &str = "STATUS";
+const CONTAINER_STATUSES: [&str; 2] = ["initContainerStatuses", "containerStatuses"];
+
+/// Alert raised for the observed resource.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    pub message: String,
+    pub kind: Kind,
+    pub namespace: Namespace,
+    pub name: String,
+    pub bell: bool,
+}
+
+/// Last known evaluation result for the observed resource.
+#[derive(Default)]
+struct ObjectState {
+    is_matching: bool,
+    count: i64,
+}
+
+/// Evaluates [`AlertRule`] on the resources streamed by the observer.\
+/// **Note** that alerts are raised only for changes that happen after the initial list of resources is received.
+pub struct AlertWatch {
+    pub rule: AlertRule,
+    kind: Kind,
+    singular_kind: String,
+    group: String,
+    columns: Vec<String>,
+    states: HashMap<String, ObjectState>,
+    is_synced: bool,
+}
+
+impl AlertWatch {
+    /// Creates new [`AlertWatch`] instance for the `rule` that observes resources of the `kind`.
+    pub fn new(rule: AlertRule, kind: Kind) -> Self {
+        Self {
+            rule,
+            kind,
+            singular_kind: String::new(),
+            group: String::new(),
+            columns: Vec::new(),
+            states: HashMap::new(),
+            is_synced: false,
+        }
+    }
+
+    /// Prepares the watch for the initial list of resources described by `init`.\
+    /// **Note** that already known resources are still compared with their last state.
+    pub fn init(&mut self, init: &InitData) {
+        self.singular_kind.clone_from(&init.kind);
+        self.group.clone_from(&init.group);
+        self.columns = ResourceItem::header(&init.kind, &init.group, None, false, ColumnsLayout::General).get_names();
+        self.is_synced = false;
+    }
+
+    /// Marks the initial list of resources as received.
+    pub fn init_done(&mut self) {
+        self.is_synced = true;
+    }
+
+    /// Evaluates the rule on the new or updated `object` and returns [`Alert`] if it should be raised.
+    pub fn apply(&mut self, object: &DynamicObject) -> Option<Alert> {
+        let uid = object.uid().unwrap_or_else(|| object.name_any());
+        let item = ResourceItem::from(
+            &self.singular_kind,
+            &self.group,
+            None,
+            &Statistics::default(),
+            object.clone(),
+            ColumnsLayout::General,
+        );
+
+        let is_matching = self.is_matching_filter(&item) && self.is_matching_condition(&item, object);
+        let count = match self.rule.condition {
+            AlertCondition::RestartsIncreased => get_restarts(&object.data["status"]),
+            AlertCondition::WarningEvent => object.data["count"].as_i64().unwrap_or(1).max(1),
+            _ => 0,
+        };
+
+        let previous = self.states.insert(uid, ObjectState { is_matching, count });
+        if !self.is_synced && previous.is_none() {
+            return None;
+        }
+
+        let previous = previous.unwrap_or_default();
+        let is_raised = match self.rule.condition {
+            AlertCondition::Status(_) | AlertCondition::NotReady => is_matching && !previous.is_matching,
+            AlertCondition::RestartsIncreased | AlertCondition::WarningEvent => is_matching && count > previous.count,
+        };
+
+        if is_raised {
+            Some(self.get_alert(object, count))
+        } else {
+            None
+        }
+    }
+
+    /// Forgets the last state of the deleted `object`.
+    pub fn delete(&mut self, object: &DynamicObject) {
+        self.states.remove(&object.uid().unwrap_or_else(|| object.name_any()));
+    }
+
+    fn is_matching_filter(&self, item: &ResourceItem) -> bool {
+        let Some(filter) = self.rule.filter.as_deref().filter(|f| !f.is_empty()) else {
+            return true;
+        };
+
+        let mut context = ResourceItem::get_context_with_columns(filter, Some("e"), &self.columns);
+        item.is_matching(&mut context)
+    }
+
+    fn is_matching_condition(&self, item: &ResourceItem, object: &DynamicObject) -> bool {
+        let status = &object.data["status"];
+        match &self.rule.condition {
+            AlertCondition::Status(value) => {
+                self.columns
+                    .iter()
+                    .position(|c| c.eq_ignore_ascii_case(STATUS_COLUMN))
+                    .is_some_and(|idx| item.column_text(idx).eq_ignore_ascii_case(value))
+                    || get_container_reasons(status).any(|r| r.eq_ignore_ascii_case(value))
+            },
+            AlertCondition::RestartsIncreased => true,
+            AlertCondition::NotReady => status::from_conditions(status["conditions"].as_array()).is_some_and(|s| s != "Ready"),
+            AlertCondition::WarningEvent => object.data["type"].as_str() == Some(WARNING_TYPE),
+        }
+    }
+
+    fn get_alert(&self, object: &DynamicObject, count: i64) -> Alert {
+        let rule = self.rule.name();
+        let (kind, namespace, name, message) = if self.rule.condition == AlertCondition::WarningEvent {
+            let involved = &object.data["involvedObject"];
+            let kind = involved["kind"].as_str().unwrap_or_default();
+            let namespace = involved["namespace"].as_str().map(String::from);
+            let name = involved["name"].as_str().unwrap_or_default().to_owned();
+            let message = format!(
+                "{rule}: {} on {}: {}",
+                object.data["reason"].as_str().unwrap_or_default(),
+                get_object_name(kind, namespace.as_deref(), &name),
+                object.data["message"].as_str().unwrap_or_default().trim(),
+            );
+            let kind = Kind::from_api_version(kind, involved["apiVersion"].as_str().unwrap_or_default());
+            (kind, namespace, name, message)
+        } else {
+            let namespace = object.namespace();
+            let name = object.name_any();
+            let object_name = get_object_name(&self.singular_kind, namespace.as_deref(), &name);
+            let message = match &self.rule.condition {
+                AlertCondition::Status(value) => format!("{rule}: {object_name} is {value}"),
+                AlertCondition::RestartsIncreased => format!("{rule}: {object_name} restarted ({count} restarts)"),
+                _ => format!("{rule}: {object_name} is not ready"),
+            };
+            (self.kind.clone(), namespace, name, message)
+        };
+
+        Alert {
+            message,
+            kind,
+            namespace: namespace.map_or_else(Namespace::all, Namespace::from),
+            name,
+            bell: self.rule.bell,
+        }
+    }
+}
+
+/// Returns object name in the `kind namespace/name` form.
+fn get_object_name(kind: &str, namespace: Option<&str>, name: &str) -> String {
+    match namespace {
+        Some(namespace) => format!("{} {}/{}", kind.to_ascii_lowercase(), namespace, name),
+        None => format!("{} {}", kind.to_ascii_lowercase(), name),
+    }
+}
+
+/// Returns total restarts count of all containers.
+fn get_restarts(status: &Value) -> i64 {
+    CONTAINER_STATUSES
+        .iter()
+        .filter_map(|statuses| status[statuses].as_array())
+        .flatten()
+        .map(|s| s["restartCount"].as_i64().unwrap_or_default())
+        .sum()
+}
+
+/// Returns reasons of the current and the last state of all containers.
+fn get_container_reasons(status: &Value) -> impl Iterator<Item = &str> {
+    CONTAINER_STATUSES
+        .iter()
+        .filter_map(|statuses| status[statuses].as_array())
+        .flatten()
+        .flat_map(|s| {
+            [
+                &s["state"]["waiting"]["reason"],
+                &s["state"]["terminated"]["reason"],
+                &s["lastState"]["terminated"]["reason"],
+            ]
+        })
+        .filter_map(Value::as_str)
+}
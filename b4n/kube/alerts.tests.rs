@@ -0,0 +1,143 @@
+use b4n_kube::ResourceRef;
+use k8s_openapi::serde_json::{self, json};
+
+use super::*;
+
+fn rule(kind: &str, filter: Option<&str>, condition: AlertCondition) -> AlertRule {
+    AlertRule {
+        name: None,
+        kind: kind.to_owned(),
+        namespace: None,
+        filter: filter.map(String::from),
+        condition,
+        bell: false,
+    }
+}
+
+fn watch(rule: AlertRule, kind: &str, plural: &str) -> AlertWatch {
+    let mut watch = AlertWatch::new(rule, plural.into());
+    let resource = ResourceRef::new(plural.into(), Namespace::all());
+    watch.init(&InitData::simple(resource, kind.to_owned(), plural.to_owned()));
+    watch
+}
+
+fn pod(name: &str, waiting: Option<&str>, last_terminated: Option<&str>, restarts: i64) -> DynamicObject {
+    let state = waiting.map_or_else(
+        || json!({ "running": {} }),
+        |reason| json!({ "waiting": { "reason": reason } }),
+    );
+    let last_state = last_terminated.map_or_else(|| json!({}), |reason| json!({ "terminated": { "reason": reason } }));
+    serde_json::from_value(json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": { "name": name, "namespace": "payments", "uid": name },
+        "status": {
+            "phase": "Running",
+            "containerStatuses": [{
+                "name": "app",
+                "ready": waiting.is_none(),
+                "restartCount": restarts,
+                "state": state,
+                "lastState": last_state,
+            }],
+        },
+    }))
+    .unwrap()
+}
+
+fn node(name: &str, is_ready: bool) -> DynamicObject {
+    serde_json::from_value(json!({
+        "apiVersion": "v1",
+        "kind": "Node",
+        "metadata": { "name": name, "uid": name },
+        "status": {
+            "conditions": [{ "type": "Ready", "status": if is_ready { "True" } else { "False" } }],
+        },
+    }))
+    .unwrap()
+}
+
+fn event(uid: &str, event_type: &str, count: i64) -> DynamicObject {
+    serde_json::from_value(json!({
+        "apiVersion": "v1",
+        "kind": "Event",
+        "metadata": { "name": format!("api-7f9.{uid}"), "namespace": "payments", "uid": uid },
+        "type": event_type,
+        "reason": "BackOff",
+        "message": "Back-off restarting failed container",
+        "count": count,
+        "involvedObject": { "apiVersion": "v1", "kind": "Pod", "namespace": "payments", "name": "api-7f9" },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn status_alert_is_raised_on_transition_test() {
+    let condition = AlertCondition::Status("CrashLoopBackOff".to_owned());
+    let mut watch = watch(rule("pods", None, condition), "Pod", "pods");
+
+    assert!(watch.apply(&pod("api-7f9", None, None, 0)).is_none());
+    assert!(watch.apply(&pod("web-1", Some("CrashLoopBackOff"), None, 4)).is_none());
+    watch.init_done();
+
+    let alert = watch.apply(&pod("api-7f9", Some("CrashLoopBackOff"), None, 1)).unwrap();
+    assert_eq!("pods: pod payments/api-7f9 is CrashLoopBackOff", alert.message);
+    assert_eq!("pods", alert.kind.as_str());
+    assert_eq!("payments", alert.namespace.as_str());
+    assert_eq!("api-7f9", alert.name);
+
+    assert!(watch.apply(&pod("api-7f9", Some("CrashLoopBackOff"), None, 2)).is_none());
+    assert!(watch.apply(&pod("web-1", Some("CrashLoopBackOff"), None, 5)).is_none());
+    assert!(watch.apply(&pod("api-7f9", None, None, 2)).is_none());
+    assert!(watch.apply(&pod("api-7f9", Some("CrashLoopBackOff"), None, 3)).is_some());
+}
+
+#[test]
+fn status_alert_checks_container_reasons_and_filter_test() {
+    let condition = AlertCondition::Status("OOMKilled".to_owned());
+    let mut watch = watch(rule("pods", Some("c:restarts>2"), condition), "Pod", "pods");
+    watch.init_done();
+
+    assert!(watch.apply(&pod("api-7f9", None, Some("OOMKilled"), 1)).is_none());
+    assert!(watch.apply(&pod("api-7f9", None, Some("OOMKilled"), 3)).is_some());
+    assert!(watch.apply(&pod("web-1", None, Some("Error"), 3)).is_none());
+}
+
+#[test]
+fn restarts_and_not_ready_alerts_test() {
+    let mut restarts = watch(rule("pods", None, AlertCondition::RestartsIncreased), "Pod", "pods");
+    restarts.apply(&pod("api-7f9", None, None, 2));
+    restarts.init_done();
+
+    assert!(restarts.apply(&pod("api-7f9", None, None, 2)).is_none());
+    let alert = restarts.apply(&pod("api-7f9", None, None, 3)).unwrap();
+    assert_eq!("pods: pod payments/api-7f9 restarted (3 restarts)", alert.message);
+
+    let mut nodes = watch(rule("nodes", None, AlertCondition::NotReady), "Node", "nodes");
+    nodes.apply(&node("worker-1", true));
+    nodes.init_done();
+
+    let alert = nodes.apply(&node("worker-1", false)).unwrap();
+    assert_eq!("nodes: node worker-1 is not ready", alert.message);
+    assert!(alert.namespace.is_all());
+    assert!(nodes.apply(&node("worker-1", false)).is_none());
+}
+
+#[test]
+fn warning_event_alert_targets_involved_object_test() {
+    let mut watch = watch(rule("events", None, AlertCondition::WarningEvent), "Event", "events");
+    watch.apply(&event("1", "Warning", 3));
+    watch.init_done();
+
+    assert!(watch.apply(&event("1", "Warning", 3)).is_none());
+    assert!(watch.apply(&event("2", "Normal", 1)).is_none());
+
+    let alert = watch.apply(&event("1", "Warning", 4)).unwrap();
+    assert_eq!(
+        "events: BackOff on pod payments/api-7f9: Back-off restarting failed container",
+        alert.message
+    );
+    assert_eq!("Pod", alert.kind.as_str());
+    assert_eq!("api-7f9", alert.name);
+    assert!(watch.apply(&event("3", "Warning", 1)).is_some());
+}
@@ -0,0 +1,200 @@
+use b4n_kube::Kind;
+use k8s_openapi::jiff::Timestamp;
+use k8s_openapi::serde_json::Value;
+use kube::{ResourceExt, api::DynamicObject};
+use std::collections::HashMap;
+
+#[cfg(test)]
+#[path = "./events.tests.rs"]
+mod events_tests;
+
+/// Default number of event groups kept in the [`EventStream`].
+pub const DEFAULT_EVENT_STREAM_CAPACITY: usize = 1_000;
+
+const WARNING_TYPE: &str = "Warning";
+
+/// Events that share the type, reason and involved object.
+#[derive(Debug, Clone)]
+pub struct EventGroup {
+    pub key: String,
+    pub namespace: Option<String>,
+    pub event_type: String,
+    pub reason: String,
+    pub object_kind: Option<Kind>,
+    pub object_namespace: Option<String>,
+    pub object_name: String,
+    pub message: String,
+    pub first_seen: Option<Timestamp>,
+    pub last_seen: Option<Timestamp>,
+    counts: HashMap<String, i64>,
+    sequence: u64,
+}
+
+impl EventGroup {
+    /// Returns total number of occurrences of all events in the group.
+    pub fn count(&self) -> i64 {
+        self.counts.values().sum()
+    }
+
+    /// Returns `true` if the group consists of warning events.
+    pub fn is_warning(&self) -> bool {
+        self.event_type == WARNING_TYPE
+    }
+
+    /// Returns involved object in the `kind/name` form.
+    pub fn object(&self) -> String {
+        match &self.object_kind {
+            Some(kind) => format!("{}/{}", kind.name().to_ascii_lowercase(), self.object_name),
+            None => "n/a".to_owned(),
+        }
+    }
+}
+
+/// Rolling buffer of events grouped by their type, reason and involved object.\
+/// **Note** that groups are kept after events expire from the API, the least recently updated ones are removed
+/// when the buffer exceeds its capacity.
+pub struct EventStream {
+    groups: HashMap<String, EventGroup>,
+    capacity: usize,
+    sequence: u64,
+}
+
+impl Default for EventStream {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_STREAM_CAPACITY)
+    }
+}
+
+impl EventStream {
+    /// Creates new [`EventStream`] instance that keeps at most `capacity` event groups.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            groups: HashMap::new(),
+            capacity: capacity.max(1),
+            sequence: 0,
+        }
+    }
+
+    /// Adds the `event` to its group and returns the key of that group.
+    pub fn add(&mut self, event: &DynamicObject) -> String {
+        let key = get_group_key(event);
+        let uid = event.uid().unwrap_or_else(|| event.name_any());
+        let count = event_count(&event.data);
+        let first_seen = first_timestamp(event);
+        let last_seen = last_timestamp(event);
+        self.sequence += 1;
+
+        let group = self.groups.entry(key.clone()).or_insert_with(|| new_group(&key, event));
+        group.counts.insert(uid, count);
+        group.sequence = self.sequence;
+        if first_seen.is_some() && (group.first_seen.is_none() || first_seen < group.first_seen) {
+            group.first_seen = first_seen;
+        }
+
+        if group.last_seen.is_none() || last_seen >= group.last_seen {
+            group.last_seen = last_seen;
+            group.message = event.data["message"].as_str().unwrap_or_default().to_owned();
+        }
+
+        key
+    }
+
+    /// Removes the least recently updated groups that exceed the buffer capacity and returns them.
+    pub fn trim(&mut self) -> Vec<EventGroup> {
+        if self.groups.len() <= self.capacity {
+            return Vec::new();
+        }
+
+        let mut sequences = self.groups.values().map(|g| g.sequence).collect::<Vec<_>>();
+        sequences.sort_unstable();
+        let threshold = sequences[self.groups.len() - self.capacity - 1];
+
+        let keys = self
+            .groups
+            .values()
+            .filter(|g| g.sequence <= threshold)
+            .map(|g| g.key.clone())
+            .collect::<Vec<_>>();
+        keys.iter().filter_map(|k| self.groups.remove(k)).collect()
+    }
+
+    /// Returns event group for the specified `key`.
+    pub fn get(&self, key: &str) -> Option<&EventGroup> {
+        self.groups.get(key)
+    }
+
+    /// Returns an iterator over all event groups.
+    pub fn iter(&self) -> impl Iterator<Item = &EventGroup> {
+        self.groups.values()
+    }
+
+    /// Returns number of event groups in the buffer.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns `true` if there are no event groups in the buffer.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+fn new_group(key: &str, event: &DynamicObject) -> EventGroup {
+    let object = &event.data["involvedObject"];
+    let object_kind = object["kind"]
+        .as_str()
+        .map(|kind| Kind::from_api_version(kind, object["apiVersion"].as_str().unwrap_or_default()));
+
+    EventGroup {
+        key: key.to_owned(),
+        namespace: event.namespace(),
+        event_type: event.data["type"].as_str().unwrap_or_default().to_owned(),
+        reason: event.data["reason"].as_str().unwrap_or_default().to_owned(),
+        object_kind,
+        object_namespace: object["namespace"].as_str().map(String::from),
+        object_name: object["name"].as_str().unwrap_or_default().to_owned(),
+        message: String::new(),
+        first_seen: None,
+        last_seen: None,
+        counts: HashMap::new(),
+        sequence: 0,
+    }
+}
+
+fn get_group_key(event: &DynamicObject) -> String {
+    let object = &event.data["involvedObject"];
+    format!(
+        "{}/{}/{}/{}/{}/{}",
+        event.namespace().unwrap_or_default(),
+        event.data["type"].as_str().unwrap_or_default(),
+        event.data["reason"].as_str().unwrap_or_default(),
+        object["kind"].as_str().unwrap_or_default(),
+        object["namespace"].as_str().unwrap_or_default(),
+        object["name"].as_str().unwrap_or_default(),
+    )
+}
+
+fn event_count(data: &Value) -> i64 {
+    data["count"]
+        .as_i64()
+        .or_else(|| data["series"]["count"].as_i64())
+        .unwrap_or(1)
+        .max(1)
+}
+
+fn first_timestamp(event: &DynamicObject) -> Option<Timestamp> {
+    parse_timestamp(&event.data["firstTimestamp"])
+        .or_else(|| parse_timestamp(&event.data["eventTime"]))
+        .or_else(|| event.metadata.creation_timestamp.as_ref().map(|t| t.0))
+}
+
+fn last_timestamp(event: &DynamicObject) -> Option<Timestamp> {
+    parse_timestamp(&event.data["lastTimestamp"])
+        .or_else(|| parse_timestamp(&event.data["series"]["lastObservedTime"]))
+        .or_else(|| parse_timestamp(&event.data["eventTime"]))
+        .or_else(|| event.metadata.creation_timestamp.as_ref().map(|t| t.0))
+}
+
+fn parse_timestamp(value: &Value) -> Option<Timestamp> {
+    value.as_str().and_then(|t| t.parse().ok())
+}
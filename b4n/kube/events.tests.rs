@@ -0,0 +1,70 @@
+use k8s_openapi::serde_json::{self, json};
+
+use super::*;
+
+fn event(uid: &str, event_type: &str, reason: &str, pod: &str, count: i64, last: &str) -> DynamicObject {
+    serde_json::from_value(json!({
+        "apiVersion": "v1",
+        "kind": "Event",
+        "metadata": { "name": format!("{pod}.{uid}"), "namespace": "default", "uid": uid },
+        "type": event_type,
+        "reason": reason,
+        "message": format!("{reason} at {last}"),
+        "count": count,
+        "lastTimestamp": last,
+        "involvedObject": { "apiVersion": "v1", "kind": "Pod", "namespace": "default", "name": pod },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn add_groups_events_test() {
+    let mut stream = EventStream::default();
+
+    let first = stream.add(&event("1", "Warning", "BackOff", "web-1", 3, "2024-01-01T10:00:00Z"));
+    let second = stream.add(&event("2", "Warning", "BackOff", "web-1", 2, "2024-01-01T10:05:00Z"));
+    let other = stream.add(&event("3", "Normal", "Pulled", "web-1", 1, "2024-01-01T10:01:00Z"));
+
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+    assert_eq!(2, stream.len());
+
+    let group = stream.get(&first).unwrap();
+    assert_eq!(5, group.count());
+    assert!(group.is_warning());
+    assert_eq!("pod/web-1", group.object());
+    assert_eq!("BackOff at 2024-01-01T10:05:00Z", group.message);
+    assert!(!stream.get(&other).unwrap().is_warning());
+}
+
+#[test]
+fn add_updates_event_count_test() {
+    let mut stream = EventStream::default();
+
+    stream.add(&event("1", "Warning", "BackOff", "web-1", 3, "2024-01-01T10:05:00Z"));
+    let key = stream.add(&event("1", "Warning", "BackOff", "web-1", 7, "2024-01-01T10:06:00Z"));
+    stream.add(&event("2", "Warning", "BackOff", "web-1", 1, "2024-01-01T10:00:00Z"));
+
+    let group = stream.get(&key).unwrap();
+    assert_eq!(8, group.count());
+    assert_eq!("BackOff at 2024-01-01T10:06:00Z", group.message);
+    assert_eq!("2024-01-01T10:06:00Z".parse::<Timestamp>().ok(), group.last_seen);
+}
+
+#[test]
+fn trim_removes_least_recently_updated_test() {
+    let mut stream = EventStream::new(2);
+
+    let first = stream.add(&event("1", "Warning", "BackOff", "web-1", 1, "2024-01-01T10:00:00Z"));
+    let second = stream.add(&event("2", "Warning", "BackOff", "web-2", 1, "2024-01-01T10:00:00Z"));
+    stream.add(&event("3", "Warning", "BackOff", "web-1", 1, "2024-01-01T10:01:00Z"));
+    let third = stream.add(&event("4", "Warning", "BackOff", "web-3", 1, "2024-01-01T10:02:00Z"));
+
+    let removed = stream.trim();
+
+    assert_eq!(1, removed.len());
+    assert_eq!(second, removed[0].key);
+    assert!(stream.get(&first).is_some());
+    assert!(stream.get(&third).is_some());
+    assert!(stream.trim().is_empty());
+}
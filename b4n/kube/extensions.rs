@@ -1,5 +1,6 @@
-use b4n_tui::ResponseEvent;
+use b4n_tasks::commands::SearchIndexItem;
 use b4n_tui::widgets::{ActionItem, ActionsListBuilder};
+use b4n_tui::{ResponseEvent, ToSelectData};
 use kube::config::NamedContext;
 
 use crate::kube::kinds::KindItem;
@@ -11,6 +12,10 @@ pub trait ActionsListBuilderExt {
 
     /// Creates new [`ActionsListBuilder`] instance from [`KindItem`] collection.
     fn from_kinds(items: Option<&[KindItem]>) -> Self;
+
+    /// Creates new [`ActionsListBuilder`] instance from [`SearchIndexItem`] collection.\
+    /// **Note** that cluster-scoped resources are shown in the `current_namespace`.
+    fn from_search_index(items: &[SearchIndexItem], current_namespace: &str) -> Self;
 }
 
 impl ActionsListBuilderExt for ActionsListBuilder {
@@ -31,4 +36,29 @@ impl ActionsListBuilderExt for ActionsListBuilder {
         let actions = items.unwrap_or(&[]).iter().map(Into::into).collect();
         ActionsListBuilder::new(actions)
     }
+
+    fn from_search_index(items: &[SearchIndexItem], current_namespace: &str) -> Self {
+        let actions = items.iter().map(|item| {
+            let kind = item.kind.name();
+            let namespace = item.namespace.as_deref().unwrap_or_default();
+            let uid = format!("_{}:{}:{}_", item.kind.as_str(), namespace, item.name);
+            let description = if namespace.is_empty() {
+                kind.to_owned()
+            } else {
+                format!("{kind} in {namespace}")
+            };
+            let to_select = ToSelectData::Some(item.name.clone(), namespace.to_owned());
+            let namespace = if namespace.is_empty() { current_namespace } else { namespace };
+            ActionItem::raw(uid, kind.to_owned(), item.name.clone(), None)
+                .with_description(&description)
+                .with_aliases([format!("{kind}/{}", item.name)])
+                .with_response(ResponseEvent::ChangeAndSelect(
+                    item.kind.as_str().to_owned(),
+                    namespace.to_owned(),
+                    to_select,
+                ))
+        });
+
+        ActionsListBuilder::new(actions.collect()).with_fuzzy_ranking()
+    }
 }
@@ -1,3 +1,5 @@
+pub mod alerts;
+pub mod events;
 pub mod extensions;
 pub mod kinds;
 pub mod resources;
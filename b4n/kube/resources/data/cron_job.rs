@@ -1,4 +1,7 @@
+use b4n_kube::cron::CronSchedule;
 use b4n_tui::table::{Column, Header, NAMESPACE};
+use k8s_openapi::jiff::Timestamp;
+use k8s_openapi::serde_json::Value;
 use kube::api::DynamicObject;
 use std::rc::Rc;
 
@@ -10,10 +13,13 @@ pub fn data(object: &DynamicObject) -> ResourceData {
     let status = &object.data["status"];
     let active = status["active"].as_array().map_or(0, Vec::len);
     let is_terminating = object.metadata.deletion_timestamp.is_some();
+    let is_suspended = spec["suspend"].as_bool().unwrap_or_default();
+    let next_run = if is_suspended { None } else { next_run(spec) };
 
-    let values: [Cell; 5] = [
+    let values: [Cell; 6] = [
         spec["schedule"].as_str().into(),
-        spec["suspend"].as_bool().unwrap_or_default().into(),
+        Cell::time_left(next_run),
+        is_suspended.into(),
         active.to_string().into(),
         Cell::time(status["lastScheduleTime"].clone()),
         Cell::time(status["lastSuccessfulTime"].clone()),
@@ -28,11 +34,20 @@ pub fn header() -> Header {
         NAMESPACE,
         Some(Box::new([
             Column::bound("SCHEDULE", 14, 32, false),
+            Column::fixed("NEXT RUN", 14, true),
             Column::fixed("SUSPEND", 8, false),
             Column::fixed("ACTIVE", 8, true),
             Column::fixed("LAST SCHEDULE", 14, true),
             Column::fixed("LAST SUCCESS", 14, true),
         ])),
-        Rc::new([' ', 'N', 'S', 'U', 'C', 'L', 'T', 'A']),
+        Rc::new([' ', 'N', 'S', 'X', 'U', 'C', 'L', 'T', 'A']),
     )
 }
+
+fn next_run(spec: &Value) -> Option<Timestamp> {
+    CronSchedule::parse(spec["schedule"].as_str()?)
+        .and_then(|s| s.with_time_zone(spec["timeZone"].as_str()))
+        .ok()?
+        .next_after(Timestamp::now())
+        .map(|t| t.timestamp())
+}
@@ -0,0 +1,39 @@
+use b4n_tui::table::{Column, Header, NAMESPACE};
+use std::rc::Rc;
+
+use crate::kube::events::EventGroup;
+use crate::kube::resources::ResourceData;
+use crate::ui::widgets::table::Cell;
+
+/// Returns [`ResourceData`] for the group of events from the event stream.
+pub fn data(group: &EventGroup) -> ResourceData {
+    let values: [Cell; 5] = [
+        Cell::timestamp(group.last_seen),
+        Cell::integer(Some(group.count()), 6),
+        group.event_type.as_str().into(),
+        group.reason.as_str().into(),
+        group.message.as_str().into(),
+    ];
+
+    let mut data = ResourceData::new(Box::new(values), false);
+    data.is_ready = !group.is_warning();
+    data
+}
+
+/// Returns [`Header`] for the group of events from the event stream.
+pub fn header() -> Header {
+    Header::from(
+        NAMESPACE,
+        Some(Box::new([
+            Column::fixed("LAST", 6, true).with_reversed_order(),
+            Column::fixed("COUNT", 6, true),
+            Column::bound("TYPE", 6, 7, false),
+            Column::bound("REASON", 6, 25, false),
+            Column::bound("MESSAGE", 15, 150, false),
+        ])),
+        Rc::new([' ', 'O', 'L', 'C', 'T', 'R', 'M', 'A']),
+    )
+    .with_name_column(Column::bound("OBJECT", 15, 50, false))
+    .with_sort_info(2, false)
+    .with_stretch_last()
+}
@@ -0,0 +1,45 @@
+use b4n_tui::table::{Column, Header, NAMESPACE};
+use k8s_openapi::jiff::Timestamp;
+use kube::api::DynamicObject;
+use std::rc::Rc;
+
+use crate::{kube::resources::ResourceData, ui::widgets::table::Cell};
+
+/// Returns [`ResourceData`] for the virtual `helmrelease` resource.\
+/// **Note** that the object is a helm release summary decoded from the release secret.
+pub fn data(object: &DynamicObject) -> ResourceData {
+    let status = object.data["status"].as_str().unwrap_or_default();
+    let updated = object.data["updated"].as_str().and_then(|t| t.parse::<Timestamp>().ok());
+    let is_terminating = status == "uninstalling";
+
+    let values: [Cell; 5] = [
+        Cell::integer(object.data["revision"].as_i64(), 5),
+        object.data["chart"].as_str().into(),
+        object.data["appVersion"].as_str().into(),
+        status.into(),
+        Cell::timestamp(updated),
+    ];
+
+    ResourceData {
+        extra_values: Box::new(values),
+        is_completed: status == "uninstalled" || status == "superseded",
+        is_ready: status == "deployed",
+        is_terminating,
+        ..Default::default()
+    }
+}
+
+/// Returns [`Header`] for the virtual `helmrelease` resource.
+pub fn header() -> Header {
+    Header::from(
+        NAMESPACE,
+        Some(Box::new([
+            Column::fixed("REVISION", 8, true),
+            Column::bound("CHART", 15, 50, false),
+            Column::bound("APP VERSION", 11, 25, false),
+            Column::bound("STATUS", 8, 20, false),
+            Column::fixed("UPDATED", 9, true),
+        ])),
+        Rc::new([' ', 'N', 'R', 'C', 'V', 'S', 'U', 'A']),
+    )
+}
@@ -0,0 +1,130 @@
+use b4n_tui::table::{Column, Header, NAMESPACE};
+use k8s_openapi::serde_json::Value;
+use kube::api::DynamicObject;
+use std::rc::Rc;
+
+use crate::{kube::resources::ResourceData, ui::widgets::table::Cell};
+
+/// Metric defined on the `horizontalpodautoscaler` with its target and current values.
+pub struct HpaMetric {
+    pub kind: String,
+    pub name: String,
+    pub target: String,
+    pub current: Option<String>,
+}
+
+/// Returns [`ResourceData`] for the `horizontalpodautoscaler` kubernetes resource.
+pub fn data(object: &DynamicObject) -> ResourceData {
+    let spec = &object.data["spec"];
+    let status = &object.data["status"];
+    let is_terminating = object.metadata.deletion_timestamp.is_some();
+    let targets = get_metrics(object)
+        .iter()
+        .map(|m| format!("{}: {}/{}", m.name, m.current.as_deref().unwrap_or("<unknown>"), m.target))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let values: [Cell; 5] = [
+        get_scale_target(spec).into(),
+        targets.into(),
+        Cell::integer(Some(spec["minReplicas"].as_i64().unwrap_or(1)), 5),
+        Cell::integer(spec["maxReplicas"].as_i64(), 5),
+        Cell::integer(status["currentReplicas"].as_i64(), 5),
+    ];
+
+    ResourceData::new(Box::new(values), is_terminating)
+}
+
+/// Returns [`Header`] for the `horizontalpodautoscaler` kubernetes resource.
+pub fn header() -> Header {
+    Header::from(
+        NAMESPACE,
+        Some(Box::new([
+            Column::bound("REFERENCE", 15, 50, false),
+            Column::bound("TARGETS", 10, 60, false),
+            Column::fixed("MIN", 5, true),
+            Column::fixed("MAX", 5, true),
+            Column::fixed("REPLICAS", 8, true),
+        ])),
+        Rc::new([' ', 'N', 'R', 'T', 'I', 'X', 'P', 'A']),
+    )
+}
+
+/// Returns scale target of the `horizontalpodautoscaler` as `kind/name`.
+pub fn get_scale_target(spec: &Value) -> Option<String> {
+    let target = &spec["scaleTargetRef"];
+    Some(format!("{}/{}", target["kind"].as_str()?, target["name"].as_str()?))
+}
+
+/// Returns all metrics for the `horizontalpodautoscaler`.\
+/// **Note** that it supports both `autoscaling/v1` and `autoscaling/v2` versions.
+pub fn get_metrics(object: &DynamicObject) -> Vec<HpaMetric> {
+    let spec = &object.data["spec"];
+    let status = &object.data["status"];
+
+    if let Some(target) = spec["targetCPUUtilizationPercentage"].as_i64() {
+        return vec![HpaMetric {
+            kind: "Resource".to_owned(),
+            name: "cpu".to_owned(),
+            target: format!("{target}%"),
+            current: status["currentCPUUtilizationPercentage"].as_i64().map(|c| format!("{c}%")),
+        }];
+    }
+
+    let current = status["currentMetrics"].as_array();
+    spec["metrics"]
+        .as_array()
+        .map(|metrics| {
+            metrics
+                .iter()
+                .filter_map(|metric| {
+                    let kind = metric["type"].as_str()?;
+                    let source = &metric[source_key(kind)];
+                    let name = metric_name(source);
+                    let current = current
+                        .and_then(|c| {
+                            c.iter()
+                                .find(|c| c["type"] == kind && metric_name(&c[source_key(kind)]) == name)
+                        })
+                        .and_then(|c| format_value(&c[source_key(kind)]["current"]));
+
+                    Some(HpaMetric {
+                        kind: kind.to_owned(),
+                        target: format_value(&source["target"]).unwrap_or_default(),
+                        name,
+                        current,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn source_key(kind: &str) -> &str {
+    match kind {
+        "Resource" => "resource",
+        "ContainerResource" => "containerResource",
+        "Pods" => "pods",
+        "Object" => "object",
+        "External" => "external",
+        _ => "",
+    }
+}
+
+fn metric_name(source: &Value) -> String {
+    let name = source["name"].as_str().or_else(|| source["metric"]["name"].as_str());
+    match (name, source["container"].as_str()) {
+        (Some(name), Some(container)) => format!("{container}/{name}"),
+        (name, _) => name.unwrap_or_default().to_owned(),
+    }
+}
+
+fn format_value(value: &Value) -> Option<String> {
+    if let Some(utilization) = value["averageUtilization"].as_i64() {
+        Some(format!("{utilization}%"))
+    } else if let Some(average) = value["averageValue"].as_str() {
+        Some(average.to_owned())
+    } else {
+        value["value"].as_str().map(String::from)
+    }
+}
@@ -0,0 +1,68 @@
+use b4n_tui::table::{Column, Header, NAMESPACE};
+use k8s_openapi::serde_json::{Map, Value};
+use kube::api::DynamicObject;
+use std::rc::Rc;
+
+use crate::{kube::resources::ResourceData, ui::widgets::table::Cell};
+
+/// Returns [`ResourceData`] for the `limitrange` kubernetes resource.
+pub fn data(object: &DynamicObject) -> ResourceData {
+    let limits = object.data["spec"]["limits"].as_array();
+    let is_terminating = object.metadata.deletion_timestamp.is_some();
+    let types = limits.map(|l| l.iter().filter_map(|l| l["type"].as_str()).collect::<Vec<_>>().join(","));
+
+    let values: [Cell; 5] = [
+        types.into(),
+        join_limits(limits, "defaultRequest").into(),
+        join_limits(limits, "default").into(),
+        join_limits(limits, "min").into(),
+        join_limits(limits, "max").into(),
+    ];
+
+    ResourceData::new(Box::new(values), is_terminating)
+}
+
+/// Returns [`Header`] for the `limitrange` kubernetes resource.
+pub fn header() -> Header {
+    Header::from(
+        NAMESPACE,
+        Some(Box::new([
+            Column::bound("TYPES", 6, 40, false),
+            Column::bound("DEFAULT REQUEST", 15, 40, false),
+            Column::bound("DEFAULT LIMIT", 13, 40, false),
+            Column::bound("MIN", 5, 40, false),
+            Column::bound("MAX", 5, 40, false),
+        ])),
+        Rc::new([' ', 'N', 'T', 'R', 'L', 'I', 'X', 'A']),
+    )
+}
+
+/// Returns resources map as a comma separated `key=value` string.
+pub fn resources_to_string(resources: Option<&Map<String, Value>>) -> Option<String> {
+    let resources = resources?
+        .iter()
+        .map(|(k, v)| format!("{k}={}", v.as_str().unwrap_or_default()))
+        .collect::<Vec<_>>();
+
+    (!resources.is_empty()).then(|| resources.join(","))
+}
+
+fn join_limits(limits: Option<&Vec<Value>>, key: &str) -> Option<String> {
+    let limits = limits?;
+    let values = limits
+        .iter()
+        .filter_map(|l| resources_to_string(l[key].as_object()).map(|r| (l["type"].as_str().unwrap_or_default(), r)))
+        .collect::<Vec<_>>();
+
+    match values.as_slice() {
+        [] => None,
+        [(_, resources)] => Some(resources.clone()),
+        values => Some(
+            values
+                .iter()
+                .map(|(kind, resources)| format!("{kind}: {resources}"))
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+    }
+}
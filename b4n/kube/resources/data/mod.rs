@@ -22,10 +22,14 @@ pub mod deployment;
 pub mod endpoint_slice;
 pub mod endpoints;
 pub mod event;
+pub mod event_group;
+pub mod helm_release;
+pub mod horizontal_pod_autoscaler;
 pub mod ingress;
 pub mod ingress_class;
 pub mod job;
 pub mod lease;
+pub mod limit_range;
 pub mod namespace;
 pub mod network_policy;
 pub mod node;
@@ -33,10 +37,13 @@ pub mod node_metrics;
 pub mod persistent_volume;
 pub mod persistent_volume_claim;
 pub mod pod;
+pub mod pod_disruption_budget;
 pub mod pod_metrics;
 pub mod priority_class;
 pub mod priority_level_configuration;
 pub mod replica_set;
+pub mod resource_quota;
+pub mod resource_user;
 pub mod role;
 pub mod role_binding;
 pub mod secret;
@@ -44,6 +51,7 @@ pub mod service;
 pub mod service_account;
 pub mod stateful_set;
 pub mod storage_class;
+pub mod tls_certificate;
 
 /// Returns name for the provided Kubernetes resource.
 pub fn get_resource_name(kind: &str, group: &str, object: &DynamicObject, columns_layout: ColumnsLayout) -> String {
@@ -82,10 +90,12 @@ pub fn get_resource_data(
         ("Endpoints", "") => endpoints::data(object),
         ("EndpointSlice", "discovery.k8s.io") => endpoint_slice::data(object),
         ("Event", "") => event::data(object, columns_layout),
+        ("HorizontalPodAutoscaler", "autoscaling") => horizontal_pod_autoscaler::data(object),
         ("Ingress", "networking.k8s.io") => ingress::data(object),
         ("IngressClass", "networking.k8s.io") => ingress_class::data(object),
         ("Job", "batch") => job::data(object),
         ("Lease", "coordination.k8s.io") => lease::data(object),
+        ("LimitRange", "") => limit_range::data(object),
         ("Namespace", "") => namespace::data(object),
         ("NetworkPolicy", "networking.k8s.io") => network_policy::data(object),
         ("Node", "") => node::data(object, stats),
@@ -93,10 +103,12 @@ pub fn get_resource_data(
         ("PersistentVolume", "") => persistent_volume::data(object),
         ("PersistentVolumeClaim", "") => persistent_volume_claim::data(object),
         ("Pod", "") => pod::data(object, stats),
+        ("PodDisruptionBudget", "policy") => pod_disruption_budget::data(object),
         ("PodMetrics", "metrics.k8s.io") => pod_metrics::data(object),
         ("PriorityClass", "scheduling.k8s.io") => priority_class::data(object),
         ("PriorityLevelConfiguration", "flowcontrol.apiserver.k8s.io") => priority_level_configuration::data(object),
         ("ReplicaSet", "apps") => replica_set::data(object),
+        ("ResourceQuota", "") => resource_quota::data(object),
         ("Role", "rbac.authorization.k8s.io") => role::data(object),
         ("Secret", "") => secret::data(object),
         ("Service", "") => service::data(object),
@@ -104,6 +116,8 @@ pub fn get_resource_data(
         ("StatefulSet", "apps") => stateful_set::data(object),
         ("StorageClass", "storage.k8s.io") => storage_class::data(object),
 
+        ("HelmRelease", "") => helm_release::data(object),
+        ("TLSCertificate", "") => tls_certificate::data(object),
         _ => default::data(object),
     }
 }
@@ -135,10 +149,13 @@ pub fn get_header_data(
         ("Endpoints", "") => endpoints::header(),
         ("EndpointSlice", "discovery.k8s.io") => endpoint_slice::header(),
         ("Event", "") => event::header(columns_layout),
+        ("EventGroup", "") => event_group::header(),
+        ("HorizontalPodAutoscaler", "autoscaling") => horizontal_pod_autoscaler::header(),
         ("Ingress", "networking.k8s.io") => ingress::header(),
         ("IngressClass", "networking.k8s.io") => ingress_class::header(),
         ("Job", "batch") => job::header(),
         ("Lease", "coordination.k8s.io") => lease::header(),
+        ("LimitRange", "") => limit_range::header(),
         ("Namespace", "") => namespace::header(),
         ("NetworkPolicy", "networking.k8s.io") => network_policy::header(),
         ("Node", "") => node::header(has_metrics),
@@ -146,10 +163,12 @@ pub fn get_header_data(
         ("PersistentVolume", "") => persistent_volume::header(),
         ("PersistentVolumeClaim", "") => persistent_volume_claim::header(),
         ("Pod", "") => pod::header(has_metrics),
+        ("PodDisruptionBudget", "policy") => pod_disruption_budget::header(),
         ("PodMetrics", "metrics.k8s.io") => pod_metrics::header(),
         ("PriorityClass", "scheduling.k8s.io") => priority_class::header(),
         ("PriorityLevelConfiguration", "flowcontrol.apiserver.k8s.io") => priority_level_configuration::header(),
         ("ReplicaSet", "apps") => replica_set::header(),
+        ("ResourceQuota", "") => resource_quota::header(),
         ("Role", "rbac.authorization.k8s.io") => role::header(),
         ("Secret", "") => secret::header(),
         ("Service", "") => service::header(),
@@ -159,6 +178,9 @@ pub fn get_header_data(
 
         ("Container", "") => container::header(has_metrics),
         ("Condition", "") => condition::header(),
+        ("ResourceUser", "") => resource_user::header(),
+        ("HelmRelease", "") => helm_release::header(),
+        ("TLSCertificate", "") => tls_certificate::header(),
         _ => default::header(),
     }
 }
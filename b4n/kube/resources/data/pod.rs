@@ -152,6 +152,16 @@ pub fn get_single_container(data: Option<&ResourceData>) -> Option<&str> {
     })
 }
 
+/// Returns name of the first regular container in the pod.
+pub fn get_first_container(data: Option<&ResourceData>) -> Option<&str> {
+    data.and_then(|d| {
+        d.tags.iter().find_map(|t| match t {
+            ResourceTag::Container(name, ContainerType::Regular, _) => Some(name.as_str()),
+            _ => None,
+        })
+    })
+}
+
 fn get_restarts(containers: &[Value]) -> i64 {
     containers
         .iter()
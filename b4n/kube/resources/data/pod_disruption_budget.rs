@@ -0,0 +1,55 @@
+use b4n_kube::utils::get_match_labels;
+use b4n_tui::table::{Column, Header, NAMESPACE};
+use k8s_openapi::serde_json::Value;
+use kube::api::DynamicObject;
+use std::rc::Rc;
+
+use crate::{kube::resources::ResourceData, ui::widgets::table::Cell};
+
+/// Returns [`ResourceData`] for the `poddisruptionbudget` kubernetes resource.
+pub fn data(object: &DynamicObject) -> ResourceData {
+    let spec = &object.data["spec"];
+    let status = &object.data["status"];
+    let allowed = status["disruptionsAllowed"].as_i64();
+    let healthy = status["currentHealthy"].as_i64().unwrap_or_default();
+    let desired = status["desiredHealthy"].as_i64().unwrap_or_default();
+    let is_terminating = object.metadata.deletion_timestamp.is_some();
+    let tags = get_match_labels(object);
+
+    let values: [Cell; 4] = [
+        int_or_string(&spec["minAvailable"]).into(),
+        int_or_string(&spec["maxUnavailable"]).into(),
+        Cell::integer(allowed, 5),
+        format!("{healthy}/{desired}").into(),
+    ];
+
+    ResourceData {
+        extra_values: Box::new(values),
+        is_ready: !is_terminating && allowed.is_some_and(|a| a > 0),
+        is_terminating,
+        tags,
+        ..Default::default()
+    }
+}
+
+/// Returns [`Header`] for the `poddisruptionbudget` kubernetes resource.
+pub fn header() -> Header {
+    Header::from(
+        NAMESPACE,
+        Some(Box::new([
+            Column::fixed("MIN AVAILABLE", 13, true),
+            Column::fixed("MAX UNAVAILABLE", 15, true),
+            Column::fixed("ALLOWED DISRUPTIONS", 19, true),
+            Column::fixed("HEALTHY", 8, true),
+        ])),
+        Rc::new([' ', 'N', 'M', 'X', 'D', 'H', 'A']),
+    )
+}
+
+/// Returns value of the `IntOrString` field as a string.
+pub fn int_or_string(value: &Value) -> Option<String> {
+    value
+        .as_i64()
+        .map(|v| v.to_string())
+        .or_else(|| value.as_str().map(String::from))
+}
@@ -0,0 +1,92 @@
+use b4n_kube::stats::{CpuMetrics, MemoryMetrics};
+use b4n_tui::table::{Column, Header, NAMESPACE};
+use kube::api::DynamicObject;
+use std::{rc::Rc, str::FromStr};
+
+use crate::{kube::resources::ResourceData, ui::widgets::table::Cell};
+
+/// Usage of a single resource limited by the `resourcequota`.
+pub struct QuotaUsage {
+    pub resource: String,
+    pub used: Option<String>,
+    pub hard: String,
+}
+
+impl QuotaUsage {
+    /// Returns `true` if the resource usage reached the hard limit.
+    pub fn is_exhausted(&self) -> bool {
+        let Some(used) = self.used.as_deref() else {
+            return false;
+        };
+
+        if self.resource.ends_with("cpu") {
+            compare(
+                CpuMetrics::from_str(used).map(|m| m.value),
+                CpuMetrics::from_str(&self.hard).map(|m| m.value),
+            )
+        } else {
+            compare(
+                MemoryMetrics::from_str(used).map(|m| m.value),
+                MemoryMetrics::from_str(&self.hard).map(|m| m.value),
+            )
+        }
+    }
+}
+
+/// Returns [`ResourceData`] for the `resourcequota` kubernetes resource.
+pub fn data(object: &DynamicObject) -> ResourceData {
+    let usage = get_usage(object);
+    let is_terminating = object.metadata.deletion_timestamp.is_some();
+    let is_exhausted = usage.iter().any(QuotaUsage::is_exhausted);
+    let (limits, requests): (Vec<_>, Vec<_>) = usage.iter().partition(|u| u.resource.starts_with("limits."));
+
+    let values: [Cell; 2] = [format_usage(&requests).into(), format_usage(&limits).into()];
+
+    ResourceData {
+        extra_values: Box::new(values),
+        is_ready: !is_terminating && !is_exhausted,
+        is_terminating,
+        ..Default::default()
+    }
+}
+
+/// Returns [`Header`] for the `resourcequota` kubernetes resource.
+pub fn header() -> Header {
+    Header::from(
+        NAMESPACE,
+        Some(Box::new([
+            Column::bound("REQUEST", 15, 80, false),
+            Column::bound("LIMIT", 15, 60, false),
+        ])),
+        Rc::new([' ', 'N', 'R', 'L', 'A']),
+    )
+}
+
+/// Returns used and hard values for all resources limited by the `resourcequota`.
+pub fn get_usage(object: &DynamicObject) -> Vec<QuotaUsage> {
+    let status = &object.data["status"];
+    let hard = status["hard"].as_object().or_else(|| object.data["spec"]["hard"].as_object());
+
+    hard.map(|hard| {
+        hard.iter()
+            .map(|(resource, hard)| QuotaUsage {
+                resource: resource.clone(),
+                used: status["used"][resource].as_str().map(String::from),
+                hard: hard.as_str().unwrap_or_default().to_owned(),
+            })
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+fn format_usage(usage: &[&QuotaUsage]) -> String {
+    usage
+        .iter()
+        .map(|u| format!("{}: {}/{}", u.resource, u.used.as_deref().unwrap_or("0"), u.hard))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn compare<E>(used: Result<u64, E>, hard: Result<u64, E>) -> bool {
+    matches!((used, hard), (Ok(used), Ok(hard)) if used >= hard)
+}
@@ -0,0 +1,25 @@
+use b4n_tasks::commands::ResourceUser;
+use b4n_tui::table::{Column, Header, NAMESPACE};
+use std::rc::Rc;
+
+use crate::{kube::resources::ResourceData, ui::widgets::table::Cell};
+
+/// Returns [`ResourceData`] for the pod or workload that uses the described resource.
+pub fn data(user: &ResourceUser) -> ResourceData {
+    let values: [Cell; 2] = [user.singular.as_str().into(), user.references.join(", ").into()];
+
+    ResourceData::new(Box::new(values), false)
+}
+
+/// Returns [`Header`] for the pod or workload that uses the described resource.
+pub fn header() -> Header {
+    Header::from(
+        NAMESPACE,
+        Some(Box::new([
+            Column::bound("KIND", 6, 25, false),
+            Column::bound("REFERENCE", 10, 60, false),
+        ])),
+        Rc::new([' ', 'N', 'K', 'R', 'A']),
+    )
+    .with_stretch_last()
+}
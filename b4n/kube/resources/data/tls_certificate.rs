@@ -0,0 +1,48 @@
+use b4n_kube::certs::days_until;
+use b4n_tui::table::{Column, Header, NAMESPACE};
+use k8s_openapi::jiff::Timestamp;
+use kube::api::DynamicObject;
+use std::rc::Rc;
+
+use crate::{kube::resources::ResourceData, ui::widgets::table::Cell};
+
+pub const DAYS_COLUMN_NO: usize = 6;
+
+/// Returns [`ResourceData`] for the virtual `tlscertificate` resource.\
+/// **Note** that the object is a summary of the first to expire certificate from the `kubernetes.io/tls` secret.
+pub fn data(object: &DynamicObject) -> ResourceData {
+    let not_after = object.data["notAfter"].as_str().and_then(|t| t.parse::<Timestamp>().ok());
+    let days_left = not_after.map(|t| days_until(t, Timestamp::now()));
+    let is_expired = days_left.is_some_and(|d| d < 0);
+
+    let values: [Cell; 5] = [
+        object.data["subject"].as_str().into(),
+        object.data["issuer"].as_str().into(),
+        object.data["keyType"].as_str().into(),
+        Cell::time_left(not_after),
+        Cell::integer(days_left, 6),
+    ];
+
+    ResourceData {
+        extra_values: Box::new(values),
+        is_ready: !is_expired,
+        is_terminating: is_expired,
+        ..Default::default()
+    }
+}
+
+/// Returns [`Header`] for the virtual `tlscertificate` resource.
+pub fn header() -> Header {
+    Header::from(
+        NAMESPACE,
+        Some(Box::new([
+            Column::bound("SUBJECT", 10, 50, false),
+            Column::bound("ISSUER", 10, 50, false),
+            Column::bound("KEY", 5, 12, false),
+            Column::fixed("EXPIRES", 12, true),
+            Column::fixed("DAYS", 6, true), // this column position must match DAYS_COLUMN_NO
+        ])),
+        Rc::new([' ', 'N', 'S', 'I', 'K', 'E', 'D', 'A']),
+    )
+    .with_sort_info(DAYS_COLUMN_NO, false)
+}
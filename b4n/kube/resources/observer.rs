@@ -1,6 +1,8 @@
 use b4n_common::NotificationSink;
+use b4n_kube::certs::{Certificate, TLS_CERTIFICATE, first_to_expire};
 use b4n_kube::client::KubernetesClient;
 use b4n_kube::crds::{CrdColumns, SharedCrdsList};
+use b4n_kube::helm::{HELM_RELEASE, HelmRelease, get_release_revision, get_release_stub};
 use b4n_kube::stats::{Metrics, PodStats, SharedStatistics, Statistics};
 use b4n_kube::{BgObserver, BgObserverError, ContainerType, InitData, Kind, Namespace, ObserverResult, PODS, ResourceRef};
 use delegate::delegate;
@@ -8,7 +10,7 @@ use k8s_openapi::serde_json::Value;
 use kube::api::{ApiResource, DynamicObject};
 use kube::discovery::{ApiCapabilities, Scope};
 use std::cell::RefCell;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::rc::Rc;
 use tokio::runtime::Handle;
 
@@ -23,6 +25,8 @@ pub struct ResourceObserver {
     crd: Option<CrdColumns>,
     statistics: SharedStatistics,
     columns_layout: Option<ColumnsLayout>,
+    helm_revisions: HashMap<String, i64>,
+    helm_pending: Option<HashMap<String, DynamicObject>>,
 }
 
 impl ResourceObserver {
@@ -36,6 +40,8 @@ impl ResourceObserver {
             crd: None,
             statistics,
             columns_layout: None,
+            helm_revisions: HashMap::new(),
+            helm_pending: None,
         }
     }
 
@@ -49,6 +55,8 @@ impl ResourceObserver {
             crd: None,
             statistics: Rc::new(RefCell::new(Statistics::default())),
             columns_layout: None,
+            helm_revisions: HashMap::new(),
+            helm_pending: None,
         }
     }
 
@@ -174,11 +182,17 @@ impl ResourceObserver {
             match *result {
                 ObserverResult::Init(mut init_data) => {
                     self.queue.clear();
+                    self.helm_revisions.clear();
+                    self.helm_pending = Some(HashMap::new());
                     self.inject_init_data(&mut init_data);
                     self.group.clone_from(&init_data.group);
                     Some(Box::new(ObserverResult::Init(init_data)))
                 },
-                ObserverResult::InitDone => Some(Box::new(ObserverResult::InitDone)),
+                ObserverResult::InitDone => {
+                    self.queue_pending_helm_releases();
+                    self.queue.push_back(Box::new(ObserverResult::InitDone));
+                    self.queue.pop_front()
+                },
                 ObserverResult::Apply(item) => self.get_next_result(item, false),
                 ObserverResult::Delete(item) => self.get_next_result(item, true),
             }
@@ -215,6 +229,10 @@ impl ResourceObserver {
                 ContainerType::Ephemeral,
                 is_delete,
             );
+        } else if self.observer.observed_kind().is_helm_releases() {
+            self.queue_helm_release(&object, is_delete);
+        } else if self.observer.observed_kind().is_tls_certificates() {
+            self.queue_tls_certificate(&object, is_delete);
         } else {
             self.queue_resource(object, is_delete);
         }
@@ -239,6 +257,121 @@ impl ResourceObserver {
         }
     }
 
+    /// Queues helm release decoded from the release secret.\
+    /// **Note** that only the latest revision of each release is shown on the list, so during the initial list
+    /// revisions are compared by their labels and only the latest one is decoded when the list is complete.
+    fn queue_helm_release(&mut self, secret: &DynamicObject, is_delete: bool) {
+        let Some((uid, revision)) = get_release_revision(secret) else {
+            return;
+        };
+
+        let latest = self.helm_revisions.get(&uid).copied();
+        if is_delete {
+            if latest != Some(revision) {
+                return;
+            }
+
+            self.helm_revisions.remove(&uid);
+        } else if latest.is_some_and(|latest| latest > revision) {
+            return;
+        }
+
+        if let Some(pending) = self.helm_pending.as_mut() {
+            if is_delete {
+                pending.remove(&uid);
+            } else {
+                self.helm_revisions.insert(uid.clone(), revision);
+                pending.insert(uid, secret.clone());
+            }
+
+            return;
+        }
+
+        if self.push_helm_release(&uid, secret, is_delete) && !is_delete {
+            self.helm_revisions.insert(uid, revision);
+        }
+    }
+
+    /// Decodes and queues the latest revisions of helm releases received with the initial list.
+    fn queue_pending_helm_releases(&mut self) {
+        let Some(pending) = self.helm_pending.take() else {
+            return;
+        };
+
+        for (uid, secret) in pending {
+            if !self.push_helm_release(&uid, &secret, false) {
+                self.helm_revisions.remove(&uid);
+            }
+        }
+    }
+
+    /// Decodes helm release from the release secret and queues it.\
+    /// **Note** that it returns `false` if the release cannot be decoded, deleted releases are not decoded.
+    fn push_helm_release(&mut self, uid: &str, secret: &DynamicObject, is_delete: bool) -> bool {
+        let object = if is_delete {
+            get_release_stub(secret)
+        } else {
+            HelmRelease::from_secret(secret)
+                .inspect_err(|error| tracing::warn!("Cannot decode helm release '{}': {}", uid, error))
+                .ok()
+                .map(|release| release.to_object())
+        };
+        let Some(object) = object else {
+            return false;
+        };
+
+        let result = ObserverResult::new(
+            ResourceItem::from(
+                HELM_RELEASE,
+                "",
+                None,
+                &self.statistics.borrow(),
+                object,
+                self.columns_layout(),
+            ),
+            is_delete,
+        );
+        self.queue.push_back(Box::new(result));
+        true
+    }
+
+    /// Queues the first to expire certificate from the `kubernetes.io/tls` secret.\
+    /// **Note** that secrets with invalid certificates are removed from the list.
+    fn queue_tls_certificate(&mut self, secret: &DynamicObject, is_delete: bool) {
+        let (object, is_delete) = match Certificate::from_secret(secret) {
+            Ok(certificates) => match first_to_expire(&certificates) {
+                Some(certificate) => (certificate.to_object(secret), is_delete),
+                None => return,
+            },
+            Err(error) => {
+                if !is_delete {
+                    let name = secret.metadata.name.as_deref().unwrap_or_default();
+                    tracing::warn!("Cannot read TLS certificate from secret '{}': {}", name, error);
+                }
+
+                let object = DynamicObject {
+                    types: None,
+                    metadata: secret.metadata.clone(),
+                    data: Value::Null,
+                };
+                (object, true)
+            },
+        };
+
+        let result = ObserverResult::new(
+            ResourceItem::from(
+                TLS_CERTIFICATE,
+                "",
+                None,
+                &self.statistics.borrow(),
+                object,
+                self.columns_layout(),
+            ),
+            is_delete,
+        );
+        self.queue.push_back(Box::new(result));
+    }
+
     fn queue_resource(&mut self, object: DynamicObject, is_delete: bool) {
         let kind = self.observer.observed_singular_kind().unwrap_or_default();
         let result = ObserverResult::new(
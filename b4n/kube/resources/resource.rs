@@ -1,18 +1,22 @@
-use b4n_common::expr::{Expression, ExpressionExt, SelectiveMap, parse};
+use b4n_common::expr::{Expression, ExpressionExt, FIELD_PREFIX, SelectiveMap, ServerSelectors, parse};
 use b4n_common::truncate;
 use b4n_config::themes::{TextColors, Theme};
 use b4n_kube::stats::{Metrics, Statistics};
 use b4n_kube::{ContainerRef, ContainerType, Kind, Namespace, PV, ResourceTag};
 use b4n_kube::{crds::CrdColumns, utils::get_object_uid};
 use b4n_list::{FilterContext, Filterable, Row};
+use b4n_tasks::commands::ResourceUser;
 use b4n_tui::table::Header;
 use k8s_openapi::jiff::Timestamp;
 use k8s_openapi::serde_json::Value;
 use kube::api::{DynamicObject, ObjectMeta};
 use std::{borrow::Cow, collections::BTreeMap};
 
-use crate::kube::resources::{ResourceData, condition, container, get_header_data, get_resource_data, get_resource_name};
-use crate::ui::widgets::table::Cell;
+use crate::kube::events::EventGroup;
+use crate::kube::resources::{
+    ResourceData, condition, container, event_group, get_header_data, get_resource_data, get_resource_name, resource_user,
+};
+use crate::ui::widgets::table::{Cell, RowFilterSource};
 
 #[cfg(test)]
 #[path = "./resource.tests.rs"]
@@ -178,6 +182,40 @@ impl ResourceItem {
         }
     }
 
+    /// Creates [`ResourceItem`] from the pod or workload that uses another resource.
+    pub fn from_resource_user(user: &ResourceUser) -> Self {
+        Self {
+            uid: user.uid.clone(),
+            name: user.name.clone(),
+            namespace: user.namespace.clone(),
+            data: Some(resource_user::data(user)),
+            involved_object: Some(InvolvedObject {
+                kind: user.kind.clone(),
+                namespace: user.namespace.clone().into(),
+                name: user.name.clone(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Creates [`ResourceItem`] from the group of events from the event stream.
+    pub fn from_event_group(group: &EventGroup) -> Self {
+        Self {
+            uid: group.key.clone(),
+            name: group.object(),
+            namespace: group.namespace.clone(),
+            age: get_age_string(group.first_seen),
+            data: Some(event_group::data(group)),
+            involved_object: group.object_kind.as_ref().map(|kind| InvolvedObject {
+                kind: kind.clone(),
+                namespace: group.object_namespace.clone().into(),
+                name: group.object_name.clone(),
+            }),
+            creation_timestamp: group.first_seen,
+            ..Default::default()
+        }
+    }
+
     /// Updates specified data column text.
     pub fn set_data_text(&mut self, idx: usize, text: impl Into<String>) {
         if let Some(data) = &mut self.data
@@ -286,6 +324,17 @@ fn get_involved_object(kind: &str, object: &DynamicObject) -> Option<InvolvedObj
         return get_involved_object_from_ref(object);
     }
 
+    if kind == "HorizontalPodAutoscaler"
+        && let Some(target) = object.data["spec"].get("scaleTargetRef")
+        && let Some(kind) = target["kind"].as_str()
+    {
+        return Some(InvolvedObject {
+            kind: Kind::from_api_version(kind, target["apiVersion"].as_str().unwrap_or_default()),
+            namespace: object.metadata.namespace.clone().into(),
+            name: target["name"].as_str().unwrap_or_default().to_owned(),
+        });
+    }
+
     if kind == "PersistentVolumeClaim"
         && let Some(name) = object.data["spec"]["volumeName"].as_str()
     {
@@ -385,6 +434,8 @@ impl Row for ResourceItem {
 pub struct ResourceFilterContext {
     pattern: String,
     extended: Option<Expression>,
+    columns: Vec<String>,
+    has_server_fields: bool,
 }
 
 impl FilterContext for ResourceFilterContext {
@@ -395,8 +446,17 @@ impl FilterContext for ResourceFilterContext {
 
 impl Filterable<ResourceFilterContext> for ResourceItem {
     fn get_context(pattern: &str, settings: Option<&str>) -> ResourceFilterContext {
+        Self::get_context_with_columns(pattern, settings, &[])
+    }
+
+    fn get_context_with_columns(pattern: &str, settings: Option<&str>, columns: &[String]) -> ResourceFilterContext {
         let expression = if let Some(settings) = settings {
-            if settings.contains('e') { parse(pattern).ok() } else { None }
+            if settings.contains('e') {
+                // field queries that cannot be sent to the API server make the whole expression invalid
+                parse(pattern).ok().filter(|_| ServerSelectors::parse(pattern).is_ok())
+            } else {
+                None
+            }
         } else {
             None
         };
@@ -404,15 +464,21 @@ impl Filterable<ResourceFilterContext> for ResourceItem {
         ResourceFilterContext {
             pattern: pattern.to_owned(),
             extended: expression,
+            columns: columns.to_vec(),
+            has_server_fields: settings.is_some_and(|s| s.contains('f')),
         }
     }
 
     /// Checks if an item match a filter using the provided context.\
     /// Extended filtering is when `e` is provided in settings.\
-    /// **Note** that currently it has only a switch for normal/extended filtering.
+    /// **Note** that field queries match only when `f` is provided in settings, as they are evaluated by the API server.
     fn is_matching(&self, context: &mut ResourceFilterContext) -> bool {
         if let Some(expression) = &context.extended {
-            self.ignore_filters || self.filter_metadata.evaluate(expression)
+            let server_key = context.has_server_fields.then_some(FIELD_PREFIX);
+            self.ignore_filters
+                || RowFilterSource::new(self, &self.filter_metadata, &context.columns)
+                    .with_server_key(server_key)
+                    .evaluate(expression)
         } else {
             self.name.contains(&context.pattern)
         }
@@ -438,6 +504,7 @@ fn get_filter_metadata(kind: &str, group: &str, metadata: &ObjectMeta) -> Select
 
     if let Some(labels) = metadata.labels.as_ref() {
         result.insert("l", flatten_metadata(labels));
+        result.insert_exact("l", labels.iter().map(|(k, v)| format!("{k}={v}")).collect());
     }
 
     if let Some(annotations) = metadata.annotations.as_ref() {
@@ -147,3 +147,61 @@ fn align_column_to_right_test() {
         item.get_text(ViewType::Full, &header, &widths, terminal_width, 0)
     );
 }
+
+#[test]
+fn column_filter_test() {
+    let header = crate::kube::resources::pod::header(false);
+    let mut item = ResourceItem::new("api-7f9", false);
+    item.namespace = Some("apps".to_owned());
+    item.data = Some(ResourceData {
+        extra_values: vec![
+            Some("7".to_owned()).into(),
+            Some("1/1".to_owned()).into(),
+            Some("".to_owned()).into(),
+            Some("Running".to_owned()).into(),
+            Some("10.42.1.201".to_owned()).into(),
+            Some("node-1".to_owned()).into(),
+        ]
+        .into_boxed_slice(),
+        ..Default::default()
+    });
+
+    let is_matching = |pattern: &str| {
+        let mut context = ResourceItem::get_context_with_columns(pattern, Some("e"), &header.get_names());
+        item.is_matching(&mut context)
+    };
+
+    assert!(is_matching("c:status=running"));
+    assert!(is_matching("c:restarts>5 & c:node=/^node-\\d$/"));
+    assert!(!is_matching("c:restarts<=5"));
+    assert!(!is_matching("c:age<10m"));
+    assert!(!is_matching("c:unknown=1"));
+}
+
+#[test]
+fn server_queries_filter_test() {
+    let object = k8s_openapi::serde_json::from_value(k8s_openapi::serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": { "name": "api-7f9", "namespace": "apps", "labels": { "app": "web-api", "tier": "Frontend" } },
+        "status": { "phase": "Running" },
+    }))
+    .unwrap();
+    let item = ResourceItem::from("Pod", "", None, &Statistics::default(), object, ColumnsLayout::General);
+
+    let is_matching = |pattern: &str, settings: &str| {
+        let mut context = ResourceItem::get_context(pattern, Some(settings));
+        item.is_matching(&mut context)
+    };
+
+    assert!(is_matching("f:status.phase=Running & api", "ef"));
+    assert!(!is_matching("f:status.phase=Running & web-1", "ef"));
+    assert!(!is_matching("f:status.phase=Running & api", "e"));
+    assert!(!is_matching("!f:status.phase=Running", "e"));
+
+    assert!(is_matching("l:app=web-api", "e"));
+    assert!(!is_matching("l:app=web", "e"));
+    assert!(is_matching("l:app & l:tier", "e"));
+    assert!(is_matching("l:tier=Frontend & api", "e"));
+    assert!(!is_matching("l:tier=frontend", "e"));
+}
@@ -173,6 +173,11 @@ impl ResourcesList {
             .map(|i| &i.data)
     }
 
+    /// Gets first resource with the specified `name`, regardless of its namespace.
+    pub fn find_resource(&self, name: &str) -> Option<&ResourceItem> {
+        self.table.list.full_iter().find(|i| i.data.name == name).map(|i| &i.data)
+    }
+
     /// Gets selected resources.
     pub fn get_selected_resources(&self) -> Vec<&ResourceItem> {
         self.table
@@ -183,6 +188,11 @@ impl ResourcesList {
             .collect::<Vec<_>>()
     }
 
+    /// Returns all resources that match the current filter.
+    pub fn get_filtered_resources(&self) -> Vec<&ResourceItem> {
+        self.table.list.iter().map(|i| &i.data).collect::<Vec<_>>()
+    }
+
     /// Returns resources as formatted strings.\
     /// **Note** that this is the same format as for drawing on the terminal.
     pub fn get_items_as_text(&mut self, view: ViewType, selected: bool) -> Vec<String> {
@@ -220,6 +230,26 @@ impl ResourcesList {
                 item.data.is_cached = !item.is_fixed;
             }
         }
+
+        self.update_filter_settings();
+    }
+
+    /// Lets field queries of the extended filter match only if the API server narrows down the observed resources with them.
+    fn update_filter_settings(&mut self) {
+        if !self.table.list.filter_settings().is_some_and(|s| s.contains('e')) {
+            return;
+        }
+
+        let has_server_fields = self
+            .data
+            .resource
+            .filter
+            .as_ref()
+            .is_some_and(|f| f.is_server_side && f.fields.is_some());
+        let settings = if has_server_fields { "ef" } else { "e" };
+        if self.table.list.filter_settings() != Some(settings) {
+            self.table.list.set_filter_settings(Some(settings));
+        }
     }
 
     /// Adds, updates or deletes `new_item` from the resources list.
@@ -298,6 +328,7 @@ impl Table for ResourcesList {
         let data = std::mem::take(&mut self.data);
         let list = std::mem::take(&mut self.table.list);
         self.table.list.set_filter_settings(list.filter_settings());
+        self.table.list.set_filter_columns(list.filter_columns().to_vec());
 
         if data.resource.kind.as_str().is_empty() {
             return;
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use b4n_config::{Config, ConfigError, History};
+use b4n_config::{Bookmarks, Config, ConfigError, History};
 use b4n_kube::PODS;
 use b4n_kube::client::{get_context, resolve_kube_config_path};
 use clap::Parser;
@@ -63,8 +63,9 @@ fn run_application(args: &cli::Args) -> Result<()> {
 
     let (config, config_error) = rt.block_on(Config::load_or_create());
     let (theme, theme_error) = rt.block_on(config.load_theme());
+    let (bookmarks, bookmarks_error) = rt.block_on(Bookmarks::load_or_create());
 
-    let mut app = App::new(rt.handle().clone(), config, history, theme, args.insecure)?;
+    let mut app = App::new(rt.handle().clone(), config, history, bookmarks, theme, args.insecure)?;
     app.start(context.name, kind, namespace)?;
 
     if let Some(error) = config_error
@@ -79,6 +80,10 @@ fn run_application(args: &cli::Args) -> Result<()> {
         app.show_theme_error(format!("Error loading theme: {error}"));
     }
 
+    if let Some(error) = bookmarks_error {
+        app.show_error(format!("Error loading bookmarks: {error}"));
+    }
+
     application_loop(&mut app)?;
     app.stop()?;
 
@@ -10,6 +10,9 @@ use ratatui::widgets::Paragraph;
 use crate::core::{AppData, ConnectionState, SharedAppData};
 use crate::ui::presentation::utils::{get_left_breadcrumbs, get_right_breadcrumbs};
 
+/// Maximum number of back navigation entries shown in the header.
+const NAVIGATION_TRAIL_SIZE: usize = 3;
+
 /// Header pane that shows context, namespace, kind and number of items as a breadcrumbs.
 pub struct ListHeader {
     app_data: SharedAppData,
@@ -46,6 +49,11 @@ impl ListHeader {
         self
     }
 
+    /// Sets new fixed kind name for the header.
+    pub fn set_kind(&mut self, kind: &'static str) {
+        self.fixed_kind = Some(kind);
+    }
+
     /// Sets fixed namespace name for the header.
     pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
         self.fixed_namespace = Some(namespace.into());
@@ -141,10 +149,21 @@ impl ListHeader {
             self.is_filtered,
         );
 
-        if !self.hide_previous
-            && let Some(previous) = self.app_data.borrow().previous.last()
-        {
-            line.push_span(Span::from(format!(" 󰕍 {}", previous.resource.kind.name())).style(&data.theme.colors.header.previous));
+        if !self.hide_previous {
+            let context = data.current.context.as_str();
+            let trail = data
+                .navigation
+                .back_entries()
+                .take(NAVIGATION_TRAIL_SIZE)
+                .map(|e| e.label(context))
+                .collect::<Vec<_>>();
+            if !trail.is_empty() {
+                line.push_span(Span::from(format!(" 󰕍 {}", trail.join(" ‹ "))).style(&data.theme.colors.header.previous));
+            }
+
+            if let Some(next) = data.navigation.next_entry() {
+                line.push_span(Span::from(format!(" › {}", next.label(context))).style(&data.theme.colors.header.previous));
+            }
         }
 
         line
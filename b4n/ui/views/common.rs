@@ -1,7 +1,8 @@
 use b4n_common::NotificationSink;
-use b4n_kube::{ContainerType, ResourceTag};
+use b4n_kube::{ContainerRef, ContainerType, Namespace, ResourceRef, ResourceTag};
 use b4n_tasks::commands::CommandResult;
 use b4n_tui::{ResponseEvent, TuiEvent};
+use k8s_openapi::jiff::Timestamp;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use std::path::PathBuf;
@@ -9,9 +10,20 @@ use std::rc::Rc;
 use std::time::{Duration, Instant};
 use tui_term::vt100::Screen;
 
-use crate::core::{SharedAppData, SharedAppDataExt};
+use crate::core::{DetailView, SharedAppData, SharedAppDataExt};
 use crate::ui::presentation::ScreenSelection;
 
+/// Details of the session running in a [`View`] that can be kept alive in the background.
+#[derive(Debug, Clone)]
+pub struct SessionInfo {
+    pub title: String,
+    pub namespace: Namespace,
+    pub name: Option<String>,
+    pub container: Option<String>,
+    pub last_activity: Timestamp,
+    pub is_running: bool,
+}
+
 /// TUI view with pages and widgets.
 pub trait View {
     /// Returns ID of the command associated with this [`View`].
@@ -61,6 +73,21 @@ pub trait View {
         let _ = result;
     }
 
+    /// Returns detail view that can be reopened when navigating back to the current location.
+    fn detail_view(&self) -> Option<DetailView> {
+        None
+    }
+
+    /// Returns session details if this [`View`] can be kept running in the background.
+    fn session(&self) -> Option<SessionInfo> {
+        None
+    }
+
+    /// Notifies [`View`] that it was moved to the background or restored to the foreground.
+    fn set_foreground(&mut self, is_foreground: bool) {
+        let _ = is_foreground;
+    }
+
     /// Processes app tick.
     fn process_tick(&mut self) -> ResponseEvent {
         ResponseEvent::Handled
@@ -104,6 +131,28 @@ pub fn get_layout_with_header(area: Rect) -> Rc<[Rect]> {
         .split(area)
 }
 
+/// Builds references to the containers whose logs should be shown for the specified `resource`.\
+/// **Note** that if no `containers` are provided, the container from the `resource` is used.
+pub fn get_logs_containers(resource: &ResourceRef, containers: Option<Vec<ResourceTag>>) -> Vec<ContainerRef> {
+    match containers {
+        Some(containers) if !containers.is_empty() => containers
+            .into_iter()
+            .map(|container| {
+                ContainerRef::new(
+                    resource.name.clone().unwrap_or_default(),
+                    resource.namespace.clone(),
+                    Some(container),
+                )
+            })
+            .collect(),
+        _ => vec![ContainerRef::simple(
+            resource.name.clone().unwrap_or_default(),
+            resource.namespace.clone(),
+            resource.container.clone(),
+        )],
+    }
+}
+
 /// Gets names for all containers in the list of resource tags.
 pub fn get_all_containers_from_resource_tags(tags: &[ResourceTag]) -> Vec<String> {
     tags.iter()
@@ -1,9 +1,10 @@
 use b4n_config::keys::KeyCommand;
 use b4n_kube::{InitData, ObserverResult, ResourceRef, status};
+use b4n_tasks::commands::{ResourceUser, ResourceUsersError};
 use b4n_tui::table::{Table, ViewType};
 use b4n_tui::utils::center;
 use b4n_tui::widgets::Spinner;
-use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, TuiEvent};
+use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, ToSelectData, TuiEvent};
 use crossterm::event::{KeyCode, KeyModifiers};
 use kube::ResourceExt;
 use kube::api::DynamicObject;
@@ -33,6 +34,7 @@ pub struct DescribeContent {
     events: ListViewer<ResourcesList>,
     events_header: Vec<StyledLine>,
     sections: Vec<SectionData>,
+    target: Option<ResponseEvent>,
     creation_time: Instant,
     has_data: bool,
     is_deleted: bool,
@@ -62,6 +64,7 @@ impl DescribeContent {
             events,
             events_header,
             sections,
+            target: None,
             creation_time: Instant::now(),
             has_data: false,
             is_deleted: false,
@@ -87,11 +90,25 @@ impl DescribeContent {
         };
 
         self.has_data = true;
+        self.target = data::get_target(&self.resource, &object);
         self.update_describe(&object);
         self.update_conditions(&object);
         self.update_additional_sections(&object);
     }
 
+    /// Returns response that navigates to the resources targeted by the described object.
+    pub fn target(&self) -> Option<&ResponseEvent> {
+        self.target.as_ref()
+    }
+
+    /// Updates pods and workloads that use the described resource.
+    pub fn update_users(&mut self, result: Result<Vec<ResourceUser>, ResourceUsersError>) {
+        data::update_users_sections(&self.resource, &self.app_data, result, &mut self.sections);
+        if !self.can_focus_section(self.focused) {
+            self.focus_section(FocusTarget::Scroll);
+        }
+    }
+
     /// Updates described resource events.
     pub fn update_events(&mut self, result: ObserverResult<ResourceItem>) {
         self.events.table.update(result);
@@ -108,6 +125,12 @@ impl DescribeContent {
             self.focus_section(self.get_clicked_section(event));
         }
 
+        if self.app_data.has_binding(event, KeyCommand::NavigateInto)
+            && let Some(response) = self.get_focused_involved_object()
+        {
+            return response;
+        }
+
         match self.focused {
             FocusTarget::Scroll => self.process_scroll_event(event),
             FocusTarget::AdditionalSection(index) => match self.sections.get_mut(index) {
@@ -164,6 +187,23 @@ impl DescribeContent {
         }
     }
 
+    /// Returns response that navigates to the object involved with the highlighted item of the focused list.
+    fn get_focused_involved_object(&self) -> Option<ResponseEvent> {
+        let FocusTarget::AdditionalSection(index) = self.focused else {
+            return None;
+        };
+        let Some(SectionData::Resources(list, _)) = self.sections.get(index) else {
+            return None;
+        };
+
+        let involved = list.table.get_highlighted_resource()?.involved_object.as_ref()?;
+        Some(ResponseEvent::ViewInvolved(
+            involved.kind.clone().into(),
+            involved.namespace.clone().into(),
+            ToSelectData::new(&involved.name, involved.namespace.as_option()),
+        ))
+    }
+
     fn create_conditions(app_data: &SharedAppData) -> (ListViewer<ResourcesList>, Vec<StyledLine>) {
         let mut viewer = ListViewer::new(
             Rc::clone(app_data),
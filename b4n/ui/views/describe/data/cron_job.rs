@@ -1,3 +1,6 @@
+use b4n_kube::cron::CronSchedule;
+use b4n_kube::utils::format_time_left;
+use k8s_openapi::jiff::Timestamp;
 use kube::api::DynamicObject;
 
 use crate::core::SharedAppData;
@@ -5,6 +8,9 @@ use crate::ui::views::describe::builder::TextSectionBuilder;
 use crate::ui::views::describe::data::job::JOB_SECTIONS_COUNT;
 use crate::ui::views::describe::data::{SectionData, SectionDataExt, job};
 
+/// Number of the next schedule runs shown in the describe view.
+const NEXT_RUNS_COUNT: usize = 5;
+
 /// Returns additional describe sections for `cronjob` resource.
 pub fn create_additional_sections(resource: &b4n_kube::ResourceRef, app_data: &SharedAppData) -> Vec<SectionData> {
     let mut sections = vec![SectionData::Text(Vec::new(), 0)];
@@ -34,7 +40,26 @@ pub fn update_additional_sections(
     let mut builder = TextSectionBuilder::new(colors, lines);
 
     builder.start_section("Schedule", 0, 2, Some(27));
+    let schedule = CronSchedule::parse(spec["schedule"].as_str().unwrap_or_default())
+        .and_then(|s| s.with_time_zone(spec["timeZone"].as_str()));
+    let next_runs = schedule
+        .as_ref()
+        .map(|s| s.next_runs(Timestamp::now(), NEXT_RUNS_COUNT))
+        .unwrap_or_default();
+
     builder.add_str("Schedule", spec["schedule"].as_str());
+    match &schedule {
+        Ok(schedule) => builder.add_str("Description", Some(schedule.describe())),
+        Err(error) => builder.add_str("Description", Some(format!("invalid schedule: {error}"))),
+    }
+    builder.add_str(
+        "Shortest Interval",
+        next_runs
+            .windows(2)
+            .map(|w| w[0].duration_until(&w[1]))
+            .min()
+            .map(|d| format!("{d:#}")),
+    );
     builder.add_str("TimeZone", spec["timeZone"].as_str());
     builder.add_str("ConcurrencyPolicy", spec["concurrencyPolicy"].as_str());
     builder.add_bool("Suspend", spec["suspend"].as_bool());
@@ -48,6 +73,18 @@ pub fn update_additional_sections(
         object.data["status"]["active"].as_array().map(|items| items.len() as i64),
     );
 
+    builder.start_section("Next Runs", 0, 2, Some(27));
+    if next_runs.is_empty() {
+        builder.add_str("Next Run", Some(if schedule.is_ok() { "never" } else { "n/a" }));
+    }
+    for (i, run) in next_runs.iter().enumerate() {
+        let time = run.strftime("%Y-%m-%d %H:%M %Z");
+        builder.add_str(
+            &format!("#{}", i + 1),
+            Some(format!("{time} ({})", format_time_left(&run.timestamp()))),
+        );
+    }
+
     builder.start_section("Job Template", 0, 0, None);
     job::update_additional_sections(resource, app_data, object, &mut sections[1..], true);
 }
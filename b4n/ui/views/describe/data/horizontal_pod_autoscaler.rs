@@ -0,0 +1,140 @@
+use b4n_kube::{Kind, ResourceRef};
+use b4n_tui::table::{Column, Table, ViewType};
+use b4n_tui::{ResponseEvent, ToSelectData};
+use k8s_openapi::serde_json::Value;
+use kube::api::DynamicObject;
+use std::rc::Rc;
+
+use crate::core::SharedAppData;
+use crate::kube::resources::horizontal_pod_autoscaler::{get_metrics, get_scale_target};
+use crate::ui::presentation::{ListViewer, StyledLine};
+use crate::ui::views::describe::builder::TextSectionBuilder;
+use crate::ui::views::describe::data::SectionData;
+use crate::ui::views::describe::utils::header;
+use crate::ui::widgets::table::{BasicRow, BasicTable};
+
+/// Returns additional describe sections for `horizontalpodautoscaler` resource.
+pub fn create_additional_sections(_resource: &ResourceRef, app_data: &SharedAppData) -> Vec<SectionData> {
+    let colors = &app_data.borrow().theme.colors.syntax.describe;
+
+    vec![
+        SectionData::Text(Vec::new(), 0),
+        SectionData::Text(vec![StyledLine::default(), header(colors, "Metrics", 0)], 0),
+        SectionData::List(Box::new(create_metrics_table(app_data)), 0),
+    ]
+}
+
+/// Updates additional describe sections for `horizontalpodautoscaler` resource.
+pub fn update_additional_sections(
+    _resource: &ResourceRef,
+    app_data: &SharedAppData,
+    object: &DynamicObject,
+    sections: &mut [SectionData],
+) {
+    if sections.len() != 3 {
+        return;
+    }
+
+    update_scaling_section(app_data, object, &mut sections[0]);
+    update_metrics_table(object, &mut sections[2]);
+}
+
+/// Returns response that navigates to the workload scaled by the `horizontalpodautoscaler`.
+pub fn get_target(object: &DynamicObject) -> Option<ResponseEvent> {
+    let target = &object.data["spec"]["scaleTargetRef"];
+    let kind = Kind::from_api_version(target["kind"].as_str()?, target["apiVersion"].as_str().unwrap_or_default());
+    let namespace = object.metadata.namespace.clone().unwrap_or_default();
+    let to_select = ToSelectData::new(target["name"].as_str()?, Some(namespace.as_str()));
+
+    Some(ResponseEvent::ViewInvolved(kind.into(), namespace, to_select))
+}
+
+fn create_metrics_table(app_data: &SharedAppData) -> ListViewer<BasicTable> {
+    let mut table = ListViewer::new(
+        Rc::clone(app_data),
+        BasicTable::new(
+            Column::bound("NAME", 10, 50, false),
+            Box::new([
+                Column::bound("TYPE", 6, 20, false),
+                Column::bound("CURRENT", 8, 20, true),
+                Column::bound("TARGET", 8, 20, true),
+            ]),
+            &['N', 'T', 'C', 'R'],
+        )
+        .with_focus(false),
+        ViewType::Compact,
+    )
+    .with_no_border()
+    .with_focus(false);
+
+    table.table.table.limit_offset(false);
+    table
+}
+
+fn update_metrics_table(object: &DynamicObject, section: &mut SectionData) {
+    if let SectionData::List(list, _) = section {
+        list.table.clear();
+        for metric in get_metrics(object) {
+            let uid = format!("_{}_{}_", metric.kind, metric.name);
+            let row = BasicRow::new(
+                uid,
+                metric.name,
+                Box::new([
+                    metric.kind.into(),
+                    metric.current.unwrap_or_else(|| "<unknown>".to_owned()).into(),
+                    metric.target.into(),
+                ]),
+            );
+            list.table.update(row, false);
+        }
+    }
+}
+
+fn update_scaling_section(app_data: &SharedAppData, object: &DynamicObject, section: &mut SectionData) {
+    let SectionData::Text(lines, _) = section else {
+        return;
+    };
+
+    lines.clear();
+
+    let colors = &app_data.borrow().theme.colors.syntax.describe;
+    let mut builder = TextSectionBuilder::new(colors, lines);
+
+    let spec = &object.data["spec"];
+    let status = &object.data["status"];
+    builder.start_section("Scaling", 0, 2, Some(22));
+    builder.add_str("Reference", get_scale_target(spec));
+    builder.add_inum("Min Replicas", Some(spec["minReplicas"].as_i64().unwrap_or(1)));
+    builder.add_inum("Max Replicas", spec["maxReplicas"].as_i64());
+    builder.add_inum("Current Replicas", status["currentReplicas"].as_i64());
+    builder.add_inum("Desired Replicas", status["desiredReplicas"].as_i64());
+    builder.add_str("Last Scale Time", status["lastScaleTime"].as_str());
+    builder.add_str("Scale Up", scaling_rules(&spec["behavior"]["scaleUp"]));
+    builder.add_str("Scale Down", scaling_rules(&spec["behavior"]["scaleDown"]));
+}
+
+fn scaling_rules(rules: &Value) -> Option<String> {
+    let rules = rules.as_object()?;
+    let mut items = Vec::new();
+
+    if let Some(window) = rules.get("stabilizationWindowSeconds").and_then(Value::as_i64) {
+        items.push(format!("stabilization {window}s"));
+    }
+
+    if let Some(select) = rules.get("selectPolicy").and_then(Value::as_str) {
+        items.push(format!("select {select}"));
+    }
+
+    if let Some(policies) = rules.get("policies").and_then(Value::as_array) {
+        for policy in policies {
+            items.push(format!(
+                "{} {} per {}s",
+                policy["type"].as_str().unwrap_or_default(),
+                policy["value"].as_i64().unwrap_or_default(),
+                policy["periodSeconds"].as_i64().unwrap_or_default()
+            ));
+        }
+    }
+
+    (!items.is_empty()).then(|| items.join(", "))
+}
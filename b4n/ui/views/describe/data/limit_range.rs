@@ -0,0 +1,99 @@
+use b4n_kube::ResourceRef;
+use b4n_tui::table::{Column, Table, ViewType};
+use kube::api::DynamicObject;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+use crate::core::SharedAppData;
+use crate::ui::presentation::{ListViewer, StyledLine};
+use crate::ui::views::describe::data::SectionData;
+use crate::ui::views::describe::utils::header;
+use crate::ui::widgets::table::{BasicRow, BasicTable};
+
+const LIMIT_KEYS: [&str; 5] = ["min", "max", "defaultRequest", "default", "maxLimitRequestRatio"];
+
+/// Returns additional describe sections for `limitrange` resource.
+pub fn create_additional_sections(_resource: &ResourceRef, app_data: &SharedAppData) -> Vec<SectionData> {
+    let colors = &app_data.borrow().theme.colors.syntax.describe;
+
+    vec![
+        SectionData::Text(vec![StyledLine::default(), header(colors, "Limits", 0)], 0),
+        SectionData::List(Box::new(create_limits_table(app_data)), 0),
+    ]
+}
+
+/// Updates additional describe sections for `limitrange` resource.
+pub fn update_additional_sections(
+    _resource: &ResourceRef,
+    _app_data: &SharedAppData,
+    object: &DynamicObject,
+    sections: &mut [SectionData],
+) {
+    if sections.len() != 2 {
+        return;
+    }
+
+    update_limits_table(object, &mut sections[1]);
+}
+
+fn create_limits_table(app_data: &SharedAppData) -> ListViewer<BasicTable> {
+    let mut table = ListViewer::new(
+        Rc::clone(app_data),
+        BasicTable::new(
+            Column::bound("TYPE", 6, 30, false),
+            Box::new([
+                Column::bound("RESOURCE", 8, 30, false),
+                Column::bound("MIN", 5, 15, true),
+                Column::bound("MAX", 5, 15, true),
+                Column::bound("DEFAULT REQUEST", 15, 20, true),
+                Column::bound("DEFAULT LIMIT", 13, 20, true),
+                Column::bound("MAX RATIO", 9, 15, true),
+            ]),
+            &['T', 'R', 'I', 'X', 'Q', 'L', 'O'],
+        )
+        .with_focus(false),
+        ViewType::Compact,
+    )
+    .with_no_border()
+    .with_focus(false);
+
+    table.table.table.limit_offset(false);
+    table
+}
+
+fn update_limits_table(object: &DynamicObject, section: &mut SectionData) {
+    let SectionData::List(list, _) = section else {
+        return;
+    };
+
+    list.table.clear();
+    let Some(limits) = object.data["spec"]["limits"].as_array() else {
+        return;
+    };
+
+    for limit in limits {
+        let kind = limit["type"].as_str().unwrap_or_default();
+        let resources = LIMIT_KEYS
+            .iter()
+            .filter_map(|key| limit[*key].as_object())
+            .flat_map(|map| map.keys())
+            .collect::<BTreeSet<_>>();
+
+        for resource in resources {
+            let value = |key: &str| limit[key][resource].as_str().unwrap_or("-").to_owned();
+            let row = BasicRow::new(
+                format!("_{kind}_{resource}_"),
+                kind,
+                Box::new([
+                    resource.as_str().into(),
+                    value("min").into(),
+                    value("max").into(),
+                    value("defaultRequest").into(),
+                    value("default").into(),
+                    value("maxLimitRequestRatio").into(),
+                ]),
+            );
+            list.table.update(row, false);
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use b4n_kube::ResourceRef;
+use b4n_tasks::commands::{ResourceUser, ResourceUsersError};
+use b4n_tui::ResponseEvent;
 use kube::api::DynamicObject;
 
 use crate::core::SharedAppData;
@@ -9,31 +11,48 @@ use crate::ui::widgets::table::BasicTable;
 mod cron_job;
 mod daemon_set;
 mod deployment;
+mod horizontal_pod_autoscaler;
 mod job;
+mod limit_range;
 mod node;
 mod persistent_volume;
 mod persistent_volume_claim;
 mod pod;
+mod pod_disruption_budget;
 mod replica_set;
+mod resource_quota;
+mod secret;
 mod service;
 mod stateful_set;
+mod used_by;
 
 /// Creates new additional sections for describe view for the specified resource.
 pub fn create_additional_sections(resource: &ResourceRef, app_data: &SharedAppData) -> Vec<SectionData> {
-    match resource.kind.name() {
+    let mut sections = match resource.kind.name() {
         "cronjobs" => cron_job::create_additional_sections(resource, app_data),
         "daemonsets" => daemon_set::create_additional_sections(resource, app_data),
         "deployments" => deployment::create_additional_sections(resource, app_data),
+        "horizontalpodautoscalers" => horizontal_pod_autoscaler::create_additional_sections(resource, app_data),
         "jobs" => job::create_additional_sections(resource, app_data),
+        "limitranges" => limit_range::create_additional_sections(resource, app_data),
         "nodes" => node::create_additional_sections(resource, app_data),
         "persistentvolumes" => persistent_volume::create_additional_sections(resource, app_data),
         "persistentvolumeclaims" => persistent_volume_claim::create_additional_sections(resource, app_data),
         "pods" => pod::create_additional_sections(resource, app_data),
+        "poddisruptionbudgets" => pod_disruption_budget::create_additional_sections(resource, app_data),
         "replicasets" => replica_set::create_additional_sections(resource, app_data),
+        "resourcequotas" => resource_quota::create_additional_sections(resource, app_data),
+        "secrets" => secret::create_additional_sections(resource, app_data),
         "services" => service::create_additional_sections(resource, app_data),
         "statefulsets" => stateful_set::create_additional_sections(resource, app_data),
         _ => Vec::new(),
+    };
+
+    if used_by::is_supported(resource) {
+        sections.extend(used_by::create_sections(app_data));
     }
+
+    sections
 }
 
 /// Updates additional sections for describe view for the specified resource.
@@ -43,22 +62,61 @@ pub fn update_additional_sections(
     object: &DynamicObject,
     sections: &mut [SectionData],
 ) {
+    let sections = if used_by::is_supported(resource) {
+        let len = sections.len().saturating_sub(used_by::USED_BY_SECTIONS);
+        &mut sections[..len]
+    } else {
+        sections
+    };
+
     match resource.kind.name() {
         "cronjobs" => cron_job::update_additional_sections(resource, app_data, object, sections),
         "daemonsets" => daemon_set::update_additional_sections(resource, app_data, object, sections),
         "deployments" => deployment::update_additional_sections(resource, app_data, object, sections),
+        "horizontalpodautoscalers" => horizontal_pod_autoscaler::update_additional_sections(resource, app_data, object, sections),
         "jobs" => job::update_additional_sections(resource, app_data, object, sections, false),
+        "limitranges" => limit_range::update_additional_sections(resource, app_data, object, sections),
         "nodes" => node::update_additional_sections(resource, app_data, object, sections),
         "persistentvolumes" => persistent_volume::update_additional_sections(resource, app_data, object, sections),
         "persistentvolumeclaims" => persistent_volume_claim::update_additional_sections(resource, app_data, object, sections),
         "pods" => pod::update_additional_sections(resource, app_data, object, sections, false),
+        "poddisruptionbudgets" => pod_disruption_budget::update_additional_sections(resource, app_data, object, sections),
         "replicasets" => replica_set::update_additional_sections(resource, app_data, object, sections),
+        "resourcequotas" => resource_quota::update_additional_sections(resource, app_data, object, sections),
+        "secrets" => secret::update_additional_sections(resource, app_data, object, sections),
         "services" => service::update_additional_sections(resource, app_data, object, sections),
         "statefulsets" => stateful_set::update_additional_sections(resource, app_data, object, sections),
         _ => (),
     }
 }
 
+/// Returns `true` if pods and workloads that use the `resource` can be looked up.
+pub fn has_users(resource: &ResourceRef) -> bool {
+    used_by::is_supported(resource)
+}
+
+/// Updates "used by" section for the specified resource with the lookup `result`.
+pub fn update_users_sections(
+    resource: &ResourceRef,
+    app_data: &SharedAppData,
+    result: Result<Vec<ResourceUser>, ResourceUsersError>,
+    sections: &mut [SectionData],
+) {
+    if used_by::is_supported(resource) {
+        let start = sections.len().saturating_sub(used_by::USED_BY_SECTIONS);
+        used_by::update_sections(resource, app_data, result, &mut sections[start..]);
+    }
+}
+
+/// Returns response that navigates to the resources targeted by the described object.
+pub fn get_target(resource: &ResourceRef, object: &DynamicObject) -> Option<ResponseEvent> {
+    match resource.kind.name() {
+        "horizontalpodautoscalers" => horizontal_pod_autoscaler::get_target(object),
+        "poddisruptionbudgets" => pod_disruption_budget::get_target(object),
+        _ => None,
+    }
+}
+
 /// Holds section's data.
 pub enum SectionData {
     Text(Vec<StyledLine>, u16),
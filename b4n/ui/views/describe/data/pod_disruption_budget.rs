@@ -0,0 +1,64 @@
+use b4n_kube::{PODS, ResourceRef, ResourceRefFilter, utils::labels_to_string};
+use b4n_tui::{ResponseEvent, ScopeData, ToSelectData};
+use kube::api::DynamicObject;
+
+use crate::core::SharedAppData;
+use crate::kube::resources::pod_disruption_budget::int_or_string;
+use crate::ui::views::describe::builder::TextSectionBuilder;
+use crate::ui::views::describe::data::SectionData;
+use crate::ui::views::describe::utils::selector;
+
+/// Returns additional describe sections for `poddisruptionbudget` resource.
+pub fn create_additional_sections(_resource: &ResourceRef, _app_data: &SharedAppData) -> Vec<SectionData> {
+    vec![SectionData::Text(Vec::new(), 0)]
+}
+
+/// Updates additional describe sections for `poddisruptionbudget` resource.
+pub fn update_additional_sections(
+    _resource: &ResourceRef,
+    app_data: &SharedAppData,
+    object: &DynamicObject,
+    sections: &mut [SectionData],
+) {
+    if sections.len() != 1 {
+        return;
+    }
+
+    let SectionData::Text(lines, _) = &mut sections[0] else {
+        return;
+    };
+
+    lines.clear();
+
+    let colors = &app_data.borrow().theme.colors.syntax.describe;
+    let spec = &object.data["spec"];
+    let status = &object.data["status"];
+    let mut builder = TextSectionBuilder::new(colors, lines);
+
+    builder.start_section("Disruption Budget", 0, 2, Some(28));
+    builder.add_num("Min Available", int_or_string(&spec["minAvailable"]));
+    builder.add_num("Max Unavailable", int_or_string(&spec["maxUnavailable"]));
+    builder.add_str("Selector", selector(spec["selector"].as_object()));
+    builder.add_str("Unhealthy Pod Eviction Policy", spec["unhealthyPodEvictionPolicy"].as_str());
+    builder.add_inum("Allowed Disruptions", status["disruptionsAllowed"].as_i64());
+    builder.add_inum("Current Healthy", status["currentHealthy"].as_i64());
+    builder.add_inum("Desired Healthy", status["desiredHealthy"].as_i64());
+    builder.add_inum("Expected Pods", status["expectedPods"].as_i64());
+
+    builder.add_empty();
+    builder.add_map("Disrupted Pods", status["disruptedPods"].as_object());
+}
+
+/// Returns response that navigates to the pods selected by the `poddisruptionbudget`.
+pub fn get_target(object: &DynamicObject) -> Option<ResponseEvent> {
+    let labels = labels_to_string(object.data["spec"]["selector"]["matchLabels"].as_object()?);
+    let name = object.metadata.name.clone()?;
+    let filter = ResourceRefFilter::labels(name, labels);
+
+    Some(ResponseEvent::ViewScoped(
+        PODS.to_owned(),
+        object.metadata.namespace.clone(),
+        ToSelectData::None,
+        ScopeData::namespace_hidden(filter),
+    ))
+}
@@ -0,0 +1,109 @@
+use b4n_kube::ResourceRef;
+use b4n_tui::table::{Column, Table, ViewType};
+use kube::api::DynamicObject;
+use std::rc::Rc;
+
+use crate::core::SharedAppData;
+use crate::kube::resources::resource_quota::get_usage;
+use crate::ui::presentation::{ListViewer, StyledLine};
+use crate::ui::views::describe::builder::TextSectionBuilder;
+use crate::ui::views::describe::data::SectionData;
+use crate::ui::views::describe::utils::{header, map_join, value_to_string};
+use crate::ui::widgets::table::{BasicRow, BasicTable};
+
+/// Returns additional describe sections for `resourcequota` resource.
+pub fn create_additional_sections(_resource: &ResourceRef, app_data: &SharedAppData) -> Vec<SectionData> {
+    let colors = &app_data.borrow().theme.colors.syntax.describe;
+
+    vec![
+        SectionData::Text(Vec::new(), 0),
+        SectionData::Text(vec![StyledLine::default(), header(colors, "Resources", 0)], 0),
+        SectionData::List(Box::new(create_usage_table(app_data)), 0),
+    ]
+}
+
+/// Updates additional describe sections for `resourcequota` resource.
+pub fn update_additional_sections(
+    _resource: &ResourceRef,
+    app_data: &SharedAppData,
+    object: &DynamicObject,
+    sections: &mut [SectionData],
+) {
+    if sections.len() != 3 {
+        return;
+    }
+
+    update_scopes_section(app_data, object, &mut sections[0]);
+    update_usage_table(object, &mut sections[2]);
+}
+
+fn create_usage_table(app_data: &SharedAppData) -> ListViewer<BasicTable> {
+    let mut table = ListViewer::new(
+        Rc::clone(app_data),
+        BasicTable::new(
+            Column::bound("RESOURCE", 10, 50, false),
+            Box::new([
+                Column::bound("USED", 6, 20, true),
+                Column::bound("HARD", 6, 20, true),
+                Column::fixed("EXHAUSTED", 10, false),
+            ]),
+            &['R', 'U', 'H', 'E'],
+        )
+        .with_focus(false),
+        ViewType::Compact,
+    )
+    .with_no_border()
+    .with_focus(false);
+
+    table.table.table.limit_offset(false);
+    table.table.sort(1, false);
+
+    table
+}
+
+fn update_usage_table(object: &DynamicObject, section: &mut SectionData) {
+    if let SectionData::List(list, _) = section {
+        list.table.clear();
+        for usage in get_usage(object) {
+            let uid = format!("_{}_", usage.resource);
+            let is_exhausted = usage.is_exhausted();
+            let row = BasicRow::new(
+                uid,
+                usage.resource,
+                Box::new([
+                    usage.used.unwrap_or_else(|| "0".to_owned()).into(),
+                    usage.hard.into(),
+                    is_exhausted.into(),
+                ]),
+            );
+            list.table.update(row, false);
+        }
+    }
+}
+
+fn update_scopes_section(app_data: &SharedAppData, object: &DynamicObject, section: &mut SectionData) {
+    let SectionData::Text(lines, _) = section else {
+        return;
+    };
+
+    lines.clear();
+
+    let colors = &app_data.borrow().theme.colors.syntax.describe;
+    let mut builder = TextSectionBuilder::new(colors, lines);
+
+    let spec = &object.data["spec"];
+    builder.start_section("Quota", 0, 2, Some(18));
+    builder.add_str("Scopes", map_join(spec["scopes"].as_array(), value_to_string));
+    builder.add_str(
+        "Scope Selector",
+        map_join(spec["scopeSelector"]["matchExpressions"].as_array(), |e| {
+            let values = map_join(e["values"].as_array(), value_to_string).unwrap_or_default();
+            Some(format!(
+                "{} {} [{}]",
+                e["scopeName"].as_str().unwrap_or_default(),
+                e["operator"].as_str().unwrap_or_default(),
+                values
+            ))
+        }),
+    );
+}
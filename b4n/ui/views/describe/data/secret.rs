@@ -0,0 +1,67 @@
+use b4n_kube::ResourceRef;
+use b4n_kube::certs::{Certificate, TLS_CERT_KEY, TLS_SECRET_TYPE};
+use k8s_openapi::jiff::Timestamp;
+use kube::api::DynamicObject;
+
+use crate::core::SharedAppData;
+use crate::ui::views::describe::builder::TextSectionBuilder;
+use crate::ui::views::describe::data::SectionData;
+
+/// Returns additional describe sections for `secret` resource.
+pub fn create_additional_sections(_resource: &ResourceRef, _app_data: &SharedAppData) -> Vec<SectionData> {
+    vec![SectionData::Text(Vec::new(), 0)]
+}
+
+/// Updates additional describe sections for `secret` resource.\
+/// **Note** that only `kubernetes.io/tls` secrets have additional sections.
+pub fn update_additional_sections(
+    _resource: &ResourceRef,
+    app_data: &SharedAppData,
+    object: &DynamicObject,
+    sections: &mut [SectionData],
+) {
+    if sections.len() != 1 {
+        return;
+    }
+
+    let SectionData::Text(lines, _) = &mut sections[0] else {
+        return;
+    };
+
+    lines.clear();
+    if object.data["type"].as_str() != Some(TLS_SECRET_TYPE) && object.data["data"][TLS_CERT_KEY].is_null() {
+        return;
+    }
+
+    let colors = &app_data.borrow().theme.colors.syntax.describe;
+    let mut builder = TextSectionBuilder::new(colors, lines);
+
+    let certificates = match Certificate::from_secret(object) {
+        Ok(certificates) => certificates,
+        Err(error) => {
+            builder.start_section("Certificates", 0, 2, Some(10));
+            builder.add_str("Error", Some(error.to_string()));
+            return;
+        },
+    };
+
+    let now = Timestamp::now();
+    for (i, certificate) in certificates.iter().enumerate() {
+        builder.start_section(&format!("Certificate #{}", i + 1), 0, 2, Some(10));
+        builder.add_str("Subject", Some(certificate.subject.as_str()));
+        builder.add_str("Issuer", Some(certificate.issuer.as_str()));
+        builder.add_str("SANs", (!certificate.sans.is_empty()).then(|| certificate.sans.join(", ")));
+        builder.add_str(
+            "NotBefore",
+            Some(certificate.not_before.strftime("%Y-%m-%d %H:%M:%S UTC").to_string()),
+        );
+        builder.add_str(
+            "NotAfter",
+            Some(certificate.not_after.strftime("%Y-%m-%d %H:%M:%S UTC").to_string()),
+        );
+        builder.add_inum("DaysLeft", Some(certificate.days_left(now)));
+        builder.add_str("KeyType", Some(certificate.key_type.as_str()));
+        builder.add_str("Serial", Some(certificate.serial.as_str()));
+        builder.add_bool("CA", Some(certificate.is_ca));
+    }
+}
@@ -0,0 +1,80 @@
+use b4n_kube::{CONFIG_MAPS, InitData, ObserverResult, PVC, ResourceRef, SECRETS, SERVICE_ACCOUNTS};
+use b4n_tasks::commands::{ResourceUser, ResourceUsersError};
+use b4n_tui::table::ViewType;
+use std::rc::Rc;
+
+use crate::core::SharedAppData;
+use crate::kube::resources::{ColumnsLayout, ResourceItem, ResourcesList};
+use crate::ui::presentation::{ListViewer, StyledLine};
+use crate::ui::views::describe::builder::TextSectionBuilder;
+use crate::ui::views::describe::data::SectionData;
+
+/// Number of sections added to the describe view by the "used by" lookup.
+pub const USED_BY_SECTIONS: usize = 2;
+
+/// Returns `true` if pods and workloads that use the `resource` can be looked up.
+pub fn is_supported(resource: &ResourceRef) -> bool {
+    !resource.kind.has_group() && matches!(resource.kind.name(), CONFIG_MAPS | SECRETS | PVC | SERVICE_ACCOUNTS)
+}
+
+/// Returns "used by" describe sections.
+pub fn create_sections(app_data: &SharedAppData) -> Vec<SectionData> {
+    vec![
+        SectionData::Text(create_header(app_data, None), 0),
+        SectionData::Resources(Box::new(create_users_table(app_data)), 0),
+    ]
+}
+
+/// Updates "used by" describe sections with the lookup `result`.
+pub fn update_sections(
+    resource: &ResourceRef,
+    app_data: &SharedAppData,
+    result: Result<Vec<ResourceUser>, ResourceUsersError>,
+    sections: &mut [SectionData],
+) {
+    let [SectionData::Text(lines, _), SectionData::Resources(list, _)] = sections else {
+        return;
+    };
+
+    let init_data = InitData::simple(resource.clone(), "ResourceUser".to_owned(), "resourceusers".to_owned());
+    list.table.update(ObserverResult::Init(Box::new(init_data)));
+    match result {
+        Ok(users) => {
+            *lines = create_header(app_data, None);
+            for user in users {
+                list.table
+                    .update(ObserverResult::new(ResourceItem::from_resource_user(&user), false));
+            }
+        },
+        Err(error) => *lines = create_header(app_data, Some(error.to_string())),
+    }
+
+    list.table.update(ObserverResult::InitDone);
+}
+
+fn create_header(app_data: &SharedAppData, error: Option<String>) -> Vec<StyledLine> {
+    let colors = &app_data.borrow().theme.colors.syntax.describe;
+    let mut lines = Vec::new();
+    let mut builder = TextSectionBuilder::new(colors, &mut lines);
+    builder.start_section("Used By", 0, 2, Some(10));
+    if error.is_some() {
+        builder.add_str("Error", error);
+    }
+
+    lines
+}
+
+fn create_users_table(app_data: &SharedAppData) -> ListViewer<ResourcesList> {
+    let mut table = ListViewer::new(
+        Rc::clone(app_data),
+        ResourcesList::default()
+            .with_columns_layout(ColumnsLayout::Compact)
+            .with_focus(false),
+        ViewType::Compact,
+    )
+    .with_no_border()
+    .with_focus(false);
+
+    table.table.table.limit_offset(false);
+    table
+}
@@ -3,6 +3,7 @@ use b4n_config::keys::KeyCommand;
 use b4n_kube::utils::get_resource;
 use b4n_kube::{BgObserver, EVENTS, ObserverResult, ResourceRefFilter};
 use b4n_kube::{Kind, ResourceRef};
+use b4n_tasks::commands::CommandResult;
 use b4n_tui::MouseEventKind;
 use b4n_tui::widgets::ActionItem;
 use b4n_tui::{ResponseEvent, Responsive, TuiEvent, widgets::ActionsListBuilder};
@@ -10,21 +11,28 @@ use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Direction, Layout, Position};
 use ratatui::{Frame, layout::Rect};
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 
-use crate::core::{SharedAppData, SharedAppDataExt, SharedBgWorker};
+use crate::core::{DetailView, SharedAppData, SharedAppDataExt, SharedBgWorker};
 use crate::kube::resources::{ColumnsLayout, ResourceObserver};
 use crate::ui::presentation::{BufferContent, ContentHeader, ScreenSelection};
 use crate::ui::views::describe::content::DescribeContent;
+use crate::ui::views::describe::data;
 use crate::ui::{views::View, widgets::CommandPalette};
 
+const USERS_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
 /// Pod's describe view.
 pub struct DescribeView {
     app_data: SharedAppData,
+    worker: SharedBgWorker,
     header: ContentHeader,
     resource: ResourceRef,
     content: DescribeContent,
     observer: BgObserver,
     events: ResourceObserver,
+    users_command_id: Option<String>,
+    last_users_refresh: Option<Instant>,
     command_palette: CommandPalette,
     selection: ScreenSelection,
     last_mouse_click: Option<Position>,
@@ -41,6 +49,7 @@ impl DescribeView {
         resource: ResourceRef,
         footer_tx: NotificationSink,
     ) -> Option<Self> {
+        let shared_worker = Rc::clone(worker);
         let worker = worker.borrow();
         let resource_name = resource.name.as_deref().map(String::from)?;
         let client = worker.kubernetes_client()?;
@@ -68,20 +77,35 @@ impl DescribeView {
 
         set_hint(&app_data, &footer_tx);
 
-        Some(Self {
+        drop(worker);
+        let mut view = Self {
             app_data,
+            worker: shared_worker,
             header,
             resource,
             content,
             observer,
             events,
+            users_command_id: None,
+            last_users_refresh: None,
             command_palette: CommandPalette::default(),
             selection,
             last_mouse_click: None,
             last_frame: None,
             area: Rect::default(),
             footer_tx,
-        })
+        };
+        view.refresh_users();
+
+        Some(view)
+    }
+
+    /// Starts looking up pods and workloads that use the described resource.
+    fn refresh_users(&mut self) {
+        if self.users_command_id.is_none() && data::has_users(&self.resource) {
+            self.last_users_refresh = Some(Instant::now());
+            self.users_command_id = self.worker.borrow_mut().get_resource_users(vec![self.resource.clone()]);
+        }
     }
 
     /// Shows command palette.
@@ -93,7 +117,7 @@ impl DescribeView {
             "table"
         };
 
-        let builder = ActionsListBuilder::default()
+        let mut builder = ActionsListBuilder::default()
             .with_back()
             .with_quit()
             .with_action(
@@ -107,6 +131,13 @@ impl DescribeView {
                 Some(KeyCommand::YamlOpen),
             )
             .with_aliases(&self.app_data.borrow().config.aliases);
+        if self.content.target().is_some() {
+            builder.add_action(
+                ActionItem::action("show target", "target").with_description("shows resources targeted by the described object"),
+                Some(KeyCommand::InvolvedObjectShow),
+            );
+        }
+
         let actions = builder.build(Some(&self.app_data.borrow().key_bindings));
 
         self.command_palette =
@@ -133,6 +164,10 @@ impl DescribeView {
             builder.add_menu_action(ActionItem::menu(1, "󰆏 copy ␝table␝", "copy"));
         }
 
+        if self.content.target().is_some() {
+            builder.add_menu_action(ActionItem::menu(2, "󰑏 show target", "target"));
+        }
+
         self.command_palette = CommandPalette::new(Rc::clone(&self.app_data), builder.build(None), 22).to_mouse_menu();
         self.command_palette.show_at((x.saturating_sub(3), y).into());
     }
@@ -146,6 +181,7 @@ impl DescribeView {
             },
             ResponseEvent::Action("yaml") => self.process_event(&TuiEvent::Command(KeyCommand::YamlOpen)),
             ResponseEvent::Action("copy") => self.copy_to_clipboard(),
+            ResponseEvent::Action("target") => self.process_event(&TuiEvent::Command(KeyCommand::InvolvedObjectShow)),
             response_event => response_event,
         }
     }
@@ -175,6 +211,21 @@ impl DescribeView {
 }
 
 impl View for DescribeView {
+    fn command_id(&self) -> Option<&str> {
+        self.users_command_id.as_deref()
+    }
+
+    fn process_command_result(&mut self, result: CommandResult) {
+        if let CommandResult::ResourceUsers(result) = result {
+            self.users_command_id = None;
+            self.content.update_users(result);
+        }
+    }
+
+    fn detail_view(&self) -> Option<DetailView> {
+        Some(DetailView::Describe(self.resource.clone()))
+    }
+
     fn process_tick(&mut self) -> ResponseEvent {
         while let Some(result) = self.observer.try_next() {
             if matches!(*result, ObserverResult::Delete(_)) {
@@ -188,6 +239,10 @@ impl View for DescribeView {
             self.content.update_events(*result);
         }
 
+        if self.last_users_refresh.is_some_and(|r| r.elapsed() >= USERS_REFRESH_INTERVAL) {
+            self.refresh_users();
+        }
+
         ResponseEvent::Handled
     }
 
@@ -225,6 +280,12 @@ impl View for DescribeView {
             return ResponseEvent::ViewYaml(self.resource.clone(), false, false);
         }
 
+        if self.app_data.has_binding(event, KeyCommand::InvolvedObjectShow)
+            && let Some(target) = self.content.target()
+        {
+            return target.clone();
+        }
+
         if self.app_data.has_binding(event, KeyCommand::CommandPaletteOpen) {
             self.show_command_palette();
             return ResponseEvent::Handled;
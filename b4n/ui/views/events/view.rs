@@ -0,0 +1,303 @@
+use b4n_common::NotificationSink;
+use b4n_config::keys::KeyCommand;
+use b4n_kube::utils::get_resource;
+use b4n_kube::{BgObserver, EVENTS, InitData, Kind, Namespace, ObserverResult, ResourceRef};
+use b4n_tui::widgets::{ActionItem, ActionsListBuilder};
+use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, TuiEvent, table::Table, table::ViewType};
+use crossterm::event::KeyModifiers;
+use kube::discovery::Scope;
+use ratatui::Frame;
+use ratatui::layout::{Position, Rect};
+use std::rc::Rc;
+
+use crate::core::{DetailView, SharedAppData, SharedAppDataExt, SharedBgWorker};
+use crate::kube::events::EventStream;
+use crate::kube::resources::{ResourceItem, ResourcesList};
+use crate::ui::presentation::{ListHeader, ListViewer};
+use crate::ui::views::resources::ResourcesTable;
+use crate::ui::views::{View, get_layout_with_header};
+use crate::ui::widgets::CommandPalette;
+
+pub const VIEW_NAME: &str = "event stream";
+const WARNINGS_VIEW_NAME: &str = "warning stream";
+
+/// Event stream view that shows grouped events from all namespaces.
+pub struct EventStreamView {
+    pub header: ListHeader,
+    pub list: ListViewer<ResourcesList>,
+    app_data: SharedAppData,
+    observer: BgObserver,
+    stream: EventStream,
+    warnings_only: bool,
+    last_mouse_click: Option<Position>,
+    command_palette: CommandPalette,
+    footer_tx: NotificationSink,
+}
+
+impl EventStreamView {
+    /// Creates new [`EventStreamView`] instance.
+    pub fn new(app_data: SharedAppData, worker: &SharedBgWorker, footer_tx: NotificationSink) -> Option<Self> {
+        let worker = worker.borrow();
+        let client = worker.kubernetes_client()?;
+        let kind = Kind::from(EVENTS);
+        let discovery = get_resource(worker.discovery_list(), &kind);
+        let resource = ResourceRef::new(kind, Namespace::all());
+        let mut observer = BgObserver::new(worker.runtime_handle().clone(), None);
+        observer
+            .start(client.get_client(), resource.clone(), discovery, None, false)
+            .ok()?;
+
+        let mut list = ListViewer::new(Rc::clone(&app_data), ResourcesList::default(), ViewType::Full);
+        let init = InitData::simple(resource, "EventGroup".to_owned(), "eventgroups".to_owned());
+        list.table.update(ObserverResult::Init(Box::new(init)));
+        list.table.update(ObserverResult::InitDone);
+
+        let header = ListHeader::new(Rc::clone(&app_data), 0)
+            .with_kind(VIEW_NAME)
+            .with_namespace(Namespace::all().as_str())
+            .with_scope(Scope::Namespaced)
+            .with_hide_previous(true);
+
+        set_hint(&app_data, &footer_tx);
+
+        Some(Self {
+            header,
+            list,
+            app_data,
+            observer,
+            stream: EventStream::default(),
+            warnings_only: false,
+            last_mouse_click: None,
+            command_palette: CommandPalette::default(),
+            footer_tx,
+        })
+    }
+
+    /// Shows only warning events or all events.
+    fn toggle_warnings(&mut self) {
+        self.warnings_only = !self.warnings_only;
+        self.header
+            .set_kind(if self.warnings_only { WARNINGS_VIEW_NAME } else { VIEW_NAME });
+
+        let init = InitData::simple(
+            self.list.table.data.resource.clone(),
+            "EventGroup".to_owned(),
+            "eventgroups".to_owned(),
+        );
+        self.list.table.update(ObserverResult::Init(Box::new(init)));
+        for group in self.stream.iter().filter(|g| !self.warnings_only || g.is_warning()) {
+            self.list
+                .table
+                .update(ObserverResult::new(ResourceItem::from_event_group(group), false));
+        }
+
+        self.list.table.update(ObserverResult::InitDone);
+        if !self.list.table.is_anything_highlighted() {
+            self.list.table.highlight_first_item();
+        }
+
+        self.header.set_count(self.list.table.len());
+    }
+
+    /// Adds new events from the observer to the stream and updates the list.
+    fn update_stream(&mut self) {
+        let mut changed = Vec::new();
+        while let Some(result) = self.observer.try_next() {
+            if let ObserverResult::Apply(event) = *result {
+                changed.push(self.stream.add(&event));
+            }
+        }
+
+        if changed.is_empty() {
+            return;
+        }
+
+        for group in self.stream.trim() {
+            self.list
+                .table
+                .update(ObserverResult::new(ResourceItem::from_event_group(&group), true));
+        }
+
+        changed.sort_unstable();
+        changed.dedup();
+        for key in changed {
+            if let Some(group) = self.stream.get(&key)
+                && (!self.warnings_only || group.is_warning())
+            {
+                self.list
+                    .table
+                    .update(ObserverResult::new(ResourceItem::from_event_group(group), false));
+            }
+        }
+
+        if !self.list.table.is_anything_highlighted() {
+            self.list.table.highlight_first_item();
+        }
+
+        self.header.set_count(self.list.table.len());
+    }
+
+    fn view_involved(&self) -> ResponseEvent {
+        self.list
+            .table
+            .get_highlighted_resource()
+            .map_or(ResponseEvent::Handled, ResourcesTable::process_view_involved)
+    }
+
+    /// Shows command palette.
+    fn show_command_palette(&mut self) {
+        let warnings = if self.warnings_only { "all events" } else { "only warnings" };
+        let mut builder = ActionsListBuilder::default().with_back().with_quit().with_action(
+            ActionItem::action("warnings", "warnings").with_description(&format!("shows {warnings}")),
+            Some(KeyCommand::WarningsToggle),
+        );
+
+        if self
+            .list
+            .table
+            .get_highlighted_resource()
+            .is_some_and(|r| r.involved_object.is_some())
+        {
+            builder.add_action(
+                ActionItem::action("show involved", "show_involved")
+                    .with_description("shows the object involved in the highlighted events"),
+                Some(KeyCommand::InvolvedObjectShow),
+            );
+        }
+
+        let actions = builder
+            .with_aliases(&self.app_data.borrow().config.aliases)
+            .build(Some(&self.app_data.borrow().key_bindings));
+        self.command_palette =
+            CommandPalette::new(Rc::clone(&self.app_data), actions, 65).with_highlighted_position(self.last_mouse_click.take());
+        self.command_palette.show();
+        self.footer_tx.hide_hint();
+    }
+
+    /// Shows menu for right mouse button.
+    fn show_mouse_menu(&mut self, x: u16, y: u16) {
+        if !self.app_data.borrow().is_connected() {
+            return;
+        }
+
+        let mut builder = ActionsListBuilder::default()
+            .with_menu_action(ActionItem::back())
+            .with_menu_action(ActionItem::command_palette());
+
+        let warnings = if self.warnings_only { "all" } else { "warnings" };
+        builder.add_menu_action(ActionItem::menu(1, &format!(" show ␝{warnings}␝"), "warnings"));
+        if self
+            .list
+            .table
+            .get_highlighted_resource()
+            .is_some_and(|r| r.involved_object.is_some())
+        {
+            builder.add_menu_action(ActionItem::menu(2, "󰑏 show ␝involved␝", "show_involved"));
+        }
+
+        self.command_palette = CommandPalette::new(Rc::clone(&self.app_data), builder.build(None), 22).to_mouse_menu();
+        self.command_palette.show_at((x.saturating_sub(3), y).into());
+    }
+
+    fn process_command_palette_event(&mut self, event: &TuiEvent) -> ResponseEvent {
+        match self.command_palette.process_event(event) {
+            ResponseEvent::Action("palette") => {
+                self.last_mouse_click = event.position();
+                self.process_event(&TuiEvent::Command(KeyCommand::CommandPaletteOpen))
+            },
+            ResponseEvent::Action("warnings") => self.process_event(&TuiEvent::Command(KeyCommand::WarningsToggle)),
+            ResponseEvent::Action("show_involved") => self.view_involved(),
+            response_event => response_event,
+        }
+    }
+}
+
+impl View for EventStreamView {
+    fn detail_view(&self) -> Option<DetailView> {
+        Some(DetailView::EventStream)
+    }
+
+    fn process_tick(&mut self) -> ResponseEvent {
+        self.update_stream();
+        let has_api_error = self.observer.has_api_error();
+        self.header.update_error_state(has_api_error);
+        self.list.update_error_state(has_api_error);
+
+        ResponseEvent::Handled
+    }
+
+    fn process_disconnection(&mut self) {
+        self.command_palette.hide();
+    }
+
+    fn process_event(&mut self, event: &TuiEvent) -> ResponseEvent {
+        if self.command_palette.is_visible {
+            let result = self.process_command_palette_event(event);
+            if result != ResponseEvent::NotHandled {
+                return result;
+            }
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::CommandPaletteOpen) {
+            self.show_command_palette();
+            return ResponseEvent::Handled;
+        }
+
+        if let TuiEvent::Mouse(mouse) = event
+            && mouse.kind == MouseEventKind::RightClick
+            && self.list.area.contains(Position::new(mouse.column, mouse.row))
+        {
+            let line_no = mouse.row.saturating_sub(self.list.area.y);
+            if !self.list.table.highlight_item_by_line(line_no) {
+                self.list.table.unhighlight_item();
+            }
+            self.show_mouse_menu(mouse.column, mouse.row);
+            return ResponseEvent::Handled;
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::NavigateBack)
+            || self.app_data.has_binding(event, KeyCommand::EventStreamOpen)
+        {
+            return ResponseEvent::Cancelled;
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::WarningsToggle) {
+            self.toggle_warnings();
+            return ResponseEvent::Handled;
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::InvolvedObjectShow)
+            || self.app_data.has_binding(event, KeyCommand::NavigateInto)
+        {
+            return self.view_involved();
+        }
+
+        if let Some(line_no) = event.get_line_no(MouseEventKind::LeftDoubleClick, KeyModifiers::NONE, self.list.area)
+            && usize::from(line_no) < self.list.table.len()
+        {
+            return self.view_involved();
+        }
+
+        self.list.process_event(event)
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, area: Rect, _has_focus: bool) {
+        let layout = get_layout_with_header(area);
+
+        self.header.draw(frame, layout[0]);
+        self.list.draw(frame, layout[1]);
+
+        self.command_palette.draw(frame, frame.area());
+    }
+}
+
+impl Drop for EventStreamView {
+    fn drop(&mut self) {
+        self.footer_tx.hide_hint();
+    }
+}
+
+fn set_hint(app_data: &SharedAppData, footer_tx: &NotificationSink) {
+    let key = app_data.get_key_name(KeyCommand::WarningsToggle).to_ascii_uppercase();
+    footer_tx.show_hint(format!(" Press ␝{key}␝ to toggle warnings only"));
+}
@@ -7,6 +7,8 @@ use b4n_tasks::PortForwardTask;
 use k8s_openapi::jiff::Timestamp;
 use std::{borrow::Cow, sync::atomic::Ordering};
 
+use crate::ui::widgets::table::RowFilterSource;
+
 /// Represents port forward list item.
 pub struct PortForwardItem {
     pub uid: String,
@@ -125,6 +127,7 @@ impl From<&PortForwardItem> for ContainerRef {
 /// Filtering context for [`PortForwardItem`].
 pub struct PortForwardFilterContext {
     pattern: Option<Expression>,
+    columns: Vec<String>,
 }
 
 impl FilterContext for PortForwardFilterContext {
@@ -134,15 +137,20 @@ impl FilterContext for PortForwardFilterContext {
 }
 
 impl Filterable<PortForwardFilterContext> for PortForwardItem {
-    fn get_context(pattern: &str, _settings: Option<&str>) -> PortForwardFilterContext {
+    fn get_context(pattern: &str, settings: Option<&str>) -> PortForwardFilterContext {
+        Self::get_context_with_columns(pattern, settings, &[])
+    }
+
+    fn get_context_with_columns(pattern: &str, _settings: Option<&str>, columns: &[String]) -> PortForwardFilterContext {
         PortForwardFilterContext {
             pattern: parse(pattern).ok(),
+            columns: columns.to_vec(),
         }
     }
 
     fn is_matching(&self, context: &mut PortForwardFilterContext) -> bool {
         if let Some(expression) = &context.pattern {
-            self.filter_metadata.evaluate(expression)
+            RowFilterSource::new(self, &self.filter_metadata, &context.columns).evaluate(expression)
         } else {
             false
         }
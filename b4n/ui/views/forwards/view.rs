@@ -411,7 +411,7 @@ impl View for ForwardsView {
 
 fn get_current_namespace(app_data: &SharedAppData) -> (Namespace, ViewType) {
     let namespace = app_data.borrow().current.get_namespace();
-    let view = if namespace.is_all() {
+    let view = if namespace.is_multiple() {
         ViewType::Full
     } else {
         ViewType::Compact
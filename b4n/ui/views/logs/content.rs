@@ -110,6 +110,12 @@ impl LogsContent {
         self.merge_sorted(line)
     }
 
+    /// Appends a single line at the end of the content without sorting and deduplicating.
+    pub fn push_log_line(&mut self, line: LogLine) {
+        self.update_max_size(&line);
+        self.lines.push(line);
+    }
+
     /// Insert a single new line into the sorted `self.lines`, deduplicating.
     fn merge_sorted(&mut self, incoming: LogLine) -> Option<usize> {
         let pos = self.lines.partition_point(|l| sort_key(l) <= sort_key(&incoming));
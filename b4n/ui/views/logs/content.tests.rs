@@ -161,3 +161,14 @@ fn add_line_to_middle() {
 
     assert_eq!(messages(&content), vec!["first", "middle", "last"]);
 }
+
+#[test]
+fn push_lines_keeps_insertion_order() {
+    let mut content = LogsContent::new(LogsSyntaxColors::default());
+    content.push_log_line(make_line_with_container("2024-01-01T00:00:03Z", "pod-b", "third"));
+    content.push_log_line(make_line_with_container("2024-01-01T00:00:01Z", "pod-a", "first"));
+    content.push_log_line(make_line_with_container("2024-01-01T00:00:01Z", "pod-a", "first"));
+
+    assert_eq!(content.len(), 3);
+    assert_eq!(messages(&content), vec!["third", "first", "first"]);
+}
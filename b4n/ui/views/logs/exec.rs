@@ -0,0 +1,222 @@
+use b4n_kube::ContainerRef;
+use b4n_kube::client::KubernetesClient;
+use futures::{Stream, StreamExt};
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Status;
+use k8s_openapi::jiff::Timestamp;
+use kube::api::AttachParams;
+use kube::{Api, Client};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::runtime::Handle;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::ui::views::logs::line::LogLine;
+
+/// Maximum number of commands that are executed at the same time.
+pub const EXEC_CONCURRENCY_LIMIT: usize = 8;
+
+/// Runs non-interactive command in multiple containers and collects their output.\
+/// **Note** that output of each container is sent as one block of lines after the command finishes,
+/// lines written to stderr are kept in the order they arrived and are shown as errors.
+pub struct ExecRunner {
+    runtime: Handle,
+    task: Option<JoinHandle<()>>,
+    cancellation_token: Option<CancellationToken>,
+    lines_tx: UnboundedSender<Box<LogLine>>,
+    lines_rx: UnboundedReceiver<Box<LogLine>>,
+    succeeded: Arc<AtomicUsize>,
+    failed: Arc<AtomicUsize>,
+}
+
+impl ExecRunner {
+    /// Creates new [`ExecRunner`] instance.
+    pub fn new(runtime: Handle) -> Self {
+        let (lines_tx, lines_rx) = mpsc::unbounded_channel();
+        Self {
+            runtime,
+            task: None,
+            cancellation_token: None,
+            lines_tx,
+            lines_rx,
+            succeeded: Arc::new(AtomicUsize::new(0)),
+            failed: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Starts executing `command` in all specified containers.
+    pub fn start(&mut self, client: &KubernetesClient, containers: Vec<ContainerRef>, command: Vec<String>) {
+        let cancellation_token = CancellationToken::new();
+        let _cancellation_token = cancellation_token.clone();
+        let _client = client.get_client();
+        let _lines_tx = self.lines_tx.clone();
+        let _succeeded = Arc::clone(&self.succeeded);
+        let _failed = Arc::clone(&self.failed);
+        let include_container = has_duplicated_pods(&containers);
+
+        let task = self.runtime.spawn(async move {
+            let run_all = futures::stream::iter(containers).for_each_concurrent(EXEC_CONCURRENCY_LIMIT, |container| {
+                let client = _client.clone();
+                let command = command.clone();
+                let lines_tx = _lines_tx.clone();
+                let succeeded = Arc::clone(&_succeeded);
+                let failed = Arc::clone(&_failed);
+                async move {
+                    let prefix = get_prefix(&container, include_container);
+                    let (lines, exit_code) = exec_in_container(client, &container, command, &prefix).await;
+                    if exit_code == Some(0) {
+                        succeeded.fetch_add(1, Ordering::Relaxed);
+                    } else {
+                        failed.fetch_add(1, Ordering::Relaxed);
+                    }
+
+                    for line in lines {
+                        let _ = lines_tx.send(Box::new(line));
+                    }
+                }
+            });
+
+            tokio::select! {
+                () = _cancellation_token.cancelled() => (),
+                () = run_all => (),
+            }
+        });
+
+        self.cancellation_token = Some(cancellation_token);
+        self.task = Some(task);
+    }
+
+    /// Cancels [`ExecRunner`] task.
+    pub fn cancel(&mut self) {
+        if let Some(cancellation_token) = self.cancellation_token.take() {
+            cancellation_token.cancel();
+        }
+    }
+
+    /// Cancels [`ExecRunner`] task and waits until it is finished.
+    pub fn stop(&mut self) {
+        self.cancel();
+        b4n_common::tasks::wait_for_task(self.task.take(), "exec");
+        while self.lines_rx.try_recv().is_ok() {}
+    }
+
+    /// Tries to get next [`LogLine`].
+    pub fn try_next(&mut self) -> Option<Box<LogLine>> {
+        self.lines_rx.try_recv().ok()
+    }
+
+    /// Checks if [`ExecRunner`] has no lines waiting.
+    pub fn is_empty(&self) -> bool {
+        self.lines_rx.is_empty()
+    }
+
+    /// Returns `true` if commands finished in all containers.
+    pub fn is_finished(&self) -> bool {
+        self.task.as_ref().is_some_and(JoinHandle::is_finished)
+    }
+
+    /// Returns number of containers in which command succeeded and failed.
+    pub fn summary(&self) -> (usize, usize) {
+        (self.succeeded.load(Ordering::Relaxed), self.failed.load(Ordering::Relaxed))
+    }
+}
+
+async fn exec_in_container(
+    client: Client,
+    container: &ContainerRef,
+    command: Vec<String>,
+    prefix: &str,
+) -> (Vec<LogLine>, Option<i32>) {
+    let api: Api<Pod> = Api::namespaced(client, container.namespace.as_str());
+    let mut params = AttachParams::default().stdin(false).stdout(true).stderr(true).tty(false);
+    if let Some(name) = &container.container {
+        params = params.container(name);
+    }
+
+    let mut attached = match api.exec(&container.name, command, &params).await {
+        Ok(attached) => attached,
+        Err(err) => {
+            let msg = format!("Cannot execute command: {err}");
+            return (vec![LogLine::error(Timestamp::now(), Some(prefix), msg)], None);
+        },
+    };
+
+    let stdout = read_lines(attached.stdout()).map(|line| (line, false));
+    let stderr = read_lines(attached.stderr()).map(|line| (line, true));
+    let status = attached.take_status();
+    let lines = futures::stream::select(stdout, stderr)
+        .map(|(line, is_error)| {
+            if is_error {
+                LogLine::error(Timestamp::now(), Some(prefix), line)
+            } else {
+                LogLine::new(Timestamp::now(), Some(prefix), line)
+            }
+        })
+        .collect::<Vec<_>>();
+    let (mut lines, status) = tokio::join!(lines, async {
+        match status {
+            Some(status) => status.await,
+            None => None,
+        }
+    });
+
+    let now = Timestamp::now();
+
+    let exit_code = status.as_ref().and_then(get_exit_code);
+    match exit_code {
+        Some(0) => lines.push(LogLine::info(now, Some(prefix), "Command exited with code 0".to_owned())),
+        Some(code) => lines.push(LogLine::error(now, Some(prefix), format!("Command exited with code {code}"))),
+        None => {
+            let message = status.and_then(|s| s.message).unwrap_or_else(|| "unknown status".to_owned());
+            lines.push(LogLine::error(now, Some(prefix), format!("Command failed: {message}")));
+        },
+    }
+
+    (lines, exit_code)
+}
+
+fn read_lines(reader: Option<impl AsyncRead + Unpin>) -> impl Stream<Item = String> {
+    futures::stream::unfold(reader.map(|r| BufReader::new(r).lines()), |lines| async move {
+        let mut lines = lines?;
+        match lines.next_line().await {
+            Ok(Some(line)) => Some((line, Some(lines))),
+            Ok(None) => None,
+            Err(err) => {
+                tracing::warn!("Cannot read command output: {}", err);
+                None
+            },
+        }
+    })
+}
+
+fn get_exit_code(status: &Status) -> Option<i32> {
+    if status.status.as_deref() == Some("Success") {
+        return Some(0);
+    }
+
+    status
+        .details
+        .as_ref()
+        .and_then(|d| d.causes.as_ref())
+        .and_then(|causes| causes.iter().find(|c| c.reason.as_deref() == Some("ExitCode")))
+        .and_then(|cause| cause.message.as_deref())
+        .and_then(|code| code.parse::<i32>().ok())
+}
+
+fn get_prefix(container: &ContainerRef, include_container: bool) -> String {
+    match container.container.as_deref() {
+        Some(name) if include_container => format!("{}/{}", container.name, name),
+        _ => container.name.clone(),
+    }
+}
+
+fn has_duplicated_pods(containers: &[ContainerRef]) -> bool {
+    let mut pods = HashSet::with_capacity(containers.len());
+    containers
+        .iter()
+        .any(|c| !pods.insert((c.namespace.as_str(), c.name.as_str())))
+}
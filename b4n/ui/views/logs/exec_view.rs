@@ -0,0 +1,132 @@
+use b4n_common::{DEFAULT_MESSAGE_DURATION, NotificationSink};
+use b4n_kube::client::KubernetesClient;
+use b4n_kube::{ContainerRef, PODS};
+use b4n_tui::{ResponseEvent, TuiEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use std::rc::Rc;
+
+use crate::core::{SharedAppData, SharedBgWorker};
+use crate::ui::presentation::ContentViewer;
+use crate::ui::views::View;
+use crate::ui::views::logs::ExecRunner;
+use crate::ui::views::logs::content::LogsContent;
+use crate::ui::views::logs::pane::{LogsPane, LogsPaneTexts};
+
+/// View that shows aggregated output of the command executed in multiple containers.
+pub struct ExecView {
+    pane: LogsPane,
+    runner: ExecRunner,
+    footer: NotificationSink,
+    containers_no: usize,
+    is_finished: bool,
+}
+
+impl ExecView {
+    /// Creates new [`ExecView`] instance.
+    pub fn new(
+        app_data: SharedAppData,
+        worker: SharedBgWorker,
+        client: &KubernetesClient,
+        containers: Vec<ContainerRef>,
+        command: String,
+        footer: NotificationSink,
+        workspace: Rect,
+    ) -> Option<Self> {
+        let first = containers.first()?;
+        let args = shlex::split(&command)?;
+        let select = app_data.borrow().theme.colors.syntax.logs.select;
+        let search = app_data.borrow().theme.colors.syntax.logs.search;
+        let area = ContentViewer::<LogsContent>::get_content_area(workspace);
+        let name = (containers.len() == 1).then(|| first.name.clone());
+        let mut output = ContentViewer::new(Rc::clone(&app_data), select, search, area).with_header(
+            "exec",
+            '',
+            first.namespace.clone(),
+            PODS.into(),
+            name,
+            Some(command),
+        );
+        output.header.set_busy(true);
+
+        let mut content = LogsContent::new(app_data.borrow().theme.colors.syntax.logs.clone());
+        content.set_timestamps(false);
+        output.set_content(content);
+
+        let containers_no = containers.len();
+        let mut runner = ExecRunner::new(worker.borrow().runtime_handle().clone());
+        runner.start(client, containers, args);
+
+        let texts = LogsPaneTexts {
+            subject: "command output",
+            copied: "Command output copied to clipboard",
+        };
+
+        Some(Self {
+            pane: LogsPane::new(app_data, worker, output, footer.clone(), texts),
+            runner,
+            footer,
+            containers_no,
+            is_finished: false,
+        })
+    }
+
+    fn show_summary(&mut self) {
+        let (succeeded, failed) = self.runner.summary();
+        if failed == 0 {
+            let msg = format!("Command succeeded in all {} container(s)", self.containers_no);
+            self.footer.show_info(msg, DEFAULT_MESSAGE_DURATION);
+        } else {
+            let msg = format!("Command succeeded in {succeeded} and failed in {failed} container(s)");
+            self.footer.show_error(msg, DEFAULT_MESSAGE_DURATION);
+        }
+    }
+}
+
+impl View for ExecView {
+    fn process_tick(&mut self) -> ResponseEvent {
+        if !self.runner.is_empty() {
+            if let Some(content) = self.pane.logs.content_mut() {
+                while let Some(line) = self.runner.try_next() {
+                    content.push_log_line(*line);
+                }
+            }
+
+            self.pane.refresh_search();
+        }
+
+        if !self.is_finished && self.runner.is_finished() && self.runner.is_empty() {
+            self.is_finished = true;
+            self.pane.logs.header.set_busy(false);
+            self.show_summary();
+        }
+
+        ResponseEvent::Handled
+    }
+
+    fn process_disconnection(&mut self) {
+        // pass
+    }
+
+    fn process_event(&mut self, event: &TuiEvent) -> ResponseEvent {
+        if let Some(result) = self.pane.process_widget_event(event) {
+            return result;
+        }
+
+        if let Some(result) = self.pane.process_bound_event(event) {
+            return result;
+        }
+
+        self.pane.logs.process_event(event)
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, area: Rect, _has_focus: bool) {
+        self.pane.draw(frame, area);
+    }
+}
+
+impl Drop for ExecView {
+    fn drop(&mut self) {
+        self.runner.stop();
+    }
+}
@@ -1,7 +1,12 @@
+pub use self::exec::*;
+pub use self::exec_view::*;
 pub use self::observer::*;
 pub use self::view::*;
 
 mod content;
+mod exec;
+mod exec_view;
 mod line;
 mod observer;
+mod pane;
 mod view;
@@ -0,0 +1,347 @@
+use b4n_common::{DEFAULT_MESSAGE_DURATION, IconKind, NotificationSink};
+use b4n_config::keys::KeyCommand;
+use b4n_tui::widgets::{ActionItem, ActionsListBuilder, Button, Dialog};
+use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, TuiEvent};
+use ratatui::Frame;
+use ratatui::layout::{Position, Rect};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::core::{SharedAppData, SharedAppDataExt, SharedBgWorker};
+use crate::ui::presentation::{Content, ContentViewer};
+use crate::ui::views::logs::content::{LogsContent, TIMESTAMP_TEXT_LENGTH};
+use crate::ui::widgets::{CommandPalette, FileSelector, Search};
+
+/// Texts that describe what is shown in the [`LogsPane`].
+pub struct LogsPaneTexts {
+    pub subject: &'static str,
+    pub copied: &'static str,
+}
+
+/// Pane that shows log lines together with the search, command palette, copy and save to file actions.\
+/// **Note** that it is shared between the views that show [`LogsContent`].
+pub struct LogsPane {
+    pub logs: ContentViewer<LogsContent>,
+    app_data: SharedAppData,
+    worker: SharedBgWorker,
+    search: Search,
+    file_picker: FileSelector,
+    modal: Dialog,
+    command_palette: CommandPalette,
+    footer: NotificationSink,
+    texts: LogsPaneTexts,
+    last_mouse_click: Option<Position>,
+    is_search_updated: bool,
+}
+
+impl LogsPane {
+    /// Creates new [`LogsPane`] instance.
+    pub fn new(
+        app_data: SharedAppData,
+        worker: SharedBgWorker,
+        logs: ContentViewer<LogsContent>,
+        footer: NotificationSink,
+        texts: LogsPaneTexts,
+    ) -> Self {
+        let search = Search::new(Rc::clone(&app_data), Some(Rc::clone(&worker)), 65);
+        let file_picker = FileSelector::new(Rc::clone(&app_data), Rc::clone(&worker), 65, PathBuf::from("."));
+
+        Self {
+            logs,
+            app_data,
+            worker,
+            search,
+            file_picker,
+            modal: Dialog::default(),
+            command_palette: CommandPalette::default(),
+            footer,
+            texts,
+            last_mouse_click: None,
+            is_search_updated: false,
+        }
+    }
+
+    /// Returns current search query.
+    pub fn search_value(&self) -> &str {
+        self.search.value()
+    }
+
+    /// Returns `true` if the search was updated since the last check.
+    pub fn take_search_updated(&mut self) -> bool {
+        std::mem::take(&mut self.is_search_updated)
+    }
+
+    /// Searches logs again if they have changed and updates the matches count.
+    pub fn refresh_search(&mut self) {
+        if self.logs.search(self.search.value(), true) {
+            self.update_search_count();
+        }
+    }
+
+    /// Returns offset for the logs that skips timestamps if they are shown.
+    pub fn get_offset(&self) -> Option<Position> {
+        if self.logs.content().is_some_and(LogsContent::show_timestamps) {
+            Some(Position::new(TIMESTAMP_TEXT_LENGTH as u16, 0))
+        } else {
+            None
+        }
+    }
+
+    /// Processes event for the visible widgets of the pane.
+    pub fn process_widget_event(&mut self, event: &TuiEvent) -> Option<ResponseEvent> {
+        if self.command_palette.is_visible {
+            let result = self.process_command_palette_event(event);
+            if result != ResponseEvent::NotHandled || (event.is_mouse(MouseEventKind::LeftClick) && self.logs.has_selection()) {
+                return Some(result);
+            }
+        }
+
+        if self.search.is_visible {
+            let result = self.search.process_event(event);
+            if self.logs.search(self.search.value(), false) {
+                self.logs.scroll_to_current_match(self.get_offset());
+                self.update_search_count();
+            }
+
+            self.is_search_updated = true;
+            return Some(result);
+        }
+
+        if self.file_picker.is_visible {
+            if self.file_picker.process_event(event) == ResponseEvent::Accepted {
+                self.save_logs_to_file(false);
+            }
+
+            return Some(ResponseEvent::Handled);
+        }
+
+        if self.modal.is_visible {
+            return Some(self.modal.process_event(event).when_action_then("overwrite", || {
+                self.save_logs_to_file(true);
+                ResponseEvent::Handled
+            }));
+        }
+
+        None
+    }
+
+    /// Processes event that is bound to one of the pane actions.
+    pub fn process_bound_event(&mut self, event: &TuiEvent) -> Option<ResponseEvent> {
+        if self.app_data.has_binding(event, KeyCommand::CommandPaletteOpen) {
+            self.show_command_palette();
+            return Some(ResponseEvent::Handled);
+        }
+
+        if let TuiEvent::Mouse(mouse) = event
+            && mouse.kind == MouseEventKind::RightClick
+        {
+            self.show_mouse_menu(mouse.column, mouse.row);
+            return Some(ResponseEvent::Handled);
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::SearchOpen) {
+            self.search.show();
+            return Some(ResponseEvent::Handled);
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::SearchReset) && !self.search.value().is_empty() {
+            self.clear_search();
+            return Some(ResponseEvent::Handled);
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::NavigateBack) {
+            return Some(ResponseEvent::Cancelled);
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::LogsTimestamps) {
+            self.toggle_timestamps();
+            return Some(ResponseEvent::Handled);
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::ContentCopy) {
+            self.copy_logs_to_clipboard();
+            return Some(ResponseEvent::Handled);
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::ContentSave) {
+            self.show_file_picker();
+            return Some(ResponseEvent::Handled);
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::MatchNext) && self.logs.matches_count().is_some() {
+            self.navigate_match(true);
+            return Some(ResponseEvent::Handled);
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::MatchPrevious) && self.logs.matches_count().is_some() {
+            self.navigate_match(false);
+            return Some(ResponseEvent::Handled);
+        }
+
+        None
+    }
+
+    /// Draws the pane on the provided frame area.
+    pub fn draw(&mut self, frame: &mut Frame<'_>, area: Rect) {
+        self.logs.draw(frame, area, self.get_offset());
+        self.command_palette.draw(frame, frame.area());
+        self.search.draw(frame, frame.area());
+        self.file_picker.draw(frame, area);
+        self.modal.draw(frame, frame.area());
+    }
+
+    fn show_command_palette(&mut self) {
+        let subject = self.texts.subject;
+        let builder = ActionsListBuilder::default()
+            .with_back()
+            .with_quit()
+            .with_action(
+                ActionItem::action("timestamps", "timestamps").with_description("toggles the display of timestamps"),
+                Some(KeyCommand::LogsTimestamps),
+            )
+            .with_action(
+                ActionItem::action("copy", "copy").with_description(&format!("copies {subject} to clipboard")),
+                Some(KeyCommand::ContentCopy),
+            )
+            .with_action(
+                ActionItem::action("save", "save").with_description(&format!("saves {subject} to a file")),
+                Some(KeyCommand::ContentSave),
+            )
+            .with_action(
+                ActionItem::action("search", "search").with_description(&format!("searches {subject} using the provided query")),
+                Some(KeyCommand::SearchOpen),
+            );
+        let actions = builder.build(Some(&self.app_data.borrow().key_bindings));
+        self.command_palette =
+            CommandPalette::new(Rc::clone(&self.app_data), actions, 65).with_highlighted_position(self.last_mouse_click.take());
+        self.command_palette.show();
+        self.footer.hide_hint();
+    }
+
+    fn show_mouse_menu(&mut self, x: u16, y: u16) {
+        let copy = if self.logs.has_selection() { "selection" } else { "all" };
+        let builder = ActionsListBuilder::default()
+            .with_menu_action(ActionItem::back())
+            .with_menu_action(ActionItem::command_palette())
+            .with_menu_action(ActionItem::menu(1, &format!("󰆏 copy ␝{copy}␝"), "copy"))
+            .with_menu_action(ActionItem::menu(2, " save to file", "save"))
+            .with_menu_action(ActionItem::menu(3, " search", "search"))
+            .with_menu_action(ActionItem::menu(4, " timestamps", "timestamps"));
+        self.command_palette = CommandPalette::new(Rc::clone(&self.app_data), builder.build(None), 22).to_mouse_menu();
+        self.command_palette.show_at((x.saturating_sub(3), y).into());
+    }
+
+    fn show_file_picker(&mut self) {
+        self.file_picker
+            .set_current_path(std::env::current_dir().unwrap_or(PathBuf::from(".")));
+        self.file_picker.reset();
+        self.file_picker.show();
+    }
+
+    fn toggle_timestamps(&mut self) {
+        self.logs.clear_selection();
+        if let Some(content) = self.logs.content_mut() {
+            content.toggle_timestamps();
+            self.logs.reset_horizontal_scroll();
+        }
+    }
+
+    fn copy_logs_to_clipboard(&mut self) {
+        if self.logs.content().is_some() {
+            let range = self.logs.get_selection();
+            let text = self.logs.content().map(|c| c.to_plain_text(range)).unwrap_or_default();
+            let copied = self.texts.copied;
+            self.app_data.copy_to_clipboard(text, &self.footer, || {
+                if self.logs.has_selection() {
+                    "Selection copied to clipboard"
+                } else {
+                    copied
+                }
+            });
+        }
+    }
+
+    fn save_logs_to_file(&mut self, force: bool) {
+        let (path, exists) = self.file_picker.selected_path();
+        if exists && !force {
+            self.ask_target_file_exists(&path);
+        } else {
+            let text = self
+                .logs
+                .content()
+                .map(|content| content.to_plain_text(None))
+                .unwrap_or_default();
+            self.worker.borrow_mut().save_content(path, text);
+        }
+    }
+
+    fn ask_target_file_exists(&mut self, path: &Path) {
+        self.modal = self.new_file_exists_dialog(path);
+        self.modal.show();
+    }
+
+    fn new_file_exists_dialog(&mut self, path: &Path) -> Dialog {
+        let colors = &self.app_data.borrow().theme.colors;
+        Dialog::new(
+            format!("The file already exists:\n\n{}\n\nDo you want to replace it?", path.display()),
+            vec![
+                Button::new(
+                    "Overwrite",
+                    ResponseEvent::Action("overwrite"),
+                    colors.modal.btn_delete.clone(),
+                ),
+                Button::new("Cancel", ResponseEvent::Action("cancel"), colors.modal.btn_cancel.clone()),
+            ],
+        )
+        .with_width(65)
+        .with_colors(colors.modal.text)
+    }
+
+    fn clear_search(&mut self) {
+        self.logs.search("", false);
+        self.search.reset();
+        self.update_search_count();
+        self.is_search_updated = true;
+    }
+
+    fn update_search_count(&mut self) {
+        self.footer
+            .set_text("900_logs_search", self.logs.get_footer_text(), IconKind::Default);
+        self.search.set_matches(self.logs.matches_count());
+    }
+
+    fn navigate_match(&mut self, forward: bool) {
+        self.logs.navigate_match(forward, self.get_offset());
+        self.footer
+            .set_text("900_logs_search", self.logs.get_footer_text(), IconKind::Default);
+        if let Some(message) = self.logs.get_footer_message(forward) {
+            self.footer.show_info(message, DEFAULT_MESSAGE_DURATION);
+        }
+    }
+
+    fn process_command_palette_event(&mut self, event: &TuiEvent) -> ResponseEvent {
+        let response = self.command_palette.process_event(event);
+        if response == ResponseEvent::Cancelled {
+            self.clear_search();
+        } else if response.is_action("palette") {
+            self.last_mouse_click = event.position();
+            self.show_command_palette();
+            return ResponseEvent::Handled;
+        } else if response.is_action("timestamps") {
+            self.toggle_timestamps();
+            return ResponseEvent::Handled;
+        } else if response.is_action("copy") {
+            self.copy_logs_to_clipboard();
+            return ResponseEvent::Handled;
+        } else if response.is_action("save") {
+            self.show_file_picker();
+            return ResponseEvent::Handled;
+        } else if response.is_action("search") {
+            self.search.highlight_position(event.position());
+            self.search.show();
+            return ResponseEvent::Handled;
+        }
+
+        response
+    }
+}
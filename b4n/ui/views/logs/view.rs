@@ -1,23 +1,20 @@
-use b4n_common::{DEFAULT_MESSAGE_DURATION, IconKind, NotificationSink};
-use b4n_config::keys::KeyCommand;
+use b4n_common::NotificationSink;
 use b4n_kube::client::KubernetesClient;
 use b4n_kube::{ContainerRef, ContainerType, PODS};
-use b4n_tui::widgets::{ActionItem, ActionsListBuilder, Button, Dialog};
-use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, TuiEvent};
+use b4n_tui::{MouseEventKind, ResponseEvent, TuiEvent};
 use crossterm::event::KeyCode;
 use k8s_openapi::jiff::{SignedDuration, Timestamp};
 use ratatui::Frame;
-use ratatui::layout::{Position, Rect};
-use std::path::{Path, PathBuf};
+use ratatui::layout::Rect;
 use std::rc::Rc;
 
-use crate::core::{SharedAppData, SharedAppDataExt, SharedBgWorker};
+use crate::core::{SharedAppData, SharedBgWorker};
 use crate::ui::presentation::{Content, ContentViewer};
 use crate::ui::views::View;
-use crate::ui::views::logs::content::{LogsContent, TIMESTAMP_TEXT_LENGTH};
+use crate::ui::views::logs::content::LogsContent;
 use crate::ui::views::logs::line::LogLine;
+use crate::ui::views::logs::pane::{LogsPane, LogsPaneTexts};
 use crate::ui::views::logs::{LogsObserver, LogsObserverError, LogsObserverOptions};
-use crate::ui::widgets::{CommandPalette, FileSelector, Search};
 
 const DEFAULT_LOOKBACK_TIME: SignedDuration = SignedDuration::from_mins(15);
 const DEFAULT_LOOKBACK_LINES: i32 = 120;
@@ -36,21 +33,15 @@ pub enum LogsViewError {
 
 /// Logs view.
 pub struct LogsView {
-    logs: ContentViewer<LogsContent>,
+    pane: LogsPane,
     app_data: SharedAppData,
     worker: SharedBgWorker,
     observers: Vec<LogsObserver>,
     fetch_observer: Option<LogsObserver>,
-    search: Search,
-    file_picker: FileSelector,
-    modal: Dialog,
-    command_palette: CommandPalette,
-    footer: NotificationSink,
     container: Option<ContainerRef>,
     previous: bool,
     requested_log_lines: Option<i64>,
     bound_to_bottom: bool,
-    last_mouse_click: Option<Position>,
     area: Rect,
 }
 
@@ -97,288 +88,32 @@ impl LogsView {
             observers.push(observer);
         }
 
-        let search = Search::new(Rc::clone(&app_data), Some(Rc::clone(&worker)), 65);
-        let file_picker = FileSelector::new(Rc::clone(&app_data), Rc::clone(&worker), 65, PathBuf::from("."));
+        let texts = LogsPaneTexts {
+            subject: "logs",
+            copied: "Container logs copied to clipboard",
+        };
 
         Ok(Self {
-            logs,
+            pane: LogsPane::new(Rc::clone(&app_data), Rc::clone(&worker), logs, footer, texts),
             app_data,
             worker,
             observers,
             fetch_observer: None,
-            search,
-            file_picker,
-            modal: Dialog::default(),
-            command_palette: CommandPalette::default(),
-            footer,
             previous,
             container,
             requested_log_lines,
             bound_to_bottom: true,
-            last_mouse_click: None,
             area: workspace,
         })
     }
 
-    fn show_command_palette(&mut self) {
-        let builder = ActionsListBuilder::default()
-            .with_back()
-            .with_quit()
-            .with_action(
-                ActionItem::action("timestamps", "timestamps").with_description("toggles the display of timestamps"),
-                Some(KeyCommand::LogsTimestamps),
-            )
-            .with_action(
-                ActionItem::action("copy", "copy").with_description("copies logs to clipboard"),
-                Some(KeyCommand::ContentCopy),
-            )
-            .with_action(
-                ActionItem::action("save", "save").with_description("saves logs to a file"),
-                Some(KeyCommand::ContentSave),
-            )
-            .with_action(
-                ActionItem::action("search", "search").with_description("searches logs using the provided query"),
-                Some(KeyCommand::SearchOpen),
-            );
-        let actions = builder.build(Some(&self.app_data.borrow().key_bindings));
-        self.command_palette =
-            CommandPalette::new(Rc::clone(&self.app_data), actions, 65).with_highlighted_position(self.last_mouse_click.take());
-        self.command_palette.show();
-        self.footer.hide_hint();
-    }
-
-    fn show_mouse_menu(&mut self, x: u16, y: u16) {
-        let copy = if self.logs.has_selection() { "selection" } else { "all" };
-        let builder = ActionsListBuilder::default()
-            .with_menu_action(ActionItem::back())
-            .with_menu_action(ActionItem::command_palette())
-            .with_menu_action(ActionItem::menu(1, &format!("󰆏 copy ␝{copy}␝"), "copy"))
-            .with_menu_action(ActionItem::menu(2, " save to file", "save"))
-            .with_menu_action(ActionItem::menu(3, " search", "search"))
-            .with_menu_action(ActionItem::menu(4, " timestamps", "timestamps"));
-        self.command_palette = CommandPalette::new(Rc::clone(&self.app_data), builder.build(None), 22).to_mouse_menu();
-        self.command_palette.show_at((x.saturating_sub(3), y).into());
-    }
-
-    fn show_file_picker(&mut self) {
-        self.file_picker
-            .set_current_path(std::env::current_dir().unwrap_or(PathBuf::from(".")));
-        self.file_picker.reset();
-        self.file_picker.show();
-    }
-
-    fn toggle_timestamps(&mut self) {
-        self.logs.clear_selection();
-        if let Some(content) = self.logs.content_mut() {
-            content.toggle_timestamps();
-            self.logs.reset_horizontal_scroll();
-        }
-    }
-
-    fn copy_logs_to_clipboard(&mut self) {
-        if self.logs.content().is_some() {
-            let range = self.logs.get_selection();
-            let text = self.logs.content().map(|c| c.to_plain_text(range)).unwrap_or_default();
-            self.app_data.copy_to_clipboard(text, &self.footer, || {
-                if self.logs.has_selection() {
-                    "Selection copied to clipboard"
-                } else {
-                    "Container logs copied to clipboard"
-                }
-            });
-        }
-    }
-
-    fn save_logs_to_file(&mut self, force: bool) {
-        let (path, exists) = self.file_picker.selected_path();
-        if exists && !force {
-            self.ask_target_file_exists(&path);
-        } else {
-            let text = self
-                .logs
-                .content()
-                .map(|content| content.to_plain_text(None))
-                .unwrap_or_default();
-            self.worker.borrow_mut().save_content(path, text);
-        }
-    }
-
-    fn ask_target_file_exists(&mut self, path: &Path) {
-        self.modal = self.new_file_exists_dialog(path);
-        self.modal.show();
-    }
-
-    fn new_file_exists_dialog(&mut self, path: &Path) -> Dialog {
-        let colors = &self.app_data.borrow().theme.colors;
-        Dialog::new(
-            format!("The file already exists:\n\n{}\n\nDo you want to replace it?", path.display()),
-            vec![
-                Button::new(
-                    "Overwrite",
-                    ResponseEvent::Action("overwrite"),
-                    colors.modal.btn_delete.clone(),
-                ),
-                Button::new("Cancel", ResponseEvent::Action("cancel"), colors.modal.btn_cancel.clone()),
-            ],
-        )
-        .with_width(65)
-        .with_colors(colors.modal.text)
-    }
-
     fn update_bound_to_bottom(&mut self) {
-        self.bound_to_bottom = self.search.value().is_empty() && self.logs.is_at_end();
-        self.logs.header.set_icon(if self.bound_to_bottom { '' } else { '' });
-    }
-
-    fn clear_search(&mut self) {
-        self.logs.search("", false);
-        self.search.reset();
-        self.update_search_count();
-        self.update_bound_to_bottom();
-    }
-
-    fn update_search_count(&mut self) {
-        self.footer
-            .set_text("900_logs_search", self.logs.get_footer_text(), IconKind::Default);
-        self.search.set_matches(self.logs.matches_count());
-    }
-
-    fn navigate_match(&mut self, forward: bool) {
-        self.logs.navigate_match(forward, self.get_offset());
-        self.footer
-            .set_text("900_logs_search", self.logs.get_footer_text(), IconKind::Default);
-        if let Some(message) = self.logs.get_footer_message(forward) {
-            self.footer.show_info(message, DEFAULT_MESSAGE_DURATION);
-        }
-    }
-
-    fn get_offset(&self) -> Option<Position> {
-        if self.logs.content().is_some_and(LogsContent::show_timestamps) {
-            Some(Position::new(TIMESTAMP_TEXT_LENGTH as u16, 0))
-        } else {
-            None
-        }
-    }
-
-    fn process_command_palette_event(&mut self, event: &TuiEvent) -> ResponseEvent {
-        let response = self.command_palette.process_event(event);
-        if response == ResponseEvent::Cancelled {
-            self.clear_search();
-        } else if response.is_action("palette") {
-            self.last_mouse_click = event.position();
-            return self.process_event(&TuiEvent::Command(KeyCommand::CommandPaletteOpen));
-        } else if response.is_action("timestamps") {
-            self.toggle_timestamps();
-            return ResponseEvent::Handled;
-        } else if response.is_action("copy") {
-            self.copy_logs_to_clipboard();
-            return ResponseEvent::Handled;
-        } else if response.is_action("save") {
-            self.show_file_picker();
-            return ResponseEvent::Handled;
-        } else if response.is_action("search") {
-            self.search.highlight_position(event.position());
-            self.search.show();
-            return ResponseEvent::Handled;
-        }
-
-        response
-    }
-
-    fn process_widget_event(&mut self, event: &TuiEvent) -> Option<ResponseEvent> {
-        if self.command_palette.is_visible {
-            let result = self.process_command_palette_event(event);
-            if result != ResponseEvent::NotHandled || (event.is_mouse(MouseEventKind::LeftClick) && self.logs.has_selection()) {
-                return Some(result);
-            }
-        }
-
-        if self.search.is_visible {
-            let result = self.search.process_event(event);
-            if self.logs.search(self.search.value(), false) {
-                self.logs.scroll_to_current_match(self.get_offset());
-                self.update_search_count();
-            }
-
-            self.update_bound_to_bottom();
-            return Some(result);
-        }
-
-        if self.file_picker.is_visible {
-            if self.file_picker.process_event(event) == ResponseEvent::Accepted {
-                self.save_logs_to_file(false);
-            }
-
-            return Some(ResponseEvent::Handled);
-        }
-
-        if self.modal.is_visible {
-            return Some(self.modal.process_event(event).when_action_then("overwrite", || {
-                self.save_logs_to_file(true);
-                ResponseEvent::Handled
-            }));
-        }
-
-        None
-    }
-
-    fn process_bound_event(&mut self, event: &TuiEvent) -> Option<ResponseEvent> {
-        if self.app_data.has_binding(event, KeyCommand::CommandPaletteOpen) {
-            self.show_command_palette();
-            return Some(ResponseEvent::Handled);
-        }
-
-        if let TuiEvent::Mouse(mouse) = event
-            && mouse.kind == MouseEventKind::RightClick
-        {
-            self.show_mouse_menu(mouse.column, mouse.row);
-            return Some(ResponseEvent::Handled);
-        }
-
-        if self.app_data.has_binding(event, KeyCommand::SearchOpen) {
-            self.search.show();
-            return Some(ResponseEvent::Handled);
-        }
-
-        if self.app_data.has_binding(event, KeyCommand::SearchReset) && !self.search.value().is_empty() {
-            self.clear_search();
-            return Some(ResponseEvent::Handled);
-        }
-
-        if self.app_data.has_binding(event, KeyCommand::NavigateBack) {
-            return Some(ResponseEvent::Cancelled);
-        }
-
-        if self.app_data.has_binding(event, KeyCommand::LogsTimestamps) {
-            self.toggle_timestamps();
-            return Some(ResponseEvent::Handled);
-        }
-
-        if self.app_data.has_binding(event, KeyCommand::ContentCopy) {
-            self.copy_logs_to_clipboard();
-            return Some(ResponseEvent::Handled);
-        }
-
-        if self.app_data.has_binding(event, KeyCommand::ContentSave) {
-            self.show_file_picker();
-            return Some(ResponseEvent::Handled);
-        }
-
-        if self.app_data.has_binding(event, KeyCommand::MatchNext) && self.logs.matches_count().is_some() {
-            self.navigate_match(true);
-            return Some(ResponseEvent::Handled);
-        }
-
-        if self.app_data.has_binding(event, KeyCommand::MatchPrevious) && self.logs.matches_count().is_some() {
-            self.navigate_match(false);
-            return Some(ResponseEvent::Handled);
-        }
-
-        None
+        self.bound_to_bottom = self.pane.search_value().is_empty() && self.pane.logs.is_at_end();
+        self.pane.logs.header.set_icon(if self.bound_to_bottom { '' } else { '' });
     }
 
     fn process_logs_event(&mut self, event: &TuiEvent) -> ResponseEvent {
-        if self.logs.is_at_beginning()
+        if self.pane.logs.is_at_beginning()
             && self.observers.len() == 1
             && (matches!(event, TuiEvent::Key(key) if key.code == KeyCode::Up)
                 || matches!(event, TuiEvent::Mouse(mouse) if mouse.kind == MouseEventKind::ScrollUp))
@@ -389,14 +124,14 @@ impl LogsView {
 
         if let TuiEvent::Key(key) = event
             && (key.code == KeyCode::Down || key.code == KeyCode::End || key.code == KeyCode::PageDown)
-            && self.logs.is_at_end()
+            && self.pane.logs.is_at_end()
         {
             self.update_bound_to_bottom();
-            self.logs.process_event(event);
+            self.pane.logs.process_event(event);
             return ResponseEvent::Handled;
         }
 
-        if self.logs.process_event(event) == ResponseEvent::Handled {
+        if self.pane.logs.process_event(event) == ResponseEvent::Handled {
             self.update_bound_to_bottom();
             return ResponseEvent::Handled;
         }
@@ -409,7 +144,7 @@ impl LogsView {
             return;
         }
 
-        let Some(content) = self.logs.content_mut() else {
+        let Some(content) = self.pane.logs.content_mut() else {
             return;
         };
 
@@ -433,7 +168,7 @@ impl LogsView {
             let since_ts = estimate_since_time(first_dt, last_dt, content.len());
             let line = LogLine::info(since_ts, None, format!("Fetching earlier logs since {since_ts}"));
             content.add_log_line(line);
-            self.logs.set_page_start(1);
+            self.pane.logs.set_page_start(1);
 
             let mut observer = LogsObserver::new(self.worker.borrow().runtime_handle().clone());
             let options = LogsObserverOptions::stop_on(since_ts, stop_on, self.previous);
@@ -449,35 +184,35 @@ impl View for LogsView {
         for observer in &mut self.observers {
             if !observer.is_empty() {
                 needs_update = true;
-                if !self.logs.has_content() {
+                if !self.pane.logs.has_content() {
                     let mut content = LogsContent::new(self.app_data.borrow().theme.colors.syntax.logs.clone());
                     content.set_timestamps(self.app_data.borrow().config.logs.timestamps.is_none_or(|t| t));
-                    self.logs.set_content(content);
+                    self.pane.logs.set_content(content);
                 }
 
-                let content = self.logs.content_mut().unwrap();
+                let content = self.pane.logs.content_mut().unwrap();
                 while let Some(line) = observer.try_next() {
                     content.add_log_line(*line);
                 }
 
                 if self.bound_to_bottom {
-                    self.logs.scroll_to_end();
+                    self.pane.logs.scroll_to_end();
                 }
             }
         }
 
         if let Some(observer) = self.fetch_observer.as_mut()
             && !observer.is_empty()
-            && self.logs.has_content()
+            && self.pane.logs.has_content()
         {
             needs_update = true;
             while let Some(line) = observer.try_next() {
-                let current = self.logs.page_position().y;
-                let added_line = self.logs.content_mut().and_then(|c| c.add_log_line(*line));
+                let current = self.pane.logs.page_position().y;
+                let added_line = self.pane.logs.content_mut().and_then(|c| c.add_log_line(*line));
                 if let Some(line) = added_line
                     && current >= line
                 {
-                    self.logs.set_page_start(current + 1);
+                    self.pane.logs.set_page_start(current + 1);
                 }
             }
         }
@@ -486,10 +221,10 @@ impl View for LogsView {
             self.fetch_observer = None;
         }
 
-        self.logs.header.set_busy(self.fetch_observer.is_some());
+        self.pane.logs.header.set_busy(self.fetch_observer.is_some());
 
-        if needs_update && self.logs.search(self.search.value(), true) {
-            self.update_search_count();
+        if needs_update {
+            self.pane.refresh_search();
         }
 
         ResponseEvent::Handled
@@ -500,11 +235,19 @@ impl View for LogsView {
     }
 
     fn process_event(&mut self, event: &TuiEvent) -> ResponseEvent {
-        if let Some(result) = self.process_widget_event(event) {
+        if let Some(result) = self.pane.process_widget_event(event) {
+            if self.pane.take_search_updated() {
+                self.update_bound_to_bottom();
+            }
+
             return result;
         }
 
-        if let Some(result) = self.process_bound_event(event) {
+        if let Some(result) = self.pane.process_bound_event(event) {
+            if self.pane.take_search_updated() {
+                self.update_bound_to_bottom();
+            }
+
             return result;
         }
 
@@ -512,15 +255,11 @@ impl View for LogsView {
     }
 
     fn draw(&mut self, frame: &mut Frame<'_>, area: Rect, _has_focus: bool) {
-        self.logs.draw(frame, area, self.get_offset());
-        self.command_palette.draw(frame, frame.area());
-        self.search.draw(frame, frame.area());
-        self.file_picker.draw(frame, area);
-        self.modal.draw(frame, frame.area());
+        self.pane.draw(frame, area);
 
         if area.height != self.area.height && self.bound_to_bottom {
             self.area = area;
-            self.logs.scroll_to_end();
+            self.pane.logs.scroll_to_end();
         }
     }
 }
@@ -1,17 +1,23 @@
-pub use self::common::{ESCAPE_SEQUENCE_TIMEOUT, EscapeSequenceTracker, ScreenExt, View, get_layout_with_header};
+pub use self::common::{
+    ESCAPE_SEQUENCE_TIMEOUT, EscapeSequenceTracker, ScreenExt, SessionInfo, View, get_layout_with_header, get_logs_containers,
+};
 pub use self::describe::DescribeView;
+pub use self::events::EventStreamView;
 pub use self::forwards::{ForwardsView, PortForwardItem, PortForwardsList};
-pub use self::logs::LogsView;
-pub use self::resources::ResourcesView;
+pub use self::logs::{ExecView, LogsView};
+pub use self::resources::{NextRefreshActions, ResourcesView};
 pub use self::shell::CmdView;
-pub use self::shell::ShellView;
+pub use self::shell::{ShellMode, ShellView};
+pub use self::xray::XrayView;
 pub use self::yaml::YamlView;
 
 mod common;
 mod describe;
+mod events;
 mod forwards;
 mod logs;
 mod resources;
 mod shell;
 mod transfer;
+mod xray;
 mod yaml;
@@ -1,8 +1,9 @@
 use b4n_config::{PluginInputType, PluginRef};
 use b4n_kube::plugins::PluginContext;
-use b4n_kube::{ResourceRef, ResourceTag};
+use b4n_kube::{MetadataChanges, Namespace, ResourceRef, ResourceTag};
+use b4n_tasks::commands::ResourceUser;
 use b4n_tui::widgets::{Button, CheckBox, Dialog, Selector, TextBox, ValidatorKind};
-use b4n_tui::{EphemeralContainer, ResponseEvent};
+use b4n_tui::{EphemeralContainer, PodCopy, ResponseEvent};
 use ratatui::layout::Position;
 use std::collections::HashMap;
 
@@ -10,11 +11,14 @@ use crate::core::{SharedAppData, SharedAppDataExt};
 use crate::ui::views::common;
 use crate::ui::views::resources::ResourcesTable;
 
+const DELETE_MESSAGE: &str = "Are you sure you want to delete the selected resources?";
+const MAX_USERS_IN_WARNING: usize = 3;
+
 /// Creates new resource delete confirmation dialog.
 pub fn new_delete_dialog(app_data: &SharedAppData, position: Option<Position>) -> Dialog {
     let colors = &app_data.borrow().theme.colors;
     Dialog::new(
-        "Are you sure you want to delete the selected resources?".to_owned(),
+        DELETE_MESSAGE.to_owned(),
         vec![
             Button::new("Delete", ResponseEvent::Action("delete"), colors.modal.btn_delete.clone()),
             Button::new("Cancel", ResponseEvent::Cancelled, colors.modal.btn_cancel.clone()),
@@ -34,6 +38,29 @@ pub fn new_delete_dialog(app_data: &SharedAppData, position: Option<Position>) -
     .with_highlighted_position(position)
 }
 
+/// Returns delete confirmation message with a warning about pods and workloads that still use the resources.
+pub fn get_delete_message(users: &[ResourceUser]) -> String {
+    let mut names = Vec::new();
+    for user in users {
+        let name = format!("{}/{}", user.singular.to_ascii_lowercase(), user.name);
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut used_by = names
+        .iter()
+        .take(MAX_USERS_IN_WARNING)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    if names.len() > MAX_USERS_IN_WARNING {
+        used_by.push_str(&format!(" and {} more", names.len() - MAX_USERS_IN_WARNING));
+    }
+
+    format!("{DELETE_MESSAGE} Warning: they are still used by {used_by}.")
+}
+
 /// Creates new stop port forwarding rules dialog.
 pub fn new_stop_port_forwards_dialog(app_data: &SharedAppData, position: Option<Position>, resource: &str) -> Dialog {
     let colors = &app_data.borrow().theme.colors;
@@ -192,6 +219,7 @@ pub fn new_inject_container_dialog(app_data: &SharedAppData, resource: &Resource
         TextBox::new(1, "Image:  ", 40, colors.textbox.clone())
             .with_value(image)
             .with_clipboard(app_data.borrow().get_clipboard())
+            .with_validator(ValidatorKind::Required)
             .with_validator(ValidatorKind::DockerImage)
             .with_button("  ", "select_image"),
         TextBox::new(2, "Command:", 40, colors.textbox.clone())
@@ -221,3 +249,137 @@ pub fn build_inject_container_response(modal: &Dialog, resource: ResourceRef) ->
     };
     ResponseEvent::InjectContainer(resource, container)
 }
+
+/// Builds modal dialog to create debug copy of the pod.
+pub fn new_copy_pod_dialog(app_data: &SharedAppData, resource: &ResourceRef, tags: &[ResourceTag]) -> Dialog {
+    let colors = &app_data.borrow().theme.colors.modal;
+    let pod_name = resource.name.as_deref().unwrap_or_default();
+    let containers = common::get_target_containers_from_resource_tags(tags);
+
+    Dialog::new(
+        format!("Create debug copy of '{pod_name}' (leave image or command empty to keep the original one):"),
+        vec![
+            Button::new("Create Copy", ResponseEvent::Action("copy_pod"), colors.btn_accent.clone()),
+            Button::new("Cancel", ResponseEvent::Cancelled, colors.btn_cancel.clone()),
+        ],
+    )
+    .with_colors(colors.text)
+    .with_textboxes(vec![
+        TextBox::new(0, "Name:     ", 40, colors.textbox.clone())
+            .with_value(format!("{pod_name}-debug"))
+            .with_clipboard(app_data.borrow().get_clipboard())
+            .with_validator(ValidatorKind::Required)
+            .with_validator(ValidatorKind::StringExcept(vec![pod_name.to_owned()]))
+            .with_validator(ValidatorKind::DnsLabel),
+        TextBox::new(1, "Image:    ", 40, colors.textbox.clone())
+            .with_clipboard(app_data.borrow().get_clipboard())
+            .with_validator(ValidatorKind::OptionalDockerImage)
+            .with_button("  ", "select_image"),
+        TextBox::new(2, "Command:  ", 40, colors.textbox.clone())
+            .with_value("sleep infinity")
+            .with_clipboard(app_data.borrow().get_clipboard())
+            .with_validator(ValidatorKind::ShellCommand),
+    ])
+    .with_selectors(vec![Selector::new(0, "Container:", &containers, &colors.selector)])
+}
+
+/// Returns new [`ResponseEvent::CopyPod`] response built from the properties set in the modal dialog.
+pub fn build_copy_pod_response(modal: &Dialog, resource: ResourceRef) -> ResponseEvent {
+    fn get_textbox_value(modal: &Dialog, idx: usize) -> String {
+        modal
+            .textbox(idx)
+            .map_or_else(String::new, |tb| tb.value().trim().to_string())
+    }
+
+    let copy = PodCopy {
+        name: get_textbox_value(modal, 0),
+        container: modal.selector(0).map(Selector::selected).map(String::from),
+        image: get_textbox_value(modal, 1),
+        command: get_textbox_value(modal, 2),
+    };
+    ResponseEvent::CopyPod(resource, copy)
+}
+
+/// Builds modal dialog to open node shell.
+pub fn new_node_shell_dialog(app_data: &SharedAppData, node_name: &str, namespace: &Namespace) -> Dialog {
+    let colors = &app_data.borrow().theme.colors.modal;
+    let image = &app_data.borrow().config.debug_images.first().cloned().unwrap_or_default();
+    let namespace = if namespace.is_multiple() {
+        "default"
+    } else {
+        namespace.as_str()
+    };
+
+    Dialog::new(
+        format!(
+            "Open shell on node '{node_name}'? A privileged pod will be created on that node and removed when the shell is closed:"
+        ),
+        vec![
+            Button::new("Open Shell", ResponseEvent::Action("node_shell"), colors.btn_accent.clone()),
+            Button::new("Cancel", ResponseEvent::Cancelled, colors.btn_cancel.clone()),
+        ],
+    )
+    .with_colors(colors.text)
+    .with_textboxes(vec![
+        TextBox::new(0, "Namespace:", 40, colors.textbox.clone())
+            .with_value(namespace)
+            .with_clipboard(app_data.borrow().get_clipboard())
+            .with_validator(ValidatorKind::Required)
+            .with_validator(ValidatorKind::DnsLabel),
+        TextBox::new(1, "Image:    ", 40, colors.textbox.clone())
+            .with_value(image)
+            .with_clipboard(app_data.borrow().get_clipboard())
+            .with_validator(ValidatorKind::Required)
+            .with_validator(ValidatorKind::DockerImage)
+            .with_button("  ", "select_image"),
+    ])
+}
+
+/// Returns new [`ResponseEvent::OpenNodeShell`] response built from the properties set in the modal dialog.
+pub fn build_node_shell_response(modal: &Dialog, node_name: String) -> ResponseEvent {
+    let namespace = modal.textbox(0).map_or_else(String::new, |tb| tb.value().to_string());
+    let image = modal.textbox(1).map_or_else(String::new, |tb| tb.value().to_string());
+    ResponseEvent::OpenNodeShell(node_name, namespace, image)
+}
+
+/// Builds modal dialog to add, overwrite or remove labels and annotations of the resources.
+pub fn new_edit_metadata_dialog(app_data: &SharedAppData, count: usize) -> Dialog {
+    let colors = &app_data.borrow().theme.colors.modal;
+
+    Dialog::new(
+        format!("Update labels and annotations of {count} resource(s) (use 'key=value' to set and 'key-' to remove):"),
+        vec![
+            Button::new("Apply", ResponseEvent::Action("edit_metadata"), colors.btn_accent.clone()),
+            Button::new("Cancel", ResponseEvent::Cancelled, colors.btn_cancel.clone()),
+        ],
+    )
+    .with_colors(colors.text)
+    .with_textboxes(vec![
+        TextBox::new(0, "Labels:     ", 50, colors.textbox.clone())
+            .with_clipboard(app_data.borrow().get_clipboard())
+            .with_validator(ValidatorKind::Labels),
+        TextBox::new(1, "Annotations:", 50, colors.textbox.clone())
+            .with_clipboard(app_data.borrow().get_clipboard())
+            .with_validator(ValidatorKind::Annotations),
+    ])
+    .with_checkboxes(vec![CheckBox::new(
+        0,
+        "Overwrite existing values",
+        false,
+        colors.checkbox.clone(),
+    )])
+}
+
+/// Returns new [`ResponseEvent::PatchMetadata`] response built from the properties set in the modal dialog.
+pub fn build_edit_metadata_response(modal: &Dialog, resources: Vec<ResourceRef>) -> ResponseEvent {
+    let labels = modal.textbox(0).map_or("", |tb| tb.value());
+    let annotations = modal.textbox(1).map_or("", |tb| tb.value());
+    let overwrite = modal.checkbox(0).is_some_and(|c| c.is_checked);
+
+    match MetadataChanges::parse(labels, annotations) {
+        Ok(changes) if !changes.is_empty() && !resources.is_empty() => {
+            ResponseEvent::PatchMetadata(resources, changes, overwrite)
+        },
+        _ => ResponseEvent::Handled,
+    }
+}
@@ -1,8 +1,12 @@
+use b4n_config::Bookmark;
 use b4n_config::keys::KeyCommand;
-use b4n_kube::{ALL_NAMESPACES, CONTAINERS, EVENTS, NAMESPACES, PODS, Port, ResourceRef, SECRETS, Scope};
+use b4n_kube::{
+    ALL_NAMESPACES, CONTAINERS, CRON_JOBS, ContainerRef, EVENTS, NAMESPACES, NODES, PODS, Port, ResourceRef, SECRETS, Scope,
+};
 use b4n_tui::table::Table;
 use b4n_tui::widgets::{ActionItem, ActionsList, ActionsListBuilder, ValidatorKind};
 use b4n_tui::{PluginsExt, ResponseEvent};
+use std::cmp::Reverse;
 use std::rc::Rc;
 
 use crate::core::SharedAppData;
@@ -11,6 +15,11 @@ use crate::kube::resources::pod::PF_COLUMN_NO;
 use crate::ui::views::resources::ResourcesTable;
 use crate::ui::widgets::{CommandPalette, StepBuilder};
 
+const QUERY_FORMAT_YAML: &str = "yaml";
+const QUERY_FORMAT_TABLE: &str = "table";
+
+type ScopedResources = Vec<(String, Vec<ResourceRef>)>;
+
 /// Builds steps required to configure port forward for specified resource container.
 pub fn build_port_forward_steps(app_data: &SharedAppData, resource: ResourceRef, list: &[Port]) -> CommandPalette {
     let actions_list = ActionsListBuilder::from_resource_ports(list).build(None);
@@ -47,11 +56,110 @@ pub fn build_image_select_palette(app_data: &SharedAppData, highlighted: &str) -
     CommandPalette::new(Rc::clone(app_data), actions, 65)
         .with_header(" Format: [registry/][namespace/]name[:tag][@digest]")
         .with_prompt("image name")
+        .with_validator(ValidatorKind::Required)
         .with_validator(ValidatorKind::DockerImage)
         .with_highlighted(highlighted)
         .with_response(|_| ResponseEvent::Action("image_selected"))
 }
 
+/// Builds command palette that asks for the command to execute in the specified containers.
+pub fn build_exec_command_palette(app_data: &SharedAppData, containers: Vec<ContainerRef>) -> CommandPalette {
+    CommandPalette::new(Rc::clone(app_data), ActionsList::default(), 65)
+        .with_header(format!(" Execute command in {} container(s):", containers.len()))
+        .with_prompt("command")
+        .with_validator(ValidatorKind::Required)
+        .with_validator(ValidatorKind::ShellCommand)
+        .with_response(|mut input| ResponseEvent::ExecCommand(containers, input.pop().unwrap_or_default()))
+}
+
+/// Builds command palette that asks which resources to export and where to save them.\
+/// **Note** that `targets` holds resources for each possible export scope, e.g. `selected` or `all`.
+pub fn build_export_palette(app_data: &SharedAppData, targets: Vec<(&str, Vec<ResourceRef>)>, path: &str) -> CommandPalette {
+    let (actions, scopes, targets) = build_scopes(targets);
+    CommandPalette::new(Rc::clone(app_data), actions, 65)
+        .with_header(" Export to a .yaml file or to a <namespace>/<kind>/<name>.yaml tree:")
+        .with_prompt("resources")
+        .with_validator(ValidatorKind::StringOneOf(scopes))
+        .with_first_highlighted()
+        .with_step(
+            StepBuilder::input(path)
+                .with_validator(ValidatorKind::Required)
+                .with_prompt("path")
+                .with_colors(app_data.borrow().theme.colors.command_palette.clone())
+                .with_clipboard(app_data.borrow().get_clipboard())
+                .build(app_data),
+        )
+        .with_response(|mut input| {
+            let path = input.pop().unwrap_or_default();
+            take_scope(targets, input.first()).map_or(ResponseEvent::Handled, |resources| {
+                ResponseEvent::ExportResources(resources, path)
+            })
+        })
+}
+
+/// Builds command palette that asks for the name of a bookmark for the current view.\
+/// **Note** that the existing bookmark with the same name is overwritten.
+pub fn build_bookmark_palette(app_data: &SharedAppData, mut bookmark: Bookmark) -> CommandPalette {
+    let names = app_data
+        .borrow()
+        .bookmarks
+        .bookmarks
+        .iter()
+        .map(|b| b.name.clone())
+        .collect::<Vec<_>>();
+    CommandPalette::new(Rc::clone(app_data), ActionsListBuilder::from_strings(&names).build(None), 65)
+        .with_header(format!(" Bookmark {}:", bookmark.description()))
+        .with_prompt("bookmark name")
+        .with_validator(ValidatorKind::Required)
+        .with_response(move |mut input| {
+            bookmark.name = input.pop().unwrap_or_default().trim().to_owned();
+            ResponseEvent::SaveBookmark(Box::new(bookmark))
+        })
+}
+
+/// Builds command palette that asks for JSONPath expression to evaluate against the chosen resources.\
+/// **Note** that `targets` holds resources for each possible query scope, e.g. `highlighted` or `all`.
+pub fn build_query_palette(app_data: &SharedAppData, targets: Vec<(&str, Vec<ResourceRef>)>) -> CommandPalette {
+    let (actions, scopes, targets) = build_scopes(targets);
+    let mut history = {
+        let data = app_data.borrow();
+        data.history.query_history(&data.current.context).to_vec()
+    };
+    history.sort_unstable_by_key(|i| Reverse(i.creation_time));
+    let history = history.into_iter().map(|i| i.value).collect::<Vec<_>>();
+    let formats = [QUERY_FORMAT_YAML, QUERY_FORMAT_TABLE];
+
+    CommandPalette::new(Rc::clone(app_data), actions, 65)
+        .with_header(" Query resources using JSONPath, e.g. $.spec.containers[*].image")
+        .with_prompt("resources")
+        .with_validator(ValidatorKind::StringOneOf(scopes))
+        .with_first_highlighted()
+        .with_step(
+            StepBuilder::actions(ActionsListBuilder::from_strings(&history).build(None))
+                .with_validator(ValidatorKind::Required)
+                .with_validator(ValidatorKind::JsonPath)
+                .with_prompt("query")
+                .with_colors(app_data.borrow().theme.colors.command_palette.clone())
+                .with_clipboard(app_data.borrow().get_clipboard())
+                .build(app_data),
+        )
+        .with_step(
+            StepBuilder::actions(ActionsListBuilder::from_strings(&formats).build(None))
+                .with_validator(ValidatorKind::StringOneOf(formats.map(String::from).to_vec()))
+                .with_prompt("format")
+                .with_colors(app_data.borrow().theme.colors.command_palette.clone())
+                .build(app_data),
+        )
+        .with_value(QUERY_FORMAT_YAML)
+        .with_response(|mut input| {
+            let as_table = input.pop().is_some_and(|f| f == QUERY_FORMAT_TABLE);
+            let expression = input.pop().unwrap_or_default();
+            take_scope(targets, input.first()).map_or(ResponseEvent::Handled, |resources| {
+                ResponseEvent::QueryResources(resources, expression, as_table)
+            })
+        })
+}
+
 /// Builds actions for creating new resource.
 pub fn build_create_resource_actions(table: &ResourcesTable) -> ActionsList {
     let mut builder = ActionsListBuilder::default()
@@ -79,6 +187,8 @@ pub fn build_mouse_menu_actions(table: &ResourcesTable) -> ActionsList {
     let is_containers = table.kind_plural() == CONTAINERS;
     let is_pods = table.kind_plural() == PODS;
     let is_events = table.kind_plural() == EVENTS;
+    let is_helm = table.is_helm_releases();
+    let is_virtual = is_helm || table.is_tls_certificates();
 
     let copy = if is_selected { "selected" } else { "all" };
     let mut builder = ActionsListBuilder::default()
@@ -94,13 +204,20 @@ pub fn build_mouse_menu_actions(table: &ResourcesTable) -> ActionsList {
         builder.add_menu_action(action);
     }
 
-    if is_highlighted {
+    if is_highlighted && is_helm {
+        builder = builder
+            .with_menu_action(ActionItem::menu(1, " values", "helm_values"))
+            .with_menu_action(ActionItem::menu(2, " manifest", "helm_manifest"))
+            .with_menu_action(ActionItem::menu(3, " notes", "helm_notes"))
+            .with_menu_action(ActionItem::menu(4, " history", "helm_history"))
+            .with_menu_action(ActionItem::menu(15, "󰆏 copy ␝name␝", "copy_name"));
+    } else if is_highlighted {
         builder = builder
             .with_menu_action(ActionItem::menu(1, " YAML", "show_yaml"))
             .with_menu_action(ActionItem::menu(5, " describe", "describe"))
             .with_menu_action(ActionItem::menu(15, "󰆏 copy ␝name␝", "copy_name"));
 
-        if table.kind_plural() == SECRETS {
+        if table.kind_plural() == SECRETS || table.is_tls_certificates() {
             builder.add_menu_action(ActionItem::menu(2, " YAML ␝decoded␝", "decode_yaml"));
         }
 
@@ -128,11 +245,30 @@ pub fn build_mouse_menu_actions(table: &ResourcesTable) -> ActionsList {
             }
         }
 
+        if (is_containers || is_pods) && (is_selected || table.is_resource_running()) {
+            builder.add_menu_action(ActionItem::menu(8, " exec ␝selected␝", "exec_selected"));
+        }
+
+        if is_pods {
+            builder.add_menu_action(ActionItem::menu(10, " debug copy", "copy_pod"));
+        }
+
+        if table.kind_plural() == CRON_JOBS {
+            builder = builder
+                .with_menu_action(ActionItem::menu(7, "󱓞 trigger now", "trigger_cron_job"))
+                .with_menu_action(ActionItem::menu(8, "󰏤 suspend", "suspend_cron_jobs"))
+                .with_menu_action(ActionItem::menu(8, "󰐊 resume", "resume_cron_jobs"));
+        }
+
+        if table.kind_plural() == NODES {
+            builder.add_menu_action(ActionItem::menu(7, " node shell", "node_shell"));
+        }
+
         if table.list.table.data.is_editable {
             builder.add_menu_action(ActionItem::menu(12, " edit", "edit_yaml"));
         }
 
-        if !is_containers && !is_events {
+        if !is_containers && !is_events && !is_virtual {
             if table.list.table.data.is_creatable {
                 builder.add_menu_action(ActionItem::menu(13, "󰐕 create new", "create"));
             }
@@ -141,6 +277,15 @@ pub fn build_mouse_menu_actions(table: &ResourcesTable) -> ActionsList {
             }
         }
 
+        if !is_containers && !is_events && !is_virtual {
+            builder.add_menu_action(ActionItem::menu(17, "󰈇 export", "export"));
+            builder.add_menu_action(ActionItem::menu(18, "󰘦 query", "query"));
+        }
+
+        if !is_containers && !is_events && table.list.table.data.is_editable {
+            builder.add_menu_action(ActionItem::menu(19, "󰓻 labels", "edit_metadata_dialog"));
+        }
+
         if has_involved_object(table) {
             builder.add_menu_action(ActionItem::menu(99, "󰑏 involved object", "show_involved"));
         }
@@ -150,17 +295,24 @@ pub fn build_mouse_menu_actions(table: &ResourcesTable) -> ActionsList {
 }
 
 /// Builds actions for highlighted resource.
-pub fn build_resources_actions(app_data: &SharedAppData, table: &ResourcesTable) -> ActionsList {
+pub fn build_resources_actions(app_data: &SharedAppData, table: &ResourcesTable, has_transfers: bool) -> ActionsList {
     let is_selected = table.list.table.is_anything_selected();
     let is_highlighted = table.list.table.is_anything_highlighted();
     let is_containers = table.kind_plural() == CONTAINERS;
     let is_pods = table.kind_plural() == PODS;
     let is_events = table.kind_plural() == EVENTS;
+    let is_helm = table.is_helm_releases();
+    let is_virtual = is_helm || table.is_tls_certificates();
     let is_deletable = is_selected && table.list.table.data.is_deletable;
 
     let mut builder = ActionsListBuilder::from_kinds(app_data.borrow().kinds.as_deref())
         .with_resources_actions(!is_containers && is_deletable)
         .with_forwards()
+        .with_event_stream()
+        .with_sessions()
+        .with_search()
+        .with_preview()
+        .with_bookmarks(&app_data.borrow().bookmarks)
         .with_filter_action("filter")
         .with_pin_filter_action("pin_filter")
         .with_actions(
@@ -170,6 +322,10 @@ pub fn build_resources_actions(app_data: &SharedAppData, table: &ResourcesTable)
                 .to_actions(table.get_kind().as_str(), is_highlighted, is_selected),
         );
 
+    if !app_data.borrow().config.alerts.is_empty() {
+        builder = builder.with_alert_jump();
+    }
+
     if table.kind_plural() != NAMESPACES {
         builder.add_action(
             ActionItem::action("back", "back").with_description("returns to the previous view"),
@@ -181,13 +337,29 @@ pub fn build_resources_actions(app_data: &SharedAppData, table: &ResourcesTable)
         builder = builder.with_namespace();
     }
 
+    if !is_containers {
+        builder.add_action(
+            ActionItem::action("bookmark", "bookmark_add")
+                .with_description("saves the current view as a named bookmark")
+                .with_aliases(["bm", "save"]),
+            Some(KeyCommand::BookmarkAdd),
+        );
+    }
+
+    builder.add_action(
+        ActionItem::action("certificates", "tls_scan")
+            .with_description("lists TLS certificates that expire soon in all namespaces")
+            .with_aliases(["certs", "tls", "expiry"]),
+        None,
+    );
+
     let selected = if is_selected { "selected" } else { "all" };
     builder.add_action(
         ActionItem::action("copy", "copy").with_description(&format!("copies {selected} resources to clipboard")),
         Some(KeyCommand::ContentCopy),
     );
 
-    if !is_containers && !is_events {
+    if !is_containers && !is_events && !is_virtual {
         if is_highlighted {
             builder.add_action(
                 ActionItem::action("show events", "show_events").with_description("shows events for the selected resource"),
@@ -205,6 +377,48 @@ pub fn build_resources_actions(app_data: &SharedAppData, table: &ResourcesTable)
         }
     }
 
+    if is_highlighted && !is_containers && !is_events && !is_virtual {
+        builder.add_action(
+            ActionItem::action("export", "export")
+                .with_description("exports resources as clean manifests")
+                .with_aliases(["backup", "save", "manifest"]),
+            None,
+        );
+        builder.add_action(
+            ActionItem::action("query", "query")
+                .with_description("evaluates JSONPath expression against resources")
+                .with_aliases(["jsonpath", "jq"]),
+            None,
+        );
+    }
+
+    if is_highlighted && !is_containers && !is_events && !is_virtual && table.list.table.data.is_editable {
+        builder.add_action(
+            ActionItem::action("labels", "edit_metadata_dialog")
+                .with_description("adds, overwrites or removes labels and annotations")
+                .with_aliases(["label", "annotate", "annotations"]),
+            None,
+        );
+    }
+
+    if has_transfers {
+        builder.add_action(
+            ActionItem::action("cancel all transfers", "cancel_transfers")
+                .with_description("cancels all file transfers in progress")
+                .with_aliases(["file", "transfer", "stop"]),
+            Some(KeyCommand::TransferCancelAll),
+        );
+    }
+
+    if (is_containers || is_pods) && (is_selected || table.is_resource_running()) {
+        builder.add_action(
+            ActionItem::action("exec on selected", "exec_selected")
+                .with_description("executes command in the selected containers or in the first container of each selected pod")
+                .with_aliases(["exec", "run"]),
+            Some(KeyCommand::ExecSelected),
+        );
+    }
+
     if has_involved_object(table) {
         builder.add_action(
             ActionItem::action("involved object", "show_involved").with_description("navigates to the involved object"),
@@ -212,8 +426,13 @@ pub fn build_resources_actions(app_data: &SharedAppData, table: &ResourcesTable)
         );
     }
 
-    if is_highlighted {
+    if is_highlighted && is_helm {
+        builder = add_helm_release_actions(builder);
+    } else if is_highlighted {
         builder = add_resource_actions(builder, table, is_containers);
+        if is_pods {
+            builder = add_debug_copy_actions(builder);
+        }
         if is_pods && table.is_resource_running() {
             builder = add_ephemeral_container_actions(builder);
             builder = add_file_transfer_actions(builder);
@@ -221,6 +440,17 @@ pub fn build_resources_actions(app_data: &SharedAppData, table: &ResourcesTable)
         if is_containers || is_pods {
             builder = add_container_actions(builder);
         }
+        if table.kind_plural() == CRON_JOBS {
+            builder = add_cron_job_actions(builder, is_selected);
+        }
+        if table.kind_plural() == NODES {
+            builder.add_action(
+                ActionItem::action("node shell", "node_shell")
+                    .with_description("opens shell on the node using a privileged pod")
+                    .with_aliases(["shell", "ssh"]),
+                Some(KeyCommand::ShellOpen),
+            );
+        }
     }
 
     builder
@@ -229,7 +459,7 @@ pub fn build_resources_actions(app_data: &SharedAppData, table: &ResourcesTable)
 }
 
 fn add_resource_actions(mut builder: ActionsListBuilder, table: &ResourcesTable, is_containers: bool) -> ActionsListBuilder {
-    if table.kind_plural() == SECRETS {
+    if table.kind_plural() == SECRETS || table.is_tls_certificates() {
         builder.add_action(
             ActionItem::action("decode", "decode_yaml").with_description("shows decoded YAML of the highlighted secret"),
             Some(KeyCommand::YamlDecode),
@@ -245,6 +475,15 @@ fn add_resource_actions(mut builder: ActionsListBuilder, table: &ResourcesTable,
         );
     }
 
+    if !is_containers {
+        builder.add_action(
+            ActionItem::action("xray", "show_relations")
+                .with_description("shows owners, dependents and references of the highlighted resource")
+                .with_aliases(["relations", "tree"]),
+            Some(KeyCommand::XrayOpen),
+        );
+    }
+
     builder
         .with_action(
             ActionItem::action("show YAML", "show_yaml")
@@ -271,6 +510,66 @@ fn add_ephemeral_container_actions(builder: ActionsListBuilder) -> ActionsListBu
     )
 }
 
+fn add_debug_copy_actions(builder: ActionsListBuilder) -> ActionsListBuilder {
+    builder.with_action(
+        ActionItem::action("debug copy", "copy_pod")
+            .with_description("creates copy of the pod with overridden image or command")
+            .with_aliases(["copy", "debug"]),
+        Some(KeyCommand::PodCopy),
+    )
+}
+
+fn add_cron_job_actions(builder: ActionsListBuilder, is_selected: bool) -> ActionsListBuilder {
+    let target = if is_selected { "selected" } else { "highlighted" };
+    builder
+        .with_action(
+            ActionItem::action("trigger now", "trigger_cron_job")
+                .with_description("creates a new job from the highlighted cron job")
+                .with_aliases(["run", "job"]),
+            None,
+        )
+        .with_action(
+            ActionItem::action("suspend", "suspend_cron_jobs")
+                .with_description(&format!("suspends {target} cron jobs"))
+                .with_aliases(["pause"]),
+            None,
+        )
+        .with_action(
+            ActionItem::action("resume", "resume_cron_jobs")
+                .with_description(&format!("resumes {target} cron jobs"))
+                .with_aliases(["unsuspend"]),
+            None,
+        )
+}
+
+fn add_helm_release_actions(builder: ActionsListBuilder) -> ActionsListBuilder {
+    builder
+        .with_action(
+            ActionItem::action("values", "helm_values")
+                .with_description("shows values supplied to the highlighted helm release")
+                .with_aliases(["helm", "config"]),
+            None,
+        )
+        .with_action(
+            ActionItem::action("manifest", "helm_manifest")
+                .with_description("shows manifest rendered by the highlighted helm release")
+                .with_aliases(["helm", "yaml", "yml"]),
+            Some(KeyCommand::YamlOpen),
+        )
+        .with_action(
+            ActionItem::action("notes", "helm_notes")
+                .with_description("shows notes of the highlighted helm release")
+                .with_aliases(["helm"]),
+            None,
+        )
+        .with_action(
+            ActionItem::action("history", "helm_history")
+                .with_description("shows revision history of the highlighted helm release")
+                .with_aliases(["helm", "revisions"]),
+            None,
+        )
+}
+
 fn add_file_transfer_actions(builder: ActionsListBuilder) -> ActionsListBuilder {
     builder
         .with_action(
@@ -328,6 +627,26 @@ fn build_port_forward_response(mut input: Vec<String>, resource: ResourceRef) ->
     }
 }
 
+fn build_scopes(targets: Vec<(&str, Vec<ResourceRef>)>) -> (ActionsList, Vec<String>, ScopedResources) {
+    let scopes = targets.iter().map(|(scope, _)| (*scope).to_owned()).collect::<Vec<_>>();
+    let actions = ActionsListBuilder::from_strings(&scopes).build(None);
+    let targets = targets
+        .into_iter()
+        .map(|(scope, resources)| (scope.to_owned(), resources))
+        .collect();
+
+    (actions, scopes, targets)
+}
+
+fn take_scope(targets: ScopedResources, scope: Option<&String>) -> Option<Vec<ResourceRef>> {
+    let scope = scope?;
+    targets
+        .into_iter()
+        .find(|(name, _)| name == scope)
+        .map(|(_, resources)| resources)
+        .filter(|resources| !resources.is_empty())
+}
+
 fn has_involved_object(table: &ResourcesTable) -> bool {
     table
         .list
@@ -1,8 +1,10 @@
 use b4n_common::NotificationSink;
 use b4n_config::keys::KeyCommand;
+use b4n_kube::helm::HelmReleaseView;
 use b4n_kube::{
-    ALL_NAMESPACES, CONTAINERS, DAEMON_SETS, DEPLOYMENTS, EVENTS, JOBS, Kind, NAMESPACES, NODES, Namespace, ObserverResult, PODS,
-    REPLICA_SETS, ResourceRef, ResourceRefFilter, ResourceTag, SECRETS, SERVICES, STATEFUL_SETS,
+    ALL_NAMESPACES, CONTAINERS, ContainerRef, DAEMON_SETS, DEPLOYMENTS, EVENTS, HELM_RELEASES, HPA, JOBS, Kind, NAMESPACES,
+    NODES, Namespace, ObserverResult, PDB, PODS, REPLICA_SETS, ResourceRef, ResourceRefFilter, ResourceTag, SECRETS, SERVICES,
+    STATEFUL_SETS, TLS_CERTIFICATES,
 };
 use b4n_list::Row;
 use b4n_tui::ToSelectData;
@@ -15,7 +17,7 @@ use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use std::{collections::HashMap, rc::Rc};
 
 use crate::core::{PreviousData, ResourcesInfo, SharedAppData, SharedAppDataExt};
-use crate::kube::resources::pod::{get_single_container, has_single_container};
+use crate::kube::resources::pod::{get_first_container, get_single_container, has_single_container};
 use crate::kube::resources::{ResourceItem, ResourcesList};
 use crate::ui::presentation::{ListHeader, ListViewer};
 
@@ -100,7 +102,7 @@ impl ResourcesTable {
 
     /// Sets initial kubernetes resources data for [`ResourcesTable`].
     pub fn set_resources_info(&mut self, context: String, namespace: Namespace, version: String, scope: Scope) {
-        if scope == Scope::Cluster || !namespace.is_all() {
+        if scope == Scope::Cluster || !namespace.is_multiple() {
             self.set_view(ViewType::Compact);
         } else {
             self.set_view(ViewType::Full);
@@ -161,6 +163,16 @@ impl ResourcesTable {
         &self.list.table.data.kind_plural
     }
 
+    /// Returns `true` if resources listed in [`ResourcesTable`] are the virtual helm releases.
+    pub fn is_helm_releases(&self) -> bool {
+        self.kind_plural() == HELM_RELEASES && self.group().is_empty()
+    }
+
+    /// Returns `true` if resources listed in [`ResourcesTable`] are the virtual TLS certificates.
+    pub fn is_tls_certificates(&self) -> bool {
+        self.kind_plural() == TLS_CERTIFICATES && self.group().is_empty()
+    }
+
     /// Gets current scope for resources listed in [`ResourcesTable`].
     pub fn scope(&self) -> &Scope {
         &self.list.table.data.scope
@@ -230,6 +242,66 @@ impl ResourcesTable {
             .collect()
     }
 
+    /// Returns collection of [`ResourceRef`]s for all items that match the current filter.
+    pub fn get_filtered_resources_ref(&self) -> Vec<ResourceRef> {
+        self.list
+            .table
+            .get_filtered_resources()
+            .iter()
+            .filter_map(|r| self.resource_ref_from(r, false))
+            .collect()
+    }
+
+    /// Returns collections of [`ResourceRef`]s for each available scope, i.e. `selected`, `highlighted` and `all` items.\
+    /// **Note** that `all` scope is named `filtered` when the list is filtered.
+    pub fn get_scoped_resources_ref(&self) -> Vec<(&'static str, Vec<ResourceRef>)> {
+        let mut targets = Vec::with_capacity(3);
+        if self.list.table.is_anything_selected() {
+            targets.push(("selected", self.get_selected_resources_ref(false)));
+        }
+
+        if let Some(resource) = self.get_resource_ref(false) {
+            targets.push(("highlighted", vec![resource]));
+        }
+
+        let scope = if self.list.table.is_filtered() { "filtered" } else { "all" };
+        targets.push((scope, self.get_filtered_resources_ref()));
+        targets
+    }
+
+    /// Returns collection of [`ResourceRef`]s for currently selected items or for the highlighted one if nothing is selected.
+    pub fn get_target_resources_ref(&self) -> Vec<ResourceRef> {
+        if self.list.table.is_anything_selected() {
+            self.get_selected_resources_ref(false)
+        } else {
+            self.get_resource_ref(false).into_iter().collect()
+        }
+    }
+
+    /// Returns response that opens the specified `view` of the highlighted helm release.
+    pub fn process_helm_release(&self, view: HelmReleaseView) -> ResponseEvent {
+        self.list
+            .table
+            .get_highlighted_resource()
+            .map_or(ResponseEvent::Handled, |r| self.process_view_helm_release(r, view))
+    }
+
+    /// Returns running containers in which command can be executed.\
+    /// **Note** that selected items are preferred over the highlighted one and for pods only the first container is used.
+    pub fn get_exec_containers(&self) -> Vec<ContainerRef> {
+        let resources = if self.list.table.is_anything_selected() {
+            self.list.table.get_selected_resources()
+        } else {
+            self.list.table.get_highlighted_resource().into_iter().collect()
+        };
+
+        resources
+            .into_iter()
+            .filter(|r| r.is_running())
+            .filter_map(|r| self.container_ref_from(r))
+            .collect()
+    }
+
     /// Returns column names for the table.
     pub fn get_column_names(&self) -> Vec<String> {
         self.list.table.table.header.get_names()
@@ -257,10 +329,10 @@ impl ResourcesTable {
 
     /// Sets namespace for [`ResourcesTable`].
     pub fn set_namespace(&mut self, namespace: Namespace) {
-        let is_full = namespace.is_all() && self.app_data.borrow().current.scope == Scope::Namespaced;
+        let is_full = namespace.is_multiple() && self.app_data.borrow().current.scope == Scope::Namespaced;
         self.set_view(if is_full { ViewType::Full } else { ViewType::Compact });
 
-        if namespace.is_all() || !self.app_data.borrow().current.is_namespace_equal(&namespace) {
+        if namespace.is_multiple() || !self.app_data.borrow().current.is_namespace_equal(&namespace) {
             self.app_data.borrow_mut().current.set_namespace(namespace);
         }
     }
@@ -307,6 +379,14 @@ impl ResourcesTable {
             return ResponseEvent::ShowPortForwards;
         }
 
+        if self.app_data.has_binding(event, KeyCommand::SessionsOpen) {
+            return ResponseEvent::ListSessions;
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::EventStreamOpen) {
+            return ResponseEvent::ShowEventStream;
+        }
+
         let response = self.list.process_event(event);
         if response == ResponseEvent::NotHandled {
             return self.process_highlighted_resource_event(event);
@@ -399,6 +479,18 @@ impl ResourcesTable {
                 return self.process_enter_key(resource);
             }
 
+            if self.is_helm_releases() {
+                if self.app_data.has_binding(event, KeyCommand::YamlOpen) {
+                    return self.process_view_helm_release(resource, HelmReleaseView::Manifest);
+                }
+
+                return ResponseEvent::NotHandled;
+            }
+
+            if self.is_tls_certificates() {
+                return self.process_tls_certificate_event(event, resource);
+            }
+
             let is_container = self.kind_plural() == CONTAINERS;
             if self.app_data.has_binding(event, KeyCommand::EventsShow) {
                 if !is_container && resource.name() != ALL_NAMESPACES {
@@ -408,20 +500,18 @@ impl ResourcesTable {
                 return ResponseEvent::NotHandled;
             }
 
-            if self.app_data.has_binding(event, KeyCommand::InvolvedObjectShow)
-                && let Some(involved) = &resource.involved_object
-            {
-                return ResponseEvent::ViewInvolved(
-                    involved.kind.clone().into(),
-                    involved.namespace.clone().into(),
-                    ToSelectData::new(&involved.name, involved.namespace.as_option()),
-                );
+            if self.app_data.has_binding(event, KeyCommand::InvolvedObjectShow) && resource.involved_object.is_some() {
+                return ResourcesTable::process_view_involved(resource);
             }
 
             if self.app_data.has_binding(event, KeyCommand::DescribeOpen) {
                 return self.process_describe(resource);
             }
 
+            if self.app_data.has_binding(event, KeyCommand::XrayOpen) && !is_container {
+                return self.process_view_relations(resource);
+            }
+
             if self.app_data.has_binding(event, KeyCommand::YamlOpen)
                 || (self.app_data.has_binding(event, KeyCommand::YamlDecode) && self.kind_plural() == SECRETS)
             {
@@ -466,6 +556,20 @@ impl ResourcesTable {
         ResponseEvent::NotHandled
     }
 
+    fn process_tls_certificate_event(&self, event: &TuiEvent, resource: &ResourceItem) -> ResponseEvent {
+        if self.app_data.has_binding(event, KeyCommand::InvolvedObjectShow) {
+            ResourcesTable::process_view_involved(resource)
+        } else if self.app_data.has_binding(event, KeyCommand::DescribeOpen) {
+            self.process_describe(resource)
+        } else if self.app_data.has_binding(event, KeyCommand::YamlOpen)
+            || self.app_data.has_binding(event, KeyCommand::YamlDecode)
+        {
+            self.process_view_yaml(resource, self.app_data.has_binding(event, KeyCommand::YamlDecode), false)
+        } else {
+            ResponseEvent::NotHandled
+        }
+    }
+
     fn process_esc_key(&self) -> ResponseEvent {
         if self.kind_plural() == NAMESPACES {
             ResponseEvent::Handled
@@ -481,10 +585,13 @@ impl ResourcesTable {
             NODES => ResourcesTable::process_view_nodes(resource),
             JOBS => self.process_view_jobs(resource),
             DEPLOYMENTS => self.process_view_selector(resource, REPLICA_SETS),
-            SERVICES | REPLICA_SETS | STATEFUL_SETS | DAEMON_SETS => self.process_view_selector(resource, PODS),
+            SERVICES | REPLICA_SETS | STATEFUL_SETS | DAEMON_SETS | PDB => self.process_view_selector(resource, PODS),
+            HPA if resource.involved_object.is_some() => ResourcesTable::process_view_involved(resource),
             NAMESPACES => ResponseEvent::Change(PODS.to_owned(), resource.name.clone()),
             PODS => ResponseEvent::ViewContainers(resource.name.clone(), resource.namespace.clone().unwrap_or_default()),
             CONTAINERS => self.process_view_logs(resource, true, false),
+            HELM_RELEASES if self.is_helm_releases() => self.process_view_helm_release(resource, HelmReleaseView::Values),
+            TLS_CERTIFICATES if self.is_tls_certificates() => ResourcesTable::process_view_involved(resource),
             _ => self.process_view_yaml(resource, false, false),
         }
     }
@@ -525,11 +632,21 @@ impl ResourcesTable {
             .map_or(ResponseEvent::NotHandled, ResponseEvent::Describe)
     }
 
+    fn process_view_relations(&self, resource: &ResourceItem) -> ResponseEvent {
+        self.resource_ref_from(resource, false)
+            .map_or(ResponseEvent::NotHandled, ResponseEvent::ViewRelations)
+    }
+
     fn process_view_yaml(&self, resource: &ResourceItem, decode: bool, edit: bool) -> ResponseEvent {
         self.resource_ref_from(resource, false)
             .map_or(ResponseEvent::NotHandled, |r| ResponseEvent::ViewYaml(r, decode, edit))
     }
 
+    fn process_view_helm_release(&self, resource: &ResourceItem, view: HelmReleaseView) -> ResponseEvent {
+        self.resource_ref_from(resource, false)
+            .map_or(ResponseEvent::NotHandled, |r| ResponseEvent::ViewHelmRelease(r, view))
+    }
+
     fn resource_ref_from(&self, resource: &ResourceItem, prefer_container: bool) -> Option<ResourceRef> {
         if self.kind_plural() == CONTAINERS {
             if let Some(name) = self.app_data.borrow().current.resource.name.clone() {
@@ -546,15 +663,33 @@ impl ResourcesTable {
                 );
             }
         } else if resource.name() != ALL_NAMESPACES && resource.group() != NAMESPACES {
+            // TLS certificates are only a view of the secrets they are stored in
+            let kind = if self.is_tls_certificates() {
+                SECRETS.into()
+            } else {
+                self.get_kind()
+            };
             return Some(
-                ResourceRef::named(self.get_kind(), resource.group().into(), resource.name().to_owned())
-                    .with_uid(resource.uid.clone()),
+                ResourceRef::named(kind, resource.group().into(), resource.name().to_owned()).with_uid(resource.uid.clone()),
             );
         }
 
         None
     }
 
+    fn container_ref_from(&self, resource: &ResourceItem) -> Option<ContainerRef> {
+        let namespace = resource.namespace.clone().into();
+        if self.kind_plural() == CONTAINERS {
+            let pod_name = self.app_data.borrow().current.resource.name.clone()?;
+            Some(ContainerRef::simple(pod_name, namespace, Some(resource.name.clone())))
+        } else if self.kind_plural() == PODS {
+            let container = get_first_container(resource.data.as_ref()).map(String::from);
+            Some(ContainerRef::simple(resource.name.clone(), namespace, container))
+        } else {
+            None
+        }
+    }
+
     fn process_view_events(&self, resource: &ResourceItem) -> ResponseEvent {
         let scope = ScopeData {
             header: self.app_data.borrow().current.scope.clone(),
@@ -564,6 +699,19 @@ impl ResourcesTable {
         ResponseEvent::ViewScoped(EVENTS.to_owned(), resource.namespace.clone(), ToSelectData::None, scope)
     }
 
+    /// Returns response that navigates to the involved object of the `resource`.
+    pub fn process_view_involved(resource: &ResourceItem) -> ResponseEvent {
+        let Some(involved) = &resource.involved_object else {
+            return ResponseEvent::NotHandled;
+        };
+
+        ResponseEvent::ViewInvolved(
+            involved.kind.clone().into(),
+            involved.namespace.clone().into(),
+            ToSelectData::new(&involved.name, involved.namespace.as_option()),
+        )
+    }
+
     fn process_view_nodes(resource: &ResourceItem) -> ResponseEvent {
         let filter = ResourceRefFilter::node(resource.name.clone(), &resource.name);
         ResponseEvent::ViewScoped(
@@ -1,9 +1,16 @@
-use b4n_common::NotificationSink;
+use b4n_common::expr::ServerSelectors;
+use b4n_common::{DEFAULT_MESSAGE_DURATION, NotificationSink};
 use b4n_config::keys::KeyCommand;
-use b4n_kube::{CONTAINERS, EVENTS, Kind, NODES, Namespace, ObserverResult, PODS, Port, ResourceRef};
+use b4n_config::{Bookmark, BookmarkItem};
+use b4n_kube::helm::HelmReleaseView;
+use b4n_kube::{
+    ALL_NAMESPACES, CONFIG_MAPS, CONTAINERS, EVENTS, Kind, NODES, Namespace, ObserverResult, PODS, PVC, Port, ResourceRef,
+    ResourceRefFilter, SECRETS, SERVICE_ACCOUNTS, TLS_CERTIFICATES,
+};
 use b4n_list::Row;
+use b4n_tasks::commands::{ResourceUser, ResourceUsersError, SearchIndexItem};
 use b4n_tui::table::{Table, ViewType};
-use b4n_tui::widgets::{ActionsList, ActionsListBuilder, Dialog, TextBox};
+use b4n_tui::widgets::{ActionsList, ActionsListBuilder, Dialog, PaletteCommand, PaletteCompletions, PaletteVerb, TextBox};
 use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, ScopeData, ToSelectData, TuiEvent};
 use delegate::delegate;
 use kube::{config::NamedContext, discovery::Scope};
@@ -11,9 +18,9 @@ use ratatui::layout::Position;
 use ratatui::{Frame, layout::Rect};
 use std::{collections::HashMap, path::PathBuf, rc::Rc};
 
-use crate::core::{PreviousData, SharedAppData, SharedAppDataExt, SharedBgWorker};
+use crate::core::{AppData, PreviousData, SharedAppData, SharedAppDataExt, SharedBgWorker};
 use crate::kube::extensions::ActionsListBuilderExt;
-use crate::kube::resources::{ResourceItem, ResourcesList, node, pod};
+use crate::kube::resources::{ResourceItem, ResourcesList, node, pod, tls_certificate};
 use crate::ui::views::resources::table::ResourcesTable;
 use crate::ui::views::resources::{NextRefreshActions, dialogs, menus};
 use crate::ui::views::{View, common, transfer};
@@ -28,6 +35,7 @@ pub struct ResourcesView {
     last_ports_generation: u16,
     last_mouse_click: Option<Position>,
     modal: Dialog,
+    delete_users_check: Option<String>,
     command_palette: CommandPalette,
     filter: Filter,
     namespace_picker: NamespaceSelector,
@@ -53,6 +61,7 @@ impl ResourcesView {
             last_ports_generation,
             last_mouse_click: None,
             modal: Dialog::default(),
+            delete_users_check: None,
             command_palette: CommandPalette::default(),
             filter,
             namespace_picker,
@@ -176,9 +185,38 @@ impl ResourcesView {
         {
             self.modal = dialogs::new_delete_dialog(&self.app_data, self.last_mouse_click.take());
             self.modal.show();
+            self.check_delete_users();
         }
     }
 
+    /// Adds warning to the delete dialog if the resources marked for deletion are still in use.\
+    /// **Note** that it returns `false` if the `command_id` is not associated with the delete dialog.
+    pub fn process_delete_users(&mut self, command_id: &str, result: &Result<Vec<ResourceUser>, ResourceUsersError>) -> bool {
+        if self.delete_users_check.as_deref() != Some(command_id) {
+            return false;
+        }
+
+        self.delete_users_check = None;
+        if self.modal.is_visible
+            && let Ok(users) = result
+            && !users.is_empty()
+        {
+            self.modal.set_message(dialogs::get_delete_message(users));
+        }
+
+        true
+    }
+
+    fn check_delete_users(&mut self) {
+        let kind = self.table.get_kind();
+        if kind.has_group() || !matches!(kind.name(), CONFIG_MAPS | SECRETS | PVC | SERVICE_ACCOUNTS) {
+            return;
+        }
+
+        let resources = self.table.get_selected_resources_ref(false);
+        self.delete_users_check = self.worker.borrow_mut().get_resource_users(resources);
+    }
+
     /// Shows stop port forwarding rules dialog if anything is selected.
     pub fn ask_stop_port_forwards(&mut self) {
         if let Some(resource) = self.table.list.table.get_highlighted_item_name().map(String::from) {
@@ -198,6 +236,64 @@ impl ResourcesView {
         }
     }
 
+    /// Shows dialog for creating debug copy of the highlighted pod.
+    pub fn ask_copy_pod(&mut self) {
+        if let Some(resource) = self.table.get_resource_ref(false) {
+            let tags = self.table.get_resource_tags();
+            self.modal = dialogs::new_copy_pod_dialog(&self.app_data, &resource, &tags);
+            self.modal.show();
+        }
+    }
+
+    /// Shows command palette that asks for the command to execute in the selected containers.
+    pub fn ask_exec_command(&mut self) {
+        let containers = self.table.get_exec_containers();
+        if containers.is_empty() {
+            self.footer_tx.show_info(
+                "There are no running containers to execute command in",
+                DEFAULT_MESSAGE_DURATION,
+            );
+        } else {
+            self.command_palette = menus::build_exec_command_palette(&self.app_data, containers);
+            self.command_palette.show();
+        }
+    }
+
+    /// Shows confirmation dialog for opening shell on the highlighted node.
+    pub fn ask_node_shell(&mut self) {
+        if let Some(node_name) = self.table.list.table.get_highlighted_item_name() {
+            let namespace = self.app_data.borrow().current.namespace.clone();
+            self.modal = dialogs::new_node_shell_dialog(&self.app_data, node_name, &namespace);
+            self.modal.show();
+        }
+    }
+
+    /// Shows dialog for editing labels and annotations of the selected or highlighted resources.
+    pub fn ask_edit_metadata(&mut self) {
+        let count = self.table.get_target_resources_ref().len();
+        if count > 0 {
+            self.modal = dialogs::new_edit_metadata_dialog(&self.app_data, count);
+            self.modal.show();
+        }
+    }
+
+    /// Shows command palette that allows to export resources as clean manifests.
+    pub fn ask_export_resources(&mut self) {
+        let targets = self.table.get_scoped_resources_ref();
+        let path = std::env::current_dir()
+            .unwrap_or(PathBuf::from("."))
+            .join(format!("{}.yaml", self.table.kind_plural()));
+        self.command_palette = menus::build_export_palette(&self.app_data, targets, &path.to_string_lossy());
+        self.command_palette.show();
+    }
+
+    /// Shows command palette that allows to query resources using JSONPath expression.
+    pub fn ask_query_resources(&mut self) {
+        let targets = self.table.get_scoped_resources_ref();
+        self.command_palette = menus::build_query_palette(&self.app_data, targets);
+        self.command_palette.show();
+    }
+
     /// Shows transfer file dialog.
     pub fn ask_transfer_file(&mut self, is_download: bool) {
         if self.table.is_resource_running()
@@ -209,6 +305,20 @@ impl ResourcesView {
         }
     }
 
+    /// Cancels all file transfers that are in progress.
+    pub fn cancel_transfers(&mut self) {
+        let count = self.worker.borrow_mut().cancel_transfers();
+        if count > 0 {
+            let msg = if count == 1 {
+                "File transfer has been cancelled".to_owned()
+            } else {
+                format!("{count} file transfers have been cancelled")
+            };
+            tracing::info!("{}", msg);
+            self.footer_tx.show_info(msg, 3_000);
+        }
+    }
+
     /// Displays a list of available contexts to choose from.
     pub fn show_contexts_list(&mut self, list: &[NamedContext]) {
         let actions_list = ActionsListBuilder::from_kube_contexts(list).build(None);
@@ -218,6 +328,14 @@ impl ResourcesView {
         self.command_palette.show();
     }
 
+    /// Displays a palette that searches the indexed resources of all kinds.
+    pub fn show_search_list(&mut self, items: &[SearchIndexItem]) {
+        let namespace = self.app_data.borrow().current.namespace.as_str().to_owned();
+        let actions_list = ActionsListBuilder::from_search_index(items, &namespace).build(None);
+        self.command_palette = CommandPalette::new(Rc::clone(&self.app_data), actions_list, 80).with_prompt("search");
+        self.command_palette.show();
+    }
+
     /// Displays a list of available themes to choose from.
     pub fn show_themes_list(&mut self, list: Vec<PathBuf>) {
         let actions_list = ActionsListBuilder::from_paths(list).build(None);
@@ -296,6 +414,22 @@ impl ResourcesView {
                     "inject" => Some(self.table.get_resource_ref(false).map_or(ResponseEvent::Handled, |resource| {
                         dialogs::build_inject_container_response(&self.modal, resource)
                     })),
+                    "copy_pod" => Some(self.table.get_resource_ref(false).map_or(ResponseEvent::Handled, |resource| {
+                        dialogs::build_copy_pod_response(&self.modal, resource)
+                    })),
+                    "edit_metadata" => Some(dialogs::build_edit_metadata_response(
+                        &self.modal,
+                        self.table.get_target_resources_ref(),
+                    )),
+                    "node_shell" => Some(
+                        self.table
+                            .list
+                            .table
+                            .get_highlighted_item_name()
+                            .map_or(ResponseEvent::Handled, |node| {
+                                dialogs::build_node_shell_response(&self.modal, node.to_owned())
+                            }),
+                    ),
                     _ => Some(ResponseEvent::Handled),
                 },
 
@@ -314,6 +448,10 @@ impl ResourcesView {
                 self.filter.update_pinned_filter();
             }
 
+            if !self.filter.is_visible {
+                return Some(self.get_server_filter_change().unwrap_or(result));
+            }
+
             return Some(result);
         }
 
@@ -365,6 +503,7 @@ impl ResourcesView {
                 "show_logs" => self.table.process_event(&TuiEvent::Command(KeyCommand::LogsOpen)),
                 "show_plogs" => self.table.process_event(&TuiEvent::Command(KeyCommand::PreviousLogsOpen)),
                 "describe" => self.table.process_event(&TuiEvent::Command(KeyCommand::DescribeOpen)),
+                "show_relations" => self.table.process_event(&TuiEvent::Command(KeyCommand::XrayOpen)),
                 "inject" => {
                     self.last_mouse_click = event.position();
                     self.process_event(&TuiEvent::Command(KeyCommand::ContainerInject))
@@ -377,8 +516,51 @@ impl ResourcesView {
                     self.last_mouse_click = event.position();
                     self.process_event(&TuiEvent::Command(KeyCommand::TransferTo))
                 },
+                "cancel_transfers" => {
+                    self.cancel_transfers();
+                    ResponseEvent::Handled
+                },
                 "attach" => self.table.process_event(&TuiEvent::Command(KeyCommand::ContainerAttach)),
                 "open_shell" => self.table.process_event(&TuiEvent::Command(KeyCommand::ShellOpen)),
+                "exec_selected" => {
+                    self.ask_exec_command();
+                    ResponseEvent::Handled
+                },
+                "copy_pod" => {
+                    self.last_mouse_click = event.position();
+                    self.process_event(&TuiEvent::Command(KeyCommand::PodCopy))
+                },
+                "node_shell" => {
+                    self.ask_node_shell();
+                    ResponseEvent::Handled
+                },
+                "export" => {
+                    self.ask_export_resources();
+                    ResponseEvent::Handled
+                },
+                "query" => {
+                    self.ask_query_resources();
+                    ResponseEvent::Handled
+                },
+                "tls_scan" => self.scan_tls_certificates(),
+                "bookmark_add" => {
+                    self.ask_bookmark_name();
+                    ResponseEvent::Handled
+                },
+                "edit_metadata_dialog" => {
+                    self.ask_edit_metadata();
+                    ResponseEvent::Handled
+                },
+                "trigger_cron_job" => self
+                    .table
+                    .get_resource_ref(false)
+                    .map_or(ResponseEvent::Handled, ResponseEvent::TriggerCronJob),
+                "helm_values" => self.table.process_helm_release(HelmReleaseView::Values),
+                "helm_manifest" => self.table.process_helm_release(HelmReleaseView::Manifest),
+                "helm_notes" => self.table.process_helm_release(HelmReleaseView::Notes),
+                "helm_history" => self.table.process_helm_release(HelmReleaseView::History),
+                "suspend_cron_jobs" => ResponseEvent::SuspendCronJobs(self.table.get_target_resources_ref(), true),
+                "resume_cron_jobs" => ResponseEvent::SuspendCronJobs(self.table.get_target_resources_ref(), false),
                 "port_forward" => {
                     self.last_mouse_click = event.position();
                     self.table.process_event(&TuiEvent::Command(KeyCommand::PortForwardsCreate))
@@ -388,6 +570,7 @@ impl ResourcesView {
                     self.ask_stop_port_forwards();
                     ResponseEvent::Handled
                 },
+                "palette_command" => self.process_palette_command(),
                 "new_clone" => self.create_new_resource(true, false),
                 "new_full" => self.create_new_resource(false, true),
                 "new_minimal" => self.create_new_resource(false, false),
@@ -402,19 +585,26 @@ impl ResourcesView {
         if !self.app_data.borrow().is_connected() {
             let actions = ActionsListBuilder::default()
                 .with_resources_actions(false)
+                .with_bookmarks(&self.app_data.borrow().bookmarks)
                 .build(Some(&self.app_data.borrow().key_bindings));
 
-            self.open_command_palette(actions);
+            self.open_command_palette(actions, None);
             return;
         }
 
-        let actions = menus::build_resources_actions(&self.app_data, &self.table);
-        self.open_command_palette(actions);
+        let has_transfers = self.worker.borrow().has_active_transfers();
+        let actions = menus::build_resources_actions(&self.app_data, &self.table, has_transfers);
+        self.open_command_palette(actions, Some(self.get_palette_completions()));
     }
 
-    fn open_command_palette(&mut self, actions: ActionsList) {
-        self.command_palette =
+    fn open_command_palette(&mut self, actions: ActionsList, completions: Option<PaletteCompletions>) {
+        let mut palette =
             CommandPalette::new(Rc::clone(&self.app_data), actions, 65).with_highlighted_position(self.last_mouse_click.take());
+        if let Some(completions) = completions {
+            palette = palette.with_commands(completions, "palette_command");
+        }
+
+        self.command_palette = palette;
         self.command_palette.show();
         self.footer_tx.hide_hint();
     }
@@ -466,12 +656,18 @@ impl ResourcesView {
     }
 
     pub fn remember_current_resource(&mut self) {
+        let previous = self.get_previous_data();
+        self.app_data.borrow_mut().previous.push(previous);
+    }
+
+    /// Returns data needed to restore the currently displayed resources list.
+    pub fn get_previous_data(&self) -> PreviousData {
         let highlighted = self.table.list.table.get_highlighted_item_name_and_group();
         let highlighted = highlighted.map_or(ToSelectData::None, |(i, g)| ToSelectData::Some(i.to_owned(), g.to_owned()));
         let header = self.table.header.get_scope();
         let namespace = self.app_data.borrow().current.namespace.clone();
         let resource = self.app_data.borrow().current.resource.clone();
-        self.app_data.borrow_mut().previous.push(PreviousData {
+        PreviousData {
             list: self.scope().clone(),
             header,
             highlighted,
@@ -480,7 +676,240 @@ impl ResourcesView {
             filter: self.table.list.table.filter().map(String::from),
             sort_info: self.table.list.table.table.header.sort_info(),
             offset: self.table.list.table.offset(),
+        }
+    }
+
+    /// Returns response that pushes label and field queries of the current filter down to the API server.\
+    /// **Note** that it returns `None` if the server-side filter for the observed resources does not need to change.
+    fn get_server_filter_change(&mut self) -> Option<ResponseEvent> {
+        let current = self.app_data.borrow().current.resource.clone();
+        if current.is_filtered() || current.is_container() {
+            return None;
+        }
+
+        let filter = ServerSelectors::parse(self.filter.value())
+            .ok()
+            .flatten()
+            .map(|s| ResourceRefFilter::server_side(&s));
+        if current.filter == filter {
+            return None;
+        }
+
+        let highlight_item = self
+            .table
+            .list
+            .table
+            .get_highlighted_item_name_and_group()
+            .map(|(name, group)| (name.to_owned(), group.to_owned()));
+        self.table.set_next_refresh(NextRefreshActions {
+            highlight_item,
+            apply_filter: self.filter.to_option(),
+            sort_info: Some(self.table.list.table.table.header.sort_info()),
+            ..Default::default()
+        });
+
+        Some(ResponseEvent::ChangeServerFilter(filter))
+    }
+
+    /// Returns kind names and values that can be used to complete arguments of the command palette commands.
+    fn get_palette_completions(&self) -> PaletteCompletions {
+        let data = self.app_data.borrow();
+        PaletteCompletions {
+            kinds: get_command_kinds(&data),
+            namespaces: data.namespaces.clone(),
+            names: self.table.list.table.get_names(),
+            contexts: data.history.context_names(),
+        }
+    }
+
+    /// Executes the command with arguments typed into the command palette.
+    fn process_palette_command(&mut self) -> ResponseEvent {
+        let kinds = get_command_kinds(&self.app_data.borrow());
+        let Ok(Some(command)) = PaletteCommand::parse(self.command_palette.selected(), &kinds) else {
+            return ResponseEvent::Handled;
+        };
+
+        match command.verb {
+            PaletteVerb::Context => ResponseEvent::ChangeContext(command.target.unwrap_or_default(), command.namespace),
+            PaletteVerb::Namespace => ResponseEvent::ChangeNamespace(command.target.unwrap_or_default()),
+            PaletteVerb::Logs => self.process_logs_command(command),
+            PaletteVerb::Yaml | PaletteVerb::Describe => self.process_resource_command(command, &kinds),
+            PaletteVerb::Resources => self.process_resources_command(command, &kinds),
+        }
+    }
+
+    fn process_resources_command(&mut self, command: PaletteCommand, kinds: &[String]) -> ResponseEvent {
+        let kind = self.resolve_command_kind(&command.name, kinds);
+        let namespace = if command.all_namespaces {
+            ALL_NAMESPACES.to_owned()
+        } else {
+            let current = self.app_data.borrow().current.namespace.as_str().to_owned();
+            command.namespace.unwrap_or(current)
+        };
+        let group = if command.all_namespaces {
+            String::new()
+        } else {
+            namespace.clone()
+        };
+        let highlight_item = command.target.map(|name| (name, group));
+
+        let is_same = {
+            let data = self.app_data.borrow();
+            data.current.is_kind_equal(&kind)
+                && data.current.is_namespace_equal(&namespace.as_str().into())
+                && data.current.resource.filter.is_none()
+        };
+        if is_same {
+            if let Some(filter) = command.filter {
+                self.filter.set_value(filter);
+                self.table.set_filter(self.filter.value());
+            }
+
+            if let Some((name, group)) = highlight_item {
+                self.table.list.table.highlight_item_by_name_and_group(&name, &group);
+            }
+
+            return self.get_server_filter_change().unwrap_or(ResponseEvent::Handled);
+        }
+
+        let to_select = highlight_item
+            .clone()
+            .map_or(ToSelectData::None, |(name, group)| ToSelectData::Some(name, group));
+        self.table.set_next_refresh(NextRefreshActions {
+            highlight_item,
+            apply_filter: command.filter,
+            clear_header_scope: true,
+            ..Default::default()
+        });
+
+        ResponseEvent::ChangeAndSelect(kind.as_str().to_owned(), namespace, to_select)
+    }
+
+    fn process_logs_command(&self, command: PaletteCommand) -> ResponseEvent {
+        let name = command.target.unwrap_or_default();
+        let namespace = command
+            .namespace
+            .map_or_else(|| self.get_command_namespace(PODS, &name), Namespace::from);
+        let containers = (command.container.is_none() && self.kind_plural() == PODS)
+            .then(|| self.table.list.table.get_resource(&name, &namespace))
+            .flatten()
+            .and_then(|r| r.data.as_ref().map(|d| d.tags.to_vec()));
+        let resource = match command.container {
+            Some(container) => ResourceRef::container(name, namespace, container),
+            None => ResourceRef::named(PODS.into(), namespace, name),
+        };
+
+        if command.previous {
+            ResponseEvent::ViewPreviousLogs(resource, containers)
+        } else {
+            ResponseEvent::ViewLogs(resource, containers)
+        }
+    }
+
+    fn process_resource_command(&self, command: PaletteCommand, kinds: &[String]) -> ResponseEvent {
+        let target = command.target.unwrap_or_default();
+        let (kind, name) = match target.split_once('/') {
+            Some((kind, name)) => (self.resolve_command_kind(kind, kinds), name.to_owned()),
+            None => (self.table.get_kind(), target),
+        };
+        let namespace = command
+            .namespace
+            .map_or_else(|| self.get_command_namespace(kind.name(), &name), Namespace::from);
+        let resource = ResourceRef::named(kind, namespace, name);
+
+        if command.verb == PaletteVerb::Yaml {
+            ResponseEvent::ViewYaml(resource, false, false)
+        } else {
+            ResponseEvent::Describe(resource)
+        }
+    }
+
+    /// Resolves kind typed into the command palette, it can be a kind name, its alias, singular form or prefix.
+    fn resolve_command_kind(&self, name: &str, kinds: &[String]) -> Kind {
+        let name = name.to_ascii_lowercase();
+        let data = self.app_data.borrow();
+        let kind = data
+            .config
+            .aliases
+            .iter()
+            .find(|(_, aliases)| aliases.split(',').any(|a| a == name))
+            .map(|(kind, _)| kind.clone())
+            .or_else(|| kinds.iter().find(|k| **k == name).cloned())
+            .or_else(|| kinds.iter().filter(|k| k.starts_with(&name)).min_by_key(|k| k.len()).cloned())
+            .unwrap_or(name);
+
+        self.worker.borrow().ensure_kind_is_plural(kind.into())
+    }
+
+    /// Returns namespace for the `name` resource typed into the command palette.\
+    /// **Note** that for all namespaces it is taken from the matching resource on the current list.
+    fn get_command_namespace(&self, kind: &str, name: &str) -> Namespace {
+        let current = self.app_data.borrow().current.namespace.clone();
+        if !current.is_all() || self.kind_plural() != kind {
+            return current;
+        }
+
+        self.table
+            .list
+            .table
+            .find_resource(name)
+            .map_or(current, |r| r.namespace.as_deref().into())
+    }
+
+    fn scan_tls_certificates(&mut self) -> ResponseEvent {
+        let window = self.app_data.borrow().config.certificates.expiry_window.unwrap_or(30);
+        self.table.set_next_refresh(NextRefreshActions {
+            apply_filter: Some(format!("c:days<={window}")),
+            sort_info: Some((tls_certificate::DAYS_COLUMN_NO, false)),
+            ..Default::default()
         });
+
+        ResponseEvent::Change(TLS_CERTIFICATES.to_owned(), ALL_NAMESPACES.to_owned())
+    }
+
+    fn ask_bookmark_name(&mut self) {
+        if self.kind_plural() == CONTAINERS {
+            return;
+        }
+
+        let bookmark = self.get_current_bookmark();
+        self.command_palette = menus::build_bookmark_palette(&self.app_data, bookmark);
+        self.command_palette.show();
+    }
+
+    /// Returns bookmark (without a name) for the currently displayed resources.
+    fn get_current_bookmark(&self) -> Bookmark {
+        let data = self.app_data.borrow();
+        let (sort_column, sort_descending) = self.table.list.table.table.header.sort_info();
+        let highlighted = self
+            .table
+            .list
+            .table
+            .get_highlighted_item_name_and_group()
+            .map(|(name, group)| BookmarkItem {
+                name: name.to_owned(),
+                group: group.to_owned(),
+            });
+
+        Bookmark {
+            name: String::new(),
+            context: data.current.context.clone(),
+            namespace: data.current.namespace.as_str().to_owned(),
+            kind: data.current.resource.kind.as_str().to_owned(),
+            filter: self.table.list.table.filter().map(String::from),
+            sort_column: Some(sort_column),
+            sort_descending,
+            highlighted,
+        }
+    }
+
+    /// Returns response that opens the bookmark bound to the key command from the `event`.
+    fn get_bookmark_to_open(&self, event: &TuiEvent) -> Option<ResponseEvent> {
+        let index = KeyCommand::BOOKMARKS
+            .iter()
+            .position(|command| self.app_data.has_binding(event, *command))?;
+        let name = self.app_data.borrow().bookmarks.get_at(index)?.name.clone();
+        Some(ResponseEvent::OpenBookmark(name))
     }
 
     fn handle_previous_resource_change(&mut self) -> ResponseEvent {
@@ -607,6 +1036,10 @@ impl View for ResourcesView {
             return result;
         }
 
+        if let Some(response) = self.get_bookmark_to_open(event) {
+            return response;
+        }
+
         if !self.app_data.borrow().is_connected() {
             if self.app_data.has_binding(event, KeyCommand::CommandPaletteOpen)
                 || event.is_in(MouseEventKind::RightClick, self.table.list.area)
@@ -651,7 +1084,7 @@ impl View for ResourcesView {
         if self.filter.is_reset_filter_event(event) {
             self.filter.reset();
             self.table.set_filter("");
-            return ResponseEvent::Handled;
+            return self.get_server_filter_change().unwrap_or(ResponseEvent::Handled);
         }
 
         if self.app_data.has_binding(event, KeyCommand::FilterOpen) {
@@ -690,7 +1123,34 @@ impl View for ResourcesView {
             return ResponseEvent::Handled;
         }
 
+        if self.app_data.has_binding(event, KeyCommand::BookmarkAdd) {
+            self.ask_bookmark_name();
+            return ResponseEvent::Handled;
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::GlobalSearchOpen) {
+            return ResponseEvent::ShowResourcesSearch;
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::TransferCancelAll) && self.worker.borrow().has_active_transfers() {
+            self.cancel_transfers();
+            return ResponseEvent::Handled;
+        }
+
+        if (is_highlighted || is_selected)
+            && (self.kind_plural() == PODS || self.kind_plural() == CONTAINERS)
+            && self.app_data.has_binding(event, KeyCommand::ExecSelected)
+        {
+            self.ask_exec_command();
+            return ResponseEvent::Handled;
+        }
+
         if is_highlighted && self.kind_plural() == PODS {
+            if self.app_data.has_binding(event, KeyCommand::PodCopy) {
+                self.ask_copy_pod();
+                return ResponseEvent::Handled;
+            }
+
             if self.app_data.has_binding(event, KeyCommand::ContainerInject) {
                 self.ask_inject_container();
                 return ResponseEvent::Handled;
@@ -707,6 +1167,11 @@ impl View for ResourcesView {
             }
         }
 
+        if is_highlighted && self.kind_plural() == NODES && self.app_data.has_binding(event, KeyCommand::ShellOpen) {
+            self.ask_node_shell();
+            return ResponseEvent::Handled;
+        }
+
         let result = self.table.process_event(event);
         if result == ResponseEvent::ViewPreviousResource {
             return self.handle_previous_resource_change();
@@ -726,3 +1191,10 @@ impl View for ResourcesView {
         self.file_picker.draw(frame, area);
     }
 }
+
+/// Returns kind names and their aliases that can be used as the command palette commands.
+fn get_command_kinds(data: &AppData) -> Vec<String> {
+    let kinds = data.kinds.as_deref().unwrap_or_default().iter().map(|k| k.name().to_owned());
+    let aliases = data.config.aliases.values().flat_map(|a| a.split(',')).map(String::from);
+    kinds.chain(aliases).collect()
+}
@@ -1,10 +1,11 @@
 use b4n_common::{DEFAULT_ERROR_DURATION, NotificationSink};
 use b4n_config::Plugin;
 use b4n_config::keys::KeyCommand;
-use b4n_kube::ResourceRef;
+use b4n_kube::{Namespace, ResourceRef};
 use b4n_tui::widgets::{ActionItem, ActionsListBuilder, Button, Dialog};
 use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, TuiEvent};
 use crossterm::event::{KeyCode, KeyModifiers};
+use k8s_openapi::jiff::Timestamp;
 use kube::api::TerminalSize;
 use ratatui::{Frame, layout::Rect};
 use std::rc::Rc;
@@ -18,7 +19,7 @@ use crate::ui::presentation::ScreenSelection;
 use crate::ui::views::shell::cmd::bridge::CmdBridge;
 use crate::ui::views::shell::keys::{encode_key, encode_mouse};
 use crate::ui::views::shell::terminal::{CursorShapeTracker, FrameExt, RectExt};
-use crate::ui::views::{ESCAPE_SEQUENCE_TIMEOUT, EscapeSequenceTracker, ScreenExt, get_layout_with_header};
+use crate::ui::views::{ESCAPE_SEQUENCE_TIMEOUT, EscapeSequenceTracker, ScreenExt, SessionInfo, get_layout_with_header};
 use crate::ui::widgets::CommandPalette;
 use crate::ui::{presentation::ContentHeader, views::View};
 
@@ -31,18 +32,23 @@ pub struct CmdView {
     bridge: CmdBridge,
     parser: Arc<RwLock<vt100::Parser>>,
     size: TerminalSize,
+    name: String,
     command: String,
+    namespace: Namespace,
+    resource: Option<String>,
     scrollback_rows: usize,
     modal: Dialog,
     command_palette: CommandPalette,
     selection: ScreenSelection,
     area: Rect,
     alt_mode: EscapeSequenceTracker,
+    last_activity: Timestamp,
     auto_mouse: bool,
     pin_to_top: bool,
     keep_output: bool,
     keep_error: bool,
     is_finished: bool,
+    is_foreground: bool,
     is_app_mode: bool,
     is_mouse_enabled: bool,
     own_cursor: bool,
@@ -61,7 +67,7 @@ impl CmdView {
         workspace: Rect,
     ) -> Self {
         let mut header = ContentHeader::new(Rc::clone(&app_data), false);
-        header.set_title(plugin.name);
+        header.set_title(plugin.name.clone());
         header.set_icon('');
 
         let area = get_layout_with_header(workspace)[1];
@@ -83,18 +89,23 @@ impl CmdView {
             bridge,
             parser,
             size: area.to_terminal_size(),
+            name: plugin.name,
             command,
+            namespace: Namespace::default(),
+            resource: None,
             scrollback_rows: 0,
             modal: Dialog::default(),
             command_palette: CommandPalette::default(),
             selection,
             area,
             alt_mode: EscapeSequenceTracker::new(ESCAPE_SEQUENCE_TIMEOUT),
+            last_activity: Timestamp::now(),
             auto_mouse: plugin.interactive && plugin.auto_mouse,
             pin_to_top: plugin.keep_output && plugin.pin_to_top,
             keep_output: plugin.keep_output,
             keep_error: plugin.keep_error,
             is_finished: false,
+            is_foreground: true,
             is_app_mode: false,
             is_mouse_enabled: false,
             own_cursor,
@@ -108,10 +119,13 @@ impl CmdView {
         if let Some(resource) = resource_ref {
             self.header
                 .set_data(resource.namespace.clone(), resource.kind.clone(), resource.name.clone(), None);
+            self.namespace = resource.namespace.clone();
+            self.resource = resource.name.clone();
         } else {
             let data = &self.app_data.borrow().current;
             self.header
                 .set_data(data.namespace.clone(), data.resource.kind.clone(), None, None);
+            self.namespace = data.namespace.clone();
         }
         self
     }
@@ -142,6 +156,7 @@ impl CmdView {
         } else {
             builder = builder
                 .with_menu_action(ActionItem::menu(2, "󰆒 paste", "paste"))
+                .with_menu_action(ActionItem::menu(99, "󰒲 send to background", "background"))
                 .with_menu_action(ActionItem::menu(100, " close", "close"));
         }
 
@@ -176,6 +191,7 @@ impl CmdView {
                 "mouse_on" => self.enable_mouse(true),
                 "mouse_off" => self.enable_mouse(false),
                 "close" => self.ask_close_forcibly(),
+                "background" => ResponseEvent::SendToBackground,
                 "back" => ResponseEvent::Cancelled,
                 _ => response,
             };
@@ -307,6 +323,7 @@ impl CmdView {
             && let Some(bytes) = encode_key(key.code, key.modifiers, is_app_mode)
         {
             self.bridge.send(bytes);
+            self.last_activity = Timestamp::now();
 
             if self.scrollback_rows > 0 {
                 self.reset_scrollback(false);
@@ -318,7 +335,36 @@ impl CmdView {
 }
 
 impl View for CmdView {
+    fn session(&self) -> Option<SessionInfo> {
+        Some(SessionInfo {
+            title: self.name.clone(),
+            namespace: self.namespace.clone(),
+            name: self.resource.clone(),
+            container: None,
+            last_activity: self.last_activity,
+            is_running: self.bridge.is_running(),
+        })
+    }
+
+    fn set_foreground(&mut self, is_foreground: bool) {
+        self.is_foreground = is_foreground;
+        self.app_data.disable_command(KeyCommand::ApplicationExit, is_foreground);
+        self.app_data.disable_command(KeyCommand::MouseSupportToggle, is_foreground);
+        if is_foreground {
+            set_hint(&self.app_data, &self.footer_tx, false);
+        } else {
+            self.command_palette.hide();
+            self.selection.reset();
+            self.cursor_shape.reset_shape();
+            self.footer_tx.hide_hint();
+        }
+    }
+
     fn process_tick(&mut self) -> ResponseEvent {
+        if !self.is_foreground {
+            return ResponseEvent::Handled;
+        }
+
         self.cursor_shape.sync_shape(self.bridge.cursor_shape());
 
         if !self.is_finished && self.bridge.is_finished() {
@@ -397,6 +443,10 @@ impl View for CmdView {
             if self.app_data.has_binding(event, KeyCommand::ShellEscape) {
                 return self.ask_close_forcibly();
             }
+
+            if self.app_data.has_binding(event, KeyCommand::SessionBackground) && self.bridge.is_running() {
+                return ResponseEvent::SendToBackground;
+            }
         }
 
         if self.app_data.has_binding(event, KeyCommand::ShellEscape) {
@@ -486,16 +536,21 @@ impl View for CmdView {
 impl Drop for CmdView {
     fn drop(&mut self) {
         self.bridge.stop();
-        self.app_data.disable_command(KeyCommand::ApplicationExit, false);
-        self.app_data.disable_command(KeyCommand::MouseSupportToggle, false);
-        self.footer_tx.hide_hint();
+        if self.is_foreground {
+            self.app_data.disable_command(KeyCommand::ApplicationExit, false);
+            self.app_data.disable_command(KeyCommand::MouseSupportToggle, false);
+            self.footer_tx.hide_hint();
+        }
     }
 }
 
 fn set_hint(app_data: &SharedAppData, footer_tx: &NotificationSink, is_alt_mode: bool) {
     let key = app_data.get_key_name(KeyCommand::ShellEscape).to_ascii_uppercase();
     if is_alt_mode {
-        footer_tx.show_hint(format!(" Escape mode: press ␝{key}␝ to close"));
+        let background = app_data.get_key_name(KeyCommand::SessionBackground).to_ascii_uppercase();
+        footer_tx.show_hint(format!(
+            " Escape mode: press ␝{key}␝ to close, ␝{background}␝ to send to background"
+        ));
     } else {
         footer_tx.show_hint(format!(" Double press ␝{key}␝ to activate escape mode"));
     }
@@ -27,6 +27,7 @@ pub struct ShellBridge {
     is_attach: bool,
     was_started: bool,
     shell: Option<String>,
+    command: Option<Vec<String>>,
 }
 
 impl ShellBridge {
@@ -44,9 +45,16 @@ impl ShellBridge {
             is_attach,
             was_started: false,
             shell: None,
+            command: None,
         }
     }
 
+    /// Sets command that is executed instead of the shell.
+    pub fn with_command(mut self, command: Vec<String>) -> Self {
+        self.command = Some(command);
+        self
+    }
+
     /// Starts new shell process.\
     /// **Note** that it stops the old task if it is running.
     pub fn start(&mut self, client: Client, pod: ContainerRef, shell: impl Into<String>, size: TerminalSize) {
@@ -64,6 +72,7 @@ impl ShellBridge {
 
         let _shell = shell.into();
         self.shell = Some(_shell.clone());
+        let _command = self.command.clone().unwrap_or_else(|| vec![_shell]);
 
         self.state.set_error(false);
         self.state.set_size(size.width, size.height);
@@ -80,7 +89,7 @@ impl ShellBridge {
             let attach_result = if _is_attach {
                 api.attach(&pod.name, &attach_params).await
             } else {
-                api.exec(&pod.name, vec![_shell], &attach_params).await
+                api.exec(&pod.name, _command, &attach_params).await
             };
 
             let mut attached = match attach_result {
@@ -1,9 +1,11 @@
 use b4n_common::{DEFAULT_ERROR_DURATION, NotificationSink};
 use b4n_config::keys::KeyCommand;
 use b4n_kube::{ContainerRef, PODS, ResourceTag};
+use b4n_tasks::commands::NODE_SHELL_COMMAND;
 use b4n_tui::widgets::{ActionItem, ActionsListBuilder, Button, Dialog, TextBox};
 use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, TuiEvent};
 use crossterm::event::{KeyCode, KeyModifiers};
+use k8s_openapi::jiff::Timestamp;
 use kube::{Client, api::TerminalSize};
 use ratatui::layout::Position;
 use ratatui::{Frame, layout::Rect};
@@ -18,7 +20,9 @@ use crate::ui::presentation::ScreenSelection;
 use crate::ui::views::shell::keys::{encode_key, encode_mouse};
 use crate::ui::views::shell::kube::bridge::ShellBridge;
 use crate::ui::views::shell::terminal::{CursorShapeTracker, FrameExt, RectExt};
-use crate::ui::views::{ESCAPE_SEQUENCE_TIMEOUT, EscapeSequenceTracker, ScreenExt, common, get_layout_with_header, transfer};
+use crate::ui::views::{
+    ESCAPE_SEQUENCE_TIMEOUT, EscapeSequenceTracker, ScreenExt, SessionInfo, common, get_layout_with_header, transfer,
+};
 use crate::ui::widgets::{CommandPalette, FileSelector};
 use crate::ui::{presentation::ContentHeader, views::View};
 
@@ -26,9 +30,23 @@ const DEFAULT_SHELL: &str = "bash";
 const FALLBACK_SHELL: &str = "sh";
 const SCROLLBACK_LEN: usize = 1_000;
 
+/// Kind of the session opened in the [`ShellView`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShellMode {
+    /// Shell process executed in the container.
+    Shell,
+
+    /// Attached to the main process of the container.
+    Attach,
+
+    /// Shell on the host executed from the node shell pod, the pod is deleted when the view is closed.
+    Node,
+}
+
 /// Pod's shell view.
 pub struct ShellView {
     app_data: SharedAppData,
+    worker: SharedBgWorker,
     header: ContentHeader,
     bridge: ShellBridge,
     parser: Arc<RwLock<vt100::Parser>>,
@@ -44,7 +62,10 @@ pub struct ShellView {
     area: Rect,
     alt_mode: EscapeSequenceTracker,
     clipboard_text: Option<String>,
+    last_activity: Timestamp,
     is_attach: bool,
+    is_node_shell: bool,
+    is_foreground: bool,
     is_app_mode: bool,
     is_mouse_enabled: bool,
     own_cursor: bool,
@@ -59,13 +80,18 @@ impl ShellView {
         worker: SharedBgWorker,
         client: Client,
         pod: ContainerRef,
-        is_attach: bool,
+        mode: ShellMode,
         footer_tx: NotificationSink,
         workspace: Rect,
     ) -> Self {
+        let is_attach = mode == ShellMode::Attach;
         let runtime = worker.borrow().runtime_handle().clone();
         let mut header = ContentHeader::new(Rc::clone(&app_data), false);
-        header.set_title(if is_attach { " attach" } else { " shell" });
+        header.set_title(match mode {
+            ShellMode::Shell => " shell",
+            ShellMode::Attach => " attach",
+            ShellMode::Node => " node shell",
+        });
         header.set_data(
             pod.namespace.clone(),
             PODS.into(),
@@ -77,10 +103,13 @@ impl ShellView {
         let selection = ScreenSelection::default().with_color(app_data.borrow().theme.colors.shell.select);
         let scrollback = app_data.borrow().config.terminal.scrollback_lines.unwrap_or(SCROLLBACK_LEN);
         let mut bridge = ShellBridge::new(runtime, area, scrollback, is_attach);
+        if mode == ShellMode::Node {
+            bridge = bridge.with_command(NODE_SHELL_COMMAND.iter().map(|&s| s.to_owned()).collect());
+        }
         bridge.start(client.clone(), pod.clone(), DEFAULT_SHELL, area.to_terminal_size());
         let parser = bridge.get_parser();
         let own_cursor = app_data.borrow().config.terminal.system_cursor.is_none_or(|c| !c);
-        let file_picker = FileSelector::new(Rc::clone(&app_data), worker, 65, PathBuf::from("."));
+        let file_picker = FileSelector::new(Rc::clone(&app_data), Rc::clone(&worker), 65, PathBuf::from("."));
 
         app_data.disable_command(KeyCommand::ApplicationExit, true);
         app_data.disable_command(KeyCommand::MouseSupportToggle, true);
@@ -88,6 +117,7 @@ impl ShellView {
 
         Self {
             app_data,
+            worker,
             header,
             bridge,
             parser,
@@ -103,7 +133,10 @@ impl ShellView {
             area,
             alt_mode: EscapeSequenceTracker::new(ESCAPE_SEQUENCE_TIMEOUT),
             clipboard_text: None,
+            last_activity: Timestamp::now(),
             is_attach,
+            is_node_shell: mode == ShellMode::Node,
+            is_foreground: true,
             is_app_mode: false,
             is_mouse_enabled: false,
             own_cursor,
@@ -122,6 +155,7 @@ impl ShellView {
             .with_menu_action(ActionItem::menu(2, "󰆒 paste", "paste"))
             .with_menu_action(ActionItem::menu(5, " download files", "download"))
             .with_menu_action(ActionItem::menu(5, " upload file", "upload"))
+            .with_menu_action(ActionItem::menu(99, "󰒲 send to background", "background"))
             .with_menu_action(ActionItem::menu(100, detach, "detach"));
 
         let is_mouse_allowed = self.bridge.is_mouse_enabled().unwrap_or(self.is_attach);
@@ -174,6 +208,7 @@ impl ShellView {
                     self.last_mouse_click = event.position();
                     self.ask_transfer_file(false)
                 },
+                "background" => ResponseEvent::SendToBackground,
                 "detach" => {
                     if self.is_attach {
                         ResponseEvent::Cancelled
@@ -266,10 +301,12 @@ impl ShellView {
         let colors = &self.app_data.borrow().theme.colors;
         Dialog::new(
             "You are about to close the shell view without terminating the running shell process. \
-             It will keep running in the background until you stop it manually. Type 'exit' to close it gracefully."
+             It will keep running in the container until you stop it manually. Type 'exit' to close it gracefully, \
+             or move the session to the background to resume it later."
                 .to_owned(),
             vec![
                 Button::new("Close Anyway", ResponseEvent::Cancelled, colors.modal.btn_delete.clone()),
+                Button::new("Background", ResponseEvent::SendToBackground, colors.modal.btn_accent.clone()),
                 Button::new("Cancel", ResponseEvent::Action("cancel"), colors.modal.btn_cancel.clone()),
             ],
         )
@@ -391,6 +428,7 @@ impl ShellView {
                     },
                     _ => ResponseEvent::Handled,
                 },
+                ResponseEvent::Cancelled | ResponseEvent::SendToBackground => response,
                 _ => ResponseEvent::Handled,
             });
         }
@@ -418,6 +456,7 @@ impl ShellView {
         let is_app_mode = self.bridge.is_application_mode().unwrap_or(self.is_app_mode);
         if let Some(bytes) = encode_key(key.code, key.modifiers, is_app_mode) {
             self.bridge.send(bytes);
+            self.last_activity = Timestamp::now();
 
             if self.scrollback_rows > 0 {
                 self.reset_scrollback(false);
@@ -429,12 +468,51 @@ impl ShellView {
 }
 
 impl View for ShellView {
+    fn session(&self) -> Option<SessionInfo> {
+        let title = if self.is_node_shell {
+            "node shell"
+        } else if self.is_attach {
+            "attach"
+        } else {
+            "shell"
+        };
+
+        Some(SessionInfo {
+            title: title.to_owned(),
+            namespace: self.pod.namespace.clone(),
+            name: Some(self.pod.name.clone()),
+            container: self.pod.container.clone(),
+            last_activity: self.last_activity,
+            is_running: !self.bridge.is_finished(),
+        })
+    }
+
+    fn set_foreground(&mut self, is_foreground: bool) {
+        self.is_foreground = is_foreground;
+        self.app_data.disable_command(KeyCommand::ApplicationExit, is_foreground);
+        self.app_data.disable_command(KeyCommand::MouseSupportToggle, is_foreground);
+        if is_foreground {
+            set_hint(&self.app_data, &self.footer_tx, self.is_attach, false);
+        } else {
+            self.command_palette.hide();
+            self.selection.reset();
+            self.cursor_shape.reset_shape();
+            self.footer_tx.hide_hint();
+        }
+    }
+
     fn process_tick(&mut self) -> ResponseEvent {
-        self.cursor_shape.sync_shape(self.bridge.cursor_shape());
+        if self.is_foreground {
+            self.cursor_shape.sync_shape(self.bridge.cursor_shape());
+        }
 
         if self.bridge.is_finished() {
             // we try to fall back to 'sh' if ShellBridge has an error and was initially started as 'bash'
-            if !self.is_attach && self.bridge.has_error() && self.bridge.shell().is_some_and(|s| s == DEFAULT_SHELL) {
+            if !self.is_attach
+                && !self.is_node_shell
+                && self.bridge.has_error()
+                && self.bridge.shell().is_some_and(|s| s == DEFAULT_SHELL)
+            {
                 let size = self.area.to_terminal_size();
                 self.bridge.start(self.client.clone(), self.pod.clone(), FALLBACK_SHELL, size);
             } else {
@@ -447,7 +525,7 @@ impl View for ShellView {
                 }
                 return ResponseEvent::Cancelled;
             }
-        } else if self.is_mouse_enabled && self.bridge.is_application_mode().is_some_and(|m| !m) {
+        } else if self.is_foreground && self.is_mouse_enabled && self.bridge.is_application_mode().is_some_and(|m| !m) {
             return self.enable_mouse(false);
         }
 
@@ -477,6 +555,10 @@ impl View for ShellView {
                 };
             }
 
+            if self.app_data.has_binding(event, KeyCommand::SessionBackground) {
+                return ResponseEvent::SendToBackground;
+            }
+
             if self.app_data.has_binding(event, KeyCommand::TransferTo) {
                 return self.ask_transfer_file(false);
             }
@@ -571,9 +653,15 @@ impl View for ShellView {
 impl Drop for ShellView {
     fn drop(&mut self) {
         self.bridge.stop();
-        self.app_data.disable_command(KeyCommand::ApplicationExit, false);
-        self.app_data.disable_command(KeyCommand::MouseSupportToggle, false);
-        self.footer_tx.hide_hint();
+        if self.is_node_shell {
+            self.worker.borrow_mut().delete_node_shell(&self.pod);
+        }
+
+        if self.is_foreground {
+            self.app_data.disable_command(KeyCommand::ApplicationExit, false);
+            self.app_data.disable_command(KeyCommand::MouseSupportToggle, false);
+            self.footer_tx.hide_hint();
+        }
     }
 }
 
@@ -583,8 +671,10 @@ fn set_hint(app_data: &SharedAppData, footer_tx: &NotificationSink, is_attach: b
         let action = if is_attach { "close attach view" } else { "detach shell" };
         let transfer_to = app_data.get_key_name(KeyCommand::TransferTo).to_ascii_uppercase();
         let transfer_from = app_data.get_key_name(KeyCommand::TransferFrom).to_ascii_uppercase();
+        let background = app_data.get_key_name(KeyCommand::SessionBackground).to_ascii_uppercase();
         footer_tx.show_hint(format!(
-            " Escape mode: press ␝{key}␝ to {action}, ␝{transfer_to}␝ or ␝{transfer_from}␝ for file transfer"
+            " Escape mode: press ␝{key}␝ to {action}, ␝{background}␝ to send to background, \
+             ␝{transfer_to}␝ or ␝{transfer_from}␝ for file transfer"
         ));
     } else {
         footer_tx.show_hint(format!(" Double press ␝{key}␝ to activate escape mode"));
@@ -1,5 +1,5 @@
 pub use self::cmd::view::CmdView;
-pub use self::kube::view::ShellView;
+pub use self::kube::view::{ShellMode, ShellView};
 
 mod cmd;
 mod keys;
@@ -32,6 +32,7 @@ pub fn new_transfer_dialog(
     .with_checkboxes(vec![
         CheckBox::new(0, "Download", is_download, colors.modal.checkbox.clone()),
         CheckBox::new(1, "Overwrite files", false, colors.modal.checkbox.clone()),
+        CheckBox::new(2, "Verify checksum", false, colors.modal.checkbox.clone()),
     ])
     .with_textboxes(get_transfer_dialog_textboxes(app_data, is_download, &colors.modal.textbox))
     .with_selectors(vec![Selector::new(
@@ -47,13 +48,13 @@ pub fn new_transfer_dialog(
         let is_ok = (is_checked && is_download) || (!is_checked && !is_download);
         if !is_ok {
             *message = get_transfer_dialog_title(is_checked);
-            controls.controls_mut().swap(2, 3);
+            controls.controls_mut().swap(3, 4);
 
-            if let Some(textbox) = controls.controls_mut()[2].as_textbox_mut() {
+            if let Some(textbox) = controls.controls_mut()[3].as_textbox_mut() {
                 setup_transfer_dialog_textbox(textbox, is_download, true);
             }
 
-            if let Some(textbox) = controls.controls_mut()[3].as_textbox_mut() {
+            if let Some(textbox) = controls.controls_mut()[4].as_textbox_mut() {
                 setup_transfer_dialog_textbox(textbox, !is_download, false);
             }
         }
@@ -64,15 +65,18 @@ pub fn new_transfer_dialog(
 pub fn get_transfer_dialog_context(dialog: &Dialog) -> Option<TransferContext> {
     let is_download = dialog.checkbox(0).is_some_and(|cb| cb.is_checked);
     let overwrite_files = dialog.checkbox(1).is_some_and(|cb| cb.is_checked);
+    let verify_checksum = dialog.checkbox(2).is_some_and(|cb| cb.is_checked);
     let container = dialog.selector(0).map(|s| s.selected().to_owned())?;
     let first = dialog.textbox(0).map(|tb| tb.value().to_owned())?;
     let second = dialog.textbox(1).map(|tb| tb.value().to_owned())?;
 
-    if is_download {
-        Some(TransferContext::download(second, first, container, overwrite_files))
+    let context = if is_download {
+        TransferContext::download(second, first, container, overwrite_files)
     } else {
-        Some(TransferContext::upload(first, second, container, overwrite_files))
-    }
+        TransferContext::upload(first, second, container, overwrite_files)
+    };
+
+    Some(context.with_checksum_verification(verify_checksum))
 }
 
 /// Updates transfer dialog textboxes with the selected path from a file picker.
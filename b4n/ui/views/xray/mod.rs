@@ -0,0 +1,5 @@
+pub use self::tree::*;
+pub use self::view::*;
+
+mod tree;
+mod view;
@@ -0,0 +1,180 @@
+use b4n_tasks::commands::RelationNode;
+use std::collections::HashSet;
+
+#[cfg(test)]
+#[path = "./tree.tests.rs"]
+mod tree_tests;
+
+/// Visible row of the [`RelationsTree`].
+pub struct TreeRow {
+    pub key: String,
+    pub path: Vec<usize>,
+    pub guides: String,
+    pub has_children: bool,
+    pub is_expanded: bool,
+}
+
+/// Relations tree with expandable nodes flattened to the visible rows.
+#[derive(Default)]
+pub struct RelationsTree {
+    root: Option<RelationNode>,
+    collapsed: HashSet<String>,
+    rows: Vec<TreeRow>,
+    highlighted: usize,
+}
+
+impl RelationsTree {
+    /// Sets new relations tree, expanded state and highlighted row are kept if possible.\
+    /// **Note** that the target resource is highlighted when the tree is set for the first time.
+    pub fn set_root(&mut self, root: RelationNode) {
+        let highlighted = self.rows.get(self.highlighted).map(|r| r.key.clone());
+        let is_first = self.root.is_none();
+        self.root = Some(root);
+        self.rebuild();
+
+        let index = if is_first {
+            self.rows.iter().position(|r| self.node(r).is_some_and(|n| n.is_target))
+        } else {
+            highlighted.and_then(|key| self.rows.iter().position(|r| r.key == key))
+        };
+        self.highlight(index.unwrap_or(self.highlighted));
+    }
+
+    /// Returns visible rows.
+    pub fn rows(&self) -> &[TreeRow] {
+        &self.rows
+    }
+
+    /// Returns number of visible rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if there are no visible rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns index of the highlighted row.
+    pub fn highlighted(&self) -> usize {
+        self.highlighted
+    }
+
+    /// Returns node for the specified `row`.
+    pub fn node(&self, row: &TreeRow) -> Option<&RelationNode> {
+        let mut node = self.root.as_ref()?;
+        for index in &row.path {
+            node = node.children.get(*index)?;
+        }
+
+        Some(node)
+    }
+
+    /// Returns highlighted node.
+    pub fn highlighted_node(&self) -> Option<&RelationNode> {
+        self.rows.get(self.highlighted).and_then(|r| self.node(r))
+    }
+
+    /// Highlights row with the specified `index`.
+    pub fn highlight(&mut self, index: usize) {
+        self.highlighted = index.min(self.rows.len().saturating_sub(1));
+    }
+
+    /// Moves highlight by `delta` rows.
+    pub fn move_highlight(&mut self, delta: isize) {
+        self.highlight(self.highlighted.saturating_add_signed(delta));
+    }
+
+    /// Expands or collapses the highlighted node.
+    pub fn toggle(&mut self) {
+        if let Some(row) = self.rows.get(self.highlighted)
+            && row.has_children
+        {
+            let expand = !row.is_expanded;
+            self.expand(expand);
+        }
+    }
+
+    /// Expands or collapses the highlighted node.\
+    /// **Note** that collapsing a leaf or already collapsed node highlights its parent.
+    pub fn expand(&mut self, expand: bool) {
+        let Some(row) = self.rows.get(self.highlighted) else {
+            return;
+        };
+
+        if expand {
+            if row.has_children && !row.is_expanded {
+                self.collapsed.remove(&row.key);
+                self.rebuild();
+            }
+        } else if row.has_children && row.is_expanded {
+            self.collapsed.insert(row.key.clone());
+            self.rebuild();
+        } else if !row.path.is_empty() {
+            let parent = &row.path[..row.path.len() - 1];
+            if let Some(index) = self.rows.iter().position(|r| r.path == parent) {
+                self.highlighted = index;
+            }
+        }
+    }
+
+    fn rebuild(&mut self) {
+        self.rows.clear();
+        if let Some(root) = &self.root {
+            add_rows(
+                &mut self.rows,
+                &self.collapsed,
+                root,
+                Vec::new(),
+                String::new(),
+                String::new(),
+            );
+        }
+
+        self.highlight(self.highlighted);
+    }
+}
+
+fn add_rows(
+    rows: &mut Vec<TreeRow>,
+    collapsed: &HashSet<String>,
+    node: &RelationNode,
+    path: Vec<usize>,
+    parent_key: String,
+    guides: String,
+) {
+    let key = format!("{parent_key}/{}:{}", node.kind.as_str(), node.name);
+    let is_expanded = !collapsed.contains(&key);
+    let child_guides = if path.is_empty() {
+        String::new()
+    } else if guides.ends_with("├─ ") {
+        format!("{}│  ", &guides[..guides.len() - "├─ ".len()])
+    } else {
+        format!("{}   ", &guides[..guides.len() - "└─ ".len()])
+    };
+
+    rows.push(TreeRow {
+        key: key.clone(),
+        path: path.clone(),
+        guides,
+        has_children: !node.children.is_empty(),
+        is_expanded,
+    });
+
+    if is_expanded {
+        let last = node.children.len().saturating_sub(1);
+        for (index, child) in node.children.iter().enumerate() {
+            let mut child_path = path.clone();
+            child_path.push(index);
+            let branch = if index == last { "└─ " } else { "├─ " };
+            add_rows(
+                rows,
+                collapsed,
+                child,
+                child_path,
+                key.clone(),
+                format!("{child_guides}{branch}"),
+            );
+        }
+    }
+}
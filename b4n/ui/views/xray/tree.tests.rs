@@ -0,0 +1,81 @@
+use b4n_kube::Kind;
+use b4n_tasks::commands::{RelationNode, RelationState};
+
+use super::*;
+
+fn node(kind: &str, name: &str, children: Vec<RelationNode>) -> RelationNode {
+    RelationNode {
+        kind: Kind::from(kind),
+        singular: kind.to_owned(),
+        namespace: Some("default".to_owned()),
+        name: name.to_owned(),
+        uid: None,
+        relation: None,
+        status: "Running".to_owned(),
+        state: RelationState::Ready,
+        is_target: false,
+        children,
+    }
+}
+
+fn sample_tree() -> RelationNode {
+    let mut target = node("pods", "web-1", vec![node("configmaps", "config", Vec::new())]);
+    target.is_target = true;
+    let rs = node("replicasets", "web", vec![node("pods", "web-0", Vec::new()), target]);
+    node("deployments", "web", vec![rs, node("serviceaccounts", "default", Vec::new())])
+}
+
+fn guides(tree: &RelationsTree) -> Vec<&str> {
+    tree.rows().iter().map(|r| r.guides.as_str()).collect()
+}
+
+#[test]
+fn set_root_test() {
+    let mut tree = RelationsTree::default();
+    tree.set_root(sample_tree());
+
+    assert_eq!(tree.len(), 6);
+    assert_eq!(tree.highlighted(), 3);
+    assert_eq!(tree.highlighted_node().unwrap().name, "web-1");
+    assert_eq!(guides(&tree), vec!["", "├─ ", "│  ├─ ", "│  └─ ", "│     └─ ", "└─ "]);
+
+    tree.highlight(1);
+    tree.set_root(sample_tree());
+    assert_eq!(tree.highlighted(), 1);
+}
+
+#[test]
+fn expand_and_collapse_test() {
+    let mut tree = RelationsTree::default();
+    tree.set_root(sample_tree());
+
+    tree.highlight(1);
+    tree.expand(false);
+    assert_eq!(tree.len(), 3);
+    assert!(!tree.rows()[1].is_expanded);
+
+    tree.expand(false);
+    assert_eq!(tree.highlighted(), 0);
+
+    tree.highlight(1);
+    tree.toggle();
+    assert_eq!(tree.len(), 6);
+
+    tree.set_root(sample_tree());
+    tree.highlight(1);
+    tree.toggle();
+    tree.set_root(sample_tree());
+    assert_eq!(tree.len(), 3);
+}
+
+#[test]
+fn move_highlight_test() {
+    let mut tree = RelationsTree::default();
+    tree.set_root(sample_tree());
+
+    tree.move_highlight(-10);
+    assert_eq!(tree.highlighted(), 0);
+
+    tree.move_highlight(10);
+    assert_eq!(tree.highlighted(), 5);
+}
@@ -0,0 +1,354 @@
+use b4n_common::NotificationSink;
+use b4n_config::keys::KeyCommand;
+use b4n_kube::{Namespace, ResourceRef};
+use b4n_tasks::commands::{CommandResult, RelationState};
+use b4n_tui::widgets::{ActionItem, ActionsListBuilder};
+use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, ToSelectData, TuiEvent};
+use crossterm::event::KeyCode;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::core::{DetailView, SharedAppData, SharedAppDataExt, SharedBgWorker};
+use crate::ui::presentation::ContentHeader;
+use crate::ui::views::xray::RelationsTree;
+use crate::ui::views::{View, get_layout_with_header};
+use crate::ui::widgets::CommandPalette;
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Xray view that shows owners, dependents and references of the resource.
+pub struct XrayView {
+    app_data: SharedAppData,
+    worker: SharedBgWorker,
+    header: ContentHeader,
+    resource: ResourceRef,
+    tree: RelationsTree,
+    command_id: Option<String>,
+    last_refresh: Instant,
+    offset: usize,
+    area: Rect,
+    command_palette: CommandPalette,
+    footer_tx: NotificationSink,
+}
+
+impl XrayView {
+    /// Creates new [`XrayView`] instance.
+    pub fn new(app_data: SharedAppData, worker: SharedBgWorker, resource: ResourceRef, footer_tx: NotificationSink) -> Self {
+        let command_id = worker.borrow_mut().get_relations(resource.clone());
+
+        let mut header = ContentHeader::new(Rc::clone(&app_data), false);
+        header.set_title(" xray");
+        header.set_data(resource.namespace.clone(), resource.kind.clone(), resource.name.clone(), None);
+        header.set_busy(command_id.is_some());
+
+        set_hint(&app_data, &footer_tx);
+
+        Self {
+            app_data,
+            worker,
+            header,
+            resource,
+            tree: RelationsTree::default(),
+            command_id,
+            last_refresh: Instant::now(),
+            offset: 0,
+            area: Rect::default(),
+            command_palette: CommandPalette::default(),
+            footer_tx,
+        }
+    }
+
+    fn refresh(&mut self) {
+        self.last_refresh = Instant::now();
+        if self.command_id.is_none() {
+            self.command_id = self.worker.borrow_mut().get_relations(self.resource.clone());
+        }
+    }
+
+    /// Shows command palette.
+    fn show_command_palette(&mut self) {
+        let builder = ActionsListBuilder::default()
+            .with_back()
+            .with_quit()
+            .with_action(
+                ActionItem::action("go to", "goto").with_description("shows the highlighted resource on the resources list"),
+                Some(KeyCommand::NavigateInto),
+            )
+            .with_action(
+                ActionItem::action("show YAML", "yaml")
+                    .with_description("shows YAML of the highlighted resource")
+                    .with_aliases(["yaml", "yml"]),
+                Some(KeyCommand::YamlOpen),
+            )
+            .with_action(
+                ActionItem::action("describe", "describe").with_description("shows details of the highlighted resource"),
+                Some(KeyCommand::DescribeOpen),
+            )
+            .with_aliases(&self.app_data.borrow().config.aliases);
+        let actions = builder.build(Some(&self.app_data.borrow().key_bindings));
+
+        self.command_palette = CommandPalette::new(Rc::clone(&self.app_data), actions, 65);
+        self.command_palette.show();
+        self.footer_tx.hide_hint();
+    }
+
+    /// Processes events that are from the command palette.
+    fn process_command_palette_event(&mut self, event: &TuiEvent) -> ResponseEvent {
+        match self.command_palette.process_event(event) {
+            ResponseEvent::Action("goto") => self.process_event(&TuiEvent::Command(KeyCommand::NavigateInto)),
+            ResponseEvent::Action("yaml") => self.process_event(&TuiEvent::Command(KeyCommand::YamlOpen)),
+            ResponseEvent::Action("describe") => self.process_event(&TuiEvent::Command(KeyCommand::DescribeOpen)),
+            response_event => response_event,
+        }
+    }
+
+    /// Returns reference to the highlighted resource, `None` if it does not exist in the cluster.
+    fn highlighted_resource(&self) -> Option<ResourceRef> {
+        let node = self.tree.highlighted_node()?;
+        if node.state == RelationState::Missing {
+            return None;
+        }
+
+        let resource = ResourceRef::named(
+            node.kind.clone(),
+            Namespace::from(node.namespace.clone().unwrap_or_default()),
+            node.name.clone(),
+        );
+        Some(match &node.uid {
+            Some(uid) => resource.with_uid(uid.clone()),
+            None => resource,
+        })
+    }
+
+    fn go_to_highlighted(&self) -> ResponseEvent {
+        let Some(node) = self.tree.highlighted_node() else {
+            return ResponseEvent::Handled;
+        };
+
+        ResponseEvent::ViewInvolved(
+            node.kind.clone().into(),
+            node.namespace.clone().unwrap_or_default(),
+            ToSelectData::new(&node.name, node.namespace.as_deref()),
+        )
+    }
+
+    fn process_key(&mut self, code: KeyCode) -> ResponseEvent {
+        let page = isize::try_from(self.area.height.max(1)).unwrap_or(1);
+        match code {
+            KeyCode::Up => self.tree.move_highlight(-1),
+            KeyCode::Down => self.tree.move_highlight(1),
+            KeyCode::PageUp => self.tree.move_highlight(-page),
+            KeyCode::PageDown => self.tree.move_highlight(page),
+            KeyCode::Home => self.tree.highlight(0),
+            KeyCode::End => self.tree.highlight(usize::MAX),
+            KeyCode::Left => self.tree.expand(false),
+            KeyCode::Right => self.tree.expand(true),
+            _ => return ResponseEvent::NotHandled,
+        }
+
+        ResponseEvent::Handled
+    }
+
+    fn process_mouse(&mut self, event: &TuiEvent) -> ResponseEvent {
+        if event.is_in(MouseEventKind::ScrollUp, self.area) {
+            self.tree.move_highlight(-1);
+        } else if event.is_in(MouseEventKind::ScrollDown, self.area) {
+            self.tree.move_highlight(1);
+        } else if event.is_in(MouseEventKind::LeftClick, self.area) || event.is_in(MouseEventKind::LeftDoubleClick, self.area) {
+            let Some(position) = event.position() else {
+                return ResponseEvent::Handled;
+            };
+
+            let index = self.offset + usize::from(position.y.saturating_sub(self.area.y));
+            if index < self.tree.len() {
+                if event.is_mouse(MouseEventKind::LeftDoubleClick) && index == self.tree.highlighted() {
+                    return self.go_to_highlighted();
+                }
+
+                self.tree.highlight(index);
+            }
+        } else {
+            return ResponseEvent::NotHandled;
+        }
+
+        ResponseEvent::Handled
+    }
+
+    fn update_offset(&mut self, height: usize) {
+        let highlighted = self.tree.highlighted();
+        if highlighted < self.offset {
+            self.offset = highlighted;
+        } else if height > 0 && highlighted >= self.offset + height {
+            self.offset = highlighted + 1 - height;
+        }
+
+        self.offset = self.offset.min(self.tree.len().saturating_sub(height));
+    }
+
+    fn get_line(&self, index: usize, width: usize) -> Option<Line<'static>> {
+        let row = self.tree.rows().get(index)?;
+        let node = self.tree.node(row)?;
+        let is_highlighted = index == self.tree.highlighted();
+
+        let colors = {
+            let theme = &self.app_data.borrow().theme.colors.list.line;
+            let colors = match node.state {
+                RelationState::Ready => &theme.ready,
+                RelationState::InProgress => &theme.in_progress,
+                RelationState::Completed => &theme.completed,
+                RelationState::Failed | RelationState::Missing => &theme.terminating,
+            };
+            colors.get_specific(is_highlighted, false)
+        };
+
+        let style = Style::from(&colors);
+        let name_style = if node.is_target {
+            style.add_modifier(Modifier::BOLD)
+        } else {
+            style
+        };
+        let dim = Style::default().fg(colors.dim).bg(colors.bg);
+
+        let marker = if !row.has_children {
+            "  "
+        } else if row.is_expanded {
+            "▾ "
+        } else {
+            "▸ "
+        };
+        let mut spans = vec![
+            Span::styled(format!(" {}", row.guides), dim),
+            Span::styled(marker, dim),
+            Span::styled(format!("{}/", node.singular.to_ascii_lowercase()), style),
+            Span::styled(node.name.clone(), name_style),
+        ];
+        if let Some(relation) = node.relation {
+            spans.push(Span::styled(format!(" [{relation}]"), dim));
+        }
+
+        let used = spans.iter().map(Span::width).sum::<usize>();
+        let status = format!("{} ", node.status);
+        let padding = width.saturating_sub(used + status.chars().count()).max(1);
+        spans.push(Span::styled(" ".repeat(padding), style));
+        spans.push(Span::styled(status, style));
+
+        Some(Line::from(spans))
+    }
+}
+
+impl View for XrayView {
+    fn command_id(&self) -> Option<&str> {
+        self.command_id.as_deref()
+    }
+
+    fn process_command_result(&mut self, result: CommandResult) {
+        if let CommandResult::ResourceRelations(Ok(root)) = result {
+            self.tree.set_root(root);
+        }
+
+        self.command_id = None;
+        self.header.set_busy(false);
+    }
+
+    fn detail_view(&self) -> Option<DetailView> {
+        Some(DetailView::Relations(self.resource.clone()))
+    }
+
+    fn process_tick(&mut self) -> ResponseEvent {
+        if self.last_refresh.elapsed() >= REFRESH_INTERVAL {
+            self.refresh();
+        }
+
+        ResponseEvent::Handled
+    }
+
+    fn process_disconnection(&mut self) {
+        self.command_palette.hide();
+    }
+
+    fn process_event(&mut self, event: &TuiEvent) -> ResponseEvent {
+        if self.command_palette.is_visible {
+            return self.process_command_palette_event(event);
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::CommandPaletteOpen) {
+            self.show_command_palette();
+            return ResponseEvent::Handled;
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::NavigateBack) {
+            return ResponseEvent::Cancelled;
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::NavigateInto) {
+            return self.go_to_highlighted();
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::NavigateSelect) {
+            self.tree.toggle();
+            return ResponseEvent::Handled;
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::YamlOpen) {
+            return self
+                .highlighted_resource()
+                .map_or(ResponseEvent::Handled, |r| ResponseEvent::ViewYaml(r, false, false));
+        }
+
+        if self.app_data.has_binding(event, KeyCommand::DescribeOpen) {
+            return self
+                .highlighted_resource()
+                .map_or(ResponseEvent::Handled, ResponseEvent::Describe);
+        }
+
+        if let TuiEvent::Key(key) = event
+            && key.modifiers.is_empty()
+        {
+            return self.process_key(key.code);
+        }
+
+        self.process_mouse(event)
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, area: Rect, _has_focus: bool) {
+        let layout = get_layout_with_header(area);
+        self.header.draw(frame, layout[0]);
+
+        self.area = layout[1];
+        let height = usize::from(self.area.height);
+        self.update_offset(height);
+
+        let width = usize::from(self.area.width);
+        let lines = if self.tree.is_empty() && self.command_id.is_some() {
+            vec![Line::from(" loading relations…")]
+        } else {
+            (self.offset..self.offset + height)
+                .filter_map(|i| self.get_line(i, width))
+                .collect::<Vec<_>>()
+        };
+
+        let style = {
+            let colors = &self.app_data.borrow().theme.colors;
+            Style::default().fg(colors.text.fg).bg(colors.text.bg)
+        };
+        frame.render_widget(Paragraph::new(lines).block(Block::new().style(style)), self.area);
+
+        self.command_palette.draw(frame, area);
+    }
+}
+
+impl Drop for XrayView {
+    fn drop(&mut self) {
+        self.footer_tx.hide_hint();
+    }
+}
+
+fn set_hint(app_data: &SharedAppData, footer_tx: &NotificationSink) {
+    let key = app_data.get_key_name(KeyCommand::NavigateSelect).to_ascii_uppercase();
+    footer_tx.show_hint(format!(" Press ␝{key}␝ to expand or collapse the highlighted node"));
+}
@@ -741,7 +741,9 @@ impl View for YamlView {
             CommandResult::GetNewResourceYaml(Ok(result)) => {
                 self.process_new_content(result.into());
             },
-            CommandResult::GetResourceYaml(Ok(result)) => {
+            CommandResult::GetResourceYaml(Ok(result))
+            | CommandResult::GetHelmRelease(Ok(result))
+            | CommandResult::QueryResources(Ok(result)) => {
                 self.process_new_content(result);
             },
             CommandResult::SetNewResourceYaml(Ok(name)) => {
@@ -1,7 +1,10 @@
 use b4n_config::keys::KeyCommand;
 use b4n_config::themes::SelectColors;
 use b4n_tui::utils::{center_horizontal, get_proportional_width};
-use b4n_tui::widgets::{ActionsList, ErrorHighlightMode, InputValidator, Select, SharedClipboard, ValidatorKind};
+use b4n_tui::widgets::{
+    ActionsList, ErrorHighlightMode, InputValidator, PaletteCommand, PaletteCompletions, PaletteVerb, Select, SharedClipboard,
+    ValidatorKind,
+};
 use b4n_tui::{MouseEventKind, ResponseEvent, Responsive, TuiEvent, table::Table};
 use crossterm::event::KeyModifiers;
 use ratatui::layout::{Margin, Position, Rect};
@@ -24,6 +27,7 @@ pub struct CommandPalette {
     position: Option<Position>,
     highlight_position: Option<Position>,
     response: Option<Box<dyn FnOnce(Vec<String>) -> ResponseEvent>>,
+    commands: Option<(PaletteCompletions, &'static str)>,
     is_mouse_menu: bool,
 }
 
@@ -108,6 +112,17 @@ impl CommandPalette {
         self
     }
 
+    /// Allows the first step to accept commands with arguments, e.g. `pods -n kube-system /crash`.\
+    /// **Note** that [`ResponseEvent::Action`] with the provided `action` is returned when such command is accepted,
+    /// and `completions` are used to complete its last word.
+    pub fn with_commands(mut self, completions: PaletteCompletions, action: &'static str) -> Self {
+        self.steps[0]
+            .validators
+            .push(InputValidator::new(ValidatorKind::PaletteCommand(completions.kinds.clone())));
+        self.commands = Some((completions, action));
+        self
+    }
+
     /// Sets this command palette to behave as mouse menu.
     pub fn to_mouse_menu(mut self) -> Self {
         let index = self.steps.len().saturating_sub(1);
@@ -299,7 +314,47 @@ impl CommandPalette {
         self.steps.iter().map(|s| s.select.value().to_owned()).collect()
     }
 
+    fn is_command(&self) -> bool {
+        self.index == 0
+            && self
+                .commands
+                .as_ref()
+                .is_some_and(|(c, _)| matches!(PaletteCommand::parse(self.select().value(), &c.kinds), Ok(Some(_))))
+    }
+
+    /// Completes the last word of the typed command, returns `false` if the value is not a command.
+    fn complete_command(&mut self) -> bool {
+        let Some((completions, _)) = self.commands.as_ref().filter(|_| self.index == 0) else {
+            return false;
+        };
+
+        let value = self.select().value();
+        let mut words = value.trim_start().trim_start_matches(':').split_whitespace();
+        let is_command = words
+            .next()
+            .is_some_and(|w| PaletteVerb::from_word(w, &completions.kinds).is_some())
+            && value.trim_start().contains(char::is_whitespace);
+        if !is_command {
+            return false;
+        }
+
+        if let Some(completed) = completions.complete(value) {
+            self.select_mut().set_value(completed);
+            self.step_mut().validate();
+        }
+
+        true
+    }
+
     fn process_enter_key(&mut self, overwrite_if_not_empty: bool) -> ResponseEvent {
+        if !overwrite_if_not_empty
+            && self.is_command()
+            && let Some((_, action)) = &self.commands
+        {
+            self.is_visible = false;
+            return ResponseEvent::Action(action);
+        }
+
         self.insert_highlighted_value(overwrite_if_not_empty);
 
         if self.step().has_valid_value() && (self.steps.len() == 1 || !self.next_step()) {
@@ -351,7 +406,10 @@ impl Responsive for CommandPalette {
         }
 
         if self.app_data.has_binding(event, KeyCommand::NavigateComplete) {
-            self.insert_highlighted_value(true);
+            if !self.complete_command() {
+                self.insert_highlighted_value(true);
+            }
+
             return ResponseEvent::Handled;
         }
 
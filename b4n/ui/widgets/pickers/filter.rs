@@ -1,4 +1,4 @@
-use b4n_common::expr::{ParserError, validate};
+use b4n_common::expr::{ParserError, ServerSelectors, validate};
 use b4n_config::keys::KeyCommand;
 use b4n_config::themes::SelectColors;
 use b4n_tui::widgets::Select;
@@ -65,6 +65,7 @@ impl Filter {
 pub struct FilterBehaviour {
     app_data: SharedAppData,
     last_validated: String,
+    last_can_push_down: bool,
     last_error: Option<usize>,
 }
 
@@ -73,6 +74,7 @@ impl FilterBehaviour {
         Self {
             app_data,
             last_validated: String::new(),
+            last_can_push_down: true,
             last_error: None,
         }
     }
@@ -123,20 +125,22 @@ impl PickerBehaviour for FilterBehaviour {
     }
 
     fn validate(&mut self, value: &str) -> Option<usize> {
-        if self.last_validated == value {
+        // field queries are accepted only if they can be sent to the API server for the current resource
+        let can_push_down = {
+            let resource = &self.app_data.borrow().current.resource;
+            !resource.is_filtered() && !resource.is_container()
+        };
+        if self.last_validated == value && self.last_can_push_down == can_push_down {
             return self.last_error;
         }
 
         value.clone_into(&mut self.last_validated);
-        self.last_error = match validate(value) {
-            Err(
-                ParserError::ExpectedOperator(i)
-                | ParserError::UnexpectedOperator(i)
-                | ParserError::ExpectedClosingBracket(i)
-                | ParserError::UnexpectedClosingBracket(i),
-            ) => Some(i),
-            _ => None,
-        };
+        self.last_can_push_down = can_push_down;
+        self.last_error = validate(value)
+            .and_then(|()| ServerSelectors::validate(value, can_push_down))
+            .err()
+            .as_ref()
+            .map(ParserError::index);
 
         self.last_error
     }
@@ -1,4 +1,5 @@
 use b4n_config::keys::KeyCombination;
+use b4n_kube::ResourceRefFilter;
 use b4n_tui::{Responsive, TuiEvent};
 use crossterm::event::KeyCode;
 use std::{cell::RefCell, rc::Rc};
@@ -42,3 +43,24 @@ fn enter_stores_value_test() {
 
     assert_eq!("test", filter.value());
 }
+
+#[test]
+fn field_queries_validation_test() {
+    let data = Rc::new(RefCell::new(AppData::default()));
+    let mut filter = Filter::new(Rc::clone(&data), None, 65);
+
+    filter.show();
+    for ch in "f:x=y | a".chars() {
+        filter.process_event(&TuiEvent::Key(KeyCombination::from(ch)));
+    }
+
+    assert!(!filter.is_valid());
+
+    filter.set_value("f:x=y & a".to_owned());
+    filter.process_event(&TuiEvent::Key(KeyCombination::from(' ')));
+    assert!(filter.is_valid());
+
+    data.borrow_mut().current.resource.filter = Some(ResourceRefFilter::node("pods".to_owned(), "node-1"));
+    filter.process_event(&TuiEvent::Key(KeyCombination::from(' ')));
+    assert!(!filter.is_valid());
+}
@@ -1,5 +1,6 @@
 use b4n_config::keys::KeyCommand;
 use b4n_config::themes::SelectColors;
+use b4n_kube::NAMESPACES_SEPARATOR;
 use b4n_tui::ResponseEvent;
 use b4n_tui::widgets::{ErrorHighlightMode, InputValidator, ValidatorKind};
 use std::rc::Rc;
@@ -35,7 +36,7 @@ impl NamespaceBehaviour {
         Self {
             app_data,
             discovered: Vec::new(),
-            validator: InputValidator::new(ValidatorKind::DnsLabel),
+            validator: InputValidator::new(ValidatorKind::Namespaces),
         }
     }
 
@@ -54,6 +55,10 @@ impl PickerBehaviour for NamespaceBehaviour {
         self.app_data.borrow().theme.colors.command_palette.clone()
     }
 
+    fn filter_delimiters(&self) -> Vec<char> {
+        vec![NAMESPACES_SEPARATOR]
+    }
+
     fn reset_key_command(&self) -> KeyCommand {
         KeyCommand::CommandPaletteReset
     }
@@ -114,13 +119,15 @@ impl PickerBehaviour for NamespaceBehaviour {
         true
     }
 
-    fn navigate_into(&mut self, _prefix: &str, value: &str, highlighted: Option<&str>) -> ResponseEvent {
+    fn navigate_into(&mut self, prefix: &str, value: &str, highlighted: Option<&str>) -> ResponseEvent {
         if value.is_empty()
             && let Some(highlighted) = highlighted
         {
-            ResponseEvent::ChangeNamespace(highlighted.to_owned())
+            ResponseEvent::ChangeNamespace(format!("{prefix}{highlighted}"))
         } else if !value.is_empty() {
-            ResponseEvent::ChangeNamespace(value.to_owned())
+            ResponseEvent::ChangeNamespace(format!("{prefix}{value}"))
+        } else if !prefix.is_empty() {
+            ResponseEvent::ChangeNamespace(prefix.to_owned())
         } else {
             ResponseEvent::Handled
         }
@@ -24,12 +24,14 @@ pub struct SideSelect<T: Table> {
     is_visible: bool,
     is_hovering: bool,
     app_data: SharedAppData,
+    name: String,
     header: String,
     header_hover: String,
     position: Position,
     result: Option<fn(String) -> ResponseEvent>,
     width: u16,
     item_to_highlight: &'static str,
+    separator: Option<char>,
     is_key_pressed: bool,
     showup_time: Instant,
 }
@@ -44,12 +46,14 @@ impl<T: Table> SideSelect<T> {
             is_visible: false,
             is_hovering: false,
             app_data,
+            name: String::new(),
             header: " SELECT ".to_owned(),
             header_hover: String::new(),
             position,
             result: None,
             width: std::cmp::max(width, 5),
             item_to_highlight: "",
+            separator: None,
             is_key_pressed: false,
             showup_time: Instant::now(),
         }
@@ -57,6 +61,7 @@ impl<T: Table> SideSelect<T> {
 
     /// Sets new name for the side select.
     pub fn with_name(mut self, name: &str, hover: &str) -> Self {
+        self.name = name.to_owned();
         self.header = format!(" SELECT {name}: ");
         self.header_hover = add_new_lines(hover);
         self
@@ -74,8 +79,19 @@ impl<T: Table> SideSelect<T> {
         self
     }
 
+    /// Allows selecting multiple items, their names are joined with `separator` for the result.
+    pub fn with_multiple_selection(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
     /// Marks [`SideSelect`] as visible, after that it can be drawn on the terminal frame.
     pub fn show(&mut self) {
+        if self.separator.is_some() {
+            self.select.items.deselect_all();
+            self.update_header();
+        }
+
         self.is_key_pressed = false;
         self.is_hovering = false;
         self.is_visible = true;
@@ -135,6 +151,33 @@ impl<T: Table> SideSelect<T> {
         }
     }
 
+    fn update_header(&mut self) {
+        let selected = self.select.items.get_selected_items().values().map(Vec::len).sum::<usize>();
+        self.header = if selected > 0 {
+            format!(" SELECTED {}: {selected} ", self.name)
+        } else {
+            format!(" SELECT {}: ", self.name)
+        };
+    }
+
+    fn get_result_value(&self) -> Option<String> {
+        if let Some(separator) = self.separator
+            && self.select.items.is_anything_selected()
+        {
+            let mut selected = self
+                .select
+                .items
+                .get_selected_items()
+                .into_values()
+                .flatten()
+                .collect::<Vec<_>>();
+            selected.sort_unstable();
+            return Some(selected.join(&separator.to_string()));
+        }
+
+        self.select.items.get_highlighted_item_name().map(String::from)
+    }
+
     fn draw_visible(&mut self, frame: &mut ratatui::Frame<'_>, area: Rect) {
         let area = self.get_positioned_area(area, self.width);
         let block = self.get_positioned_block(false);
@@ -263,12 +306,18 @@ impl<T: Table> Responsive for SideSelect<T> {
             navigate_into = true;
         }
 
+        if !navigate_into && self.separator.is_some() && self.app_data.has_binding(event, KeyCommand::NavigateSelect) {
+            self.select.items.select_highlighted_item();
+            self.update_header();
+            return ResponseEvent::Handled;
+        }
+
         if navigate_into || self.app_data.has_binding(event, KeyCommand::NavigateInto) {
             self.is_visible = false;
             if let Some(result_fn) = self.result
-                && let Some(selected_name) = self.select.items.get_highlighted_item_name()
+                && let Some(value) = self.get_result_value()
             {
-                return result_fn(selected_name.to_owned());
+                return result_fn(value);
             }
 
             return ResponseEvent::Handled;
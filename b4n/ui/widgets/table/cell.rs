@@ -11,6 +11,7 @@ pub struct Cell {
     sort_text: Option<String>,
     time: Option<Timestamp>,
     is_time: bool,
+    is_time_left: bool,
 }
 
 impl Cell {
@@ -53,7 +54,11 @@ impl Cell {
 
     /// Creates new [`Cell`] instance as a time value.
     pub fn time(value: Value) -> Self {
-        let time = from_value::<Time>(value).ok().map(|t| t.0);
+        Self::timestamp(from_value::<Time>(value).ok().map(|t| t.0))
+    }
+
+    /// Creates new [`Cell`] instance from the optional timestamp.
+    pub fn timestamp(time: Option<Timestamp>) -> Self {
         let sort = time.as_ref().map(|t| t.as_millisecond().to_string());
         Self {
             time,
@@ -63,6 +68,14 @@ impl Cell {
         }
     }
 
+    /// Creates new [`Cell`] instance from the optional timestamp that is displayed as the time left to it.
+    pub fn time_left(time: Option<Timestamp>) -> Self {
+        Self {
+            is_time_left: true,
+            ..Self::timestamp(time)
+        }
+    }
+
     /// Sets cell raw text.
     pub fn set_raw_text(&mut self, text: String) {
         self.text = Some(text);
@@ -86,7 +99,12 @@ impl Cell {
     /// Returns cell text.
     pub fn text(&self) -> Cow<'_, str> {
         if self.is_time {
-            Cow::Owned(self.time.as_ref().map_or("n/a".to_owned(), b4n_kube::utils::format_datetime))
+            let format = if self.is_time_left {
+                b4n_kube::utils::format_time_left
+            } else {
+                b4n_kube::utils::format_datetime
+            };
+            Cow::Owned(self.time.as_ref().map_or("n/a".to_owned(), format))
         } else {
             Cow::Borrowed(self.text.as_deref().unwrap_or("n/a"))
         }
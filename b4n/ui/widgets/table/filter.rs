@@ -0,0 +1,71 @@
+use b4n_common::expr::{EvaluationSource, SelectiveMap};
+use b4n_list::Row;
+use k8s_openapi::jiff::Timestamp;
+use regex::Regex;
+use std::borrow::Cow;
+
+/// Name of the column that is evaluated using the row creation timestamp.
+const AGE_COLUMN: &str = "age";
+
+/// [`EvaluationSource`] for the table row that resolves column predicates using the header `columns` names.\
+/// **Note** that the age column is always evaluated as a number of seconds since the row creation.
+pub struct RowFilterSource<'a, R: Row> {
+    row: &'a R,
+    metadata: &'a SelectiveMap,
+    columns: &'a [String],
+    server_key: Option<&'static str>,
+}
+
+impl<'a, R: Row> RowFilterSource<'a, R> {
+    /// Creates new [`RowFilterSource`] instance.
+    pub fn new(row: &'a R, metadata: &'a SelectiveMap, columns: &'a [String]) -> Self {
+        Self {
+            row,
+            metadata,
+            columns,
+            server_key: None,
+        }
+    }
+
+    /// Sets the metadata `key` whose queries were already evaluated by the API server, so they always match.
+    pub fn with_server_key(mut self, key: Option<&'static str>) -> Self {
+        self.server_key = key;
+        self
+    }
+}
+
+impl<R: Row> EvaluationSource for RowFilterSource<'_, R> {
+    fn contains_in_key(&self, key: &str, value: &str) -> bool {
+        self.server_key == Some(key) || self.metadata.contains_in_key(key, value)
+    }
+
+    fn equals_in_key(&self, key: &str, value: &str) -> bool {
+        self.server_key == Some(key) || self.metadata.equals_in_key(key, value)
+    }
+
+    fn contains_in_any(&self, value: &str) -> bool {
+        self.metadata.contains_in_any(value)
+    }
+
+    fn is_match_in_key(&self, key: &str, regex: &Regex) -> bool {
+        self.server_key == Some(key) || self.metadata.is_match_in_key(key, regex)
+    }
+
+    fn is_match_in_any(&self, regex: &Regex) -> bool {
+        self.metadata.is_match_in_any(regex)
+    }
+
+    fn column_text(&self, name: &str) -> Option<Cow<'_, str>> {
+        if name == AGE_COLUMN {
+            return self.row.creation_timestamp().map(|time| {
+                let seconds = Timestamp::now().duration_since(*time).as_secs().max(0);
+                Cow::Owned(format!("{seconds}s"))
+            });
+        }
+
+        self.columns
+            .iter()
+            .position(|column| column.eq_ignore_ascii_case(name))
+            .map(|index| self.row.column_text(index))
+    }
+}
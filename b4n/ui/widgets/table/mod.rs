@@ -1,7 +1,9 @@
 pub use self::basic::BasicTable;
 pub use self::cell::Cell;
+pub use self::filter::RowFilterSource;
 pub use self::row::BasicRow;
 
 mod basic;
 mod cell;
+mod filter;
 mod row;